@@ -5,22 +5,26 @@
 use crate::{
     accept_type::AcceptType,
     response::{
-        bcs_api_disabled, block_not_found_by_height, block_not_found_by_version,
+        api_disabled, bcs_api_disabled, block_not_found_by_height, block_not_found_by_version,
         block_pruned_by_height, json_api_disabled, version_not_found, version_pruned,
-        ForbiddenError, InternalError, NotFoundError, ServiceUnavailableError, StdApiError,
+        BadRequestError, ForbiddenError, GoneError, InternalError, NotFoundError,
+        ServiceUnavailableError, StdApiError,
     },
 };
 use anyhow::{bail, ensure, format_err, Context as AnyhowContext, Result};
 use aptos_api_types::{
     AptosErrorCode, AsConverter, BcsBlock, GasEstimation, LedgerInfo, ResourceGroup,
-    TransactionOnChainData,
+    StateOverrides, TransactionOnChainData,
 };
 use aptos_config::config::{NodeConfig, RoleType};
 use aptos_crypto::HashValue;
 use aptos_gas_schedule::{AptosGasParameters, FromOnChainGasSchedule};
 use aptos_logger::error;
-use aptos_mempool::{MempoolClientRequest, MempoolClientSender, SubmissionStatus};
-use aptos_state_view::TStateView;
+use aptos_mempool::{
+    MempoolClientRequest, MempoolClientSender, PendingTransactionInfo, PendingTransactionsCursor,
+    PendingTransactionsQuery, SubmissionStatus,
+};
+use aptos_state_view::{overlay_state_view::OverlayStateView, StateView, TStateView};
 use aptos_storage_interface::{
     state_view::{DbStateView, DbStateViewAtVersion, LatestDbStateCheckpointView},
     DbReader, Order, MAX_REQUEST_LIMIT,
@@ -48,13 +52,17 @@ use aptos_vm::{
     move_vm_ext::MoveResolverExt,
 };
 use futures::{channel::oneshot, SinkExt};
-use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
 use std::{
     collections::{BTreeMap, HashMap},
     ops::{Bound::Included, Deref},
-    sync::{Arc, RwLock, RwLockWriteGuard},
-    time::Instant,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, RwLock, RwLockWriteGuard,
+    },
+    time::{Duration, Instant},
 };
+use tokio::sync::watch;
 
 // Context holds application scope context
 #[derive(Clone)]
@@ -66,6 +74,9 @@ pub struct Context {
     gas_schedule_cache: Arc<RwLock<GasScheduleCache>>,
     gas_estimation_cache: Arc<RwLock<GasEstimationCache>>,
     gas_limit_cache: Arc<RwLock<GasLimitCache>>,
+    commit_notifications: Option<watch::Receiver<Version>>,
+    wait_by_hash_active_connections: Arc<AtomicUsize>,
+    stream_active_connections: Arc<AtomicUsize>,
 }
 
 impl std::fmt::Debug for Context {
@@ -100,9 +111,66 @@ impl Context {
                 last_updated_epoch: None,
                 block_gas_limit: None,
             })),
+            commit_notifications: None,
+            wait_by_hash_active_connections: Arc::new(AtomicUsize::new(0)),
+            stream_active_connections: Arc::new(AtomicUsize::new(0)),
         }
     }
 
+    /// Sets the receiver of the highest committed version, which lets requests
+    /// wait for new commits instead of polling storage
+    pub fn with_commit_notifications(
+        mut self,
+        commit_notifications: watch::Receiver<Version>,
+    ) -> Self {
+        self.commit_notifications = Some(commit_notifications);
+        self
+    }
+
+    /// Waits until a version newer than `known_version` is committed, or until
+    /// `timeout` passes. Callers must check storage again afterwards.
+    ///
+    /// Without commit notifications, this waits at most `poll_interval` instead.
+    pub async fn wait_for_commit(
+        &self,
+        known_version: Version,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) {
+        let mut receiver = match self.commit_notifications.clone() {
+            Some(receiver) => receiver,
+            None => return tokio::time::sleep(std::cmp::min(timeout, poll_interval)).await,
+        };
+        let wait = async move {
+            while *receiver.borrow_and_update() <= known_version {
+                if receiver.changed().await.is_err() {
+                    // The notifier is gone, so fall back to polling
+                    tokio::time::sleep(poll_interval).await;
+                    return;
+                }
+            }
+        };
+        let _ = tokio::time::timeout(timeout, wait).await;
+    }
+
+    /// Reserves a slot for a wait by hash request, returning None if the maximum
+    /// number of waiting requests has been reached
+    pub fn try_acquire_wait_by_hash_slot(&self) -> Option<ConnectionSlot> {
+        ConnectionSlot::try_acquire(
+            &self.wait_by_hash_active_connections,
+            self.node_config.api.wait_by_hash_max_active_connections,
+        )
+    }
+
+    /// Reserves a slot for a stream, returning None if the maximum number of
+    /// open streams has been reached
+    pub fn try_acquire_stream_slot(&self) -> Option<ConnectionSlot> {
+        ConnectionSlot::try_acquire(
+            &self.stream_active_connections,
+            self.node_config.api.stream_max_active_connections,
+        )
+    }
+
     pub fn max_transactions_page_size(&self) -> u16 {
         self.node_config.api.max_transactions_page_size
     }
@@ -153,6 +221,33 @@ impl Context {
         self.db.state_view_at_version(Some(version))
     }
 
+    /// Layers the state overrides of a request, if any, over `state_view`.
+    pub fn override_state_view<'a, S: StateView, E: BadRequestError + ForbiddenError>(
+        &self,
+        state_view: &'a S,
+        state_overrides: Option<StateOverrides>,
+        ledger_info: &LedgerInfo,
+    ) -> Result<OverlayStateView<'a, S>, E> {
+        let mut overlay_state_view = OverlayStateView::new(state_view);
+        if let Some(state_overrides) = state_overrides {
+            if !self.node_config.api.state_overrides_enabled {
+                return Err(api_disabled("State overrides"));
+            }
+            let state_values = state_view
+                .as_move_resolver()
+                .as_converter(self.db.clone())
+                .try_into_state_values(state_overrides)
+                .context("Invalid state overrides")
+                .map_err(|err| {
+                    E::bad_request_with_code(err, AptosErrorCode::InvalidInput, ledger_info)
+                })?;
+            for (state_key, state_value) in state_values {
+                overlay_state_view.set_state_value(state_key, state_value);
+            }
+        }
+        Ok(overlay_state_view)
+    }
+
     pub fn chain_id(&self) -> ChainId {
         self.chain_id
     }
@@ -233,7 +328,19 @@ impl Context {
         requested_ledger_version: Option<Version>,
     ) -> Result<(LedgerInfo, Version), E> {
         let latest_ledger_info = self.get_latest_ledger_info()?;
+        let requested_ledger_version =
+            self.verify_lookup_version(&latest_ledger_info, requested_ledger_version)?;
+
+        Ok((latest_ledger_info, requested_ledger_version))
+    }
 
+    /// Checks that the state at the requested version is available, defaulting to the latest
+    /// version of `latest_ledger_info`.
+    pub fn verify_lookup_version<E: NotFoundError + GoneError>(
+        &self,
+        latest_ledger_info: &LedgerInfo,
+        requested_ledger_version: Option<Version>,
+    ) -> Result<Version, E> {
         let requested_ledger_version =
             requested_ledger_version.unwrap_or_else(|| latest_ledger_info.version());
 
@@ -241,16 +348,13 @@ impl Context {
         if requested_ledger_version > latest_ledger_info.version() {
             return Err(version_not_found(
                 requested_ledger_version,
-                &latest_ledger_info,
+                latest_ledger_info,
             ));
         } else if requested_ledger_version < latest_ledger_info.oldest_ledger_version.0 {
-            return Err(version_pruned(
-                requested_ledger_version,
-                &latest_ledger_info,
-            ));
+            return Err(version_pruned(requested_ledger_version, latest_ledger_info));
         }
 
-        Ok((latest_ledger_info, requested_ledger_version))
+        Ok(requested_ledger_version)
     }
 
     pub fn get_latest_ledger_info_with_signatures(&self) -> Result<LedgerInfoWithSignatures> {
@@ -751,6 +855,27 @@ impl Context {
         callback.await.map_err(anyhow::Error::from)
     }
 
+    pub async fn get_pending_transactions(
+        &self,
+        query: PendingTransactionsQuery,
+        limit: usize,
+    ) -> Result<(
+        Vec<PendingTransactionInfo>,
+        Option<PendingTransactionsCursor>,
+    )> {
+        let (req_sender, callback) = oneshot::channel();
+
+        self.mp_sender
+            .clone()
+            .send(MempoolClientRequest::GetPendingTransactions(
+                query, limit, req_sender,
+            ))
+            .await
+            .map_err(anyhow::Error::from)?;
+
+        callback.await.map_err(anyhow::Error::from)
+    }
+
     pub fn get_transaction_by_version(
         &self,
         version: u64,
@@ -811,6 +936,23 @@ impl Context {
         }
     }
 
+    pub fn get_events_by_type(
+        &self,
+        event_type: &TypeTag,
+        start_version: Version,
+        end_version: Version,
+        limit: u16,
+        ledger_version: u64,
+    ) -> Result<Vec<EventWithVersion>> {
+        self.db.get_events_by_type(
+            event_type,
+            start_version,
+            end_version,
+            limit as u64,
+            ledger_version,
+        )
+    }
+
     fn next_bucket(&self, gas_unit_price: u64) -> u64 {
         match self
             .node_config
@@ -1263,3 +1405,30 @@ pub struct GasLimitCache {
     last_updated_epoch: Option<u64>,
     block_gas_limit: Option<u64>,
 }
+
+/// A reserved slot of a connection limited API, released when dropped
+pub struct ConnectionSlot {
+    active_connections: Arc<AtomicUsize>,
+}
+
+impl ConnectionSlot {
+    fn try_acquire(
+        active_connections: &Arc<AtomicUsize>,
+        max_active_connections: usize,
+    ) -> Option<Self> {
+        active_connections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+                (active < max_active_connections).then_some(active + 1)
+            })
+            .ok()
+            .map(|_| Self {
+                active_connections: active_connections.clone(),
+            })
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.active_connections.fetch_sub(1, Ordering::SeqCst);
+    }
+}
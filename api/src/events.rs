@@ -9,18 +9,21 @@ use crate::{
     failpoint::fail_point_poem,
     page::Page,
     response::{
-        BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404,
-        InternalError,
+        version_pruned, BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus,
+        BasicResultWith404, ForbiddenError, InternalError,
     },
+    stream::{stream_committed, ApiEventStream, StreamFilter, StreamKind},
     ApiTags,
 };
 use anyhow::Context as AnyhowContext;
 use aptos_api_types::{
-    verify_field_identifier, Address, AptosErrorCode, AsConverter, IdentifierWrapper, LedgerInfo,
-    MoveStructTag, VerifyInputWithRecursion, VersionedEvent, U64,
+    verify_field_identifier, Address, AptosErrorCode, AsConverter, EntryFunctionId,
+    IdentifierWrapper, LedgerInfo, MoveStructTag, MoveType, VerifyInputWithRecursion,
+    VersionedEvent, U64,
 };
-use aptos_types::event::EventKey;
+use aptos_types::{contract_event::EventWithVersion, event::EventKey};
 use aptos_vm::data_cache::AsMoveResolver;
+use move_core_types::language_storage::TypeTag;
 use poem_openapi::{
     param::{Path, Query},
     OpenApi,
@@ -141,6 +144,149 @@ impl EventsApi {
         let key = account.find_event_key(event_handle.0, field_name.0.into())?;
         self.list(account.latest_ledger_info, accept_type, page, key)
     }
+
+    /// Get events by type
+    ///
+    /// Returns the events of the given type emitted by transactions within a range of
+    /// versions, in the order they were emitted, regardless of the account or object that
+    /// emitted them. The type must be given with its type arguments, if any, e.g.
+    /// `0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>`.
+    ///
+    /// A page of events ends at the end of a transaction, and may hold slightly more than
+    /// `limit` events because of that. To get the next page, pass the version following the
+    /// version of the last event returned as `start_version`.
+    ///
+    /// This API relies on an optional index, it returns a 403 on nodes where the index is not
+    /// enabled. If the start version has been pruned, then a 410 will be returned.
+    #[oai(
+        path = "/events/by_type/:event_type",
+        method = "get",
+        operation_id = "get_events_by_type",
+        tag = "ApiTags::Events"
+    )]
+    async fn get_events_by_type(
+        &self,
+        accept_type: AcceptType,
+        /// Type of the events, e.g. `0x1::coin::DepositEvent`
+        event_type: Path<MoveType>,
+        /// Ledger version to start looking for events from
+        ///
+        /// If unspecified, defaults to the oldest version that has not been pruned
+        start_version: Query<Option<U64>>,
+        /// Ledger version to stop looking for events at, exclusive
+        ///
+        /// If unspecified, events up to the latest version are returned
+        end_version: Query<Option<U64>>,
+        /// Max number of events to retrieve.
+        ///
+        /// If unspecified, defaults to default page size
+        limit: Query<Option<u16>>,
+    ) -> BasicResultWith404<Vec<VersionedEvent>> {
+        fail_point_poem("endpoint_get_events_by_type")?;
+        self.context
+            .check_api_output_enabled("Get events by type", &accept_type)?;
+        if !self.context.db.event_type_index_enabled() {
+            return Err(BasicErrorWith404::forbidden_with_code_no_info(
+                "Get events by type is not enabled on this node, it requires the event type \
+                index of the internal indexer",
+                AptosErrorCode::ApiDisabled,
+            ));
+        }
+        let event_type = TypeTag::try_from(event_type.0)
+            .context("'event_type' invalid")
+            .map_err(|err| {
+                BasicErrorWith404::bad_request_with_code_no_info(err, AptosErrorCode::InvalidInput)
+            })?;
+
+        let latest_ledger_info = self.context.get_latest_ledger_info()?;
+        let ledger_version = latest_ledger_info.version();
+        let start_version = start_version
+            .0
+            .map_or(latest_ledger_info.oldest_ledger_version.0, |v| v.0);
+        if start_version < latest_ledger_info.oldest_ledger_version.0 {
+            return Err(version_pruned(start_version, &latest_ledger_info));
+        }
+        let end_version = end_version.0.map_or(ledger_version + 1, |v| v.0);
+        let page = Page::new(None, limit.0, self.context.max_events_page_size());
+
+        let events = self
+            .context
+            .get_events_by_type(
+                &event_type,
+                start_version,
+                end_version,
+                page.limit(&latest_ledger_info)?,
+                ledger_version,
+            )
+            .context(format!("Failed to find events of type {}", event_type))
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &latest_ledger_info,
+                )
+            })?;
+        self.render(latest_ledger_info, accept_type, events)
+    }
+
+    /// Stream events
+    ///
+    /// Streams events emitted by on-chain committed transactions as server-sent
+    /// events, following the chain tip as new transactions are committed. Each
+    /// message carries a single event, rendered the same way as the other events
+    /// APIs: as JSON, or as hex encoded BCS if BCS is requested.
+    ///
+    /// The id of each message is `<version>:<event index>`, the ledger version of the
+    /// transaction that emitted the event and the index of the event in it. To resume
+    /// a dropped stream, pass the version of the last received id as the start version
+    /// and its event index plus one as the start event index.
+    ///
+    /// If the start version has been pruned, then a 410 will be returned.
+    #[oai(
+        path = "/events/stream",
+        method = "get",
+        operation_id = "stream_events",
+        tag = "ApiTags::Experimental"
+    )]
+    async fn stream_events(
+        &self,
+        accept_type: AcceptType,
+        /// Ledger version to start streaming events from
+        ///
+        /// If not provided, only events committed after the request are streamed
+        start: Query<Option<U64>>,
+        /// Index of the first event of the start transaction to stream
+        ///
+        /// Requires a start version. If not provided, all its events are streamed
+        start_event_index: Query<Option<U64>>,
+        /// Only stream events emitted by user transactions sent by this account
+        sender: Query<Option<Address>>,
+        /// Only stream events emitted by user transactions calling this entry
+        /// function, e.g. `0x1::aptos_account::transfer`
+        entry_function: Query<Option<EntryFunctionId>>,
+        /// Only stream events of this type, e.g. `0x1::coin::DepositEvent`
+        event_type: Query<Option<MoveType>>,
+        /// Max number of events to stream before closing the stream
+        ///
+        /// If not provided, the stream stays open until the client disconnects
+        limit: Query<Option<U64>>,
+    ) -> poem::Result<ApiEventStream<VersionedEvent>, BasicErrorWith404> {
+        fail_point_poem("endpoint_stream_events")?;
+        self.context
+            .check_api_output_enabled("Stream events", &accept_type)?;
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let filter = StreamFilter::new(sender.0, entry_function.0, event_type.0, &ledger_info)?;
+        stream_committed(
+            self.context.clone(),
+            StreamKind::Events,
+            accept_type,
+            start.0.map(|v| v.0),
+            start_event_index.0.map(|v| v.0),
+            limit.0.map(|v| v.0),
+            filter,
+            &ledger_info,
+        )
+    }
 }
 
 impl EventsApi {
@@ -169,7 +315,15 @@ impl EventsApi {
                     &latest_ledger_info,
                 )
             })?;
+        self.render(latest_ledger_info, accept_type, events)
+    }
 
+    fn render(
+        &self,
+        latest_ledger_info: LedgerInfo,
+        accept_type: AcceptType,
+        events: Vec<EventWithVersion>,
+    ) -> BasicResultWith404<Vec<VersionedEvent>> {
         match accept_type {
             AcceptType::Json => {
                 let events = self
@@ -42,6 +42,7 @@ impl IndexApi {
                     ledger_info.clone(),
                     node_role,
                     Some(aptos_build_info::get_git_hash()),
+                    self.context.node_config.api.wait_by_hash_enabled,
                 );
                 BasicResponse::try_from_json((
                     index_response,
@@ -16,12 +16,14 @@ mod events;
 mod failpoint;
 mod index;
 mod log;
+mod mempool;
 pub mod metrics;
 mod page;
 mod response;
 mod runtime;
 mod set_failpoints;
 mod state;
+mod stream;
 #[cfg(test)]
 pub mod tests;
 mod transactions;
@@ -0,0 +1,225 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    accept_type::AcceptType,
+    context::Context,
+    failpoint::fail_point_poem,
+    page::Page,
+    response::{
+        api_disabled, BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus,
+        BasicResultWith404, InternalError,
+    },
+    ApiTags,
+};
+use anyhow::Context as AnyhowContext;
+use aptos_api_types::{
+    Address, AptosErrorCode, AsConverter, HexEncodedBytes, LedgerInfo, MempoolTransaction,
+};
+use aptos_mempool::{PendingTransactionInfo, PendingTransactionsCursor, PendingTransactionsQuery};
+use aptos_vm::data_cache::AsMoveResolver;
+use poem_openapi::{
+    param::{Path, Query},
+    OpenApi,
+};
+use std::{sync::Arc, time::UNIX_EPOCH};
+
+/// API for inspecting the transactions waiting in the node's mempool
+pub struct MempoolApi {
+    pub context: Arc<Context>,
+}
+
+#[OpenApi]
+impl MempoolApi {
+    /// Get mempool transactions
+    ///
+    /// Retrieve the ready transactions currently waiting in this node's mempool, in the
+    /// order they are pulled into blocks. Each transaction comes with its ranking score,
+    /// insertion time and broadcast bucket. Parked transactions, which wait for a missing
+    /// sequence number, are only listed by the account mempool transactions API.
+    ///
+    /// The mempool of each node is different, so the result only reflects the node that
+    /// served the request. This API is disabled unless the node operator enables it, and
+    /// only JSON output is supported.
+    #[oai(
+        path = "/mempool/transactions",
+        method = "get",
+        operation_id = "get_mempool_transactions",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn get_mempool_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Cursor specifying where to start for pagination
+        ///
+        /// This cursor cannot be derived manually client-side. Instead, you must
+        /// call this endpoint once without this query parameter specified, and
+        /// then use the cursor returned in the X-Aptos-Cursor header in the
+        /// response.
+        start: Query<Option<HexEncodedBytes>>,
+        /// Max number of transactions to retrieve.
+        ///
+        /// If not provided, defaults to default page size
+        limit: Query<Option<u16>>,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        fail_point_poem("endpoint_get_mempool_transactions")?;
+        self.context
+            .check_api_output_enabled("Get mempool transactions", &accept_type)?;
+        if !self.context.node_config.api.mempool_transactions_enabled {
+            return Err(api_disabled("Get mempool transactions"));
+        }
+        self.list(
+            &accept_type,
+            PendingTransactionsQuery::Ready(parse_cursor(start.0)?),
+            limit.0,
+        )
+        .await
+    }
+
+    /// Get account mempool transactions
+    ///
+    /// Retrieve the transactions sent by the given account that are currently waiting in
+    /// this node's mempool, ordered by sequence number. This includes parked transactions,
+    /// which can't be included in a block until the transactions with lower sequence numbers
+    /// arrive. If more transactions are left, the cursor of the next page is returned in the
+    /// X-Aptos-Cursor header.
+    ///
+    /// This API is disabled unless the node operator enables it, and only JSON output is
+    /// supported.
+    #[oai(
+        path = "/mempool/accounts/:address",
+        method = "get",
+        operation_id = "get_mempool_account_transactions",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn get_mempool_account_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Address of account with or without a `0x` prefix
+        address: Path<Address>,
+        /// Cursor specifying where to start for pagination
+        ///
+        /// This cursor cannot be derived manually client-side. Instead, you must
+        /// call this endpoint once without this query parameter specified, and
+        /// then use the cursor returned in the X-Aptos-Cursor header in the
+        /// response.
+        start: Query<Option<HexEncodedBytes>>,
+        /// Max number of transactions to retrieve.
+        ///
+        /// If not provided, defaults to default page size
+        limit: Query<Option<u16>>,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        fail_point_poem("endpoint_get_mempool_account_transactions")?;
+        self.context
+            .check_api_output_enabled("Get account mempool transactions", &accept_type)?;
+        if !self.context.node_config.api.mempool_transactions_enabled {
+            return Err(api_disabled("Get account mempool transactions"));
+        }
+        self.list(
+            &accept_type,
+            PendingTransactionsQuery::Account(address.0.into(), parse_cursor(start.0)?),
+            limit.0,
+        )
+        .await
+    }
+}
+
+impl MempoolApi {
+    async fn list(
+        &self,
+        accept_type: &AcceptType,
+        query: PendingTransactionsQuery,
+        limit: Option<u16>,
+    ) -> BasicResultWith404<Vec<MempoolTransaction>> {
+        // Mempool metadata isn't part of any on-chain type, so there is no BCS representation
+        if accept_type == &AcceptType::Bcs {
+            return Err(BasicErrorWith404::bad_request_with_code_no_info(
+                "BCS is not supported for mempool transactions",
+                AptosErrorCode::BcsNotSupported,
+            ));
+        }
+
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let page = Page::new(None, limit, self.context.max_transactions_page_size());
+        let limit = page.limit(&ledger_info)?;
+
+        let (pending_txns, next_cursor) = self
+            .context
+            .get_pending_transactions(query, limit as usize)
+            .await
+            .context("Failed to get pending transactions from mempool")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+        let next_cursor = next_cursor
+            .map(|cursor| {
+                bcs::to_bytes(&cursor).map(|bytes| HexEncodedBytes::from(bytes).to_string())
+            })
+            .transpose()
+            .context("Failed to serialize next cursor")
+            .map_err(|err| {
+                BasicErrorWith404::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+
+        let transactions = self.render(&ledger_info, pending_txns)?;
+        BasicResponse::try_from_json((transactions, &ledger_info, BasicResponseStatus::Ok))
+            .map(|response| response.with_encoded_cursor(next_cursor))
+    }
+
+    fn render(
+        &self,
+        ledger_info: &LedgerInfo,
+        pending_txns: Vec<PendingTransactionInfo>,
+    ) -> Result<Vec<MempoolTransaction>, BasicErrorWith404> {
+        let state_view = self.context.latest_state_view_poem(ledger_info)?;
+        let resolver = state_view.as_move_resolver();
+        let converter = resolver.as_converter(self.context.db.clone());
+        pending_txns
+            .into_iter()
+            .map(|info| {
+                let transaction = converter
+                    .try_into_pending_transaction_poem(info.transaction)
+                    .context("Failed to convert pending transaction")
+                    .map_err(|err| {
+                        BasicErrorWith404::internal_with_code(
+                            err,
+                            AptosErrorCode::InternalError,
+                            ledger_info,
+                        )
+                    })?;
+                let insertion_timestamp_usecs = info
+                    .insertion_time
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_micros() as u64)
+                    .unwrap_or_default();
+                Ok(MempoolTransaction {
+                    transaction,
+                    ranking_score: info.ranking_score.into(),
+                    insertion_timestamp_usecs: insertion_timestamp_usecs.into(),
+                    bucket: info.bucket,
+                    parked: info.parked,
+                })
+            })
+            .collect()
+    }
+}
+
+fn parse_cursor(
+    start: Option<HexEncodedBytes>,
+) -> Result<Option<PendingTransactionsCursor>, BasicErrorWith404> {
+    start
+        .map(|cursor| bcs::from_bytes(cursor.inner()))
+        .transpose()
+        .context("Failed to parse given start cursor")
+        .map_err(|err| {
+            BasicErrorWith404::bad_request_with_code_no_info(err, AptosErrorCode::InvalidInput)
+        })
+}
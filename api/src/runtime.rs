@@ -5,8 +5,8 @@
 use crate::{
     accounts::AccountsApi, basic::BasicApi, blocks::BlocksApi, check_size::PostSizeLimit,
     context::Context, error_converter::convert_error, events::EventsApi, index::IndexApi,
-    log::middleware_log, set_failpoints, state::StateApi, transactions::TransactionsApi,
-    view_function::ViewFunctionApi,
+    log::middleware_log, mempool::MempoolApi, set_failpoints, state::StateApi,
+    transactions::TransactionsApi, view_function::ViewFunctionApi,
 };
 use anyhow::Context as AnyhowContext;
 use aptos_api_types::X_APTOS_CLIENT;
@@ -14,7 +14,7 @@ use aptos_config::config::{ApiConfig, NodeConfig};
 use aptos_logger::info;
 use aptos_mempool::MempoolClientSender;
 use aptos_storage_interface::DbReader;
-use aptos_types::chain_id::ChainId;
+use aptos_types::{chain_id::ChainId, transaction::Version};
 use poem::{
     http::{header, Method},
     listener::{Listener, RustlsCertificate, RustlsConfig, TcpListener},
@@ -23,21 +23,29 @@ use poem::{
 };
 use poem_openapi::{ContactObject, LicenseObject, OpenApiService};
 use std::{net::SocketAddr, sync::Arc};
-use tokio::runtime::{Handle, Runtime};
+use tokio::{
+    runtime::{Handle, Runtime},
+    sync::watch,
+};
 
 const VERSION: &str = include_str!("../doc/.version");
 
 /// Create a runtime and attach the Poem webserver to it.
+///
+/// `commit_notifications` receives the highest committed version every time
+/// new transactions are committed to storage.
 pub fn bootstrap(
     config: &NodeConfig,
     chain_id: ChainId,
     db: Arc<dyn DbReader>,
     mp_sender: MempoolClientSender,
+    commit_notifications: watch::Receiver<Version>,
 ) -> anyhow::Result<Runtime> {
     let max_runtime_workers = get_max_runtime_workers(&config.api);
     let runtime = aptos_runtimes::spawn_named_runtime("api".into(), Some(max_runtime_workers));
 
-    let context = Context::new(chain_id, db, mp_sender, config.clone());
+    let context = Context::new(chain_id, db, mp_sender, config.clone())
+        .with_commit_notifications(commit_notifications);
 
     attach_poem_to_runtime(runtime.handle(), context, config, false)
         .context("Failed to attach poem to runtime")?;
@@ -61,6 +69,7 @@ pub fn get_api_service(
         BlocksApi,
         EventsApi,
         IndexApi,
+        MempoolApi,
         StateApi,
         TransactionsApi,
         ViewFunctionApi,
@@ -84,6 +93,9 @@ pub fn get_api_service(
         IndexApi {
             context: context.clone(),
         },
+        MempoolApi {
+            context: context.clone(),
+        },
         StateApi {
             context: context.clone(),
         },
@@ -224,6 +236,7 @@ mod tests {
     use aptos_config::config::{ApiConfig, NodeConfig};
     use aptos_types::chain_id::ChainId;
     use std::time::Duration;
+    use tokio::sync::watch;
 
     // TODO: Unignore this when I figure out why this only works when being
     // run alone (it fails when run with other tests).
@@ -283,6 +296,7 @@ mod tests {
             ChainId::test(),
             context.db.clone(),
             context.mempool.ac_client.clone(),
+            watch::channel(0).1,
         );
         assert!(ret.is_ok());
 
@@ -0,0 +1,502 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Server-sent event streams of committed transactions and events.
+//!
+//! A stream follows the chain tip by waiting for commit notifications (or
+//! periodically checking the latest ledger version in storage, if there are
+//! none) and reading any newly committed transactions in pages of at most
+//! `max_transactions_page_size`. Transactions that pass the
+//! [`StreamFilter`] are rendered exactly as the paginated endpoints render
+//! them: JSON by default, or hex encoded BCS if BCS was requested.
+
+use crate::{
+    accept_type::AcceptType,
+    context::{ConnectionSlot, Context},
+    response::{
+        api_disabled, version_pruned, BadRequestError, ForbiddenError, GoneError,
+        ServiceUnavailableError,
+    },
+};
+use anyhow::Context as AnyhowContext;
+use aptos_api_types::{
+    Address, AptosError, AptosErrorCode, AsConverter, EntryFunctionId, HexEncodedBytes, LedgerInfo,
+    MoveType, TransactionOnChainData,
+};
+use aptos_types::{
+    account_address::AccountAddress,
+    contract_event::{ContractEvent, EventWithVersion},
+    transaction::{MultisigTransactionPayload, Transaction, TransactionPayload, Version},
+};
+use aptos_vm::data_cache::AsMoveResolver;
+use bytes::Bytes;
+use futures::stream::{self, BoxStream, StreamExt};
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+};
+use poem::{web::sse::Event, Body, IntoResponse, Response};
+use poem_openapi::{
+    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef, Registry},
+    types::Type,
+    ApiResponse,
+};
+use std::{
+    collections::VecDeque,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+const EVENT_STREAM_CONTENT_TYPE: &str = "text/event-stream";
+/// An SSE comment, which clients ignore, sent to keep idle connections open
+const KEEP_ALIVE_MESSAGE: &[u8] = b":\n\n";
+
+/// SSE event type of messages carrying a transaction
+const TRANSACTION_EVENT_TYPE: &str = "transaction";
+/// SSE event type of messages carrying an event
+const EVENT_EVENT_TYPE: &str = "event";
+/// SSE event type of the final message of a stream that failed
+const ERROR_EVENT_TYPE: &str = "error";
+
+/// A `text/event-stream` response where each message carries a single `T`
+///
+/// The OpenAPI spec describes the stream as an array of `T`, which is the same
+/// convention poem uses for its own event stream payloads. Unlike poem's `SSE`,
+/// the response body ends as soon as the underlying stream does, which is what
+/// allows bounded streams to complete.
+pub struct ApiEventStream<T> {
+    body: BoxStream<'static, Bytes>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Type> ApiResponse for ApiEventStream<T> {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "",
+                status: Some(200),
+                content: vec![MetaMediaType {
+                    content_type: EVENT_STREAM_CONTENT_TYPE,
+                    schema: MetaSchemaRef::Inline(Box::new(MetaSchema {
+                        items: Some(Box::new(T::schema_ref())),
+                        ..MetaSchema::new_with_format("array", "event-stream")
+                    })),
+                }],
+                headers: vec![],
+            }],
+        }
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+}
+
+impl<T> IntoResponse for ApiEventStream<T> {
+    fn into_response(self) -> Response {
+        Response::builder()
+            .content_type(EVENT_STREAM_CONTENT_TYPE)
+            .header("X-Accel-Buffering", "no")
+            .header("Cache-Control", "no-cache")
+            .body(Body::from_bytes_stream(
+                self.body.map(Ok::<_, std::io::Error>),
+            ))
+    }
+}
+
+/// What a stream pushes to the client for every matching transaction
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamKind {
+    /// One message per matching transaction
+    Transactions,
+    /// One message per matching event of a matching transaction
+    Events,
+}
+
+/// Filters applied to committed transactions before they are pushed to a stream
+///
+/// All filters that are set must match for a transaction to be included.
+#[derive(Clone, Debug, Default)]
+pub struct StreamFilter {
+    sender: Option<AccountAddress>,
+    entry_function: Option<(ModuleId, Identifier)>,
+    event_type: Option<TypeTag>,
+}
+
+impl StreamFilter {
+    pub fn new<E: BadRequestError>(
+        sender: Option<Address>,
+        entry_function: Option<EntryFunctionId>,
+        event_type: Option<MoveType>,
+        ledger_info: &LedgerInfo,
+    ) -> Result<Self, E> {
+        let event_type = event_type
+            .map(TypeTag::try_from)
+            .transpose()
+            .context("'event_type' invalid")
+            .map_err(|err| {
+                E::bad_request_with_code(err, AptosErrorCode::InvalidInput, ledger_info)
+            })?;
+        Ok(Self {
+            sender: sender.map(AccountAddress::from),
+            entry_function: entry_function
+                .map(|function| (function.module.into(), function.name.into())),
+            event_type,
+        })
+    }
+
+    /// Checks the sender and entry function filters against a transaction
+    fn matches_transaction(&self, txn: &Transaction) -> bool {
+        if self.sender.is_none() && self.entry_function.is_none() {
+            return true;
+        }
+        let user_txn = match txn.try_as_signed_user_txn() {
+            Some(user_txn) => user_txn,
+            None => return false,
+        };
+        if let Some(sender) = &self.sender {
+            if user_txn.sender() != *sender {
+                return false;
+            }
+        }
+        if let Some((module, function)) = &self.entry_function {
+            let entry_function = match user_txn.payload() {
+                TransactionPayload::EntryFunction(entry_function) => Some(entry_function),
+                TransactionPayload::Multisig(multisig) => multisig
+                    .transaction_payload
+                    .as_ref()
+                    .map(|payload| match payload {
+                        MultisigTransactionPayload::EntryFunction(entry_function) => entry_function,
+                    }),
+                TransactionPayload::Script(_) | TransactionPayload::ModuleBundle(_) => None,
+            };
+            match entry_function {
+                Some(entry_function) => {
+                    if entry_function.module() != module
+                        || entry_function.function() != function.as_ident_str()
+                    {
+                        return false;
+                    }
+                },
+                None => return false,
+            }
+        }
+        true
+    }
+
+    /// Checks the event type filter against an event
+    fn matches_event(&self, event: &ContractEvent) -> bool {
+        match &self.event_type {
+            Some(event_type) => event.type_tag() == event_type,
+            None => true,
+        }
+    }
+
+    /// Checks all filters against a committed transaction. With an event type
+    /// filter, the transaction must have emitted at least one matching event.
+    pub fn matches(&self, txn: &TransactionOnChainData) -> bool {
+        self.matches_transaction(&txn.transaction)
+            && (self.event_type.is_none()
+                || txn.events.iter().any(|event| self.matches_event(event)))
+    }
+}
+
+/// Builds a stream of committed data starting at `start`
+///
+/// If `start` isn't given, the stream begins with the first transaction committed
+/// after the request. For event streams, the events of the `start` transaction
+/// before `start_event_index` are skipped. The stream ends after `limit` messages,
+/// if given.
+pub fn stream_committed<T, E>(
+    context: Arc<Context>,
+    kind: StreamKind,
+    accept_type: AcceptType,
+    start: Option<Version>,
+    start_event_index: Option<u64>,
+    limit: Option<u64>,
+    filter: StreamFilter,
+    ledger_info: &LedgerInfo,
+) -> Result<ApiEventStream<T>, E>
+where
+    E: BadRequestError + ForbiddenError + GoneError + ServiceUnavailableError,
+{
+    if !context.node_config.api.stream_enabled {
+        return Err(api_disabled("Stream"));
+    }
+    if limit == Some(0) {
+        return Err(E::bad_request_with_code(
+            "Given limit value (0) must not be zero",
+            AptosErrorCode::InvalidInput,
+            ledger_info,
+        ));
+    }
+    if start_event_index.is_some() && (kind != StreamKind::Events || start.is_none()) {
+        return Err(E::bad_request_with_code(
+            "Given start_event_index requires an event stream with a start version",
+            AptosErrorCode::InvalidInput,
+            ledger_info,
+        ));
+    }
+    let start = start.unwrap_or_else(|| ledger_info.version() + 1);
+    if start < ledger_info.oldest_ledger_version.0 {
+        return Err(version_pruned(start, ledger_info));
+    }
+
+    let slot = context.try_acquire_stream_slot().ok_or_else(|| {
+        E::service_unavailable_with_code(
+            "Too many streams are open, try again later",
+            AptosErrorCode::InternalError,
+            ledger_info,
+        )
+    })?;
+
+    let api_config = &context.node_config.api;
+    let poll_interval = Duration::from_millis(api_config.stream_poll_interval_ms);
+    let keep_alive = Duration::from_secs(api_config.stream_keep_alive_secs);
+    let state = StreamState {
+        reader: Some(PageReader {
+            context: context.clone(),
+            kind,
+            accept_type,
+            filter,
+            next_version: start,
+            resume_at: start_event_index.map(|event_index| (start, event_index)),
+            remaining: limit,
+        }),
+        context,
+        next_version: start,
+        pending: VecDeque::new(),
+        poll_interval,
+        keep_alive,
+        last_sent: Instant::now(),
+        finished: false,
+        _slot: slot,
+    };
+    let body = stream::unfold(state, |mut state| async move {
+        state.next_message().await.map(|message| (message, state))
+    })
+    .boxed();
+
+    Ok(ApiEventStream {
+        body,
+        _marker: PhantomData,
+    })
+}
+
+/// Progress of a single stream
+struct StreamState {
+    context: Arc<Context>,
+    /// Reads the pages, taken while a page is being read off the async workers
+    reader: Option<PageReader>,
+    /// The version of the next transaction to read from storage
+    next_version: Version,
+    /// Rendered messages that haven't been sent yet
+    pending: VecDeque<Event>,
+    poll_interval: Duration,
+    keep_alive: Duration,
+    /// When the last message or keep-alive was sent
+    last_sent: Instant,
+    finished: bool,
+    /// Held for as long as the stream is open
+    _slot: ConnectionSlot,
+}
+
+impl StreamState {
+    /// Returns the next chunk of the response body, or None once the stream is over
+    async fn next_message(&mut self) -> Option<Bytes> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.last_sent = Instant::now();
+                return Some(Bytes::from(event.to_string()));
+            }
+            if self.finished {
+                return None;
+            }
+            match self.read_next_page().await {
+                // More transactions may already be committed, keep reading after
+                // letting other requests run, as a narrow filter may skip many pages
+                Ok(true) => tokio::task::yield_now().await,
+                // We are caught up with storage, wait for new commits
+                Ok(false) => {
+                    let idle = self.last_sent.elapsed();
+                    if idle >= self.keep_alive {
+                        self.last_sent = Instant::now();
+                        return Some(Bytes::from_static(KEEP_ALIVE_MESSAGE));
+                    }
+                    self.context
+                        .wait_for_commit(
+                            self.next_version.saturating_sub(1),
+                            self.keep_alive - idle,
+                            self.poll_interval,
+                        )
+                        .await
+                },
+                Err(err) => {
+                    self.finished = true;
+                    let error = AptosError::new_with_error_code(
+                        format!(
+                            "Failed to stream committed data at version {}: {:#}",
+                            self.next_version, err
+                        ),
+                        AptosErrorCode::InternalError,
+                    );
+                    let data = serde_json::to_string(&error).unwrap_or(error.message);
+                    let event = Event::message(data).event_type(ERROR_EVENT_TYPE);
+                    return Some(Bytes::from(event.to_string()));
+                },
+            }
+        }
+    }
+
+    /// Reads and renders the next page of committed transactions on a blocking
+    /// thread, returning false if there was nothing new in storage
+    async fn read_next_page(&mut self) -> anyhow::Result<bool> {
+        let mut reader = self
+            .reader
+            .take()
+            .expect("The reader is put back after each page");
+        let (reader, result) = tokio::task::spawn_blocking(move || {
+            let result = reader.read_next_page();
+            (reader, result)
+        })
+        .await
+        .context("Failed to read the next page")?;
+
+        self.next_version = reader.next_version;
+        self.finished = reader.remaining == Some(0);
+        self.reader = Some(reader);
+        let messages = result?;
+        let read_any = messages.is_some();
+        self.pending.extend(messages.into_iter().flatten());
+        Ok(read_any)
+    }
+}
+
+/// Reads the committed transactions of a stream from storage and renders them
+struct PageReader {
+    context: Arc<Context>,
+    kind: StreamKind,
+    accept_type: AcceptType,
+    filter: StreamFilter,
+    /// The version of the next transaction to read from storage
+    next_version: Version,
+    /// The version and index of the first event to send from a resumed event stream
+    resume_at: Option<(Version, u64)>,
+    /// The number of messages left to send, if the stream is bounded
+    remaining: Option<u64>,
+}
+
+impl PageReader {
+    /// Reads and renders the next page of committed transactions, returning
+    /// None if there was nothing new in storage
+    fn read_next_page(&mut self) -> anyhow::Result<Option<Vec<Event>>> {
+        let ledger_version = self
+            .context
+            .get_latest_ledger_info_with_signatures()?
+            .ledger_info()
+            .version();
+        if self.next_version > ledger_version {
+            return Ok(None);
+        }
+
+        let limit = std::cmp::min(
+            self.context.max_transactions_page_size() as u64,
+            ledger_version - self.next_version + 1,
+        ) as u16;
+        let data = self
+            .context
+            .get_transactions(self.next_version, limit, ledger_version)
+            .context("Failed to read raw transactions from storage")?;
+
+        // Track block timestamps the same way the paginated endpoints do
+        let mut timestamp = self.context.db.get_block_timestamp(self.next_version)?;
+        let state_view = self.context.latest_state_view()?;
+        let resolver = state_view.as_move_resolver();
+        let converter = resolver.as_converter(self.context.db.clone());
+
+        self.next_version += data.len() as u64;
+        let mut pending = vec![];
+        for txn in data {
+            if let Some(block_metadata) = txn.transaction.try_as_block_metadata() {
+                timestamp = block_metadata.timestamp_usecs();
+            }
+            if !self.filter.matches(&txn) {
+                continue;
+            }
+
+            let version = txn.version;
+            let messages = match self.kind {
+                StreamKind::Transactions => {
+                    let data = match self.accept_type {
+                        AcceptType::Json => serde_json::to_string(
+                            &converter.try_into_onchain_transaction(timestamp, txn)?,
+                        )?,
+                        AcceptType::Bcs => HexEncodedBytes::from(bcs::to_bytes(&txn)?).to_string(),
+                    };
+                    // The message id is the transaction version, which is what
+                    // clients pass back as `start` to resume the stream
+                    vec![(version.to_string(), TRANSACTION_EVENT_TYPE, data)]
+                },
+                StreamKind::Events => {
+                    // Events are identified by their index in the transaction, so
+                    // the filtered out ones don't shift the ids of the others
+                    let first_event_index = match self.resume_at {
+                        Some((resume_version, event_index)) if resume_version == version => {
+                            event_index
+                        },
+                        _ => 0,
+                    };
+                    let (ids, events): (Vec<_>, Vec<_>) = txn
+                        .events
+                        .into_iter()
+                        .enumerate()
+                        .filter(|(event_index, event)| {
+                            *event_index as u64 >= first_event_index
+                                && self.filter.matches_event(event)
+                        })
+                        .map(|(event_index, event)| {
+                            // The message id is the version and event index, which
+                            // clients pass back as `start` and `start_event_index`
+                            // (plus one) to resume the stream
+                            (
+                                format!("{}:{}", version, event_index),
+                                EventWithVersion::new(version, event),
+                            )
+                        })
+                        .unzip();
+                    let data: Vec<String> = match self.accept_type {
+                        AcceptType::Json => converter
+                            .try_into_versioned_events(&events)?
+                            .iter()
+                            .map(serde_json::to_string)
+                            .collect::<Result<_, _>>()?,
+                        AcceptType::Bcs => events
+                            .iter()
+                            .map(|event| {
+                                Ok::<_, anyhow::Error>(
+                                    HexEncodedBytes::from(bcs::to_bytes(event)?).to_string(),
+                                )
+                            })
+                            .collect::<anyhow::Result<_>>()?,
+                    };
+                    ids.into_iter()
+                        .zip(data)
+                        .map(|(id, data)| (id, EVENT_EVENT_TYPE, data))
+                        .collect()
+                },
+            };
+
+            for (id, event_type, data) in messages {
+                pending.push(Event::message(data).id(id).event_type(event_type));
+                if let Some(remaining) = self.remaining.as_mut() {
+                    *remaining -= 1;
+                    if *remaining == 0 {
+                        return Ok(Some(pending));
+                    }
+                }
+            }
+        }
+        Ok(Some(pending))
+    }
+}
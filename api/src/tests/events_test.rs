@@ -2,7 +2,7 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use super::new_test_context;
+use super::{new_test_context, new_test_context_with_db_indexer};
 use aptos_api_test_context::current_function_name;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
@@ -155,3 +155,47 @@ async fn test_get_events_by_struct_type_has_generic_type_parameter() {
     let resp = context.expect_status_code(404).get(path.as_str()).await;
     context.check_golden_output(resp);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_events_by_type() {
+    let mut context = new_test_context_with_db_indexer(current_function_name!());
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let start_version = context.get_latest_ledger_info().version() + 1;
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+    context.commit_block(&vec![txn1, txn2]).await;
+
+    let path = format!(
+        "/events/by_type/{}?start_version={}",
+        utf8_percent_encode("0x1::coin::DepositEvent", NON_ALPHANUMERIC),
+        start_version
+    );
+    let resp = context.get(&path).await;
+    let events = resp.as_array().unwrap();
+    assert!(events.len() >= 2);
+    assert!(events
+        .iter()
+        .all(|event| event["type"] == "0x1::coin::DepositEvent"));
+    assert_eq!(events.last().unwrap()["data"]["amount"], "100000");
+
+    // A page holds all the events of its last transaction
+    let resp = context.get(&format!("{}&limit=1", path)).await;
+    let page = resp.as_array().unwrap();
+    assert!(!page.is_empty());
+    assert!(page
+        .iter()
+        .all(|event| event["version"] == events[0]["version"]));
+    assert_eq!(page.as_slice(), &events[..page.len()]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_events_by_type_not_enabled() {
+    let context = new_test_context(current_function_name!());
+
+    let resp = context
+        .expect_status_code(403)
+        .get("/events/by_type/0x1::coin::DepositEvent")
+        .await;
+    assert_eq!(resp["error_code"], "api_disabled");
+}
@@ -0,0 +1,140 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{new_test_context, new_test_context_with_config};
+use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_config::config::NodeConfig;
+
+fn new_mempool_test_context(test_name: String) -> TestContext {
+    let mut node_config = NodeConfig::default();
+    node_config.api.mempool_transactions_enabled = true;
+    new_test_context_with_config(test_name, node_config)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_mempool_account_transactions() {
+    let mut context = new_mempool_test_context(current_function_name!());
+    let mut root = context.root_account().await;
+    let account_1 = context.gen_account();
+    let account_2 = context.gen_account();
+    let txn_1 = context.create_user_account_by(&mut root, &account_1);
+    let txn_2 = context.create_user_account_by(&mut root, &account_2);
+    let path = format!("/mempool/accounts/{}", root.address());
+
+    // Without its predecessor, the second transaction is parked
+    let pending_txn_2 = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs::to_bytes(&txn_2).unwrap())
+        .await;
+    let txns = context.get(&path).await;
+    assert_eq!(txns.as_array().unwrap().len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_2["hash"]);
+    assert_eq!(txns[0]["parked"], true);
+
+    let pending_txn_1 = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs::to_bytes(&txn_1).unwrap())
+        .await;
+    let txns = context.get(&path).await;
+    assert_eq!(txns.as_array().unwrap().len(), 2);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_1["hash"]);
+    assert_eq!(txns[1]["transaction"]["hash"], pending_txn_2["hash"]);
+    for txn in txns.as_array().unwrap() {
+        assert_eq!(txn["parked"], false);
+        assert!(txn["insertion_timestamp_usecs"].as_str().unwrap() != "0");
+        assert!(!txn["bucket"].as_str().unwrap().is_empty());
+    }
+
+    // Ready transactions are listed one page at a time
+    let req = warp::test::request()
+        .method("GET")
+        .path("/v1/mempool/transactions?limit=1");
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 200);
+    let cursor = resp
+        .headers()
+        .get("X-Aptos-Cursor")
+        .expect("Cursor header was missing")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let txns: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(txns.as_array().unwrap().len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_1["hash"]);
+
+    let req = warp::test::request().method("GET").path(&format!(
+        "/v1/mempool/transactions?limit=1&start={}",
+        cursor
+    ));
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(!resp.headers().contains_key("X-Aptos-Cursor"));
+    let txns: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(txns.as_array().unwrap().len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_2["hash"]);
+
+    // So are the transactions of an account
+    let req = warp::test::request()
+        .method("GET")
+        .path(&format!("/v1{}?limit=1", path));
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 200);
+    let cursor = resp
+        .headers()
+        .get("X-Aptos-Cursor")
+        .expect("Cursor header was missing")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let txns: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(txns.as_array().unwrap().len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_1["hash"]);
+
+    let req = warp::test::request()
+        .method("GET")
+        .path(&format!("/v1{}?limit=1&start={}", path, cursor));
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(!resp.headers().contains_key("X-Aptos-Cursor"));
+    let txns: serde_json::Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(txns.as_array().unwrap().len(), 1);
+    assert_eq!(txns[0]["transaction"]["hash"], pending_txn_2["hash"]);
+
+    // Committed transactions leave the mempool
+    context.commit_mempool_txns(2).await;
+    let txns = context.get(&path).await;
+    assert!(txns.as_array().unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_mempool_transactions_invalid_limit() {
+    let context = new_mempool_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .get("/mempool/transactions?limit=0")
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_mempool_transactions_invalid_cursor() {
+    let context = new_mempool_test_context(current_function_name!());
+    let resp = context
+        .expect_status_code(400)
+        .get("/mempool/transactions?start=0x01")
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_get_mempool_transactions_disabled_by_default() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    for path in [
+        "/mempool/transactions".to_string(),
+        format!("/mempool/accounts/{}", account.address()),
+    ] {
+        let resp = context.expect_status_code(403).get(&path).await;
+        assert_eq!(resp["error_code"], "api_disabled");
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{new_test_context, new_test_context_with_config};
+use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_config::config::NodeConfig;
+use serde_json::Value;
+
+/// Reads a bounded stream to completion and returns the data of every message
+async fn read_stream(context: &TestContext, path: &str) -> Vec<Value> {
+    read_stream_with_ids(context, path)
+        .await
+        .into_iter()
+        .map(|(_, data)| data)
+        .collect()
+}
+
+/// Reads a bounded stream to completion and returns the id and data of every message
+async fn read_stream_with_ids(context: &TestContext, path: &str) -> Vec<(String, Value)> {
+    let resp = context
+        .reply(
+            warp::test::request()
+                .method("GET")
+                .path(&context.prepend_path(path)),
+        )
+        .await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers()["content-type"], "text/event-stream");
+
+    std::str::from_utf8(resp.body())
+        .unwrap()
+        .split("\n\n")
+        .filter(|message| !message.is_empty())
+        .map(|message| {
+            let field = |name: &str| {
+                message
+                    .lines()
+                    .find_map(|line| line.strip_prefix(name))
+                    .unwrap()
+                    .to_string()
+            };
+            (
+                field("id: "),
+                serde_json::from_str(&field("data: ")).unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_by_sender() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+
+    let txns = read_stream(
+        &context,
+        "/transactions/stream?start=0&limit=1&sender=0xa550c18",
+    )
+    .await;
+    assert_eq!(txns.len(), 1);
+
+    let expected = context.get("/transactions?start=2&limit=1").await;
+    assert_eq!(txns[0], expected[0]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_follows_new_commits() {
+    let mut context = new_test_context(current_function_name!());
+    let start = context.get_latest_ledger_info().version() + 1;
+
+    let reader = context.clone();
+    let stream = tokio::spawn(async move {
+        read_stream(
+            &reader,
+            &format!(
+                "/transactions/stream?start={}&limit=1&entry_function=0x1::aptos_account::create_account",
+                start
+            ),
+        )
+        .await
+    });
+
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn.clone()]).await;
+
+    let txns = stream.await.unwrap();
+    assert_eq!(txns.len(), 1);
+    assert_eq!(txns[0]["hash"], txn.committed_hash().to_hex_literal());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_events_by_type() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+    let start = context.get_latest_ledger_info().version() + 1;
+    let mut root = context.root_account().await;
+    let txn = context.account_transfer(&mut root, &account, 10);
+    context.commit_block(&vec![txn]).await;
+
+    let events = read_stream(
+        &context,
+        &format!(
+            "/events/stream?start={}&limit=1&event_type=0x1::coin::DepositEvent",
+            start
+        ),
+    )
+    .await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0]["type"], "0x1::coin::DepositEvent");
+    assert_eq!(events[0]["data"]["amount"], "10");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_events_resume_within_transaction() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+    let mut root = context.root_account().await;
+    let txn = context.account_transfer(&mut root, &account, 10);
+    context.commit_block(&vec![txn]).await;
+    let version = context.get_latest_ledger_info().version();
+    let user_txn_version = version - 1;
+
+    let events = read_stream_with_ids(
+        &context,
+        &format!("/events/stream?start={}&limit=2", user_txn_version),
+    )
+    .await;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].0, format!("{}:0", user_txn_version));
+    assert_eq!(events[1].0, format!("{}:1", user_txn_version));
+
+    // Resuming after the first event must continue with the second one,
+    // neither dropping nor repeating events of the same transaction
+    let resumed = read_stream_with_ids(
+        &context,
+        &format!(
+            "/events/stream?start={}&start_event_index=1&limit=1",
+            user_txn_version
+        ),
+    )
+    .await;
+    assert_eq!(resumed, vec![events[1].clone()]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_transactions_with_zero_limit() {
+    let context = new_test_context(current_function_name!());
+
+    context
+        .expect_status_code(400)
+        .get("/transactions/stream?limit=0")
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_events_with_start_event_index_and_no_start() {
+    let context = new_test_context(current_function_name!());
+
+    context
+        .expect_status_code(400)
+        .get("/events/stream?start_event_index=1")
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_over_max_active_connections() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.stream_max_active_connections = 0;
+    let context = new_test_context_with_config(current_function_name!(), node_config);
+
+    context
+        .expect_status_code(503)
+        .get("/transactions/stream?start=0&limit=1")
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_stream_releases_connection_when_done() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.stream_max_active_connections = 1;
+    let context = new_test_context_with_config(current_function_name!(), node_config);
+
+    // The second stream only gets a slot if the first one gave its slot back
+    for _ in 0..2 {
+        let txns = read_stream(&context, "/transactions/stream?start=0&limit=1").await;
+        assert_eq!(txns.len(), 1);
+    }
+}
@@ -488,6 +488,79 @@ async fn test_get_pending_transaction_by_hash() {
     context.check_golden_output(not_found);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_wait_transaction_by_hash_committed() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn.clone()]).await;
+
+    let txns = context.get("/transactions?start=2&limit=1").await;
+    let resp = context
+        .get(&format!(
+            "/transactions/wait_by_hash/{}",
+            txns[0]["hash"].as_str().unwrap()
+        ))
+        .await;
+    assert_json(resp, txns[0].clone());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_wait_transaction_by_hash_until_committed() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    let pending_txn = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs::to_bytes(&txn).unwrap())
+        .await;
+    let txn_hash = pending_txn["hash"].as_str().unwrap().to_owned();
+
+    let waiter = context.clone();
+    let resp = tokio::spawn(async move {
+        waiter
+            .get(&format!("/transactions/wait_by_hash/{}", txn_hash))
+            .await
+    });
+    context.commit_block(&vec![txn]).await;
+
+    let resp = resp.await.unwrap();
+    assert_eq!(resp["type"], "user_transaction");
+    assert_eq!(resp["hash"], pending_txn["hash"]);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_wait_transaction_by_hash_timeout() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.wait_by_hash_timeout_ms = 100;
+    let mut context = new_test_context_with_config(current_function_name!(), node_config);
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    let pending_txn = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs::to_bytes(&txn).unwrap())
+        .await;
+
+    let resp = context
+        .get(&format!(
+            "/transactions/wait_by_hash/{}",
+            pending_txn["hash"].as_str().unwrap()
+        ))
+        .await;
+    assert_eq!(resp["type"], "pending_transaction");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_wait_transaction_by_hash_not_found() {
+    let context = new_test_context(current_function_name!());
+
+    let resp = context
+        .expect_status_code(404)
+        .get("/transactions/wait_by_hash/0xdadfeddcca7cb6396c735e9094c76c6e4e9cb3e3ef814730693aed59bd87b31d")
+        .await;
+    assert_eq!(resp["error_code"], "transaction_not_found");
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_signing_message_with_entry_function_payload() {
     let mut context = new_test_context(current_function_name!());
@@ -1293,6 +1366,180 @@ async fn test_gas_estimation_static_override() {
     context.check_golden_output(resp);
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_with_gas_profile() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+    let root = &mut context.root_account().await;
+    let payload = json!({
+        "type": "entry_function_payload",
+        "function": "0x1::aptos_account::transfer",
+        "type_arguments": [],
+        "arguments": [account.address().to_hex_literal(), "100"]
+    });
+
+    let resp = context
+        .simulate_transaction(root, payload.clone(), 200)
+        .await;
+    assert!(resp[0].get("gas_profile").is_none());
+
+    let resp = context
+        .simulate_transaction_with_query(root, payload.clone(), "?profile_gas=true", 200)
+        .await;
+    assert!(resp[0]["success"].as_bool().unwrap());
+    let gas_profile = &resp[0]["gas_profile"];
+    let functions = gas_profile["functions"].as_array().unwrap();
+    assert!(functions
+        .iter()
+        .any(|function| function["name"] == "0x1::aptos_account::transfer"));
+    assert!(!gas_profile["instructions"].as_array().unwrap().is_empty());
+    assert!(!gas_profile["storage_fee_for_writes"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(gas_profile.get("execution_flamegraph").is_none());
+
+    let resp = context
+        .simulate_transaction_with_query(
+            root,
+            payload,
+            "?profile_gas=true&profile_gas_flamegraph=true",
+            200,
+        )
+        .await;
+    let execution_flamegraph = resp[0]["gas_profile"]["execution_flamegraph"]
+        .as_str()
+        .unwrap();
+    assert!(execution_flamegraph.contains("<svg"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_bundle() {
+    let mut context = new_test_context(current_function_name!());
+    let root = context.root_account().await;
+    let account = context.gen_account();
+    let transfer = |to: AccountAddress, amount: &str| {
+        json!({
+            "type": "entry_function_payload",
+            "function": "0x1::aptos_account::transfer",
+            "type_arguments": [],
+            "arguments": [to.to_hex_literal(), amount]
+        })
+    };
+
+    // The account doesn't exist yet, so it can't send anything on its own.
+    let resp = context
+        .simulate_transaction(&account, transfer(root.address(), "100"), 200)
+        .await;
+    assert!(!resp[0]["success"].as_bool().unwrap());
+
+    // In a bundle, it is created by the first transaction.
+    let resp = context
+        .simulate_bundle(
+            vec![
+                (&root, transfer(account.address(), "1000")),
+                (&account, transfer(root.address(), "100")),
+            ],
+            200,
+        )
+        .await;
+    let resp = resp.as_array().unwrap();
+    assert_eq!(resp.len(), 2);
+    for txn in resp {
+        assert!(txn["success"].as_bool().unwrap(), "{}", pretty(txn));
+    }
+
+    // Nothing is committed.
+    context
+        .expect_status_code(404)
+        .get(&format!("/accounts/{}", account.address().to_hex_literal()))
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_with_state_overrides() {
+    let mut context = new_test_context(current_function_name!());
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+    let root = context.root_account().await;
+
+    let mut request = context
+        .simulation_request(
+            &account,
+            json!({
+                "type": "entry_function_payload",
+                "function": "0x1::aptos_account::transfer",
+                "type_arguments": [],
+                "arguments": [root.address().to_hex_literal(), "1000"]
+            }),
+        )
+        .await;
+    let resp = context
+        .post("/transactions/simulate", request.clone())
+        .await;
+    assert!(!resp[0]["success"].as_bool().unwrap());
+
+    // Simulate as if the account held some coins.
+    let mut coin_store = context
+        .api_get_account_resource(
+            root.address(),
+            "0x1",
+            "coin",
+            "CoinStore<0x1::aptos_coin::AptosCoin>",
+        )
+        .await;
+    coin_store["data"]["coin"]["value"] = json!("5000");
+    request["state_overrides"] = json!({
+        "resources": [{
+            "address": account.address().to_hex_literal(),
+            "type": coin_store["type"],
+            "data": coin_store["data"],
+        }],
+    });
+    let resp = context.post("/transactions/simulate", request).await;
+    assert!(resp[0]["success"].as_bool().unwrap(), "{}", pretty(&resp));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_with_state_overrides_disabled() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.state_overrides_enabled = false;
+    let mut context = new_test_context_with_config(current_function_name!(), node_config);
+    let account = context.gen_account();
+    let txn = context.create_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+
+    let mut request = context
+        .simulation_request(
+            &account,
+            json!({
+                "type": "entry_function_payload",
+                "function": "0x1::aptos_account::transfer",
+                "type_arguments": [],
+                "arguments": [account.address().to_hex_literal(), "0"]
+            }),
+        )
+        .await;
+    request["state_overrides"] = json!({ "modules": [{ "bytecode": "0x00" }] });
+    let resp = context
+        .expect_status_code(403)
+        .post("/transactions/simulate", request)
+        .await;
+    assert_eq!(resp["error_code"], "api_disabled");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_empty_bundle() {
+    let context = new_test_context(current_function_name!());
+    context
+        .expect_status_code(400)
+        .post("/transactions/simulate_bundle", json!([]))
+        .await;
+}
+
 fn gen_string(len: u64) -> String {
     let mut rng = thread_rng();
     std::iter::repeat(())
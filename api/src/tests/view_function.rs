@@ -1,8 +1,9 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use super::new_test_context;
+use super::{new_test_context, new_test_context_with_config};
 use aptos_api_test_context::current_function_name;
+use aptos_config::config::NodeConfig;
 use serde_json::json;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -111,3 +112,156 @@ async fn test_view_tuple() {
         .await;
     context.check_golden_output_no_prune(resp);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_batch_view() {
+    let mut context = new_test_context(current_function_name!());
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+    let txn3 = context.account_transfer(creator, owner, 100_000);
+
+    context.commit_block(&vec![txn1, txn2, txn3]).await;
+
+    let balance = json!({
+        "function":"0x1::coin::balance",
+        "arguments": vec![owner.address().to_string()],
+        "type_arguments": vec!["0x1::aptos_coin::AptosCoin"],
+    });
+    let balance_at = |version: u64| {
+        let mut request = balance.clone();
+        request["ledger_version"] = json!(version.to_string());
+        request
+    };
+    let resp = context
+        .post(
+            "/view/batch",
+            json!([
+                balance.clone(),
+                balance_at(3),
+                balance_at(1_000_000),
+                {
+                    "function":"0x1::aptos_account::assert_account_exists",
+                    "arguments": vec![owner.address().to_string()],
+                    "type_arguments": [],
+                },
+            ]),
+        )
+        .await;
+
+    assert_eq!(resp[0]["values"], json!(["200000"]));
+    assert_eq!(resp[1]["values"], json!(["100000"]));
+    assert_eq!(resp[2]["error"]["error_code"], "version_not_found");
+    assert_eq!(resp[3]["error"]["error_code"], "invalid_input");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_batch_view_too_large() {
+    let mut context = new_test_context(current_function_name!());
+    let max_batch_size = context.context.node_config.api.max_view_function_batch_size;
+    let request = json!({
+        "function":"0x1::coin::balance",
+        "arguments": vec!["0x1"],
+        "type_arguments": vec!["0x1::aptos_coin::AptosCoin"],
+    });
+
+    context
+        .expect_status_code(400)
+        .post("/view/batch", json!(vec![request; max_batch_size + 1]))
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_with_state_overrides() {
+    let mut context = new_test_context(current_function_name!());
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+
+    context.commit_block(&vec![txn1, txn2]).await;
+
+    let mut coin_store = context
+        .api_get_account_resource(
+            owner.address(),
+            "0x1",
+            "coin",
+            "CoinStore<0x1::aptos_coin::AptosCoin>",
+        )
+        .await;
+    coin_store["data"]["coin"]["value"] = json!("1000000000");
+    let request = |state_overrides| {
+        json!({
+            "function":"0x1::coin::balance",
+            "arguments": vec![owner.address().to_string()],
+            "type_arguments": vec!["0x1::aptos_coin::AptosCoin"],
+            "state_overrides": state_overrides,
+        })
+    };
+
+    let resp = context
+        .post(
+            "/view",
+            request(json!({
+                "resources": [{
+                    "address": owner.address().to_hex_literal(),
+                    "type": coin_store["type"],
+                    "data": coin_store["data"],
+                }],
+            })),
+        )
+        .await;
+    assert_eq!(resp, json!(["1000000000"]));
+
+    // Deleting the coin store makes the view function abort.
+    context
+        .expect_status_code(400)
+        .post(
+            "/view",
+            request(json!({
+                "resources": [{
+                    "address": owner.address().to_hex_literal(),
+                    "type": coin_store["type"],
+                }],
+            })),
+        )
+        .await;
+
+    // Nothing is written to storage.
+    assert_eq!(context.get_apt_balance(owner.address()).await, 100_000);
+
+    context
+        .expect_status_code(400)
+        .post(
+            "/view",
+            request(json!({
+                "modules": [{ "bytecode": "0x00" }],
+            })),
+        )
+        .await;
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_with_state_overrides_disabled() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.state_overrides_enabled = false;
+    let mut context = new_test_context_with_config(current_function_name!(), node_config);
+    let owner = context.gen_account();
+
+    let resp = context
+        .expect_status_code(403)
+        .post(
+            "/view",
+            json!({
+                "function":"0x1::coin::balance",
+                "arguments": vec![owner.address().to_string()],
+                "type_arguments": vec!["0x1::aptos_coin::AptosCoin"],
+                "state_overrides": {
+                    "modules": [{ "bytecode": "0x00" }],
+                },
+            }),
+        )
+        .await;
+    assert_eq!(resp["error_code"], "api_disabled");
+}
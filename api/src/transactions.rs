@@ -16,35 +16,45 @@ use crate::{
         BasicResponse, BasicResponseStatus, BasicResult, BasicResultWith404,
         InsufficientStorageError, InternalError,
     },
+    stream::{stream_committed, ApiEventStream, StreamFilter, StreamKind},
     ApiTags,
 };
 use anyhow::{anyhow, Context as AnyhowContext};
 use aptos_api_types::{
     verify_function_identifier, verify_module_identifier, Address, AptosError, AptosErrorCode,
-    AsConverter, EncodeSubmissionRequest, GasEstimation, GasEstimationBcs, HashValue,
-    HexEncodedBytes, LedgerInfo, MoveType, PendingTransaction, SubmitTransactionRequest,
+    AsConverter, EncodeSubmissionRequest, EntryFunctionId, GasEstimation, GasEstimationBcs,
+    GasProfile, GasProfileItem, HashValue, HexEncodedBytes, LedgerInfo, MoveType,
+    PendingTransaction, SimulateTransactionRequest, StateOverrides, SubmitTransactionRequest,
     Transaction, TransactionData, TransactionOnChainData, TransactionsBatchSingleSubmissionFailure,
     TransactionsBatchSubmissionResult, UserTransaction, VerifyInput, VerifyInputWithRecursion,
     MAX_RECURSIVE_TYPES_ALLOWED, U64,
 };
 use aptos_crypto::{hash::CryptoHash, signing_message};
+use aptos_gas_meter::{StandardGasAlgebra, StandardGasMeter};
+use aptos_gas_profiling::{GasProfiler, TransactionGasLog};
+use aptos_memory_usage_tracker::MemoryTrackedGasMeter;
 use aptos_types::{
     account_config::CoinStoreResource,
     account_view::AccountView,
     mempool_status::MempoolStatusCode,
     transaction::{
         EntryFunction, ExecutionStatus, MultisigTransactionPayload, RawTransaction,
-        RawTransactionWithData, SignedTransaction, TransactionPayload, TransactionStatus,
+        RawTransactionWithData, SignedTransaction, TransactionOutput, TransactionPayload,
+        TransactionStatus, Version,
     },
     vm_status::StatusCode,
 };
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
+use move_core_types::gas_algebra::GasQuantity;
 use poem_openapi::{
     param::{Path, Query},
     payload::Json,
     ApiRequest, OpenApi,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 generate_success_response!(SubmitTransactionResponse, (202, Accepted));
 
@@ -99,6 +109,38 @@ impl VerifyInput for SubmitTransactionPost {
     }
 }
 
+// Same as SubmitTransactionPost, but the JSON request can also carry state
+// overrides.
+#[derive(ApiRequest, Debug)]
+pub enum SimulateTransactionPost {
+    #[oai(content_type = "application/json")]
+    Json(Json<SimulateTransactionRequest>),
+
+    #[oai(content_type = "application/x.aptos.signed_transaction+bcs")]
+    Bcs(Bcs),
+}
+
+impl SimulateTransactionPost {
+    fn into_parts(self) -> (SubmitTransactionPost, Option<StateOverrides>) {
+        match self {
+            SimulateTransactionPost::Json(inner) => (
+                SubmitTransactionPost::Json(Json(inner.0.transaction)),
+                inner.0.state_overrides,
+            ),
+            SimulateTransactionPost::Bcs(data) => (SubmitTransactionPost::Bcs(data), None),
+        }
+    }
+}
+
+impl VerifyInput for SimulateTransactionPost {
+    fn verify(&self) -> anyhow::Result<()> {
+        match self {
+            SimulateTransactionPost::Json(inner) => inner.0.verify(),
+            SimulateTransactionPost::Bcs(_) => Ok(()),
+        }
+    }
+}
+
 // We need a custom type here because we use different types for each of the
 // content types possible for the POST data.
 #[derive(ApiRequest, Debug)]
@@ -127,6 +169,14 @@ impl VerifyInput for SubmitTransactionsBatchPost {
     }
 }
 
+/// Whether to profile the gas usage of a simulated transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasProfiling {
+    Disabled,
+    Enabled,
+    EnabledWithFlamegraphs,
+}
+
 /// API for interacting with transactions
 pub struct TransactionsApi {
     pub context: Arc<Context>,
@@ -171,6 +221,60 @@ impl TransactionsApi {
         self.list(&accept_type, page)
     }
 
+    /// Stream transactions
+    ///
+    /// Streams on-chain committed transactions as server-sent events, following the
+    /// chain tip as new transactions are committed. Each message carries a single
+    /// transaction, rendered the same way as in /transactions: as JSON, or as hex
+    /// encoded BCS of the transaction data if BCS is requested.
+    ///
+    /// The id of each message is the ledger version of its transaction. To resume a
+    /// dropped stream, pass the last received id plus one as the start version.
+    ///
+    /// If the start version has been pruned, then a 410 will be returned.
+    #[oai(
+        path = "/transactions/stream",
+        method = "get",
+        operation_id = "stream_transactions",
+        tag = "ApiTags::Experimental"
+    )]
+    async fn stream_transactions(
+        &self,
+        accept_type: AcceptType,
+        /// Ledger version to start streaming transactions from
+        ///
+        /// If not provided, only transactions committed after the request are streamed
+        start: Query<Option<U64>>,
+        /// Only stream user transactions sent by this account
+        sender: Query<Option<Address>>,
+        /// Only stream user transactions calling this entry function,
+        /// e.g. `0x1::aptos_account::transfer`
+        entry_function: Query<Option<EntryFunctionId>>,
+        /// Only stream transactions emitting at least one event of this type,
+        /// e.g. `0x1::coin::DepositEvent`
+        event_type: Query<Option<MoveType>>,
+        /// Max number of transactions to stream before closing the stream
+        ///
+        /// If not provided, the stream stays open until the client disconnects
+        limit: Query<Option<U64>>,
+    ) -> poem::Result<ApiEventStream<Transaction>, BasicErrorWith404> {
+        fail_point_poem("endpoint_stream_transactions")?;
+        self.context
+            .check_api_output_enabled("Stream transactions", &accept_type)?;
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let filter = StreamFilter::new(sender.0, entry_function.0, event_type.0, &ledger_info)?;
+        stream_committed(
+            self.context.clone(),
+            StreamKind::Transactions,
+            accept_type,
+            start.0.map(|v| v.0),
+            None,
+            limit.0.map(|v| v.0),
+            filter,
+            &ledger_info,
+        )
+    }
+
     /// Get transaction by hash
     ///
     /// Look up a transaction by its hash. This is the same hash that is returned
@@ -205,6 +309,38 @@ impl TransactionsApi {
             .await
     }
 
+    /// Wait for transaction by hash
+    ///
+    /// Same as /transactions/by_hash, but if the transaction is pending, the request
+    /// is held until the transaction is committed, it expires, or the server side
+    /// timeout passes, whichever comes first. The response is then the same as the
+    /// one of /transactions/by_hash. If the transaction is still pending, the client
+    /// should call this endpoint again.
+    ///
+    /// If the transaction is neither committed nor in the mempool, a 404 is returned
+    /// right away.
+    #[oai(
+        path = "/transactions/wait_by_hash/:txn_hash",
+        method = "get",
+        operation_id = "wait_transaction_by_hash",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn wait_transaction_by_hash(
+        &self,
+        accept_type: AcceptType,
+        /// Hash of transaction to wait for
+        txn_hash: Path<HashValue>,
+    ) -> BasicResultWith404<Transaction> {
+        fail_point_poem("endpoint_wait_transaction_by_hash")?;
+        self.context
+            .check_api_output_enabled("Wait transaction by hash", &accept_type)?;
+        if !self.context.node_config.api.wait_by_hash_enabled {
+            return Err(api_disabled("Wait transaction by hash"));
+        }
+        self.wait_transaction_by_hash_inner(&accept_type, txn_hash.0)
+            .await
+    }
+
     /// Get transaction by version
     ///
     /// Retrieves a transaction by a given version. If the version has been
@@ -394,6 +530,14 @@ impl TransactionsApi {
     ///
     /// To use this endpoint with BCS, you must submit a SignedTransaction
     /// encoded as BCS. See SignedTransaction in types/src/transaction/mod.rs.
+    ///
+    /// With `profile_gas`, the transaction returned also has a `gas_profile`,
+    /// breaking its gas usage down per function, instruction and storage slot.
+    /// This is only supported for JSON responses.
+    ///
+    /// A JSON request can also carry `state_overrides`, to simulate the
+    /// transaction as if resources, modules or raw state values were different
+    /// on chain, e.g. as if an account held more coins.
     #[oai(
         path = "/transactions/simulate",
         method = "post",
@@ -412,7 +556,13 @@ impl TransactionsApi {
         /// If set to true, the transaction will use a higher price than the original
         /// estimate.
         estimate_prioritized_gas_unit_price: Query<Option<bool>>,
-        data: SubmitTransactionPost,
+        /// If set to true, the gas usage of the transaction will be profiled and
+        /// returned in its `gas_profile`
+        profile_gas: Query<Option<bool>>,
+        /// If set to true along with `profile_gas`, the gas profile will also include
+        /// flamegraphs of the gas usage as SVG images
+        profile_gas_flamegraph: Query<Option<bool>>,
+        data: SimulateTransactionPost,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         data.verify()
             .context("Simulated transaction invalid")
@@ -428,7 +578,22 @@ impl TransactionsApi {
         }
         self.context
             .check_api_output_enabled("Simulate transaction", &accept_type)?;
+        let gas_profiling = match (
+            profile_gas.0.unwrap_or_default(),
+            profile_gas_flamegraph.0.unwrap_or_default(),
+        ) {
+            (false, _) => GasProfiling::Disabled,
+            (true, false) => GasProfiling::Enabled,
+            (true, true) => GasProfiling::EnabledWithFlamegraphs,
+        };
+        if gas_profiling != GasProfiling::Disabled && accept_type == AcceptType::Bcs {
+            return Err(SubmitTransactionError::bad_request_with_code_no_info(
+                "Gas profiling is not supported for BCS",
+                AptosErrorCode::BcsNotSupported,
+            ));
+        }
         let ledger_info = self.context.get_latest_ledger_info()?;
+        let (data, state_overrides) = data.into_parts();
         let mut signed_transaction = self.get_signed_transaction(&ledger_info, data)?;
 
         let estimated_gas_unit_price = match (
@@ -521,7 +686,73 @@ impl TransactionsApi {
             );
         }
 
-        self.simulate(&accept_type, ledger_info, signed_transaction)
+        self.simulate(
+            &accept_type,
+            ledger_info,
+            signed_transaction,
+            gas_profiling,
+            state_overrides,
+        )
+        .await
+    }
+
+    /// Simulate a bundle of transactions
+    ///
+    /// Simulates the given transactions one after the other, in order. Each transaction
+    /// runs against the state as changed by the transactions before it, so a transaction
+    /// can depend on the resources created or modified earlier in the bundle, e.g. register
+    /// a coin, then swap it, then stake it. Nothing is committed.
+    ///
+    /// Returns the outputs and events of every transaction, in the same order. A failed or
+    /// discarded transaction doesn't stop the simulation, but doesn't change the state seen
+    /// by the transactions after it, except for the gas charged for failed ones.
+    ///
+    /// The transactions are built the same way as for /transactions/simulate, with
+    /// zero-padded signatures, and there can be at most as many of them as in a batch
+    /// submission. To use this endpoint with BCS, you must submit a vector of
+    /// SignedTransaction encoded as BCS.
+    #[oai(
+        path = "/transactions/simulate_bundle",
+        method = "post",
+        operation_id = "simulate_bundle",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn simulate_transaction_bundle(
+        &self,
+        accept_type: AcceptType,
+        data: SubmitTransactionsBatchPost,
+    ) -> SimulateTransactionResult<Vec<UserTransaction>> {
+        data.verify()
+            .context("Simulated transactions invalid")
+            .map_err(|err| {
+                SubmitTransactionError::bad_request_with_code_no_info(
+                    err,
+                    AptosErrorCode::InvalidInput,
+                )
+            })?;
+        fail_point_poem("endpoint_simulate_bundle")?;
+        if !self.context.node_config.api.transaction_simulation_enabled {
+            return Err(api_disabled("Simulate bundle"));
+        }
+        self.context
+            .check_api_output_enabled("Simulate bundle", &accept_type)?;
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let signed_transactions = self.get_signed_transactions_batch(&ledger_info, data)?;
+        if signed_transactions.is_empty()
+            || self.context.max_submit_transaction_batch_size() < signed_transactions.len()
+        {
+            return Err(SubmitTransactionError::bad_request_with_code(
+                format!(
+                    "Bundle must have between 1 and {} transactions, got {}",
+                    self.context.max_submit_transaction_batch_size(),
+                    signed_transactions.len(),
+                ),
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            ));
+        }
+
+        self.simulate_bundle(&accept_type, ledger_info, signed_transactions)
             .await
     }
 
@@ -667,22 +898,67 @@ impl TransactionsApi {
         hash: HashValue,
     ) -> BasicResultWith404<Transaction> {
         let ledger_info = self.context.get_latest_ledger_info()?;
-        let txn_data = self
-            .get_by_hash(hash.into(), &ledger_info)
+        let txn_data = self.find_by_hash(hash, &ledger_info).await?;
+
+        self.get_transaction_inner(accept_type, txn_data, &ledger_info)
+            .await
+    }
+
+    async fn wait_transaction_by_hash_inner(
+        &self,
+        accept_type: &AcceptType,
+        hash: HashValue,
+    ) -> BasicResultWith404<Transaction> {
+        // Don't hold the request if too many requests are already waiting
+        let _slot = match self.context.try_acquire_wait_by_hash_slot() {
+            Some(slot) => slot,
+            None => return self.get_transaction_by_hash_inner(accept_type, hash).await,
+        };
+
+        let api_config = &self.context.node_config.api;
+        let deadline = Instant::now() + Duration::from_millis(api_config.wait_by_hash_timeout_ms);
+        let poll_interval = Duration::from_millis(api_config.wait_by_hash_poll_interval_ms);
+        loop {
+            let ledger_info = self.context.get_latest_ledger_info()?;
+            let txn_data = self.find_by_hash(hash, &ledger_info).await?;
+
+            // Keep waiting only while the transaction is pending and can still be committed
+            if let TransactionData::Pending(txn) = &txn_data {
+                let now = Instant::now();
+                let expired =
+                    txn.expiration_timestamp_secs() <= ledger_info.timestamp() / 1_000_000;
+                if !expired && now < deadline {
+                    self.context
+                        .wait_for_commit(ledger_info.version(), deadline - now, poll_interval)
+                        .await;
+                    continue;
+                }
+            }
+
+            return self
+                .get_transaction_inner(accept_type, txn_data, &ledger_info)
+                .await;
+        }
+    }
+
+    /// Retrieves a transaction by hash, returning a 404 if it's neither committed nor pending
+    async fn find_by_hash(
+        &self,
+        hash: HashValue,
+        ledger_info: &LedgerInfo,
+    ) -> Result<TransactionData, BasicErrorWith404> {
+        self.get_by_hash(hash.into(), ledger_info)
             .await
             .context(format!("Failed to get transaction by hash {}", hash))
             .map_err(|err| {
                 BasicErrorWith404::internal_with_code(
                     err,
                     AptosErrorCode::InternalError,
-                    &ledger_info,
+                    ledger_info,
                 )
             })?
             .context(format!("Failed to find transaction with hash: {}", hash))
-            .map_err(|_| transaction_not_found_by_hash(hash, &ledger_info))?;
-
-        self.get_transaction_inner(accept_type, txn_data, &ledger_info)
-            .await
+            .map_err(|_| transaction_not_found_by_hash(hash, ledger_info))
     }
 
     async fn get_transaction_by_version_inner(
@@ -1169,6 +1445,8 @@ impl TransactionsApi {
         accept_type: &AcceptType,
         ledger_info: LedgerInfo,
         txn: SignedTransaction,
+        gas_profiling: GasProfiling,
+        state_overrides: Option<StateOverrides>,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         // Transactions shouldn't have a valid signature or this could be used to attack
         if txn.signature_is_valid() {
@@ -1180,58 +1458,60 @@ impl TransactionsApi {
         }
 
         // Simulate transaction
-        let state_view = self.context.latest_state_view_poem(&ledger_info)?;
+        let db_state_view = self.context.latest_state_view_poem(&ledger_info)?;
+        let state_view =
+            self.context
+                .override_state_view(&db_state_view, state_overrides, &ledger_info)?;
         let move_resolver = state_view.as_move_resolver();
-        let (_, output) = AptosVM::simulate_signed_transaction(&txn, &move_resolver);
-        let version = ledger_info.version();
-
-        // Ensure that all known statuses return their values in the output (even if they aren't supposed to)
-        let exe_status = match output.status().clone() {
-            TransactionStatus::Keep(exec_status) => exec_status,
-            TransactionStatus::Discard(status) => ExecutionStatus::MiscellaneousError(Some(status)),
-            _ => ExecutionStatus::MiscellaneousError(None),
-        };
-
-        // Build up a transaction from the outputs
-        // All state hashes are invalid, and will be filled with 0s
-        let txn = aptos_types::transaction::Transaction::UserTransaction(txn);
-        let zero_hash = aptos_crypto::HashValue::zero();
-        let info = aptos_types::transaction::TransactionInfo::new(
-            txn.hash(),
-            zero_hash,
-            zero_hash,
-            None,
-            output.gas_used(),
-            exe_status,
-        );
-        let simulated_txn = TransactionOnChainData {
-            version,
-            transaction: txn,
-            info,
-            events: output.events().to_vec(),
-            accumulator_root_hash: zero_hash,
-            changes: output.write_set().clone(),
+        let (output, gas_log) = if gas_profiling == GasProfiling::Disabled {
+            let (_, output) = AptosVM::simulate_signed_transaction(&txn, &move_resolver);
+            (output, None)
+        } else {
+            let (_, output, gas_profiler) =
+                AptosVM::simulate_signed_transaction_with_custom_gas_meter(
+                    &txn,
+                    &move_resolver,
+                    |gas_feature_version, gas_params, storage_gas_params, balance| {
+                        let gas_meter = MemoryTrackedGasMeter::new(StandardGasMeter::new(
+                            StandardGasAlgebra::new(
+                                gas_feature_version,
+                                gas_params,
+                                storage_gas_params,
+                                balance,
+                            ),
+                        ));
+                        Ok(new_gas_profiler(gas_meter, txn.payload()))
+                    },
+                )
+                .map_err(|status| {
+                    SubmitTransactionError::internal_with_code(
+                        format!("Failed to simulate transaction: {:?}", status),
+                        AptosErrorCode::InternalError,
+                        &ledger_info,
+                    )
+                })?;
+            (output, Some(gas_profiler.finish()))
         };
+        let simulated_txn = simulated_transaction_data(ledger_info.version(), txn, &output);
 
         match accept_type {
             AcceptType::Json => {
-                let transactions = self
-                    .context
-                    .render_transactions_non_sequential(&ledger_info, vec![simulated_txn])?;
-
-                // Users can only make requests to simulate UserTransactions, so unpack
-                // the Vec<Transaction> into Vec<UserTransaction>.
-                let mut user_transactions = Vec::new();
-                for transaction in transactions.into_iter() {
-                    match transaction {
-                        Transaction::UserTransaction(user_txn) => user_transactions.push(*user_txn),
-                        _ => {
-                            return Err(SubmitTransactionError::internal_with_code(
-                                "Simulation transaction resulted in a non-UserTransaction",
-                                AptosErrorCode::InternalError,
-                                &ledger_info,
-                            ))
-                        },
+                let mut user_transactions =
+                    self.render_simulated_transactions(&ledger_info, vec![simulated_txn])?;
+                if let Some(gas_log) = &gas_log {
+                    let gas_profile = to_gas_profile(
+                        gas_log,
+                        gas_profiling == GasProfiling::EnabledWithFlamegraphs,
+                    )
+                    .map_err(|err| {
+                        SubmitTransactionError::internal_with_code(
+                            format!("Failed to build gas profile: {}", err),
+                            AptosErrorCode::InternalError,
+                            &ledger_info,
+                        )
+                    })?;
+                    if let Some(user_txn) = user_transactions.first_mut() {
+                        user_txn.gas_profile = Some(gas_profile);
                     }
                 }
                 BasicResponse::try_from_json((
@@ -1246,6 +1526,76 @@ impl TransactionsApi {
         }
     }
 
+    /// Simulate a bundle of transactions in the VM, in order, each one seeing the state changes
+    /// of the ones before it
+    pub async fn simulate_bundle(
+        &self,
+        accept_type: &AcceptType,
+        ledger_info: LedgerInfo,
+        txns: Vec<SignedTransaction>,
+    ) -> SimulateTransactionResult<Vec<UserTransaction>> {
+        // Transactions shouldn't have a valid signature or this could be used to attack
+        if let Some(index) = txns.iter().position(|txn| txn.signature_is_valid()) {
+            return Err(SubmitTransactionError::bad_request_with_code(
+                format!(
+                    "Simulated transactions must have a non-valid signature, position {}",
+                    index
+                ),
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            ));
+        }
+
+        let state_view = self.context.latest_state_view_poem(&ledger_info)?;
+        let move_resolver = state_view.as_move_resolver();
+        let outputs = AptosVM::simulate_signed_transactions(&txns, &move_resolver);
+        let simulated_txns = txns
+            .into_iter()
+            .zip(outputs)
+            .map(|(txn, (_, output))| {
+                simulated_transaction_data(ledger_info.version(), txn, &output)
+            })
+            .collect::<Vec<_>>();
+
+        match accept_type {
+            AcceptType::Json => BasicResponse::try_from_json((
+                self.render_simulated_transactions(&ledger_info, simulated_txns)?,
+                &ledger_info,
+                BasicResponseStatus::Ok,
+            )),
+            AcceptType::Bcs => {
+                BasicResponse::try_from_bcs((simulated_txns, &ledger_info, BasicResponseStatus::Ok))
+            },
+        }
+    }
+
+    fn render_simulated_transactions(
+        &self,
+        ledger_info: &LedgerInfo,
+        simulated_txns: Vec<TransactionOnChainData>,
+    ) -> Result<Vec<UserTransaction>, SubmitTransactionError> {
+        let transactions = self
+            .context
+            .render_transactions_non_sequential(ledger_info, simulated_txns)?;
+
+        // Users can only make requests to simulate UserTransactions, so unpack
+        // the Vec<Transaction> into Vec<UserTransaction>.
+        let mut user_transactions = Vec::new();
+        for transaction in transactions.into_iter() {
+            match transaction {
+                Transaction::UserTransaction(user_txn) => user_transactions.push(*user_txn),
+                _ => {
+                    return Err(SubmitTransactionError::internal_with_code(
+                        "Simulation transaction resulted in a non-UserTransaction",
+                        AptosErrorCode::InternalError,
+                        ledger_info,
+                    ))
+                },
+            }
+        }
+        Ok(user_transactions)
+    }
+
     /// Encode message as BCS
     pub fn get_signing_message(
         &self,
@@ -1305,6 +1655,40 @@ impl TransactionsApi {
     }
 }
 
+/// Builds up a transaction from the outputs of its simulation. All state hashes are invalid, and
+/// are filled with 0s.
+fn simulated_transaction_data(
+    version: Version,
+    txn: SignedTransaction,
+    output: &TransactionOutput,
+) -> TransactionOnChainData {
+    // Ensure that all known statuses return their values in the output (even if they aren't supposed to)
+    let exe_status = match output.status().clone() {
+        TransactionStatus::Keep(exec_status) => exec_status,
+        TransactionStatus::Discard(status) => ExecutionStatus::MiscellaneousError(Some(status)),
+        _ => ExecutionStatus::MiscellaneousError(None),
+    };
+
+    let txn = aptos_types::transaction::Transaction::UserTransaction(txn);
+    let zero_hash = aptos_crypto::HashValue::zero();
+    let info = aptos_types::transaction::TransactionInfo::new(
+        txn.hash(),
+        zero_hash,
+        zero_hash,
+        None,
+        output.gas_used(),
+        exe_status,
+    );
+    TransactionOnChainData {
+        version,
+        transaction: txn,
+        info,
+        events: output.events().to_vec(),
+        accumulator_root_hash: zero_hash,
+        changes: output.write_set().clone(),
+    }
+}
+
 fn override_gas_parameters(
     signed_txn: &SignedTransaction,
     max_gas_amount: Option<u64>,
@@ -1325,3 +1709,72 @@ fn override_gas_parameters(
     // TODO: Check that signature is null, this would just be helpful for downstream use
     SignedTransaction::new_with_authenticator(raw_txn, signed_txn.authenticator())
 }
+
+/// The gas profiler starts from a frame for the function called, or for the script if there is
+/// none, e.g. for a multisig transaction whose payload is stored on chain.
+fn new_gas_profiler<G>(gas_meter: G, payload: &TransactionPayload) -> GasProfiler<G> {
+    let entry_function = match payload {
+        TransactionPayload::EntryFunction(entry_function) => Some(entry_function),
+        TransactionPayload::Multisig(multisig) => match &multisig.transaction_payload {
+            Some(MultisigTransactionPayload::EntryFunction(entry_function)) => Some(entry_function),
+            None => None,
+        },
+        TransactionPayload::Script(_) | TransactionPayload::ModuleBundle(_) => None,
+    };
+    match entry_function {
+        Some(entry_function) => GasProfiler::new_function(
+            gas_meter,
+            entry_function.module().clone(),
+            entry_function.function().to_owned(),
+            entry_function.ty_args().to_vec(),
+        ),
+        None => GasProfiler::new_script(gas_meter),
+    }
+}
+
+fn to_gas_profile_items<U>(items: Vec<(String, usize, GasQuantity<U>)>) -> Vec<GasProfileItem> {
+    items
+        .into_iter()
+        .map(|(name, count, cost)| GasProfileItem {
+            name,
+            count: (count as u64).into(),
+            cost: u64::from(cost).into(),
+        })
+        .collect()
+}
+
+fn to_gas_profile(log: &TransactionGasLog, with_flamegraphs: bool) -> anyhow::Result<GasProfile> {
+    let to_svg = |svg: Option<Vec<u8>>| {
+        svg.map(String::from_utf8)
+            .transpose()
+            .context("Flamegraph is not valid UTF-8")
+    };
+
+    let exec_io = log.exec_io.aggregate_gas_events();
+    let storage = log.storage.aggregate_storage_fees();
+    let (execution_flamegraph, storage_flamegraph) = if with_flamegraphs {
+        (
+            to_svg(log.exec_io.to_flamegraph("Execution & IO".to_string())?)?,
+            to_svg(log.storage.to_flamegraph("Storage Fee".to_string())?)?,
+        )
+    } else {
+        (None, None)
+    };
+
+    Ok(GasProfile {
+        gas_scaling_factor: u64::from(exec_io.gas_scaling_factor).into(),
+        execution_and_io: u64::from(exec_io.total).into(),
+        intrinsic: u64::from(log.exec_io.intrinsic_cost).into(),
+        functions: to_gas_profile_items(exec_io.functions),
+        instructions: to_gas_profile_items(exec_io.ops),
+        storage_reads: to_gas_profile_items(exec_io.storage_reads),
+        storage_writes: to_gas_profile_items(exec_io.storage_writes),
+        storage_fee: u64::from(storage.total).into(),
+        storage_fee_for_transaction: u64::from(storage.txn_storage).into(),
+        storage_fee_for_writes: to_gas_profile_items(storage.writes),
+        storage_fee_for_events: to_gas_profile_items(storage.events),
+        storage_fee_discount_for_events: u64::from(storage.event_discount).into(),
+        execution_flamegraph,
+        storage_flamegraph,
+    })
+}
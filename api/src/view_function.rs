@@ -5,15 +5,24 @@ use crate::{
     accept_type::AcceptType,
     failpoint::fail_point_poem,
     response::{
-        BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404,
+        AptosErrorResponse, BadRequestError, BasicError, BasicErrorWith404, BasicResponse,
+        BasicResponseStatus, BasicResult, BasicResultWith404, InternalError,
     },
     ApiTags, Context,
 };
-use aptos_api_types::{AptosErrorCode, AsConverter, MoveValue, ViewRequest, U64};
+use aptos_api_types::{
+    AptosErrorCode, AsConverter, LedgerInfo, MoveValue, ViewBatchRequest, ViewBatchResult,
+    ViewRequest, U64,
+};
+use aptos_state_view::StateView;
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
+use futures::future::join_all;
 use move_core_types::language_storage::TypeTag;
 use poem_openapi::{param::Query, payload::Json, OpenApi};
-use std::sync::Arc;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
 
 /// API for executing Move view function.
 pub struct ViewFunctionApi {
@@ -26,6 +35,9 @@ impl ViewFunctionApi {
     ///
     /// Execute the Move function with the given parameters and return its execution result.
     ///
+    /// With `state_overrides`, the function is executed as if resources, modules or raw
+    /// state values were different on chain, e.g. to try out a new version of a module.
+    ///
     /// The Aptos nodes prune account state history, via a configurable time window.
     /// If the requested ledger version has been pruned, the server responds with a 410.
     #[oai(
@@ -53,21 +65,8 @@ impl ViewFunctionApi {
             .get_latest_ledger_info_and_verify_lookup_version(
                 ledger_version.map(|inner| inner.0),
             )?;
-
-        let state_view = self.context.latest_state_view_poem(&ledger_info)?;
-        let resolver = state_view.as_move_resolver();
-
-        let entry_func = resolver
-            .as_converter(self.context.db.clone())
-            .convert_view_function(request.0)
-            .map_err(|err| {
-                BasicErrorWith404::bad_request_with_code(
-                    err,
-                    AptosErrorCode::InvalidInput,
-                    &ledger_info,
-                )
-            })?;
-        let state_view = self
+        let latest_db_state_view = self.context.latest_state_view_poem(&ledger_info)?;
+        let requested_db_state_view = self
             .context
             .state_view_at_version(requested_version)
             .map_err(|err| {
@@ -78,57 +77,226 @@ impl ViewFunctionApi {
                 )
             })?;
 
-        let return_vals = AptosVM::execute_view_function(
-            &state_view,
-            entry_func.module().clone(),
-            entry_func.function().to_owned(),
-            entry_func.ty_args().to_owned(),
-            entry_func.args().to_owned(),
-            self.context.node_config.api.max_gas_view_function,
-        )
-        .map_err(|err| {
-            BasicErrorWith404::bad_request_with_code_no_info(err, AptosErrorCode::InvalidInput)
-        })?;
-        match accept_type {
-            AcceptType::Bcs => {
+        match execute_view_function(
+            &self.context,
+            request.0,
+            &latest_db_state_view,
+            &requested_db_state_view,
+            &ledger_info,
+            &accept_type,
+        )? {
+            ViewFunctionOutput::Bcs(return_vals) => {
                 BasicResponse::try_from_bcs((return_vals, &ledger_info, BasicResponseStatus::Ok))
             },
-            AcceptType::Json => {
-                let return_types = resolver
-                    .as_converter(self.context.db.clone())
-                    .function_return_types(&entry_func)
-                    .and_then(|tys| {
-                        tys.into_iter()
-                            .map(TypeTag::try_from)
-                            .collect::<anyhow::Result<Vec<_>>>()
-                    })
-                    .map_err(|err| {
-                        BasicErrorWith404::bad_request_with_code(
-                            err,
-                            AptosErrorCode::InternalError,
-                            &ledger_info,
-                        )
-                    })?;
-
-                let move_vals = return_vals
-                    .into_iter()
-                    .zip(return_types.into_iter())
-                    .map(|(v, ty)| {
-                        resolver
-                            .as_converter(self.context.db.clone())
-                            .try_into_move_value(&ty, &v)
-                    })
-                    .collect::<anyhow::Result<Vec<_>>>()
-                    .map_err(|err| {
-                        BasicErrorWith404::bad_request_with_code(
-                            err,
-                            AptosErrorCode::InternalError,
-                            &ledger_info,
-                        )
-                    })?;
-
+            ViewFunctionOutput::Json(move_vals) => {
                 BasicResponse::try_from_json((move_vals, &ledger_info, BasicResponseStatus::Ok))
             },
         }
     }
+
+    /// Execute a batch of view functions
+    ///
+    /// Execute each Move function with its parameters, at its own ledger version, and return
+    /// the execution results in the order of the requests. A function failing doesn't fail the
+    /// batch, its result holds the error the view API would have returned for it instead.
+    ///
+    /// The functions are executed in parallel, the ones at the same ledger version sharing the
+    /// state they are executed against. Only JSON output is supported.
+    #[oai(
+        path = "/view/batch",
+        method = "post",
+        operation_id = "view_batch",
+        tag = "ApiTags::View"
+    )]
+    async fn view_function_batch(
+        &self,
+        accept_type: AcceptType,
+        /// View function requests, each optionally with the ledger version to execute it at
+        requests: Json<Vec<ViewBatchRequest>>,
+    ) -> BasicResult<Vec<ViewBatchResult>> {
+        fail_point_poem("endpoint_view_function_batch")?;
+        self.context
+            .check_api_output_enabled("View function", &accept_type)?;
+        if accept_type == AcceptType::Bcs {
+            return Err(BasicError::bad_request_with_code_no_info(
+                "BCS is not supported for batches of view functions",
+                AptosErrorCode::BcsNotSupported,
+            ));
+        }
+        let max_batch_size = self.context.node_config.api.max_view_function_batch_size;
+        if requests.0.len() > max_batch_size {
+            return Err(BasicError::bad_request_with_code_no_info(
+                format!(
+                    "Too many view functions in the batch, {} given, the limit is {}",
+                    requests.0.len(),
+                    max_batch_size
+                ),
+                AptosErrorCode::InvalidInput,
+            ));
+        }
+
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let latest_db_state_view = Arc::new(self.context.latest_state_view_poem(&ledger_info)?);
+        let mut db_state_views = HashMap::new();
+        let mut tasks = vec![];
+        for ViewBatchRequest {
+            request,
+            ledger_version,
+        } in requests.0
+        {
+            let requested_db_state_view = self
+                .context
+                .verify_lookup_version::<BasicErrorWith404>(
+                    &ledger_info,
+                    ledger_version.map(|inner| inner.0),
+                )
+                .and_then(|version| match db_state_views.entry(version) {
+                    Entry::Occupied(entry) => Ok(Arc::clone(entry.get())),
+                    Entry::Vacant(entry) => {
+                        let db_state_view =
+                            self.context.state_view_at_version(version).map_err(|err| {
+                                BasicErrorWith404::internal_with_code(
+                                    err,
+                                    AptosErrorCode::InternalError,
+                                    &ledger_info,
+                                )
+                            })?;
+                        Ok(Arc::clone(entry.insert(Arc::new(db_state_view))))
+                    },
+                });
+            let context = self.context.clone();
+            let latest_db_state_view = latest_db_state_view.clone();
+            let ledger_info = ledger_info.clone();
+            tasks.push(tokio::task::spawn_blocking(
+                move || -> Result<Vec<serde_json::Value>, BasicErrorWith404> {
+                    let requested_db_state_view = requested_db_state_view?;
+                    let output = execute_view_function(
+                        &context,
+                        request,
+                        latest_db_state_view.as_ref(),
+                        requested_db_state_view.as_ref(),
+                        &ledger_info,
+                        &AcceptType::Json,
+                    )?;
+                    let ViewFunctionOutput::Json(move_vals) = output else {
+                        unreachable!("JSON output was requested");
+                    };
+                    move_vals
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| {
+                            BasicErrorWith404::internal_with_code(
+                                err,
+                                AptosErrorCode::InternalError,
+                                &ledger_info,
+                            )
+                        })
+                },
+            ));
+        }
+
+        let mut results = vec![];
+        for result in join_all(tasks).await {
+            let result = result.map_err(|err| {
+                BasicError::internal_with_code(err, AptosErrorCode::InternalError, &ledger_info)
+            })?;
+            results.push(match result {
+                Ok(values) => ViewBatchResult {
+                    values: Some(values),
+                    error: None,
+                },
+                Err(mut err) => ViewBatchResult {
+                    values: None,
+                    error: Some(err.inner_mut().clone()),
+                },
+            });
+        }
+
+        BasicResponse::try_from_json((results, &ledger_info, BasicResponseStatus::Ok))
+    }
+}
+
+enum ViewFunctionOutput {
+    Bcs(Vec<Vec<u8>>),
+    Json(Vec<MoveValue>),
+}
+
+/// Executes a view function against `requested_db_state_view`, with the state overrides of the
+/// request layered over it.
+///
+/// The overrides also apply to the ABIs the arguments and return values are converted with,
+/// taken from `latest_db_state_view`, so that functions of overridden modules can be called.
+fn execute_view_function<S: StateView>(
+    context: &Context,
+    request: ViewRequest,
+    latest_db_state_view: &S,
+    requested_db_state_view: &S,
+    ledger_info: &LedgerInfo,
+    accept_type: &AcceptType,
+) -> Result<ViewFunctionOutput, BasicErrorWith404> {
+    let state_overrides = request.state_overrides.clone();
+    let latest_state_view =
+        context.override_state_view(latest_db_state_view, state_overrides.clone(), ledger_info)?;
+    let resolver = latest_state_view.as_move_resolver();
+
+    let entry_func = resolver
+        .as_converter(context.db.clone())
+        .convert_view_function(request)
+        .map_err(|err| {
+            BasicErrorWith404::bad_request_with_code(err, AptosErrorCode::InvalidInput, ledger_info)
+        })?;
+    let state_view =
+        context.override_state_view(requested_db_state_view, state_overrides, ledger_info)?;
+
+    let return_vals = AptosVM::execute_view_function(
+        &state_view,
+        entry_func.module().clone(),
+        entry_func.function().to_owned(),
+        entry_func.ty_args().to_owned(),
+        entry_func.args().to_owned(),
+        context.node_config.api.max_gas_view_function,
+    )
+    .map_err(|err| {
+        BasicErrorWith404::bad_request_with_code_no_info(err, AptosErrorCode::InvalidInput)
+    })?;
+    match accept_type {
+        AcceptType::Bcs => Ok(ViewFunctionOutput::Bcs(return_vals)),
+        AcceptType::Json => {
+            let return_types = resolver
+                .as_converter(context.db.clone())
+                .function_return_types(&entry_func)
+                .and_then(|tys| {
+                    tys.into_iter()
+                        .map(TypeTag::try_from)
+                        .collect::<anyhow::Result<Vec<_>>>()
+                })
+                .map_err(|err| {
+                    BasicErrorWith404::bad_request_with_code(
+                        err,
+                        AptosErrorCode::InternalError,
+                        ledger_info,
+                    )
+                })?;
+
+            let move_vals = return_vals
+                .into_iter()
+                .zip(return_types.into_iter())
+                .map(|(v, ty)| {
+                    resolver
+                        .as_converter(context.db.clone())
+                        .try_into_move_value(&ty, &v)
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map_err(|err| {
+                    BasicErrorWith404::bad_request_with_code(
+                        err,
+                        AptosErrorCode::InternalError,
+                        ledger_info,
+                    )
+                })?;
+
+            Ok(ViewFunctionOutput::Json(move_vals))
+        },
+    }
 }
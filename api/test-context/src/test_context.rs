@@ -10,7 +10,7 @@ use aptos_api_types::{
 use aptos_cached_packages::aptos_stdlib;
 use aptos_config::{
     config::{
-        NodeConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+        ColdStorageConfig, NodeConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
         DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
     },
     keys::ConfigKey,
@@ -39,7 +39,7 @@ use aptos_types::{
     block_metadata::BlockMetadata,
     chain_id::ChainId,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
-    transaction::{Transaction, TransactionPayload, TransactionStatus},
+    transaction::{Transaction, TransactionPayload, TransactionStatus, Version},
 };
 use aptos_vm::AptosVM;
 use aptos_vm_validator::vm_validator::VMValidator;
@@ -48,6 +48,7 @@ use hyper::{HeaderMap, Response};
 use rand::SeedableRng;
 use serde_json::{json, Value};
 use std::{boxed::Box, iter::once, net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+use tokio::sync::watch;
 use warp::{http::header::CONTENT_TYPE, Filter, Rejection, Reply};
 use warp_reverse_proxy::reverse_proxy_filter;
 
@@ -124,8 +125,10 @@ pub fn new_test_context(
                 NO_OP_STORAGE_PRUNER_CONFIG, /* pruner */
                 RocksdbConfigs::default(),
                 false, /* indexer */
+                false, /* event type index */
                 BUFFERED_STATE_TARGET_ITEMS,
                 DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+                ColdStorageConfig::default(),
             )
             .unwrap(),
         )
@@ -136,12 +139,14 @@ pub fn new_test_context(
 
     let mempool = MockSharedMempool::new_in_runtime(&db_rw, VMValidator::new(db.clone()));
 
+    let (commit_notifier, commit_notifications) = watch::channel(0);
     let context = Context::new(
         ChainId::test(),
         db.clone(),
         mempool.ac_client.clone(),
         node_config.clone(),
-    );
+    )
+    .with_commit_notifications(commit_notifications);
 
     // Configure the testing depending on which API version we're testing.
     let runtime_handle = tokio::runtime::Handle::current();
@@ -157,6 +162,7 @@ pub fn new_test_context(
         Box::new(BlockExecutor::<AptosVM>::new(db_rw)),
         mempool,
         db,
+        commit_notifier,
         test_name,
         api_specific_config,
     )
@@ -168,6 +174,7 @@ pub struct TestContext {
     pub validator_owner: AccountAddress,
     pub mempool: Arc<MockSharedMempool>,
     pub db: Arc<AptosDB>,
+    commit_notifier: Arc<watch::Sender<Version>>,
     rng: rand::rngs::StdRng,
     root_key: ConfigKey<Ed25519PrivateKey>,
     executor: Arc<dyn BlockExecutorTrait>,
@@ -187,6 +194,7 @@ impl TestContext {
         executor: Box<dyn BlockExecutorTrait>,
         mempool: MockSharedMempool,
         db: Arc<AptosDB>,
+        commit_notifier: watch::Sender<Version>,
         test_name: String,
         api_specific_config: ApiSpecificConfig,
     ) -> Self {
@@ -199,6 +207,7 @@ impl TestContext {
             mempool: Arc::new(mempool),
             expect_status_code: 200,
             db,
+            commit_notifier: Arc::new(commit_notifier),
             test_name,
             golden_output: None,
             fake_time_usecs: 0,
@@ -636,6 +645,8 @@ impl TestContext {
             .notify_new_commit(txns, timestamp, 1000)
             .await
             .unwrap();
+        self.commit_notifier
+            .send_replace(self.get_latest_ledger_info().version());
     }
 
     pub async fn get_sequence_number(&self, account: AccountAddress) -> u64 {
@@ -833,6 +844,41 @@ impl TestContext {
         payload: Value,
         status_code: u16,
     ) -> Value {
+        self.simulate_transaction_with_query(sender, payload, "", status_code)
+            .await
+    }
+
+    /// Simulates with the given query string, e.g. `?profile_gas=true`.
+    pub async fn simulate_transaction_with_query(
+        &mut self,
+        sender: &LocalAccount,
+        payload: Value,
+        query: &str,
+        status_code: u16,
+    ) -> Value {
+        let request = self.simulation_request(sender, payload).await;
+        self.expect_status_code(status_code)
+            .post(&format!("/transactions/simulate{}", query), request)
+            .await
+    }
+
+    /// Simulates the transactions, given as (sender, payload), as a bundle.
+    pub async fn simulate_bundle(
+        &mut self,
+        transactions: Vec<(&LocalAccount, Value)>,
+        status_code: u16,
+    ) -> Value {
+        let mut requests = vec![];
+        for (sender, payload) in transactions {
+            requests.push(self.simulation_request(sender, payload).await);
+        }
+        self.expect_status_code(status_code)
+            .post("/transactions/simulate_bundle", json!(requests))
+            .await
+    }
+
+    /// Builds a request to simulate `payload`, signed with a random key.
+    pub async fn simulation_request(&mut self, sender: &LocalAccount, payload: Value) -> Value {
         let mut request = json!({
             "sender": sender.address(),
             "sequence_number": sender.sequence_number().to_string(),
@@ -863,10 +909,7 @@ impl TestContext {
             "public_key": HexEncodedBytes::from(sender.public_key().to_bytes().to_vec()),
             "signature": HexEncodedBytes::from(sig.to_bytes().to_vec()),
         });
-
-        self.expect_status_code(status_code)
-            .post("/transactions/simulate", request)
-            .await
+        request
     }
 
     pub fn prepend_path(&self, path: &str) -> String {
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    state_override::StateOverrides,
     transaction::{
         DecodedTableData, DeleteModule, DeleteResource, DeleteTableItem, DeletedTableData,
         ModuleBundlePayload, MultisigPayload, MultisigTransactionPayload,
@@ -25,6 +26,7 @@ use aptos_types::{
     contract_event::{ContractEvent, EventWithVersion},
     state_store::{
         state_key::{StateKey, StateKeyInner},
+        state_value::StateValue,
         table::TableHandle,
     },
     transaction::{
@@ -35,7 +37,7 @@ use aptos_types::{
     write_set::WriteOp,
 };
 use aptos_vm::move_vm_ext::MoveResolverExt;
-use move_binary_format::file_format::FunctionHandleIndex;
+use move_binary_format::{file_format::FunctionHandleIndex, CompiledModule};
 use move_core_types::{
     account_address::AccountAddress,
     ident_str,
@@ -887,6 +889,7 @@ impl<'a, R: MoveResolverExt + ?Sized> MoveConverter<'a, R> {
             function,
             type_arguments,
             arguments,
+            state_overrides: _,
         } = view_request;
 
         let module = function.module.clone();
@@ -917,6 +920,69 @@ impl<'a, R: MoveResolverExt + ?Sized> MoveConverter<'a, R> {
             args,
         ))
     }
+
+    /// Converts the overrides into the state values to replace, `None` meaning deleted.
+    pub fn try_into_state_values(
+        &self,
+        state_overrides: StateOverrides,
+    ) -> Result<Vec<(StateKey, Option<StateValue>)>> {
+        let StateOverrides {
+            resources,
+            modules,
+            state_values,
+        } = state_overrides;
+        let mut ret = Vec::new();
+
+        for (i, resource) in resources.into_iter().enumerate() {
+            let struct_tag: StructTag = resource.typ.try_into()?;
+            let bytes = match (resource.data, resource.bcs_data) {
+                (Some(_), Some(_)) => bail!(
+                    "resources[{}] of type {} has both data and bcs_data",
+                    i,
+                    struct_tag
+                ),
+                (Some(data), None) => {
+                    let typ = TypeTag::Struct(Box::new(struct_tag.clone()));
+                    let value = self.try_into_vm_value(&typ, data).with_context(|| {
+                        format!("parse resources[{}] of type {} failed", i, struct_tag)
+                    })?;
+                    Some(bcs::to_bytes(&value)?)
+                },
+                (None, Some(bcs_data)) => Some(bcs_data.into()),
+                (None, None) => None,
+            };
+            let access_path =
+                AccessPath::resource_access_path(resource.address.into(), struct_tag)?;
+            ret.push((
+                StateKey::access_path(access_path),
+                bytes.map(StateValue::new_legacy),
+            ));
+        }
+
+        for (i, module) in modules.into_iter().enumerate() {
+            let bytes: Vec<u8> = module.bytecode.into();
+            let module_id = CompiledModule::deserialize(&bytes)
+                .with_context(|| format!("parse modules[{}] failed", i))?
+                .self_id();
+            ret.push((
+                StateKey::access_path(AccessPath::code_access_path(module_id)),
+                Some(StateValue::new_legacy(bytes)),
+            ));
+        }
+
+        for (i, state_value) in state_values.into_iter().enumerate() {
+            let state_key = bcs::from_bytes(&state_value.state_key.0)
+                .with_context(|| format!("parse state_values[{}] failed", i))?;
+            ret.push((
+                state_key,
+                state_value
+                    .value
+                    .map(|value| StateValue::new_legacy(value.into())),
+            ));
+        }
+
+        Ok(ret)
+    }
 }
 
 impl<'a, R: MoveResolverExt + ?Sized> ExplainVMStatus for MoveConverter<'a, R> {
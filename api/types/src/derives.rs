@@ -293,10 +293,12 @@ impl_poem_type!(
 
 impl_poem_parameter!(
     Address,
+    EntryFunctionId,
     HashValue,
     IdentifierWrapper,
     HexEncodedBytes,
     MoveStructTag,
+    MoveType,
     StateKeyWrapper,
     U64,
     U128
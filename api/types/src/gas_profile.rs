@@ -0,0 +1,57 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::U64;
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// Breakdown of the gas charged to a simulated transaction
+///
+/// Execution and IO costs are in internal gas units, which are `gas_scaling_factor` times
+/// smaller than the gas units `max_gas_amount` and `gas_used` are expressed in. Storage fees
+/// are in octas. Items within each list are sorted by cost, from high to low.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfile {
+    pub gas_scaling_factor: U64,
+    /// Total execution and IO cost, including the intrinsic cost
+    pub execution_and_io: U64,
+    /// Cost charged for the transaction itself, based on its size
+    pub intrinsic: U64,
+    /// Cost of the instructions executed in the body of each function, excluding the functions
+    /// it calls
+    pub functions: Vec<GasProfileItem>,
+    /// Cost of each type of instruction or native function
+    pub instructions: Vec<GasProfileItem>,
+    /// Cost of the reads of each storage slot
+    pub storage_reads: Vec<GasProfileItem>,
+    /// Cost of the writes of each storage slot
+    pub storage_writes: Vec<GasProfileItem>,
+    /// Total storage fee, in octas
+    pub storage_fee: U64,
+    /// Storage fee for the transaction itself
+    pub storage_fee_for_transaction: U64,
+    /// Storage fee for each storage slot written
+    pub storage_fee_for_writes: Vec<GasProfileItem>,
+    /// Storage fee for each type of event emitted
+    pub storage_fee_for_events: Vec<GasProfileItem>,
+    /// Part of the storage fee for events that is waived
+    pub storage_fee_discount_for_events: U64,
+    /// Flamegraph of the execution and IO costs, as an SVG image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub execution_flamegraph: Option<String>,
+    /// Flamegraph of the storage fees, as an SVG image
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub storage_flamegraph: Option<String>,
+}
+
+/// Aggregated cost of one item of a gas profile
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfileItem {
+    /// Function, instruction, storage slot or event type
+    pub name: String,
+    /// Number of times it was charged for
+    pub count: U64,
+    pub cost: U64,
+}
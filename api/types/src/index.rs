@@ -26,6 +26,10 @@ pub struct IndexResponse {
     /// Git hash of the build of the API endpoint.  Can be used to determine the exact
     /// software version used by the API endpoint.
     pub git_hash: Option<String>,
+    // This must be optional to be backwards compatible
+    /// Whether the node supports `/transactions/wait_by_hash`, which waits for a
+    /// transaction to be committed instead of returning it while pending.
+    pub wait_by_hash_enabled: Option<bool>,
 }
 
 impl IndexResponse {
@@ -33,6 +37,7 @@ impl IndexResponse {
         ledger_info: LedgerInfo,
         node_role: RoleType,
         git_hash: Option<String>,
+        wait_by_hash_enabled: bool,
     ) -> IndexResponse {
         Self {
             chain_id: ledger_info.chain_id,
@@ -44,6 +49,7 @@ impl IndexResponse {
             block_height: ledger_info.block_height,
             node_role,
             git_hash,
+            wait_by_hash_enabled: Some(wait_by_hash_enabled),
         }
     }
 }
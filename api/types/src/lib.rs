@@ -11,13 +11,16 @@ mod bytecode;
 mod convert;
 mod derives;
 mod error;
+mod gas_profile;
 mod hash;
 mod headers;
 mod index;
 mod ledger_info;
+mod mempool;
 pub mod mime_types;
 mod move_types;
 mod state;
+mod state_override;
 mod table;
 pub mod transaction;
 mod view;
@@ -29,10 +32,12 @@ pub use block::{BcsBlock, Block};
 pub use bytecode::Bytecode;
 pub use convert::{new_vm_utf8_string, AsConverter, ExplainVMStatus, MoveConverter};
 pub use error::{AptosError, AptosErrorCode};
+pub use gas_profile::{GasProfile, GasProfileItem};
 pub use hash::HashValue;
 pub use headers::*;
 pub use index::{IndexResponse, IndexResponseBcs};
 pub use ledger_info::LedgerInfo;
+pub use mempool::MempoolTransaction;
 pub use move_types::{
     verify_field_identifier, verify_function_identifier, verify_module_identifier, EntryFunctionId,
     HexEncodedBytes, MoveAbility, MoveFunction, MoveFunctionGenericTypeParam,
@@ -42,6 +47,7 @@ pub use move_types::{
 };
 use serde::{Deserialize, Deserializer};
 pub use state::RawStateValueRequest;
+pub use state_override::{ModuleOverride, ResourceOverride, StateOverrides, StateValueOverride};
 use std::str::FromStr;
 pub use table::{RawTableItemRequest, TableItemRequest};
 pub use transaction::{
@@ -50,13 +56,14 @@ pub use transaction::{
     FeePayerSignature, GasEstimation, GasEstimationBcs, GenesisPayload, GenesisTransaction,
     ModuleBundlePayload, MultiAgentSignature, MultiEd25519Signature, MultisigPayload,
     MultisigTransactionPayload, PendingTransaction, ScriptPayload, ScriptWriteSet,
-    SubmitTransactionRequest, Transaction, TransactionData, TransactionId, TransactionInfo,
-    TransactionOnChainData, TransactionPayload, TransactionSignature, TransactionSigningMessage,
-    TransactionsBatchSingleSubmissionFailure, TransactionsBatchSubmissionResult,
-    UserCreateSigningMessageRequest, UserTransaction, UserTransactionRequest, VersionedEvent,
-    WriteModule, WriteResource, WriteSet, WriteSetChange, WriteSetPayload, WriteTableItem,
+    SimulateTransactionRequest, SubmitTransactionRequest, Transaction, TransactionData,
+    TransactionId, TransactionInfo, TransactionOnChainData, TransactionPayload,
+    TransactionSignature, TransactionSigningMessage, TransactionsBatchSingleSubmissionFailure,
+    TransactionsBatchSubmissionResult, UserCreateSigningMessageRequest, UserTransaction,
+    UserTransactionRequest, VersionedEvent, WriteModule, WriteResource, WriteSet, WriteSetChange,
+    WriteSetPayload, WriteTableItem,
 };
-pub use view::ViewRequest;
+pub use view::{ViewBatchRequest, ViewBatchResult, ViewRequest};
 pub use wrappers::{EventGuid, IdentifierWrapper, StateKeyWrapper};
 
 pub fn deserialize_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
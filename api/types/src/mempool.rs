@@ -0,0 +1,26 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{PendingTransaction, U64};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// A transaction waiting in the node's mempool
+///
+/// Along with the transaction, this contains how the mempool is handling it, e.g. to
+/// find out why a transaction has not been included in a block yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct MempoolTransaction {
+    pub transaction: PendingTransaction,
+    /// Score the mempool orders transactions by, higher scores are pulled into blocks first
+    pub ranking_score: U64,
+    /// Time the transaction was inserted into the mempool in Unix epoch microseconds
+    pub insertion_timestamp_usecs: U64,
+    /// Broadcast bucket of the transaction, based on its ranking score
+    pub bucket: String,
+    /// Whether the transaction is parked
+    ///
+    /// Parked transactions are waiting for a transaction with a lower sequence number
+    /// from the same account, and can't be included in a block until it arrives.
+    pub parked: bool,
+}
@@ -0,0 +1,69 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Address, HexEncodedBytes, MoveStructTag};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// State to run a simulation or a view function against instead of the on-chain state
+///
+/// The overrides are layered over the state at the requested version and only apply to the
+/// request they are sent with, nothing is written to storage.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct StateOverrides {
+    /// Resources to replace, create or delete
+    #[serde(default)]
+    #[oai(default)]
+    pub resources: Vec<ResourceOverride>,
+    /// Modules to replace or publish
+    #[serde(default)]
+    #[oai(default)]
+    pub modules: Vec<ModuleOverride>,
+    /// Raw state values to replace, create or delete
+    #[serde(default)]
+    #[oai(default)]
+    pub state_values: Vec<StateValueOverride>,
+}
+
+/// Override of a resource
+///
+/// The new value is given either as JSON, in the same format resources are returned in,
+/// or as BCS. If neither is given, the resource is deleted.
+///
+/// Resources stored in a resource group, e.g. the ones of objects, can only be overridden
+/// through the state value of the whole group.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ResourceOverride {
+    pub address: Address,
+    #[serde(rename = "type")]
+    #[oai(rename = "type")]
+    pub typ: MoveStructTag,
+    /// New value of the resource as JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub data: Option<serde_json::Value>,
+    /// New value of the resource as BCS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub bcs_data: Option<HexEncodedBytes>,
+}
+
+/// Override of a module
+///
+/// The module is published under the address and name in its bytecode, replacing the
+/// module already there if any, without checking that the upgrade is compatible.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ModuleOverride {
+    pub bytecode: HexEncodedBytes,
+}
+
+/// Override of a raw state value
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct StateValueOverride {
+    /// BCS encoded state key
+    pub state_key: HexEncodedBytes,
+    /// New value, the state value is deleted if not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub value: Option<HexEncodedBytes>,
+}
@@ -3,9 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    Address, AptosError, EntryFunctionId, EventGuid, HashValue, HexEncodedBytes,
+    Address, AptosError, EntryFunctionId, EventGuid, GasProfile, HashValue, HexEncodedBytes,
     MoveModuleBytecode, MoveModuleId, MoveResource, MoveScriptBytecode, MoveStructTag, MoveType,
-    MoveValue, VerifyInput, VerifyInputWithRecursion, U64,
+    MoveValue, StateOverrides, VerifyInput, VerifyInputWithRecursion, U64,
 };
 use anyhow::{bail, Context as AnyhowContext};
 use aptos_crypto::{
@@ -264,6 +264,7 @@ impl
             request: (txn, payload).into(),
             events,
             timestamp: timestamp.into(),
+            gas_profile: None,
         }))
     }
 }
@@ -364,6 +365,10 @@ pub struct UserTransaction {
     /// Events generated by the transaction
     pub events: Vec<Event>,
     pub timestamp: U64,
+    /// Gas breakdown of the transaction, only returned when simulating with `profile_gas`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub gas_profile: Option<GasProfile>,
 }
 
 /// A state checkpoint transaction
@@ -393,6 +398,26 @@ impl VerifyInput for SubmitTransactionRequest {
     }
 }
 
+/// A request to simulate a transaction
+///
+/// This is a request to submit a transaction, optionally along with state to simulate
+/// it against instead of the on-chain state
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct SimulateTransactionRequest {
+    #[serde(flatten)]
+    #[oai(flatten)]
+    pub transaction: SubmitTransactionRequest,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub state_overrides: Option<StateOverrides>,
+}
+
+impl VerifyInput for SimulateTransactionRequest {
+    fn verify(&self) -> anyhow::Result<()> {
+        self.transaction.verify()
+    }
+}
+
 /// Batch transaction submission result
 ///
 /// Tells which transactions failed
@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EntryFunctionId, MoveType};
+use crate::{AptosError, EntryFunctionId, MoveType, StateOverrides, U64};
 use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 
@@ -13,4 +13,37 @@ pub struct ViewRequest {
     pub type_arguments: Vec<MoveType>,
     /// Arguments of the function
     pub arguments: Vec<serde_json::Value>,
+    /// State to execute the function against instead of the on-chain state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub state_overrides: Option<StateOverrides>,
+}
+
+/// View request for the batch Move View Function API
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ViewBatchRequest {
+    #[serde(flatten)]
+    #[oai(flatten)]
+    pub request: ViewRequest,
+    /// Ledger version to execute the function at
+    ///
+    /// If not provided, it will be the latest version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub ledger_version: Option<U64>,
+}
+
+/// Outcome of one view function of a batch
+///
+/// Exactly one of `values` and `error` is set.
+#[derive(Clone, Debug, Serialize, Deserialize, Object)]
+pub struct ViewBatchResult {
+    /// Return values of the function, as the Move View Function API returns them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub values: Option<Vec<serde_json::Value>>,
+    /// Why the function failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub error: Option<AptosError>,
 }
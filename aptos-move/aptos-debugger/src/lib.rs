@@ -3,21 +3,25 @@
 
 use anyhow::{format_err, Result};
 use aptos_gas_meter::{StandardGasAlgebra, StandardGasMeter};
-use aptos_gas_profiling::{GasProfiler, TransactionGasLog};
+use aptos_gas_profiling::{GasHotspotReport, GasProfiler, TransactionGasLog};
 use aptos_gas_schedule::{MiscGasParameters, NativeGasParameters, LATEST_GAS_FEATURE_VERSION};
 use aptos_memory_usage_tracker::MemoryTrackedGasMeter;
 use aptos_resource_viewer::{AnnotatedAccountStateBlob, AptosValueAnnotator};
 use aptos_rest_client::Client;
-use aptos_state_view::TStateView;
+use aptos_state_view::{overlay_state_view::OverlayStateView, StateView, TStateView};
 use aptos_types::{
+    access_path::AccessPath,
     account_address::AccountAddress,
     chain_id::ChainId,
+    contract_event::ContractEvent,
     on_chain_config::{Features, OnChainConfig, TimedFeatures},
+    state_store::{state_key::StateKey, state_value::StateValue},
     transaction::{
-        SignedTransaction, Transaction, TransactionInfo, TransactionOutput, TransactionPayload,
-        Version,
+        ExecutionStatus, SignedTransaction, Transaction, TransactionInfo, TransactionOutput,
+        TransactionPayload, TransactionStatus, Version,
     },
     vm_status::VMStatus,
+    write_set::{WriteOp, WriteSet},
 };
 use aptos_validator_interface::{
     AptosValidatorInterface, DBDebuggerInterface, DebuggerStateView, RestDebuggerInterface,
@@ -29,7 +33,7 @@ use aptos_vm::{
 };
 use aptos_vm_logging::log_schema::AdapterLogSchema;
 use aptos_vm_types::{change_set::VMChangeSet, output::VMOutput, storage::ChangeSetConfigs};
-use move_binary_format::errors::VMResult;
+use move_binary_format::{errors::VMResult, CompiledModule};
 use std::{path::Path, sync::Arc};
 
 pub struct AptosDebugger {
@@ -55,8 +59,20 @@ impl AptosDebugger {
         &self,
         version: Version,
         txns: Vec<Transaction>,
+    ) -> Result<Vec<TransactionOutput>> {
+        self.execute_transactions_at_version_with_overrides(version, txns, &[])
+    }
+
+    /// Executes the transactions with `modules` in place of the modules on chain of the same
+    /// name, or in addition to them.
+    pub fn execute_transactions_at_version_with_overrides(
+        &self,
+        version: Version,
+        txns: Vec<Transaction>,
+        modules: &[CompiledModule],
     ) -> Result<Vec<TransactionOutput>> {
         let state_view = DebuggerStateView::new(self.debugger.clone(), version);
+        let state_view = override_modules(&state_view, modules)?;
         AptosVM::execute_block(txns, &state_view, None)
             .map_err(|err| format_err!("Unexpected VM Error: {:?}", err))
     }
@@ -133,6 +149,82 @@ impl AptosDebugger {
         Ok(ret)
     }
 
+    /// Replays the committed transactions with `modules` in place of the modules on chain, and
+    /// compares their outputs with the committed ones.
+    pub async fn execute_past_transactions_with_overrides(
+        &self,
+        mut begin: Version,
+        mut limit: u64,
+        modules: &[CompiledModule],
+    ) -> Result<Vec<TransactionOutputDiff>> {
+        let (mut txns, mut txn_infos, mut committed_outputs) = self
+            .debugger
+            .get_committed_transactions_with_outputs(begin, limit)
+            .await?;
+
+        let mut ret = vec![];
+        while limit != 0 {
+            println!(
+                "Starting epoch execution at {:?}, {:?} transactions remaining",
+                begin, limit
+            );
+            let epoch_result = self
+                .execute_transactions_by_epoch_with_overrides(begin, txns.clone(), modules)
+                .await?;
+            let epoch_txn_infos = txn_infos.drain(0..epoch_result.len());
+            let epoch_committed_outputs = committed_outputs.drain(0..epoch_result.len());
+            for (idx, ((output, txn_info), (write_set, events))) in epoch_result
+                .iter()
+                .zip(epoch_txn_infos)
+                .zip(epoch_committed_outputs)
+                .enumerate()
+            {
+                ret.push(TransactionOutputDiff::new(
+                    begin + idx as Version,
+                    &txn_info,
+                    &write_set,
+                    &events,
+                    output,
+                ));
+            }
+            begin += epoch_result.len() as u64;
+            limit -= epoch_result.len() as u64;
+            txns = txns.split_off(epoch_result.len());
+        }
+        Ok(ret)
+    }
+
+    /// Replays the committed user transactions with the gas profiler attached, and merges their
+    /// gas logs into a report of what costs the most gas across the range. Module publishing and
+    /// multisig transactions aren't supported by the profiler and are skipped.
+    pub async fn profile_gas_of_past_transactions(
+        &self,
+        begin: Version,
+        limit: u64,
+    ) -> Result<GasHotspotReport> {
+        let (txns, _) = self
+            .debugger
+            .get_committed_transactions(begin, limit)
+            .await?;
+
+        let mut report = GasHotspotReport::new();
+        for (idx, txn) in txns.into_iter().enumerate() {
+            let Transaction::UserTransaction(txn) = txn else {
+                continue;
+            };
+            if matches!(
+                txn.payload(),
+                TransactionPayload::ModuleBundle(..) | TransactionPayload::Multisig(..)
+            ) {
+                continue;
+            }
+            let (_, _, gas_log) =
+                self.execute_transaction_at_version_with_gas_profiler(begin + idx as Version, txn)?;
+            report.add(&gas_log)?;
+        }
+        Ok(report)
+    }
+
     fn print_mismatches(
         txn_outputs: &[TransactionOutput],
         expected_txn_infos: &[TransactionInfo],
@@ -153,7 +245,17 @@ impl AptosDebugger {
         begin: Version,
         txns: Vec<Transaction>,
     ) -> Result<Vec<TransactionOutput>> {
-        let results = self.execute_transactions_at_version(begin, txns)?;
+        self.execute_transactions_by_epoch_with_overrides(begin, txns, &[])
+            .await
+    }
+
+    pub async fn execute_transactions_by_epoch_with_overrides(
+        &self,
+        begin: Version,
+        txns: Vec<Transaction>,
+        modules: &[CompiledModule],
+    ) -> Result<Vec<TransactionOutput>> {
+        let results = self.execute_transactions_at_version_with_overrides(begin, txns, modules)?;
         let mut ret = vec![];
         let mut is_reconfig = false;
 
@@ -220,10 +322,25 @@ impl AptosDebugger {
     }
 
     pub fn run_session_at_version<F>(&self, version: Version, f: F) -> Result<VMChangeSet>
+    where
+        F: FnOnce(&mut SessionExt) -> VMResult<()>,
+    {
+        self.run_session_at_version_with_overrides(version, &[], f)
+    }
+
+    /// Runs the session with `modules` in place of the modules on chain of the same name, or in
+    /// addition to them.
+    pub fn run_session_at_version_with_overrides<F>(
+        &self,
+        version: Version,
+        modules: &[CompiledModule],
+        f: F,
+    ) -> Result<VMChangeSet>
     where
         F: FnOnce(&mut SessionExt) -> VMResult<()>,
     {
         let state_view = DebuggerStateView::new(self.debugger.clone(), version);
+        let state_view = override_modules(&state_view, modules)?;
         let state_view_storage = StorageAdapter::new(&state_view);
         let features = Features::fetch_config(&state_view_storage).unwrap_or_default();
         let move_vm = MoveVmExt::new(
@@ -247,6 +364,94 @@ impl AptosDebugger {
     }
 }
 
+fn override_modules<'a, S: StateView>(
+    state_view: &'a S,
+    modules: &[CompiledModule],
+) -> Result<OverlayStateView<'a, S>> {
+    let mut overlay_state_view = OverlayStateView::new(state_view);
+    for module in modules {
+        let mut bytes = vec![];
+        module.serialize(&mut bytes)?;
+        overlay_state_view.set_state_value(
+            StateKey::access_path(AccessPath::code_access_path(module.self_id())),
+            Some(StateValue::new_legacy(bytes)),
+        );
+    }
+    Ok(overlay_state_view)
+}
+
+/// How the output of a replayed transaction differs from the committed one
+#[derive(Debug)]
+pub struct TransactionOutputDiff {
+    pub version: Version,
+    pub committed_status: ExecutionStatus,
+    pub status: TransactionStatus,
+    pub committed_gas_used: u64,
+    pub gas_used: u64,
+    /// The state keys written differently, with the committed write and the replayed one, if any
+    pub write_set: Vec<(StateKey, Option<WriteOp>, Option<WriteOp>)>,
+    /// Committed events which weren't emitted by the replayed transaction
+    pub removed_events: Vec<ContractEvent>,
+    /// Events emitted by the replayed transaction which weren't committed
+    pub added_events: Vec<ContractEvent>,
+}
+
+impl TransactionOutputDiff {
+    fn new(
+        version: Version,
+        txn_info: &TransactionInfo,
+        committed_write_set: &WriteSet,
+        committed_events: &[ContractEvent],
+        output: &TransactionOutput,
+    ) -> Self {
+        let mut write_set = vec![];
+        for (state_key, committed_write_op) in committed_write_set {
+            let write_op = output.write_set().get(state_key);
+            if write_op != Some(committed_write_op) {
+                write_set.push((
+                    state_key.clone(),
+                    Some(committed_write_op.clone()),
+                    write_op.cloned(),
+                ));
+            }
+        }
+        for (state_key, write_op) in output.write_set() {
+            if committed_write_set.get(state_key).is_none() {
+                write_set.push((state_key.clone(), None, Some(write_op.clone())));
+            }
+        }
+
+        Self {
+            version,
+            committed_status: txn_info.status().clone(),
+            status: output.status().clone(),
+            committed_gas_used: txn_info.gas_used(),
+            gas_used: output.gas_used(),
+            write_set,
+            removed_events: committed_events
+                .iter()
+                .filter(|event| !output.events().contains(event))
+                .cloned()
+                .collect(),
+            added_events: output
+                .events()
+                .iter()
+                .filter(|event| !committed_events.contains(event))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Whether the replayed transaction had the same output as the committed one
+    pub fn is_unchanged(&self) -> bool {
+        self.status == TransactionStatus::Keep(self.committed_status.clone())
+            && self.gas_used == self.committed_gas_used
+            && self.write_set.is_empty()
+            && self.removed_events.is_empty()
+            && self.added_events.is_empty()
+    }
+}
+
 fn is_reconfiguration(vm_output: &TransactionOutput) -> bool {
     let new_epoch_event_key = aptos_types::on_chain_config::new_epoch_event_key();
     vm_output
@@ -254,3 +459,113 @@ fn is_reconfiguration(vm_output: &TransactionOutput) -> bool {
         .iter()
         .any(|event| *event.key() == new_epoch_event_key)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_types::{event::EventKey, write_set::WriteSetMut};
+    use move_core_types::language_storage::TypeTag;
+
+    fn key(name: &str) -> StateKey {
+        StateKey::raw(name.as_bytes().to_vec())
+    }
+
+    fn write_set(write_ops: Vec<(StateKey, WriteOp)>) -> WriteSet {
+        WriteSetMut::new(write_ops).freeze().unwrap()
+    }
+
+    fn event(sequence_number: u64) -> ContractEvent {
+        ContractEvent::new(
+            EventKey::new(0, AccountAddress::ONE),
+            sequence_number,
+            TypeTag::U64,
+            bcs::to_bytes(&sequence_number).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_transaction_output_diff() {
+        let txn_info = TransactionInfo::new_placeholder(10, None, ExecutionStatus::Success);
+        let committed_write_set = write_set(vec![
+            (key("unchanged"), WriteOp::Modification(vec![1])),
+            (key("changed"), WriteOp::Modification(vec![2])),
+            (key("removed"), WriteOp::Deletion),
+        ]);
+        let committed_events = vec![event(0), event(1)];
+
+        // Same output as committed
+        let output = TransactionOutput::new(
+            committed_write_set.clone(),
+            committed_events.clone(),
+            10,
+            TransactionStatus::Keep(ExecutionStatus::Success),
+        );
+        let diff = TransactionOutputDiff::new(
+            5,
+            &txn_info,
+            &committed_write_set,
+            &committed_events,
+            &output,
+        );
+        assert!(diff.is_unchanged(), "{:#?}", diff);
+
+        // Different writes, events, gas and status
+        let output = TransactionOutput::new(
+            write_set(vec![
+                (key("unchanged"), WriteOp::Modification(vec![1])),
+                (key("changed"), WriteOp::Modification(vec![3])),
+                (key("added"), WriteOp::Creation(vec![4])),
+            ]),
+            vec![event(1), event(2)],
+            12,
+            TransactionStatus::Keep(ExecutionStatus::OutOfGas),
+        );
+        let diff = TransactionOutputDiff::new(
+            5,
+            &txn_info,
+            &committed_write_set,
+            &committed_events,
+            &output,
+        );
+        assert!(!diff.is_unchanged());
+        assert_eq!(diff.version, 5);
+        assert_eq!(diff.committed_status, ExecutionStatus::Success);
+        assert_eq!(
+            diff.status,
+            TransactionStatus::Keep(ExecutionStatus::OutOfGas)
+        );
+        assert_eq!((diff.committed_gas_used, diff.gas_used), (10, 12));
+        let mut write_set_diff = diff.write_set.clone();
+        write_set_diff.sort_by(|(left, ..), (right, ..)| left.cmp(right));
+        let mut expected = vec![
+            (
+                key("changed"),
+                Some(WriteOp::Modification(vec![2])),
+                Some(WriteOp::Modification(vec![3])),
+            ),
+            (key("removed"), Some(WriteOp::Deletion), None),
+            (key("added"), None, Some(WriteOp::Creation(vec![4]))),
+        ];
+        expected.sort_by(|(left, ..), (right, ..)| left.cmp(right));
+        assert_eq!(write_set_diff, expected);
+        assert_eq!(diff.removed_events, vec![event(0)]);
+        assert_eq!(diff.added_events, vec![event(2)]);
+
+        // A different gas usage alone is a change too
+        let output = TransactionOutput::new(
+            committed_write_set.clone(),
+            committed_events.clone(),
+            11,
+            TransactionStatus::Keep(ExecutionStatus::Success),
+        );
+        let diff = TransactionOutputDiff::new(
+            5,
+            &txn_info,
+            &committed_write_set,
+            &committed_events,
+            &output,
+        );
+        assert!(diff.write_set.is_empty());
+        assert!(!diff.is_unchanged());
+    }
+}
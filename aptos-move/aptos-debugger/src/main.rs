@@ -1,12 +1,14 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{format_err, Result};
 use aptos_debugger::AptosDebugger;
+use aptos_gas_profiling::GasHotspotReport;
 use aptos_rest_client::Client;
 use aptos_vm::AptosVM;
 use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use move_binary_format::CompiledModule;
+use std::path::{Path, PathBuf};
 use url::Url;
 
 #[derive(Subcommand)]
@@ -29,6 +31,24 @@ pub struct Argument {
 
     #[clap(long, default_value_t = 1)]
     concurrency_level: usize,
+
+    /// Compiled modules (`.mv` files) to replay the transactions with, in place of the modules
+    /// on chain. Directories are searched for `.mv` files, without recursing, e.g. the
+    /// `build/<package>/bytecode_modules` directory of a package. The outputs are then compared
+    /// with the committed ones. Can be repeated, or given as a comma separated list.
+    #[clap(long, value_delimiter = ',')]
+    override_modules: Vec<PathBuf>,
+
+    /// Directory to write a gas report to, instead of printing the outputs. The user transactions
+    /// are replayed with the gas profiler, and the most expensive functions, native calls and
+    /// storage slots across all of them, as well as the most loaded modules, are ranked in
+    /// `report.txt`, along with merged flamegraphs of their execution costs and storage fees.
+    #[clap(long, conflicts_with = "override_modules")]
+    gas_report_dir: Option<PathBuf>,
+
+    /// Number of items to list in each section of the gas report.
+    #[clap(long, default_value_t = 20)]
+    gas_report_top: usize,
 }
 
 #[tokio::main]
@@ -44,13 +64,82 @@ async fn main() -> Result<()> {
         Target::DB { path } => AptosDebugger::db(path)?,
     };
 
-    println!(
-        "{:#?}",
-        debugger
-            .execute_past_transactions(args.begin_version, args.limit)
+    if let Some(dir) = &args.gas_report_dir {
+        let report = debugger
+            .profile_gas_of_past_transactions(args.begin_version, args.limit)
+            .await?;
+        write_gas_report(&report, dir, args.gas_report_top, &args)?;
+    } else if args.override_modules.is_empty() {
+        println!(
+            "{:#?}",
+            debugger
+                .execute_past_transactions(args.begin_version, args.limit)
+                .await?
+        );
+    } else {
+        let mut modules = vec![];
+        for path in &args.override_modules {
+            load_modules(path, &mut modules)?;
+        }
+        for diff in debugger
+            .execute_past_transactions_with_overrides(args.begin_version, args.limit, &modules)
             .await?
+        {
+            if diff.is_unchanged() {
+                println!("Version {}: output unchanged", diff.version);
+            } else {
+                println!("{:#?}", diff);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn write_gas_report(
+    report: &GasHotspotReport,
+    dir: &Path,
+    top: usize,
+    args: &Argument,
+) -> Result<()> {
+    let versions = format!(
+        "Versions {}..{}",
+        args.begin_version,
+        args.begin_version + args.limit
     );
+    std::fs::create_dir_all(dir)?;
+
+    let mut text = String::new();
+    report.textualize(&mut text, top)?;
+    std::fs::write(dir.join("report.txt"), &text)?;
+    println!("{}", text);
+
+    if let Some(graph) =
+        report.to_execution_flamegraph(format!("{} -- Execution & IO", versions))?
+    {
+        std::fs::write(dir.join("exec_io.svg"), graph)?;
+    }
+    if let Some(graph) = report.to_storage_flamegraph(format!("{} -- Storage Fee", versions))? {
+        std::fs::write(dir.join("storage.svg"), graph)?;
+    }
+    println!("Gas report written to {}", dir.display());
+    Ok(())
+}
 
+fn load_modules(path: &Path, modules: &mut Vec<CompiledModule>) -> Result<()> {
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().map_or(false, |ext| ext == "mv") {
+                load_modules(&path, modules)?;
+            }
+        }
+    } else {
+        let module = CompiledModule::deserialize(&std::fs::read(path)?).map_err(|err| {
+            format_err!("Failed to deserialize module {}: {:?}", path.display(), err)
+        })?;
+        modules.push(module);
+    }
     Ok(())
 }
 
@@ -2,10 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    log::{ExecutionAndIOCosts, ExecutionGasEvent},
+    log::{CallFrame, ExecutionAndIOCosts, ExecutionGasEvent, StorageFees},
     render::{Render, TableKey},
 };
-use aptos_gas_algebra::{GasQuantity, GasScalingFactor, InternalGas};
+use aptos_gas_algebra::{Fee, GasQuantity, GasScalingFactor, InternalGas};
 use aptos_types::state_store::state_key::StateKeyInner;
 use std::{
     collections::{btree_map, BTreeMap},
@@ -27,9 +27,24 @@ pub struct AggregatedExecutionGasEvents {
     pub ops: Vec<(String, usize, InternalGas)>,
     pub storage_reads: Vec<(String, usize, InternalGas)>,
     pub storage_writes: Vec<(String, usize, InternalGas)>,
+    /// The cost of the instructions executed in the body of each function, i.e. excluding the
+    /// functions it calls, along with the number of calls.
+    pub functions: Vec<(String, usize, InternalGas)>,
 }
 
-fn insert_or_add<K, U>(
+/// Represents the storage fees of a transaction, aggregated by storage slot and by event type.
+///
+/// The items are sorted by fee, from high to low.
+pub struct AggregatedStorageFees {
+    pub total: Fee,
+
+    pub txn_storage: Fee,
+    pub event_discount: Fee,
+    pub writes: Vec<(String, usize, Fee)>,
+    pub events: Vec<(String, usize, Fee)>,
+}
+
+pub(crate) fn insert_or_add<K, U>(
     map: &mut BTreeMap<K, (usize, GasQuantity<U>)>,
     key: K,
     amount: GasQuantity<U>,
@@ -51,7 +66,7 @@ fn insert_or_add<K, U>(
     }
 }
 
-fn into_sorted_vec<I, K, N>(collection: I) -> Vec<(K, usize, N)>
+pub(crate) fn into_sorted_vec<I, K, N>(collection: I) -> Vec<(K, usize, N)>
 where
     N: Ord,
     I: IntoIterator<Item = (K, (usize, N))>,
@@ -65,6 +80,18 @@ where
     v
 }
 
+fn aggregate_functions(frame: &CallFrame, functions: &mut BTreeMap<String, (usize, InternalGas)>) {
+    let mut cost = InternalGas::new(0);
+    for event in &frame.events {
+        match event {
+            ExecutionGasEvent::Bytecode { cost: op_cost, .. } => cost += *op_cost,
+            ExecutionGasEvent::Call(callee) => aggregate_functions(callee, functions),
+            _ => (),
+        }
+    }
+    insert_or_add(functions, format!("{}", frame.name), cost);
+}
+
 impl ExecutionAndIOCosts {
     /// Counts the number of hits and aggregates the gas costs for each type of event.
     pub fn aggregate_gas_events(&self) -> AggregatedExecutionGasEvents {
@@ -117,6 +144,9 @@ impl ExecutionAndIOCosts {
             insert_or_add(&mut storage_writes, key, write.cost);
         }
 
+        let mut functions = BTreeMap::new();
+        aggregate_functions(&self.call_graph, &mut functions);
+
         AggregatedExecutionGasEvents {
             gas_scaling_factor: self.gas_scaling_factor,
             total: self.total,
@@ -124,6 +154,35 @@ impl ExecutionAndIOCosts {
             ops: into_sorted_vec(ops),
             storage_reads: into_sorted_vec(storage_reads),
             storage_writes: into_sorted_vec(storage_writes),
+            functions: into_sorted_vec(functions),
+        }
+    }
+}
+
+impl StorageFees {
+    /// Aggregates the fees for each storage slot written and each type of event emitted.
+    pub fn aggregate_storage_fees(&self) -> AggregatedStorageFees {
+        let mut writes = BTreeMap::new();
+        for write in &self.write_set_storage {
+            insert_or_add(
+                &mut writes,
+                format!("{}<{}>", Render(&write.op_type), Render(&write.key)),
+                write.cost,
+            );
+        }
+
+        let mut events = BTreeMap::new();
+        for event in &self.events {
+            insert_or_add(&mut events, format!("{}", event.ty), event.cost);
+        }
+
+        AggregatedStorageFees {
+            total: self.total,
+
+            txn_storage: self.txn_storage,
+            event_discount: self.event_discount,
+            writes: into_sorted_vec(writes),
+            events: into_sorted_vec(events),
         }
     }
 }
@@ -5,12 +5,13 @@ use crate::{
     log::{CallFrame, ExecutionAndIOCosts, ExecutionGasEvent, StorageFees},
     render::Render,
 };
+use aptos_gas_algebra::GasScalingFactor;
 use inferno::flamegraph::TextTruncateDirection;
 use move_core_types::gas_algebra::InternalGas;
 use regex::Captures;
 
 #[derive(Debug)]
-struct LineBuffer(Vec<String>);
+struct LineBuffer(Vec<(String, u64)>);
 
 impl LineBuffer {
     fn new() -> Self {
@@ -21,19 +22,82 @@ impl LineBuffer {
         let count: u64 = count.into();
 
         if count > 0 {
-            self.0.push(format!("{} {}", item.as_ref(), count));
+            self.0.push((item.as_ref().to_string(), count));
         }
     }
 
-    fn into_inner(self) -> Vec<String> {
+    fn into_inner(self) -> Vec<(String, u64)> {
         self.0
     }
 }
 
+/// Renders folded stacks, along with their costs, as a flamegraph.
+///
+/// As a flame chart, the stacks are laid out in the order given, otherwise identical stacks are
+/// merged. `fmt_cost` formats the costs shown for each frame.
+pub(crate) fn render_flamegraph(
+    stacks: &[(String, u64)],
+    title: String,
+    flame_chart: bool,
+    fmt_cost: impl Fn(u64) -> String,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    if stacks.is_empty() {
+        return Ok(None);
+    }
+    let lines = stacks
+        .iter()
+        .map(|(stack, count)| format!("{} {}", stack, count))
+        .collect::<Vec<_>>();
+
+    let mut options = inferno::flamegraph::Options::default();
+    options.flame_chart = flame_chart;
+    options.text_truncate_direction = TextTruncateDirection::Right;
+    options.color_diffusion = true;
+    options.title = title;
+
+    let mut graph_content = vec![];
+    inferno::flamegraph::from_lines(
+        &mut options,
+        lines.iter().rev().map(|s| s.as_str()),
+        &mut graph_content,
+    )?;
+    let graph_content = String::from_utf8_lossy(&graph_content);
+
+    // Inferno does not allow us to customize some of the text in the resulting graph,
+    // so we have to do it through regex replacement.
+    let re = regex::Regex::new("([1-9][0-9]*(,[0-9]+)*) samples")
+        .expect("should be able to build regex successfully");
+    let graph_content = re.replace_all(&graph_content, |caps: &Captures| {
+        let count: u64 = caps[1]
+            .replace(',', "")
+            .parse()
+            .expect("should be able parse count as u64");
+
+        fmt_cost(count)
+    });
+
+    Ok(Some(graph_content.as_bytes().to_vec()))
+}
+
+/// Formats a cost in octas for a flamegraph.
+pub(crate) fn fmt_octas(count: u64) -> String {
+    format!("{} Octa", count)
+}
+
+/// Formats a cost in internal gas units for a flamegraph, in gas units.
+pub(crate) fn fmt_gas_units(count: u64, gas_scaling_factor: GasScalingFactor) -> String {
+    let count_scaled = count as f64 / u64::from(gas_scaling_factor) as f64;
+
+    format!(
+        "{} gas units",
+        crate::misc::strip_trailing_zeros_and_decimal_point(&format!("{:.8}", count_scaled))
+    )
+}
+
 impl StorageFees {
-    /// Convert the storage fee log into folded stack lines, which can
+    /// Convert the storage fee log into folded stacks, along with their costs, which can
     /// then be used to generate a flamegraph.
-    fn to_folded_stack_lines(&self) -> Vec<String> {
+    pub(crate) fn to_folded_stacks(&self) -> Vec<(String, u64)> {
         let mut lines = LineBuffer::new();
 
         lines.push("transaction", self.txn_storage);
@@ -56,47 +120,14 @@ impl StorageFees {
     /// Tries to generate a flamegraph from the execution log.
     /// None will be returned if the log is empty.
     pub fn to_flamegraph(&self, title: String) -> anyhow::Result<Option<Vec<u8>>> {
-        let lines = self.to_folded_stack_lines();
-
-        if lines.is_empty() {
-            return Ok(None);
-        }
-
-        let mut options = inferno::flamegraph::Options::default();
-        options.flame_chart = true;
-        options.text_truncate_direction = TextTruncateDirection::Right;
-        options.color_diffusion = true;
-        options.title = title;
-
-        let mut graph_content = vec![];
-        inferno::flamegraph::from_lines(
-            &mut options,
-            lines.iter().rev().map(|s| s.as_str()),
-            &mut graph_content,
-        )?;
-        let graph_content = String::from_utf8_lossy(&graph_content);
-
-        // Inferno does not allow us to customize some of the text in the resulting graph,
-        // so we have to do it through regex replacement.
-        let re = regex::Regex::new("([1-9][0-9]*(,[0-9]+)*) samples")
-            .expect("should be able to build regex successfully");
-        let graph_content = re.replace_all(&graph_content, |caps: &Captures| {
-            let count: u64 = caps[1]
-                .replace(',', "")
-                .parse()
-                .expect("should be able parse count as u64");
-
-            format!("{} Octa", count)
-        });
-
-        Ok(Some(graph_content.as_bytes().to_vec()))
+        render_flamegraph(&self.to_folded_stacks(), title, true, fmt_octas)
     }
 }
 
 impl ExecutionAndIOCosts {
-    /// Convert the execution gas log into folded stack lines, which can
+    /// Convert the execution gas log into folded stacks, along with their costs, which can
     /// then be used to generate a flamegraph.
-    fn to_folded_stack_lines(&self) -> Vec<String> {
+    pub(crate) fn to_folded_stacks(&self) -> Vec<(String, u64)> {
         let mut lines = LineBuffer::new();
 
         lines.push("intrinsic", self.intrinsic_cost);
@@ -169,47 +200,8 @@ impl ExecutionAndIOCosts {
     /// Tries to generate a flamegraph from the execution log.
     /// None will be returned if the log is empty.
     pub fn to_flamegraph(&self, title: String) -> anyhow::Result<Option<Vec<u8>>> {
-        let lines = self.to_folded_stack_lines();
-
-        if lines.is_empty() {
-            return Ok(None);
-        }
-
-        let mut options = inferno::flamegraph::Options::default();
-        options.flame_chart = true;
-        options.text_truncate_direction = TextTruncateDirection::Right;
-        options.color_diffusion = true;
-        options.title = title;
-
-        let mut graph_content = vec![];
-        inferno::flamegraph::from_lines(
-            &mut options,
-            lines.iter().rev().map(|s| s.as_str()),
-            &mut graph_content,
-        )?;
-        let graph_content = String::from_utf8_lossy(&graph_content);
-
-        // Inferno does not allow us to customize some of the text in the resulting graph,
-        // so we have to do it through regex replacement.
-        let re = regex::Regex::new("([1-9][0-9]*(,[0-9]+)*) samples")
-            .expect("should be able to build regex successfully");
-        let graph_content = re.replace_all(&graph_content, |caps: &Captures| {
-            let count: u64 = caps[1]
-                .replace(',', "")
-                .parse()
-                .expect("should be able parse count as u64");
-
-            let count_scaled = count as f64 / u64::from(self.gas_scaling_factor) as f64;
-
-            format!(
-                "{} gas units",
-                crate::misc::strip_trailing_zeros_and_decimal_point(&format!(
-                    "{:.8}",
-                    count_scaled
-                ))
-            )
-        });
-
-        Ok(Some(graph_content.as_bytes().to_vec()))
+        render_flamegraph(&self.to_folded_stacks(), title, true, |count| {
+            fmt_gas_units(count, self.gas_scaling_factor)
+        })
     }
 }
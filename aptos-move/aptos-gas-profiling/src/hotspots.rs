@@ -0,0 +1,491 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    aggregate::{insert_or_add, into_sorted_vec},
+    flamegraph::{fmt_gas_units, fmt_octas, render_flamegraph},
+    log::{CallFrame, ExecutionGasEvent, FrameName, TransactionGasLog},
+    render::Render,
+};
+use anyhow::bail;
+use aptos_gas_algebra::{Fee, GasQuantity, GasScalingFactor, InternalGas};
+use aptos_types::state_store::state_key::{StateKey, StateKeyInner};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Deref,
+};
+
+/// The gas logs of many transactions merged together, e.g. those of all the user transactions in
+/// a range of versions, to find what dominates gas usage across them.
+///
+/// Unlike in the log of a single transaction, functions are named without their type arguments
+/// and storage slots without the address they are stored at, so that all the calls to a generic
+/// function, or all the writes to a type of resource, add up. Loading modules isn't charged for,
+/// so module loads are only counted.
+pub struct GasHotspotReport {
+    /// The gas scaling factor shared by all the transactions, to display costs in gas units.
+    pub gas_scaling_factor: GasScalingFactor,
+    pub num_transactions: usize,
+    /// The total execution and IO cost.
+    pub execution_and_io: InternalGas,
+    /// The total storage fee.
+    pub storage_fee: Fee,
+
+    functions: BTreeMap<String, (usize, InternalGas)>,
+    natives: BTreeMap<String, (usize, InternalGas)>,
+    instructions: BTreeMap<String, (usize, InternalGas)>,
+    storage_reads: BTreeMap<String, (usize, InternalGas)>,
+    storage_writes: BTreeMap<String, (usize, InternalGas)>,
+    storage_fees: BTreeMap<String, (usize, Fee)>,
+    module_loads: BTreeMap<String, usize>,
+
+    execution_stacks: BTreeMap<String, u64>,
+    storage_stacks: BTreeMap<String, u64>,
+}
+
+/// Names a storage slot by what's stored in it, e.g. the type of a resource or the handle of a
+/// table.
+fn storage_slot(key: &StateKey) -> String {
+    use StateKeyInner::*;
+
+    match key.deref() {
+        AccessPath(ap) => format!("{}", Render(&ap.get_path())),
+        TableItem { handle, .. } => format!("table_item<{}>", Render(handle)),
+        Raw(..) => "raw".to_string(),
+    }
+}
+
+fn function_name(name: &FrameName) -> String {
+    match name {
+        FrameName::Script => "<script>".to_string(),
+        FrameName::Function {
+            module_id, name, ..
+        } => format!("{}::{}", Render(module_id), name),
+    }
+}
+
+fn sorted<U>(
+    map: &BTreeMap<String, (usize, GasQuantity<U>)>,
+) -> Vec<(String, usize, GasQuantity<U>)> {
+    into_sorted_vec(map.iter().map(|(key, amount)| (key.clone(), *amount)))
+}
+
+fn add_stacks(map: &mut BTreeMap<String, u64>, stacks: Vec<(String, u64)>) {
+    for (stack, cost) in stacks {
+        *map.entry(stack).or_default() += cost;
+    }
+}
+
+impl GasHotspotReport {
+    pub fn new() -> Self {
+        Self {
+            gas_scaling_factor: GasScalingFactor::new(1),
+            num_transactions: 0,
+            execution_and_io: InternalGas::new(0),
+            storage_fee: Fee::new(0),
+
+            functions: BTreeMap::new(),
+            natives: BTreeMap::new(),
+            instructions: BTreeMap::new(),
+            storage_reads: BTreeMap::new(),
+            storage_writes: BTreeMap::new(),
+            storage_fees: BTreeMap::new(),
+            module_loads: BTreeMap::new(),
+
+            execution_stacks: BTreeMap::new(),
+            storage_stacks: BTreeMap::new(),
+        }
+    }
+
+    /// Merges the gas log of one more transaction into the report.
+    ///
+    /// Costs are added up in internal gas units, so the transactions must all have the same gas
+    /// scaling factor, which only changes with the gas schedule.
+    pub fn add(&mut self, log: &TransactionGasLog) -> anyhow::Result<()> {
+        use ExecutionGasEvent::*;
+
+        let exec_io = &log.exec_io;
+        if self.num_transactions == 0 {
+            self.gas_scaling_factor = exec_io.gas_scaling_factor;
+        } else if self.gas_scaling_factor != exec_io.gas_scaling_factor {
+            bail!(
+                "The gas scaling factor changed from {} to {}, the gas schedule must be the same \
+                 for all the transactions",
+                self.gas_scaling_factor,
+                exec_io.gas_scaling_factor
+            );
+        }
+        self.num_transactions += 1;
+        self.execution_and_io += exec_io.total;
+        self.storage_fee += log.storage.total;
+
+        self.add_function(&exec_io.call_graph);
+        let mut modules = BTreeSet::new();
+        if let FrameName::Function { module_id, .. } = &exec_io.call_graph.name {
+            modules.insert(format!("{}", Render(module_id)));
+        }
+        for event in exec_io.gas_events() {
+            match event {
+                Loc(..) => (),
+                Call(frame) => {
+                    if let FrameName::Function { module_id, .. } = &frame.name {
+                        modules.insert(format!("{}", Render(module_id)));
+                    }
+                },
+                Bytecode { op, cost } => insert_or_add(
+                    &mut self.instructions,
+                    format!("{:?}", op).to_ascii_lowercase(),
+                    *cost,
+                ),
+                CallNative {
+                    module_id,
+                    fn_name,
+                    cost,
+                    ..
+                } => {
+                    modules.insert(format!("{}", Render(module_id)));
+                    insert_or_add(
+                        &mut self.natives,
+                        format!("{}::{}", Render(module_id), fn_name),
+                        *cost,
+                    )
+                },
+                LoadResource { ty, cost, .. } => {
+                    insert_or_add(&mut self.storage_reads, format!("{}", ty), *cost)
+                },
+            }
+        }
+        for write in &exec_io.write_set_transient {
+            insert_or_add(
+                &mut self.storage_writes,
+                storage_slot(&write.key),
+                write.cost,
+            );
+        }
+        for write in &log.storage.write_set_storage {
+            insert_or_add(&mut self.storage_fees, storage_slot(&write.key), write.cost);
+        }
+        for module in modules {
+            *self.module_loads.entry(module).or_default() += 1;
+        }
+
+        add_stacks(&mut self.execution_stacks, exec_io.to_folded_stacks());
+        add_stacks(&mut self.storage_stacks, log.storage.to_folded_stacks());
+        Ok(())
+    }
+
+    fn add_function(&mut self, frame: &CallFrame) {
+        let mut cost = InternalGas::new(0);
+        for event in &frame.events {
+            match event {
+                ExecutionGasEvent::Bytecode { cost: op_cost, .. } => cost += *op_cost,
+                ExecutionGasEvent::Call(callee) => self.add_function(callee),
+                _ => (),
+            }
+        }
+        insert_or_add(&mut self.functions, function_name(&frame.name), cost);
+    }
+
+    /// The cost of the instructions executed in the body of each function, i.e. excluding the
+    /// functions it calls, along with the number of calls.
+    ///
+    /// Like for all the items of the report, sorted by cost, from high to low.
+    pub fn functions(&self) -> Vec<(String, usize, InternalGas)> {
+        sorted(&self.functions)
+    }
+
+    pub fn natives(&self) -> Vec<(String, usize, InternalGas)> {
+        sorted(&self.natives)
+    }
+
+    pub fn instructions(&self) -> Vec<(String, usize, InternalGas)> {
+        sorted(&self.instructions)
+    }
+
+    pub fn storage_reads(&self) -> Vec<(String, usize, InternalGas)> {
+        sorted(&self.storage_reads)
+    }
+
+    /// The IO cost of the writes to each kind of storage slot.
+    pub fn storage_writes(&self) -> Vec<(String, usize, InternalGas)> {
+        sorted(&self.storage_writes)
+    }
+
+    /// The storage fees for the writes to each kind of storage slot.
+    pub fn storage_fees(&self) -> Vec<(String, usize, Fee)> {
+        sorted(&self.storage_fees)
+    }
+
+    /// The number of transactions loading each module, to call its functions, sorted from most to
+    /// least. The dependencies loaded along with a module aren't counted.
+    pub fn module_loads(&self) -> Vec<(String, usize)> {
+        let mut module_loads = self
+            .module_loads
+            .iter()
+            .map(|(module, count)| (module.clone(), *count))
+            .collect::<Vec<_>>();
+        module_loads.sort_by(|(_, count1), (_, count2)| count2.cmp(count1));
+        module_loads
+    }
+
+    /// Generates a flamegraph of the execution and IO costs of all the transactions, with the
+    /// identical stacks of different transactions merged. None will be returned if there is no
+    /// cost at all.
+    pub fn to_execution_flamegraph(&self, title: String) -> anyhow::Result<Option<Vec<u8>>> {
+        let stacks = self
+            .execution_stacks
+            .clone()
+            .into_iter()
+            .collect::<Vec<_>>();
+        render_flamegraph(&stacks, title, false, |count| {
+            fmt_gas_units(count, self.gas_scaling_factor)
+        })
+    }
+
+    /// Generates a flamegraph of the storage fees of all the transactions, with the identical
+    /// stacks of different transactions merged. None will be returned if there is no fee at all.
+    pub fn to_storage_flamegraph(&self, title: String) -> anyhow::Result<Option<Vec<u8>>> {
+        let stacks = self.storage_stacks.clone().into_iter().collect::<Vec<_>>();
+        render_flamegraph(&stacks, title, false, fmt_octas)
+    }
+}
+
+impl Default for GasHotspotReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::{ExecutionAndIOCosts, StorageFees, WriteOpType, WriteStorage, WriteTransient};
+    use move_binary_format::file_format_common::Opcodes;
+    use move_core_types::{
+        account_address::AccountAddress,
+        identifier::Identifier,
+        language_storage::{ModuleId, TypeTag},
+    };
+
+    fn frame(module: &str, function: &str, events: Vec<ExecutionGasEvent>) -> CallFrame {
+        CallFrame {
+            name: FrameName::Function {
+                module_id: ModuleId::new(AccountAddress::ONE, Identifier::new(module).unwrap()),
+                name: Identifier::new(function).unwrap(),
+                ty_args: vec![],
+            },
+            events,
+        }
+    }
+
+    fn bytecode(cost: u64) -> ExecutionGasEvent {
+        ExecutionGasEvent::Bytecode {
+            op: Opcodes::ADD,
+            cost: InternalGas::new(cost),
+        }
+    }
+
+    /// The log of a transfer: `coin::transfer` calls `coin::withdraw` and a native, reads a
+    /// resource and writes a storage slot.
+    fn transfer_log(gas_scaling_factor: u64) -> TransactionGasLog {
+        let withdraw = frame("coin", "withdraw", vec![bytecode(5), bytecode(5)]);
+        let call_graph = frame(
+            "coin",
+            "transfer",
+            vec![
+                bytecode(20),
+                ExecutionGasEvent::Call(withdraw),
+                ExecutionGasEvent::CallNative {
+                    module_id: ModuleId::new(
+                        AccountAddress::ONE,
+                        Identifier::new("signer").unwrap(),
+                    ),
+                    fn_name: Identifier::new("borrow_address").unwrap(),
+                    ty_args: vec![],
+                    cost: InternalGas::new(3),
+                },
+                ExecutionGasEvent::LoadResource {
+                    addr: AccountAddress::ONE,
+                    ty: TypeTag::U64,
+                    cost: InternalGas::new(7),
+                },
+            ],
+        );
+        TransactionGasLog {
+            exec_io: ExecutionAndIOCosts {
+                gas_scaling_factor: GasScalingFactor::new(gas_scaling_factor),
+                total: InternalGas::new(100),
+                intrinsic_cost: InternalGas::new(40),
+                call_graph,
+                write_set_transient: vec![WriteTransient {
+                    key: StateKey::raw(vec![1]),
+                    op_type: WriteOpType::Modification,
+                    cost: InternalGas::new(20),
+                }],
+            },
+            storage: StorageFees {
+                total: Fee::new(50),
+                write_set_storage: vec![WriteStorage {
+                    key: StateKey::raw(vec![1]),
+                    op_type: WriteOpType::Creation,
+                    cost: Fee::new(50),
+                }],
+                events: vec![],
+                event_discount: Fee::new(0),
+                txn_storage: Fee::new(0),
+            },
+        }
+    }
+
+    /// The log of a transaction only running the body of `account::create`.
+    fn create_account_log() -> TransactionGasLog {
+        TransactionGasLog {
+            exec_io: ExecutionAndIOCosts {
+                gas_scaling_factor: GasScalingFactor::new(10),
+                total: InternalGas::new(60),
+                intrinsic_cost: InternalGas::new(0),
+                call_graph: frame("account", "create", vec![bytecode(60)]),
+                write_set_transient: vec![],
+            },
+            storage: StorageFees {
+                total: Fee::new(0),
+                write_set_storage: vec![],
+                events: vec![],
+                event_discount: Fee::new(0),
+                txn_storage: Fee::new(0),
+            },
+        }
+    }
+
+    fn report() -> GasHotspotReport {
+        let mut report = GasHotspotReport::new();
+        report.add(&transfer_log(10)).unwrap();
+        report.add(&transfer_log(10)).unwrap();
+        report.add(&create_account_log()).unwrap();
+        report
+    }
+
+    #[test]
+    fn test_add() {
+        let report = report();
+        assert_eq!(report.num_transactions, 3);
+        assert_eq!(report.gas_scaling_factor, GasScalingFactor::new(10));
+        assert_eq!(report.execution_and_io, InternalGas::new(260));
+        assert_eq!(report.storage_fee, Fee::new(100));
+    }
+
+    #[test]
+    fn test_rankings() {
+        let report = report();
+        let gas = InternalGas::new;
+        assert_eq!(
+            report.functions(),
+            vec![
+                ("0x1::account::create".to_string(), 1, gas(60)),
+                ("0x1::coin::transfer".to_string(), 2, gas(40)),
+                ("0x1::coin::withdraw".to_string(), 2, gas(20)),
+            ]
+        );
+        assert_eq!(
+            report.natives(),
+            vec![("0x1::signer::borrow_address".to_string(), 2, gas(6))]
+        );
+        assert_eq!(
+            report.instructions(),
+            vec![("add".to_string(), 7, gas(120))]
+        );
+        assert_eq!(
+            report.storage_reads(),
+            vec![("u64".to_string(), 2, gas(14))]
+        );
+        assert_eq!(
+            report.storage_writes(),
+            vec![("raw".to_string(), 2, gas(40))]
+        );
+        assert_eq!(
+            report.storage_fees(),
+            vec![("raw".to_string(), 2, Fee::new(100))]
+        );
+        assert_eq!(
+            report.module_loads(),
+            vec![
+                ("0x1::coin".to_string(), 2),
+                ("0x1::signer".to_string(), 2),
+                ("0x1::account".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mixed_gas_scaling_factors() {
+        let mut report = GasHotspotReport::new();
+        report.add(&transfer_log(10)).unwrap();
+        assert!(report.add(&transfer_log(100)).is_err());
+        assert_eq!(report.num_transactions, 1);
+    }
+
+    #[test]
+    fn test_textualize() {
+        let report = report();
+        let mut text = String::new();
+        report.textualize(&mut text, 2).unwrap();
+
+        assert!(text.starts_with("3 transactions\n"));
+        // The columns of a row, looked up by its first column.
+        let row = |name: &str| {
+            text.lines().find_map(|line| {
+                let mut columns = line.split("  ").map(str::trim).filter(|c| !c.is_empty());
+                if columns.next() == Some(name) {
+                    Some(columns.collect::<Vec<_>>())
+                } else {
+                    None
+                }
+            })
+        };
+        // Costs are shown in gas units, with their share of the total.
+        assert_eq!(
+            row("execution & IO (gas unit, all transactions)"),
+            Some(vec!["26"])
+        );
+        assert_eq!(row("0x1::account::create"), Some(vec!["x1", "6", "23.08%"]));
+        assert_eq!(row("0x1::coin::transfer"), Some(vec!["x2", "4", "15.38%"]));
+        assert_eq!(row("raw"), Some(vec!["x2", "4", "15.38%"]));
+        assert_eq!(
+            row("storage fees (APT, all transactions)"),
+            Some(vec!["0.000001"])
+        );
+        assert_eq!(row("0x1::coin"), Some(vec!["x2", "66.67%"]));
+        assert_eq!(row("0x1::signer"), Some(vec!["x2", "66.67%"]));
+        // Only the top 2 items of each kind are listed.
+        assert_eq!(row("0x1::coin::withdraw"), None);
+        assert_eq!(row("0x1::account"), None);
+    }
+
+    #[test]
+    fn test_merged_flamegraphs() {
+        let report = report();
+        let stack = |name: &str| report.execution_stacks.get(name).copied();
+        // Identical stacks of different transactions are merged
+        assert_eq!(stack("intrinsic"), Some(80));
+        assert_eq!(stack("0x1::coin::transfer"), Some(40));
+        assert_eq!(stack("0x1::coin::transfer;0x1::coin::withdraw"), Some(20));
+        assert_eq!(stack("0x1::account::create"), Some(60));
+        assert_eq!(report.storage_stacks.len(), 1);
+
+        let graph = report
+            .to_execution_flamegraph("Execution & IO".to_string())
+            .unwrap()
+            .unwrap();
+        assert!(String::from_utf8(graph)
+            .unwrap()
+            .contains("0x1::coin::withdraw"));
+        assert!(report
+            .to_storage_flamegraph("Storage Fee".to_string())
+            .unwrap()
+            .is_some());
+        assert!(GasHotspotReport::new()
+            .to_execution_flamegraph("Empty".to_string())
+            .unwrap()
+            .is_none());
+    }
+}
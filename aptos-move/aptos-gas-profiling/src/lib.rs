@@ -4,11 +4,14 @@
 mod aggregate;
 mod erased;
 mod flamegraph;
+mod hotspots;
 mod log;
 mod misc;
 mod profiler;
 mod render;
 mod textualize;
 
+pub use aggregate::{AggregatedExecutionGasEvents, AggregatedStorageFees};
+pub use hotspots::GasHotspotReport;
 pub use log::{FrameName, TransactionGasLog};
 pub use profiler::GasProfiler;
@@ -4,8 +4,9 @@
 use crate::{
     aggregate::AggregatedExecutionGasEvents,
     erased::{Node, TypeErasedExecutionAndIoCosts, TypeErasedGasLog, TypeErasedStorageFees},
+    hotspots::GasHotspotReport,
 };
-use aptos_gas_algebra::{GasQuantity, InternalGas};
+use aptos_gas_algebra::{Fee, GasQuantity, InternalGas};
 use std::fmt::{self, Write};
 
 fn indent(output: &mut impl Write, count: usize) -> fmt::Result {
@@ -191,6 +192,127 @@ impl AggregatedExecutionGasEvents {
             table.push(fmt_item(name, *count, *cost));
         }
 
+        table.push([
+            "    function bodies".to_string(),
+            "".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]);
+        for (name, count, cost) in &self.functions {
+            table.push(fmt_item(name, *count, *cost));
+        }
+
+        render_table(output, &table, 4)
+    }
+}
+
+impl GasHotspotReport {
+    /// Lists the `top` most expensive items of each kind.
+    pub fn textualize(&self, output: &mut impl Write, top: usize) -> fmt::Result {
+        fn push_items<U>(
+            table: &mut Vec<[String; 4]>,
+            title: &str,
+            items: Vec<(String, usize, GasQuantity<U>)>,
+            top: usize,
+            total: GasQuantity<U>,
+            fmt_cost: &impl Fn(GasQuantity<U>) -> String,
+        ) {
+            table.push([
+                format!("    {}", title),
+                "".to_string(),
+                "".to_string(),
+                "".to_string(),
+            ]);
+            for (name, count, cost) in items.into_iter().take(top) {
+                let percentage = if total.is_zero() {
+                    "".to_string()
+                } else {
+                    format!(
+                        "{:.2}%",
+                        u64::from(cost) as f64 / u64::from(total) as f64 * 100.0
+                    )
+                };
+                table.push([
+                    format!("        {}", name),
+                    format!("x{}", count),
+                    fmt_cost(cost),
+                    percentage,
+                ]);
+            }
+        }
+
+        let scaling_factor = u64::from(self.gas_scaling_factor) as f64;
+        let fmt_gas = |cost: InternalGas| {
+            let cost_scaled = format!("{:.8}", (u64::from(cost) as f64 / scaling_factor));
+            crate::misc::strip_trailing_zeros_and_decimal_point(&cost_scaled).to_string()
+        };
+        let fmt_fee = |cost: Fee| {
+            let cost_scaled = format!("{:.8}", (u64::from(cost) as f64 / 1_0000_0000f64));
+            crate::misc::strip_trailing_zeros_and_decimal_point(&cost_scaled).to_string()
+        };
+
+        writeln!(output, "{} transactions", self.num_transactions)?;
+        writeln!(output)?;
+
+        let mut table = vec![[
+            "execution & IO (gas unit, all transactions)".to_string(),
+            "".to_string(),
+            fmt_gas(self.execution_and_io),
+            "".to_string(),
+        ]];
+        for (title, items) in [
+            ("function bodies", self.functions()),
+            ("native calls", self.natives()),
+            ("instructions", self.instructions()),
+            ("storage reads", self.storage_reads()),
+            ("storage writes", self.storage_writes()),
+        ] {
+            push_items(
+                &mut table,
+                title,
+                items,
+                top,
+                self.execution_and_io,
+                &fmt_gas,
+            );
+        }
+        render_table(output, &table, 4)?;
+        writeln!(output)?;
+
+        let mut table = vec![[
+            "storage fees (APT, all transactions)".to_string(),
+            "".to_string(),
+            fmt_fee(self.storage_fee),
+            "".to_string(),
+        ]];
+        push_items(
+            &mut table,
+            "writes",
+            self.storage_fees(),
+            top,
+            self.storage_fee,
+            &fmt_fee,
+        );
+        render_table(output, &table, 4)?;
+        writeln!(output)?;
+
+        // Loading modules isn't charged for, so they are ranked by the number of transactions
+        // loading them instead.
+        let mut table = vec![[
+            "module loads (transactions)".to_string(),
+            "".to_string(),
+            "".to_string(),
+        ]];
+        for (module, count) in self.module_loads().into_iter().take(top) {
+            table.push([
+                format!("    {}", module),
+                format!("x{}", count),
+                format!(
+                    "{:.2}%",
+                    count as f64 / self.num_transactions as f64 * 100.0
+                ),
+            ]);
+        }
         render_table(output, &table, 4)
     }
 }
@@ -13,11 +13,13 @@ use aptos_types::{
     account_config::CORE_CODE_ADDRESS,
     account_state::AccountState,
     account_view::AccountView,
+    contract_event::ContractEvent,
     on_chain_config::ValidatorSet,
     state_store::{
         state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue,
     },
     transaction::{Transaction, TransactionInfo, Version},
+    write_set::WriteSet,
 };
 use lru::LruCache;
 use move_binary_format::file_format::CompiledModule;
@@ -46,6 +48,18 @@ pub trait AptosValidatorInterface: Sync {
         limit: u64,
     ) -> Result<(Vec<Transaction>, Vec<TransactionInfo>)>;
 
+    /// The committed transactions in the range along with their write sets and events, e.g. to
+    /// compare them with the output of replaying the transactions.
+    async fn get_committed_transactions_with_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<(
+        Vec<Transaction>,
+        Vec<TransactionInfo>,
+        Vec<(WriteSet, Vec<ContractEvent>)>,
+    )>;
+
     async fn get_latest_version(&self) -> Result<Version>;
 
     async fn get_version_by_account_sequence(
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::AptosValidatorInterface;
-use anyhow::{anyhow, Result};
-use aptos_api_types::{AptosError, AptosErrorCode};
+use anyhow::{anyhow, bail, Result};
+use aptos_api_types::{AptosError, AptosErrorCode, TransactionOnChainData};
 use aptos_rest_client::{
     error::{AptosErrorResponse, RestError},
     Client,
@@ -11,8 +11,10 @@ use aptos_rest_client::{
 use aptos_types::{
     account_address::AccountAddress,
     account_state::AccountState,
+    contract_event::ContractEvent,
     state_store::{state_key::StateKey, state_value::StateValue},
     transaction::{Transaction, TransactionInfo, Version},
+    write_set::WriteSet,
 };
 use std::collections::BTreeMap;
 
@@ -22,6 +24,34 @@ impl RestDebuggerInterface {
     pub fn new(client: Client) -> Self {
         Self(client)
     }
+
+    /// Fetches the committed transactions in the range, one page at a time.
+    async fn get_transactions_on_chain_data(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<Vec<TransactionOnChainData>> {
+        let mut txns = Vec::with_capacity(limit as usize);
+        while (txns.len() as u64) < limit {
+            let page_size = (limit - txns.len() as u64).min(u16::MAX as u64) as u16;
+            let page = self
+                .0
+                .get_transactions_bcs(Some(start + txns.len() as u64), Some(page_size))
+                .await?
+                .into_inner();
+            if page.is_empty() {
+                bail!(
+                    "Only {} of the {} transactions requested from version {} are committed",
+                    txns.len(),
+                    limit,
+                    start
+                );
+            }
+            txns.extend(page);
+            println!("Got {}/{} txns from RestApi.", txns.len(), limit);
+        }
+        Ok(txns)
+    }
 }
 
 #[async_trait::async_trait]
@@ -70,26 +100,32 @@ impl AptosValidatorInterface for RestDebuggerInterface {
         start: Version,
         limit: u64,
     ) -> Result<(Vec<Transaction>, Vec<TransactionInfo>)> {
+        Ok(self
+            .get_transactions_on_chain_data(start, limit)
+            .await?
+            .into_iter()
+            .map(|txn| (txn.transaction, txn.info))
+            .unzip())
+    }
+
+    async fn get_committed_transactions_with_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<(
+        Vec<Transaction>,
+        Vec<TransactionInfo>,
+        Vec<(WriteSet, Vec<ContractEvent>)>,
+    )> {
         let mut txns = Vec::with_capacity(limit as usize);
         let mut txn_infos = Vec::with_capacity(limit as usize);
-
-        while txns.len() < limit as usize {
-            self.0
-                .get_transactions_bcs(
-                    Some(start + txns.len() as u64),
-                    Some(limit as u16 - txns.len() as u16),
-                )
-                .await?
-                .into_inner()
-                .into_iter()
-                .for_each(|txn| {
-                    txns.push(txn.transaction);
-                    txn_infos.push(txn.info);
-                });
-            println!("Got {}/{} txns from RestApi.", txns.len(), limit);
+        let mut outputs = Vec::with_capacity(limit as usize);
+        for txn in self.get_transactions_on_chain_data(start, limit).await? {
+            txns.push(txn.transaction);
+            txn_infos.push(txn.info);
+            outputs.push((txn.changes, txn.events));
         }
-
-        Ok((txns, txn_infos))
+        Ok((txns, txn_infos, outputs))
     }
 
     async fn get_latest_version(&self) -> Result<Version> {
@@ -5,16 +5,18 @@
 use crate::AptosValidatorInterface;
 use anyhow::{bail, ensure, Result};
 use aptos_config::config::{
-    RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
-    NO_OP_STORAGE_PRUNER_CONFIG,
+    ColdStorageConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
 };
 use aptos_db::AptosDB;
 use aptos_storage_interface::{DbReader, MAX_REQUEST_LIMIT};
 use aptos_types::{
     account_address::AccountAddress,
     account_state::AccountState,
+    contract_event::ContractEvent,
     state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
     transaction::{Transaction, TransactionInfo, Version},
+    write_set::WriteSet,
 };
 use std::{path::Path, sync::Arc};
 
@@ -28,8 +30,10 @@ impl DBDebuggerInterface {
             NO_OP_STORAGE_PRUNER_CONFIG,
             RocksdbConfigs::default(),
             false,
+            false,
             BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            ColdStorageConfig::default(),
         )?)))
     }
 }
@@ -79,6 +83,32 @@ impl AptosValidatorInterface for DBDebuggerInterface {
         Ok((txns, txn_infos))
     }
 
+    async fn get_committed_transactions_with_outputs(
+        &self,
+        start: Version,
+        limit: u64,
+    ) -> Result<(
+        Vec<Transaction>,
+        Vec<TransactionInfo>,
+        Vec<(WriteSet, Vec<ContractEvent>)>,
+    )> {
+        let (txns, txn_infos) = self.get_committed_transactions(start, limit).await?;
+        let write_sets = self
+            .0
+            .get_write_set_iterator(start, limit)?
+            .collect::<Result<Vec<_>>>()?;
+        let events = self
+            .0
+            .get_events_iterator(start, limit)?
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(txns.len() == write_sets.len() && txns.len() == events.len());
+        Ok((
+            txns,
+            txn_infos,
+            write_sets.into_iter().zip(events).collect(),
+        ))
+    }
+
     async fn get_latest_version(&self) -> Result<Version> {
         self.0.get_latest_version()
     }
@@ -27,7 +27,7 @@ use aptos_gas_meter::{AptosGasMeter, StandardGasAlgebra, StandardGasMeter};
 use aptos_gas_schedule::VMGasParameters;
 use aptos_logger::{enabled, prelude::*, Level};
 use aptos_memory_usage_tracker::MemoryTrackedGasMeter;
-use aptos_state_view::StateView;
+use aptos_state_view::{overlay_state_view::OverlayStateView, StateView};
 use aptos_types::{
     account_config,
     account_config::new_block_event_key,
@@ -1356,21 +1356,88 @@ impl AptosVM {
         txn: &SignedTransaction,
         state_view: &impl StateView,
     ) -> (VMStatus, TransactionOutput) {
+        let result = Self::simulate_signed_transaction_with_custom_gas_meter(
+            txn,
+            state_view,
+            |gas_feature_version, gas_params, storage_gas_params, balance| {
+                Ok(MemoryTrackedGasMeter::new(StandardGasMeter::new(
+                    StandardGasAlgebra::new(
+                        gas_feature_version,
+                        gas_params,
+                        storage_gas_params,
+                        balance,
+                    ),
+                )))
+            },
+        );
+        match result {
+            Ok((vm_status, output, _gas_meter)) => (vm_status, output),
+            Err(err) => {
+                let (vm_status, vm_output) = discard_error_vm_status(err);
+                (
+                    vm_status,
+                    vm_output
+                        .into_transaction_output(state_view)
+                        .expect("Simulation cannot fail"),
+                )
+            },
+        }
+    }
+
+    /// Same as `simulate_signed_transaction`, but charges gas with the meter returned by
+    /// `make_gas_meter`, which is handed back afterwards, e.g. to profile the gas usage.
+    pub fn simulate_signed_transaction_with_custom_gas_meter<G, F>(
+        txn: &SignedTransaction,
+        state_view: &impl StateView,
+        make_gas_meter: F,
+    ) -> Result<(VMStatus, TransactionOutput, G), VMStatus>
+    where
+        G: AptosGasMeter,
+        F: FnOnce(u64, VMGasParameters, StorageGasParameters, Gas) -> Result<G, VMStatus>,
+    {
         let vm = AptosVM::new(state_view);
-        let simulation_vm = AptosSimulationVM(vm);
         let log_context = AdapterLogSchema::new(state_view.id(), 0);
 
+        let balance = TransactionMetadata::new(txn).max_gas_amount();
+        let mut gas_meter = make_gas_meter(
+            vm.0.get_gas_feature_version(),
+            vm.0.get_gas_parameters(&log_context)?.vm.clone(),
+            vm.0.get_storage_gas_parameters(&log_context)?.clone(),
+            balance,
+        )?;
+
+        let simulation_vm = AptosSimulationVM(vm);
         let (vm_status, vm_output) = simulation_vm.simulate_signed_transaction(
             &simulation_vm.0.as_move_resolver(state_view),
             txn,
             &log_context,
+            &mut gas_meter,
         );
-        (
+        Ok((
             vm_status,
             vm_output
                 .into_transaction_output(state_view)
                 .expect("Simulation cannot fail"),
-        )
+            gas_meter,
+        ))
+    }
+
+    /// Simulates `txns` in order. Each transaction runs against the state as changed by the ones
+    /// before it, i.e. its change set, with deltas materialized, is applied on top of
+    /// `state_view` before the next one runs. Discarded transactions leave the state unchanged.
+    pub fn simulate_signed_transactions(
+        txns: &[SignedTransaction],
+        state_view: &impl StateView,
+    ) -> Vec<(VMStatus, TransactionOutput)> {
+        let mut overlay_state_view = OverlayStateView::new(state_view);
+        txns.iter()
+            .map(|txn| {
+                let (vm_status, output) =
+                    Self::simulate_signed_transaction(txn, &overlay_state_view);
+                overlay_state_view.apply_write_set(output.write_set());
+                (vm_status, output)
+            })
+            .collect()
     }
 
     pub fn execute_view_function(
@@ -1795,6 +1862,7 @@ impl AptosSimulationVM {
         resolver: &impl MoveResolverExt,
         txn: &SignedTransaction,
         log_context: &AdapterLogSchema,
+        gas_meter: &mut impl AptosGasMeter,
     ) -> (VMStatus, VMOutput) {
         // simulation transactions should not carry valid signatures, otherwise malicious fullnodes
         // may execute them without user's explicit permission.
@@ -1811,23 +1879,11 @@ impl AptosSimulationVM {
             return discard_error_vm_status(err);
         };
 
-        let gas_params = match self.0 .0.get_gas_parameters(log_context) {
-            Err(err) => return discard_error_vm_status(err),
-            Ok(s) => s,
-        };
         let storage_gas_params = match self.0 .0.get_storage_gas_parameters(log_context) {
             Err(err) => return discard_error_vm_status(err),
             Ok(s) => s,
         };
 
-        let mut gas_meter =
-            MemoryTrackedGasMeter::new(StandardGasMeter::new(StandardGasAlgebra::new(
-                self.0 .0.get_gas_feature_version(),
-                gas_params.vm.clone(),
-                storage_gas_params.clone(),
-                txn_data.max_gas_amount(),
-            )));
-
         let mut new_published_modules_loaded = false;
         let result = match txn.payload() {
             payload @ TransactionPayload::Script(_)
@@ -1835,7 +1891,7 @@ impl AptosSimulationVM {
                 self.0.execute_script_or_entry_function(
                     resolver,
                     session,
-                    &mut gas_meter,
+                    gas_meter,
                     &txn_data,
                     payload,
                     log_context,
@@ -1850,7 +1906,7 @@ impl AptosSimulationVM {
                             aptos_try!({
                                 return_on_failure!(self.0.execute_multisig_entry_function(
                                     &mut session,
-                                    &mut gas_meter,
+                                    gas_meter,
                                     multisig.multisig_address,
                                     &entry_function,
                                     &mut new_published_modules_loaded,
@@ -1863,14 +1919,14 @@ impl AptosSimulationVM {
                                     self.0.charge_change_set_and_respawn_session(
                                         session,
                                         resolver,
-                                        &mut gas_meter,
+                                        gas_meter,
                                         &storage_gas_params.change_set_configs,
                                         &txn_data,
                                     )?;
 
                                 self.0.success_transaction_cleanup(
                                     respawned_session,
-                                    &mut gas_meter,
+                                    gas_meter,
                                     &txn_data,
                                     log_context,
                                     &storage_gas_params.change_set_configs,
@@ -1887,7 +1943,7 @@ impl AptosSimulationVM {
             TransactionPayload::ModuleBundle(m) => self.0.execute_modules(
                 resolver,
                 session,
-                &mut gas_meter,
+                gas_meter,
                 &txn_data,
                 m,
                 log_context,
@@ -1919,7 +1975,7 @@ impl AptosSimulationVM {
                 } else {
                     let (vm_status, output) = self.0.failed_transaction_cleanup_and_keep_vm_status(
                         err,
-                        &mut gas_meter,
+                        gas_meter,
                         &txn_data,
                         resolver,
                         log_context,
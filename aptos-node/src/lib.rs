@@ -518,7 +518,7 @@ pub fn setup_environment_and_start_node(
     );
 
     // Start state sync and get the notification endpoints for mempool and consensus
-    let (state_sync_runtimes, mempool_listener, consensus_notifier) =
+    let (state_sync_runtimes, mempool_listener, consensus_notifier, api_commit_listener) =
         state_sync::start_state_sync_and_get_notification_handles(
             &node_config,
             storage_service_network_interfaces,
@@ -529,7 +529,7 @@ pub fn setup_environment_and_start_node(
 
     // Bootstrap the API and indexer
     let (mempool_client_receiver, api_runtime, indexer_runtime, indexer_grpc_runtime) =
-        services::bootstrap_api_and_indexer(&node_config, aptos_db, chain_id)?;
+        services::bootstrap_api_and_indexer(&node_config, aptos_db, chain_id, api_commit_listener)?;
 
     // Create mempool and get the consensus to mempool sender
     let (mempool_runtime, consensus_to_mempool_sender) =
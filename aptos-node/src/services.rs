@@ -19,10 +19,10 @@ use aptos_peer_monitoring_service_server::{
 use aptos_peer_monitoring_service_types::PeerMonitoringServiceMessage;
 use aptos_storage_interface::{DbReader, DbReaderWriter};
 use aptos_time_service::TimeService;
-use aptos_types::chain_id::ChainId;
+use aptos_types::{chain_id::ChainId, transaction::Version};
 use futures::channel::{mpsc, mpsc::Sender};
 use std::{sync::Arc, time::Instant};
-use tokio::runtime::Runtime;
+use tokio::{runtime::Runtime, sync::watch};
 
 const AC_SMP_CHANNEL_BUFFER_SIZE: usize = 1_024;
 const INTRA_NODE_CHANNEL_BUFFER_SIZE: usize = 1;
@@ -33,6 +33,7 @@ pub fn bootstrap_api_and_indexer(
     node_config: &NodeConfig,
     aptos_db: Arc<dyn DbReader>,
     chain_id: ChainId,
+    api_commit_listener: watch::Receiver<Version>,
 ) -> anyhow::Result<(
     Receiver<MempoolClientRequest>,
     Option<Runtime>,
@@ -50,6 +51,7 @@ pub fn bootstrap_api_and_indexer(
             chain_id,
             aptos_db.clone(),
             mempool_client_sender.clone(),
+            api_commit_listener,
         )?)
     } else {
         None
@@ -23,16 +23,22 @@ use aptos_state_sync_driver::{
 };
 use aptos_storage_interface::{DbReader, DbReaderWriter};
 use aptos_storage_service_client::StorageServiceClient;
-use aptos_storage_service_notifications::StorageServiceNotificationListener;
+use aptos_storage_service_notifications::{
+    Error as StorageServiceNotificationError, StorageServiceNotificationListener,
+    StorageServiceNotificationSender, StorageServiceNotifier,
+};
 use aptos_storage_service_server::{
     network::StorageServiceNetworkEvents, storage::StorageReader, StorageServiceServer,
 };
 use aptos_storage_service_types::StorageServiceMessage;
 use aptos_time_service::TimeService;
-use aptos_types::{on_chain_config::ON_CHAIN_CONFIG_REGISTRY, waypoint::Waypoint};
+use aptos_types::{
+    on_chain_config::ON_CHAIN_CONFIG_REGISTRY, transaction::Version, waypoint::Waypoint,
+};
 use aptos_vm::AptosVM;
+use async_trait::async_trait;
 use std::sync::Arc;
-use tokio::runtime::Runtime;
+use tokio::{runtime::Runtime, sync::watch};
 
 /// Creates the event subscription service and two reconfiguration
 /// notification listeners (for mempool and consensus, respectively).
@@ -73,6 +79,28 @@ pub fn create_event_subscription_service(
     )
 }
 
+/// Notifies the storage service of new commits, and publishes the highest
+/// committed version to the API so that it doesn't have to poll storage.
+#[derive(Clone)]
+struct CommitNotifier {
+    storage_service_notifier: StorageServiceNotifier,
+    api_commit_sender: Arc<watch::Sender<Version>>,
+}
+
+#[async_trait]
+impl StorageServiceNotificationSender for CommitNotifier {
+    async fn notify_new_commit(
+        &self,
+        highest_synced_version: u64,
+    ) -> Result<(), StorageServiceNotificationError> {
+        // The API may be disabled (i.e., have no receivers), so the value is always replaced
+        self.api_commit_sender.send_replace(highest_synced_version);
+        self.storage_service_notifier
+            .notify_new_commit(highest_synced_version)
+            .await
+    }
+}
+
 /// Sets up all state sync runtimes and return the notification endpoints
 pub fn start_state_sync_and_get_notification_handles(
     node_config: &NodeConfig,
@@ -84,6 +112,7 @@ pub fn start_state_sync_and_get_notification_handles(
     StateSyncRuntimes,
     MempoolNotificationListener,
     ConsensusNotifier,
+    watch::Receiver<Version>,
 )> {
     // Get the network client and events
     let network_client = storage_network_interfaces.network_client;
@@ -115,6 +144,14 @@ pub fn start_state_sync_and_get_notification_handles(
     let (storage_service_notifier, storage_service_listener) =
         aptos_storage_service_notifications::new_storage_service_notifier_listener_pair();
 
+    // Create the commit notifier, which also notifies the API of new commits
+    let latest_version = db_rw.reader.get_latest_version().unwrap_or(0);
+    let (api_commit_sender, api_commit_listener) = watch::channel(latest_version);
+    let commit_notifier = CommitNotifier {
+        storage_service_notifier,
+        api_commit_sender: Arc::new(api_commit_sender),
+    };
+
     // Start the state sync storage service
     let storage_service_runtime = setup_state_sync_storage_service(
         node_config.state_sync.storage_service,
@@ -132,7 +169,7 @@ pub fn start_state_sync_and_get_notification_handles(
         db_rw,
         chunk_executor,
         mempool_notifier,
-        storage_service_notifier,
+        commit_notifier,
         metadata_storage,
         consensus_listener,
         event_subscription_service,
@@ -149,7 +186,12 @@ pub fn start_state_sync_and_get_notification_handles(
         streaming_service_runtime,
     );
 
-    Ok((state_sync_runtimes, mempool_listener, consensus_notifier))
+    Ok((
+        state_sync_runtimes,
+        mempool_listener,
+        consensus_notifier,
+        api_commit_listener,
+    ))
 }
 
 /// Sets up the data streaming service runtime
@@ -48,6 +48,39 @@ pub struct ApiConfig {
     /// Enables transaction simulation
     #[serde(default = "default_enabled")]
     pub transaction_simulation_enabled: bool,
+    /// Enables the state overrides of transaction simulation and view function requests
+    #[serde(default = "default_enabled")]
+    pub state_overrides_enabled: bool,
+    /// Enables the server-sent event streaming APIs for committed transactions and events
+    #[serde(default = "default_enabled")]
+    pub stream_enabled: bool,
+    /// Interval in milliseconds at which streaming APIs check storage for newly committed data,
+    /// used only if the API doesn't receive commit notifications
+    pub stream_poll_interval_ms: u64,
+    /// Interval in seconds between keep-alive messages on idle streams
+    pub stream_keep_alive_secs: u64,
+    /// Maximum number of streams open at the same time
+    ///
+    /// Requests for more streams are rejected until some of the open ones close
+    pub stream_max_active_connections: usize,
+    /// Enables the API that holds a request until a transaction is committed
+    #[serde(default = "default_enabled")]
+    pub wait_by_hash_enabled: bool,
+    /// Maximum time in milliseconds a wait by hash request is held before it returns
+    pub wait_by_hash_timeout_ms: u64,
+    /// Interval in milliseconds at which wait by hash requests check storage for the
+    /// transaction, used only if the API doesn't receive commit notifications
+    pub wait_by_hash_poll_interval_ms: u64,
+    /// Maximum number of wait by hash requests held at the same time
+    ///
+    /// Requests beyond this limit return the current state of the transaction immediately
+    pub wait_by_hash_max_active_connections: usize,
+    /// Enables the APIs listing the transactions waiting in mempool
+    ///
+    /// These expose what users have submitted to this node before it is public on chain, so
+    /// they are off by default and should stay off on public fullnodes.
+    #[serde(default = "default_disabled")]
+    pub mempool_transactions_enabled: bool,
     /// Maximum number of transactions that can be sent with the Batch submit API
     pub max_submit_transaction_batch_size: usize,
     /// Maximum page size for transaction paginated APIs
@@ -62,6 +95,8 @@ pub struct ApiConfig {
     ///
     /// This limits the execution length of a view function to the given gas used.
     pub max_gas_view_function: u64,
+    /// Maximum number of view functions that can be executed with the batch view API
+    pub max_view_function_batch_size: usize,
     /// Optional: Maximum number of worker threads for the API.
     ///
     /// If not set, `runtime_worker_multiplier` will multiply times the number of CPU cores on the machine
@@ -82,6 +117,13 @@ pub const DEFAULT_MAX_PAGE_SIZE: u16 = 100;
 const DEFAULT_MAX_ACCOUNT_RESOURCES_PAGE_SIZE: u16 = 9999;
 const DEFAULT_MAX_ACCOUNT_MODULES_PAGE_SIZE: u16 = 9999;
 const DEFAULT_MAX_VIEW_GAS: u64 = 2_000_000; // We keep this value the same as the max number of gas allowed for one single transaction defined in aptos-gas.
+const DEFAULT_MAX_VIEW_FUNCTION_BATCH_SIZE: usize = 100;
+const DEFAULT_STREAM_POLL_INTERVAL_MS: u64 = 100;
+const DEFAULT_STREAM_KEEP_ALIVE_SECS: u64 = 15;
+const DEFAULT_STREAM_MAX_ACTIVE_CONNECTIONS: usize = 100;
+const DEFAULT_WAIT_BY_HASH_TIMEOUT_MS: u64 = 5_000; // Well under the default client request timeout
+const DEFAULT_WAIT_BY_HASH_POLL_INTERVAL_MS: u64 = 100;
+const DEFAULT_WAIT_BY_HASH_MAX_ACTIVE_CONNECTIONS: usize = 100;
 
 fn default_enabled() -> bool {
     true
@@ -107,12 +149,23 @@ impl Default for ApiConfig {
             encode_submission_enabled: default_enabled(),
             transaction_submission_enabled: default_enabled(),
             transaction_simulation_enabled: default_enabled(),
+            state_overrides_enabled: default_enabled(),
+            stream_enabled: default_enabled(),
+            stream_poll_interval_ms: DEFAULT_STREAM_POLL_INTERVAL_MS,
+            stream_keep_alive_secs: DEFAULT_STREAM_KEEP_ALIVE_SECS,
+            stream_max_active_connections: DEFAULT_STREAM_MAX_ACTIVE_CONNECTIONS,
+            wait_by_hash_enabled: default_enabled(),
+            wait_by_hash_timeout_ms: DEFAULT_WAIT_BY_HASH_TIMEOUT_MS,
+            wait_by_hash_poll_interval_ms: DEFAULT_WAIT_BY_HASH_POLL_INTERVAL_MS,
+            wait_by_hash_max_active_connections: DEFAULT_WAIT_BY_HASH_MAX_ACTIVE_CONNECTIONS,
+            mempool_transactions_enabled: default_disabled(),
             max_submit_transaction_batch_size: DEFAULT_MAX_SUBMIT_TRANSACTION_BATCH_SIZE,
             max_transactions_page_size: DEFAULT_MAX_PAGE_SIZE,
             max_events_page_size: DEFAULT_MAX_PAGE_SIZE,
             max_account_resources_page_size: DEFAULT_MAX_ACCOUNT_RESOURCES_PAGE_SIZE,
             max_account_modules_page_size: DEFAULT_MAX_ACCOUNT_MODULES_PAGE_SIZE,
             max_gas_view_function: DEFAULT_MAX_VIEW_GAS,
+            max_view_function_batch_size: DEFAULT_MAX_VIEW_FUNCTION_BATCH_SIZE,
             max_runtime_workers: None,
             runtime_worker_multiplier: 2,
             gas_estimation: GasEstimationConfig::default(),
@@ -163,6 +216,22 @@ impl ConfigSanitizer for ApiConfig {
             ));
         }
 
+        // Validate the streaming properties
+        if api_config.stream_enabled && api_config.stream_poll_interval_ms == 0 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "stream_poll_interval_ms must be greater than 0!".into(),
+            ));
+        }
+
+        // Validate the wait by hash properties
+        if api_config.wait_by_hash_enabled && api_config.wait_by_hash_poll_interval_ms == 0 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "wait_by_hash_poll_interval_ms must be greater than 0!".into(),
+            ));
+        }
+
         GasEstimationConfig::sanitize(node_config, node_type, chain_id)?;
 
         Ok(())
@@ -227,4 +296,44 @@ mod tests {
             .unwrap_err();
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
+
+    #[test]
+    fn test_sanitize_invalid_stream_poll_interval() {
+        // Create a node config with an invalid stream poll interval
+        let mut node_config = NodeConfig {
+            api: ApiConfig {
+                enabled: true,
+                stream_enabled: true,
+                stream_poll_interval_ms: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the stream poll interval is invalid.
+        let error = ApiConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::mainnet())
+            .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_invalid_wait_by_hash_poll_interval() {
+        // Create a node config with an invalid wait by hash poll interval
+        let mut node_config = NodeConfig {
+            api: ApiConfig {
+                enabled: true,
+                wait_by_hash_enabled: true,
+                wait_by_hash_poll_interval_ms: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails because
+        // the wait by hash poll interval is invalid.
+        let error = ApiConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::mainnet())
+            .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
 }
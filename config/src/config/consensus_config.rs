@@ -65,6 +65,8 @@ pub struct ConsensusConfig {
     // must match one of the CHAIN_HEALTH_WINDOW_SIZES values.
     pub window_for_chain_health: usize,
     pub chain_health_backoff: Vec<ChainHealthBackoffValues>,
+    // Anchor election used by DAG consensus
+    pub dag_anchor_election: DagAnchorElectionConfig,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -80,6 +82,46 @@ pub struct PipelineBackpressureValues {
     pub backpressure_proposal_delay_ms: u64,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DagAnchorElectionConfig {
+    RoundRobin,
+    // Weights anchors by stake and their reputation over recently committed anchors
+    Reputation(DagReputationConfig),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DagReputationConfig {
+    // Selection weight for active validators with anchor failures below threshold
+    pub active_weight: u64,
+    // Selection weight for inactive validators with anchor failures below threshold
+    pub inactive_weight: u64,
+    // Selection weight for validators with anchor failures above threshold
+    pub failed_weight: u64,
+    // Threshold of failures in the rounds validator was selected to be anchor
+    pub failure_threshold_percent: u32,
+    // Number of committed anchors considered for reputation, multiplier
+    // on top of number of validators
+    pub window_num_validators_multiplier: usize,
+    // Anchors committed in this many rounds before the elected round are not
+    // considered, so the election doesn't depend on how far a validator has ordered
+    pub exclude_round: u64,
+}
+
+impl Default for DagReputationConfig {
+    fn default() -> Self {
+        Self {
+            active_weight: 1000,
+            inactive_weight: 10,
+            failed_weight: 1,
+            failure_threshold_percent: 10,
+            window_num_validators_multiplier: 10,
+            exclude_round: 40,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct ChainHealthBackoffValues {
     pub backoff_if_below_participating_voting_power_percentage: usize,
@@ -205,6 +247,7 @@ impl Default for ConsensusConfig {
                     backoff_proposal_delay_ms: 300,
                 },
             ],
+            dag_anchor_election: DagAnchorElectionConfig::RoundRobin,
         }
     }
 }
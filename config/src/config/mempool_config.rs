@@ -53,6 +53,9 @@ pub struct MempoolConfig {
     pub broadcast_buckets: Vec<u64>,
     pub eager_expire_threshold_ms: Option<u64>,
     pub eager_expire_time_ms: u64,
+    /// Whether to journal transactions to disk, so pending transactions are replayed into the
+    /// Mempool after a node restart.
+    pub journal_enabled: bool,
 }
 
 impl Default for MempoolConfig {
@@ -77,6 +80,7 @@ impl Default for MempoolConfig {
             broadcast_buckets: DEFAULT_BUCKETS.to_vec(),
             eager_expire_threshold_ms: Some(10_000),
             eager_expire_time_ms: 3_000,
+            journal_enabled: false,
         }
     }
 }
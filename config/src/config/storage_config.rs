@@ -118,6 +118,41 @@ pub struct StorageConfig {
     /// since genesis. To recover operation after data loss, or to bootstrap a node in fast sync
     /// mode, the indexer db needs to be copied in from another node.
     pub enable_indexer: bool,
+    /// Index events by type in the internal indexer db, to serve the events by type API.
+    /// Requires the internal indexer. Events already pruned when the index is enabled are not
+    /// indexed.
+    pub enable_event_type_index: bool,
+    /// Cold tier for the old ledger data of archive nodes
+    pub cold_storage_config: ColdStorageConfig,
+}
+
+/// Moves the transactions, write sets and events older than `hot_window` versions out of RocksDB,
+/// into immutable, compressed segment files. They're still served by the DB reader, the backup
+/// service and to the indexer, and their proofs still come from the transaction accumulator,
+/// which stays in RocksDB like all the indexes.
+///
+/// Meant for archive nodes, so it can't be combined with the ledger pruner.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColdStorageConfig {
+    pub enable: bool,
+    /// Directory of the segment files, relative to the storage directory unless absolute
+    pub dir: PathBuf,
+    /// Number of the latest versions to keep in RocksDB
+    pub hot_window: u64,
+    /// Number of versions in each segment file
+    pub segment_size: u64,
+}
+
+impl Default for ColdStorageConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            dir: PathBuf::from("cold"),
+            hot_window: 100_000_000,
+            segment_size: 1_000_000,
+        }
+    }
 }
 
 pub const NO_OP_STORAGE_PRUNER_CONFIG: PrunerConfig = PrunerConfig {
@@ -126,11 +161,13 @@ pub const NO_OP_STORAGE_PRUNER_CONFIG: PrunerConfig = PrunerConfig {
         prune_window: 0,
         batch_size: 0,
         user_pruning_window_offset: 0,
+        retention: None,
     },
     state_merkle_pruner_config: StateMerklePrunerConfig {
         enable: false,
         prune_window: 0,
         batch_size: 0,
+        retention: None,
     },
     epoch_snapshot_pruner_config: EpochSnapshotPrunerConfig {
         enable: false,
@@ -155,6 +192,9 @@ pub struct LedgerPrunerConfig {
     pub batch_size: usize,
     /// The offset for user pruning window to adjust
     pub user_pruning_window_offset: u64,
+    /// History to keep on top of the `prune_window`, e.g. 90 days, which doesn't translate to a
+    /// stable number of versions as the TPS changes. Also applies to the state values.
+    pub retention: Option<PrunerRetention>,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -167,6 +207,23 @@ pub struct StateMerklePrunerConfig {
     pub prune_window: u64,
     /// Number of stale nodes to prune a time.
     pub batch_size: usize,
+    /// History to keep on top of the `prune_window`.
+    pub retention: Option<PrunerRetention>,
+}
+
+/// A retention policy of a pruner, in wall-clock time or epochs rather than in versions.
+///
+/// It's resolved to a version through the block timestamps and the epoch ending versions, and a
+/// version is only pruned once it's out of both the retention and the `prune_window`, which is
+/// still needed to keep the data being read by in flight requests.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrunerRetention {
+    /// Keep the versions of the blocks proposed in the last given number of seconds, counting
+    /// from the latest block.
+    DurationSecs(u64),
+    /// Keep the given number of most recent epochs, including the current one.
+    Epochs(u64),
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -189,6 +246,7 @@ impl From<EpochSnapshotPrunerConfig> for StateMerklePrunerConfig {
             enable: config.enable,
             prune_window: config.prune_window,
             batch_size: config.batch_size,
+            retention: None,
         }
     }
 }
@@ -210,6 +268,7 @@ impl Default for LedgerPrunerConfig {
             prune_window: 150_000_000,
             batch_size: 500,
             user_pruning_window_offset: 200_000,
+            retention: None,
         }
     }
 }
@@ -227,6 +286,7 @@ impl Default for StateMerklePrunerConfig {
             // A 10k transaction block (touching 60k state values, in the case of the account
             // creation benchmark) on a 4B items DB (or 1.33B accounts) yields 300k JMT nodes
             batch_size: 1_000,
+            retention: None,
         }
     }
 }
@@ -264,6 +324,8 @@ impl Default for StorageConfig {
             data_dir: PathBuf::from("/opt/aptos/data"),
             rocksdb_configs: RocksdbConfigs::default(),
             enable_indexer: false,
+            enable_event_type_index: false,
+            cold_storage_config: ColdStorageConfig::default(),
             buffered_state_target_items: BUFFERED_STATE_TARGET_ITEMS,
             max_num_nodes_per_lru_cache_shard: DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
         }
@@ -291,16 +353,63 @@ impl StorageConfig {
 
 impl ConfigSanitizer for StorageConfig {
     fn sanitize(
-        _node_config: &mut NodeConfig,
+        node_config: &mut NodeConfig,
         _node_type: NodeType,
         _chain_id: ChainId,
     ) -> Result<(), Error> {
-        Ok(()) // TODO: add validation of higher-level properties once we have variable configs
+        let sanitizer_name = Self::get_sanitizer_name();
+        let storage_config = &node_config.storage;
+
+        // The event type index lives in the internal indexer db
+        if storage_config.enable_event_type_index && !storage_config.enable_indexer {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "enable_event_type_index requires enable_indexer!".into(),
+            ));
+        }
+
+        // The cold tier keeps the whole history, and empty segments can't be migrated
+        let cold_storage_config = &storage_config.cold_storage_config;
+        if cold_storage_config.enable {
+            if storage_config
+                .storage_pruner_config
+                .ledger_pruner_config
+                .enable
+            {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    "cold_storage_config can't be enabled along with the ledger pruner!".into(),
+                ));
+            }
+            if cold_storage_config.segment_size == 0 {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    "cold_storage_config.segment_size must be positive!".into(),
+                ));
+            }
+        }
+
+        // A retention of no epochs would prune the current epoch
+        let pruner_config = &storage_config.storage_pruner_config;
+        for retention in [
+            pruner_config.ledger_pruner_config.retention,
+            pruner_config.state_merkle_pruner_config.retention,
+        ] {
+            if retention == Some(PrunerRetention::Epochs(0)) {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    "Pruner retention must be at least one epoch!".into(),
+                ));
+            }
+        }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::config::PrunerConfig;
 
     #[test]
@@ -312,4 +421,98 @@ mod test {
         assert!(config.state_merkle_pruner_config.prune_window >= 100_000);
         assert!(config.epoch_snapshot_pruner_config.prune_window > 50_000_000);
     }
+
+    #[test]
+    fn test_sanitize_event_type_index_without_indexer() {
+        // Create a node config with the event type index enabled, but not the indexer
+        let mut node_config = NodeConfig {
+            storage: StorageConfig {
+                enable_indexer: false,
+                enable_event_type_index: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Enable the indexer and verify that the config is now valid
+        node_config.storage.enable_indexer = true;
+        StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet()).unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_cold_storage_with_ledger_pruner() {
+        // Create a node config with the cold tier enabled, along with the ledger pruner
+        let mut node_config = NodeConfig {
+            storage: StorageConfig {
+                cold_storage_config: ColdStorageConfig {
+                    enable: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Disable the ledger pruner and verify that the config is now valid
+        node_config
+            .storage
+            .storage_pruner_config
+            .ledger_pruner_config
+            .enable = false;
+        StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet()).unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_zero_epoch_retention() {
+        // Create a node config with a ledger pruner retention of zero epochs
+        let mut node_config = NodeConfig::default();
+        node_config
+            .storage
+            .storage_pruner_config
+            .ledger_pruner_config
+            .retention = Some(PrunerRetention::Epochs(0));
+
+        // Sanitize the config and verify that it fails
+        let error =
+            StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet())
+                .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Retain one epoch and verify that the config is now valid
+        node_config
+            .storage
+            .storage_pruner_config
+            .ledger_pruner_config
+            .retention = Some(PrunerRetention::Epochs(1));
+        StorageConfig::sanitize(&mut node_config, NodeType::Validator, ChainId::testnet()).unwrap();
+    }
+
+    #[test]
+    fn test_parse_pruner_retention() {
+        let config: LedgerPrunerConfig = serde_yaml::from_str(
+            r#"
+            prune_window: 1000000
+            retention:
+                duration_secs: 7776000
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.prune_window, 1_000_000);
+        assert_eq!(
+            config.retention,
+            Some(PrunerRetention::DurationSecs(7_776_000))
+        );
+    }
 }
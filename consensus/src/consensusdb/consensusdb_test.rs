@@ -84,6 +84,10 @@ fn test_dag() {
     let node = Node::new(1, 1, Author::random(), 123, Payload::empty(false), vec![]);
 
     db.save_node(&node).unwrap();
+    assert_eq!(
+        db.get_nodes().unwrap(),
+        HashMap::from([(node.digest(), node.clone())])
+    );
 
     let certified_node = CertifiedNode::new(node, AggregateSignature::empty());
 
@@ -199,9 +199,17 @@ impl ConsensusDB {
         Ok(())
     }
 
-    pub fn delete_node(&self, digest: HashValue) -> Result<(), DbError> {
+    pub fn get_nodes(&self) -> Result<HashMap<HashValue, Node>, DbError> {
+        let mut iter = self.db.iter::<NodeSchema>(ReadOptions::default())?;
+        iter.seek_to_first();
+        Ok(iter.collect::<Result<HashMap<HashValue, Node>>>()?)
+    }
+
+    pub fn delete_nodes(&self, digests: Vec<HashValue>) -> Result<(), DbError> {
         let batch = SchemaBatch::new();
-        batch.delete::<NodeSchema>(&digest)?;
+        digests
+            .iter()
+            .try_for_each(|digest| batch.delete::<NodeSchema>(digest))?;
         self.commit(batch)
     }
 
@@ -1,12 +1,56 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::liveness::proposer_election::choose_index;
+use aptos_config::config::{DagAnchorElectionConfig, DagReputationConfig};
 use aptos_consensus_types::common::{Author, Round};
+use aptos_infallible::Mutex;
+use aptos_types::epoch_state::EpochState;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Information about an ordered anchor that an anchor election can learn from.
+#[derive(Clone, Debug)]
+pub struct CommitEvent {
+    round: Round,
+    anchor: Author,
+    parents: Vec<Author>,
+    failed_anchors: Vec<Author>,
+}
+
+impl CommitEvent {
+    pub fn new(
+        round: Round,
+        anchor: Author,
+        parents: Vec<Author>,
+        failed_anchors: Vec<Author>,
+    ) -> Self {
+        Self {
+            round,
+            anchor,
+            parents,
+            failed_anchors,
+        }
+    }
+}
 
 pub trait AnchorElection {
     fn get_anchor(&self, round: Round) -> Author;
 
-    fn commit(&mut self, round: Round);
+    fn commit(&mut self, event: CommitEvent);
+}
+
+pub fn create_anchor_election(
+    config: &DagAnchorElectionConfig,
+    epoch_state: &EpochState,
+) -> Box<dyn AnchorElection> {
+    match config {
+        DagAnchorElectionConfig::RoundRobin => Box::new(RoundRobinAnchorElection::new(
+            epoch_state.verifier.get_ordered_account_addresses(),
+        )),
+        DagAnchorElectionConfig::Reputation(config) => {
+            Box::new(ReputationAnchorElection::new(epoch_state, config.clone()))
+        },
+    }
 }
 
 pub struct RoundRobinAnchorElection {
@@ -24,5 +68,123 @@ impl AnchorElection for RoundRobinAnchorElection {
         self.validators[(round / 2) as usize % self.validators.len()]
     }
 
-    fn commit(&mut self, _round: Round) {}
+    fn commit(&mut self, _event: CommitEvent) {}
+}
+
+/// Anchor election weighted by stake and the reputation validators earned over committed anchors.
+///
+/// The anchor of a round is elected with the reputation over the anchors committed at least
+/// `exclude_round` rounds before it, so it doesn't change as this validator orders the anchors
+/// of the rounds in between, and validators at different commit points elect the same anchor.
+pub struct ReputationAnchorElection {
+    epoch: u64,
+    validators: Vec<Author>,
+    voting_powers: Vec<u64>,
+    config: DagReputationConfig,
+    window_size: usize,
+    /// Committed anchors in round order, dropped once they can't be in the window of a round
+    /// that's still to be ordered.
+    history: VecDeque<CommitEvent>,
+    /// Weights of the last window elected with, keyed by the round of its latest anchor.
+    cached_weights: Mutex<Option<(Option<Round>, Vec<u128>)>>,
+}
+
+impl ReputationAnchorElection {
+    pub fn new(epoch_state: &EpochState, config: DagReputationConfig) -> Self {
+        let validators = epoch_state.verifier.get_ordered_account_addresses();
+        let voting_powers = validators
+            .iter()
+            .map(|author| {
+                epoch_state
+                    .verifier
+                    .get_voting_power(author)
+                    .expect("Validator should have voting power")
+            })
+            .collect();
+        let window_size = validators.len() * config.window_num_validators_multiplier;
+        Self {
+            epoch: epoch_state.epoch,
+            validators,
+            voting_powers,
+            config,
+            window_size,
+            history: VecDeque::new(),
+            cached_weights: Mutex::new(None),
+        }
+    }
+
+    /// Number of committed anchors in the history before the boundary round
+    fn num_events_before(&self, boundary: Round) -> usize {
+        self.history.partition_point(|event| event.round < boundary)
+    }
+
+    fn compute_weights<'a>(&self, window: impl Iterator<Item = &'a CommitEvent>) -> Vec<u128> {
+        let mut anchors = HashMap::new();
+        let mut failed_anchors = HashMap::new();
+        let mut voters = HashSet::new();
+        for event in window {
+            *anchors.entry(event.anchor).or_insert(0u32) += 1;
+            for author in &event.failed_anchors {
+                *failed_anchors.entry(*author).or_insert(0u32) += 1;
+            }
+            voters.extend(event.parents.iter().copied());
+        }
+
+        self.validators
+            .iter()
+            .zip(self.voting_powers.iter())
+            .map(|(author, voting_power)| {
+                let cur_anchors = *anchors.get(author).unwrap_or(&0);
+                let cur_failed_anchors = *failed_anchors.get(author).unwrap_or(&0);
+
+                let weight = if cur_failed_anchors * 100
+                    > (cur_anchors + cur_failed_anchors) * self.config.failure_threshold_percent
+                {
+                    self.config.failed_weight
+                } else if cur_anchors > 0 || voters.contains(author) {
+                    self.config.active_weight
+                } else {
+                    self.config.inactive_weight
+                };
+                weight as u128 * *voting_power as u128
+            })
+            .collect()
+    }
+
+    fn weights_for_round(&self, round: Round) -> Vec<u128> {
+        let end = self.num_events_before(round.saturating_sub(self.config.exclude_round));
+        let start = end.saturating_sub(self.window_size);
+        let latest_round = end.checked_sub(1).map(|idx| self.history[idx].round);
+
+        let mut cached_weights = self.cached_weights.lock();
+        match cached_weights.as_ref() {
+            Some((cached_round, weights)) if *cached_round == latest_round => weights.clone(),
+            _ => {
+                let weights = self.compute_weights(self.history.range(start..end));
+                *cached_weights = Some((latest_round, weights.clone()));
+                weights
+            },
+        }
+    }
+}
+
+impl AnchorElection for ReputationAnchorElection {
+    fn get_anchor(&self, round: Round) -> Author {
+        let state = [
+            self.epoch.to_le_bytes().to_vec(),
+            round.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        self.validators[choose_index(self.weights_for_round(round), state)]
+    }
+
+    fn commit(&mut self, event: CommitEvent) {
+        // Only rounds after the committed anchor are left to elect anchors for
+        let lowest_boundary = (event.round + 1).saturating_sub(self.config.exclude_round);
+        self.history.push_back(event);
+        let num_expired = self
+            .num_events_before(lowest_boundary)
+            .saturating_sub(self.window_size);
+        self.history.drain(..num_expired);
+    }
 }
@@ -0,0 +1,115 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    anchor_election::create_anchor_election,
+    dag_driver::DagDriver,
+    dag_fetcher::{DagFetcher, LocalFetchRequest},
+    dag_handler::NetworkHandler,
+    dag_network::DAGNetworkSender,
+    dag_store::Dag,
+    order_rule::OrderRule,
+    reliable_broadcast::ReliableBroadcast,
+    storage::DAGStorage,
+    CertifiedNode,
+};
+use crate::{
+    network::IncomingDAGRequest, state_replication::PayloadClient, util::time_service::TimeService,
+};
+use aptos_channels::aptos_channel;
+use aptos_config::config::DagAnchorElectionConfig;
+use aptos_consensus_types::common::Author;
+use aptos_infallible::RwLock;
+use aptos_types::{
+    epoch_state::EpochState, ledger_info::LedgerInfo, validator_signer::ValidatorSigner,
+};
+use futures::future::{AbortHandle, Abortable};
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use std::sync::Arc;
+use tokio::sync::mpsc::Sender;
+
+/// The DAG components of an epoch. The network handler and fetcher tasks are aborted on drop.
+pub struct DagBootstrapUnit {
+    pub driver: DagDriver,
+    pub order_rule: OrderRule,
+    pub ordered_nodes_rx: UnboundedReceiver<Vec<Arc<CertifiedNode>>>,
+    pub fetch_requester: Sender<LocalFetchRequest>,
+    handler_abort_handle: AbortHandle,
+    fetcher_abort_handle: AbortHandle,
+}
+
+impl Drop for DagBootstrapUnit {
+    fn drop(&mut self) {
+        self.handler_abort_handle.abort();
+        self.fetcher_abort_handle.abort();
+    }
+}
+
+/// Builds the DAG of the epoch from what was persisted before a restart, dropping everything
+/// below the latest committed round, resumes the broadcast of our own pending node and starts
+/// serving DAG RPCs.
+pub fn bootstrap_dag(
+    signer: ValidatorSigner,
+    epoch_state: Arc<EpochState>,
+    latest_ledger_info: LedgerInfo,
+    storage: Arc<dyn DAGStorage>,
+    network_sender: Arc<dyn DAGNetworkSender>,
+    dag_rpc_rx: aptos_channel::Receiver<Author, IncomingDAGRequest>,
+    payload_client: Arc<dyn PayloadClient>,
+    time_service: Arc<dyn TimeService>,
+    anchor_election_config: &DagAnchorElectionConfig,
+) -> anyhow::Result<DagBootstrapUnit> {
+    let author = signer.author();
+    let validators = epoch_state.verifier.get_ordered_account_addresses();
+    // The ledger info ending the previous epoch doesn't tell anything about the rounds of this one
+    let committed_round = if latest_ledger_info.ends_epoch() {
+        0
+    } else {
+        latest_ledger_info.round()
+    };
+
+    let dag = Arc::new(RwLock::new(Dag::new(
+        epoch_state.clone(),
+        storage.clone(),
+        committed_round,
+    )));
+
+    let (ordered_nodes_tx, ordered_nodes_rx) = unbounded();
+    let order_rule = OrderRule::new(
+        epoch_state.clone(),
+        latest_ledger_info,
+        dag.clone(),
+        create_anchor_election(anchor_election_config, &epoch_state),
+        ordered_nodes_tx,
+    );
+
+    let current_round = dag.read().highest_round();
+    let driver = DagDriver::new(
+        author,
+        epoch_state.clone(),
+        dag.clone(),
+        payload_client,
+        Arc::new(ReliableBroadcast::new(validators, network_sender.clone())),
+        current_round,
+        time_service,
+        storage.clone(),
+    )?;
+
+    let (fetcher, fetch_requester) =
+        DagFetcher::new(epoch_state.clone(), network_sender, dag.clone());
+    let handler = NetworkHandler::new(dag, dag_rpc_rx, signer, epoch_state, storage);
+
+    let (handler_abort_handle, handler_abort_registration) = AbortHandle::new_pair();
+    tokio::spawn(Abortable::new(handler.start(), handler_abort_registration));
+    let (fetcher_abort_handle, fetcher_abort_registration) = AbortHandle::new_pair();
+    tokio::spawn(Abortable::new(fetcher.start(), fetcher_abort_registration));
+
+    Ok(DagBootstrapUnit {
+        driver,
+        order_rule,
+        ordered_nodes_rx,
+        fetch_requester,
+        handler_abort_handle,
+        fetcher_abort_handle,
+    })
+}
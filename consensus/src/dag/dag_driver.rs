@@ -13,10 +13,11 @@ use crate::{
 };
 use aptos_consensus_types::common::{Author, Payload};
 use aptos_infallible::RwLock;
+use aptos_logger::error;
 use aptos_types::{block_info::Round, epoch_state::EpochState};
 use futures::{
     future::{AbortHandle, Abortable},
-    FutureExt,
+    Future, FutureExt,
 };
 use std::sync::Arc;
 
@@ -42,9 +43,25 @@ impl DagDriver {
         current_round: Round,
         time_service: Arc<dyn TimeService>,
         storage: Arc<dyn DAGStorage>,
-    ) -> Self {
-        // TODO: rebroadcast nodes after recovery
-        Self {
+    ) -> anyhow::Result<Self> {
+        // Only our latest node of this epoch may still need to be certified, the others are
+        // already certified, superseded or from an epoch that's over
+        let (mut nodes, expired_nodes): (Vec<_>, Vec<_>) = storage
+            .get_nodes()?
+            .into_values()
+            .partition(|node| node.metadata().epoch() == epoch_state.epoch);
+        nodes.sort_by_key(|node| node.metadata().round());
+        let pending_node = nodes.pop();
+        let expired_digests = expired_nodes
+            .iter()
+            .chain(&nodes)
+            .map(Node::digest)
+            .collect();
+        if let Err(e) = storage.delete_nodes(expired_digests) {
+            error!("Error deleting expired nodes: {:?}", e);
+        }
+
+        let mut driver = Self {
             author,
             epoch_state,
             dag,
@@ -54,7 +71,16 @@ impl DagDriver {
             time_service,
             rb_abort_handle: None,
             storage,
+        };
+
+        // rebroadcast the node we were broadcasting before a restart
+        if let Some(node) = pending_node {
+            if node.metadata().round() >= driver.current_round {
+                driver.current_round = node.metadata().round();
+                driver.rebroadcast_node(node);
+            }
         }
+        Ok(driver)
     }
 
     pub fn add_node(&mut self, node: CertifiedNode) -> anyhow::Result<()> {
@@ -97,7 +123,6 @@ impl DagDriver {
 
     pub fn broadcast_node(&mut self, node: Node) {
         let rb = self.reliable_broadcast.clone();
-        let (abort_handle, abort_registration) = AbortHandle::new_pair();
         let signature_builder =
             SignatureBuilder::new(node.metadata().clone(), self.epoch_state.clone());
         let cert_ack_set = CertificateAckState::new(self.epoch_state.verifier.len());
@@ -108,9 +133,46 @@ impl DagDriver {
                 let certified_node = CertifiedNode::new(node, certificate.signatures().to_owned());
                 rb.broadcast(certified_node, cert_ack_set)
             });
+        self.spawn_broadcast(task);
+    }
+
+    /// Resumes broadcasting a node recovered from storage, skipping the vote collection if it was
+    /// already certified.
+    fn rebroadcast_node(&mut self, node: Node) {
+        let maybe_certified_node = self
+            .dag
+            .read()
+            .get_node(node.metadata())
+            .filter(|certified_node| certified_node.digest() == node.digest());
+        match maybe_certified_node {
+            Some(certified_node) => {
+                let cert_ack_set = CertificateAckState::new(self.epoch_state.verifier.len());
+                let task = self
+                    .reliable_broadcast
+                    .broadcast(certified_node.as_ref().clone(), cert_ack_set);
+                self.spawn_broadcast(task);
+            },
+            None => self.broadcast_node(node),
+        }
+    }
+
+    fn spawn_broadcast<T>(&mut self, task: T)
+    where
+        T: Future + Send + 'static,
+        T::Output: Send + 'static,
+    {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
         tokio::spawn(Abortable::new(task, abort_registration));
         if let Some(prev_handle) = self.rb_abort_handle.replace(abort_handle) {
             prev_handle.abort();
         }
     }
 }
+
+impl Drop for DagDriver {
+    fn drop(&mut self) {
+        if let Some(handle) = self.rb_abort_handle.take() {
+            handle.abort();
+        }
+    }
+}
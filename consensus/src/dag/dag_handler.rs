@@ -22,7 +22,7 @@ use bytes::Bytes;
 use futures::StreamExt;
 use std::sync::Arc;
 
-struct NetworkHandler {
+pub struct NetworkHandler {
     dag_rpc_rx: aptos_channel::Receiver<Author, IncomingDAGRequest>,
     node_receiver: NodeBroadcastHandler,
     certified_node_receiver: CertifiedNodeHandler,
@@ -31,7 +31,7 @@ struct NetworkHandler {
 }
 
 impl NetworkHandler {
-    fn new(
+    pub fn new(
         dag: Arc<RwLock<Dag>>,
         dag_rpc_rx: aptos_channel::Receiver<Author, IncomingDAGRequest>,
         signer: ValidatorSigner,
@@ -52,7 +52,7 @@ impl NetworkHandler {
         }
     }
 
-    async fn start(mut self) {
+    pub async fn start(mut self) {
         // TODO(ibalajiarun): clean up Reliable Broadcast storage periodically.
         while let Some(msg) = self.dag_rpc_rx.next().await {
             if let Err(e) = self.process_rpc(msg).await {
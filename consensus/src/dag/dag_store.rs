@@ -45,10 +45,16 @@ pub struct Dag {
     /// Map between peer id to vector index
     author_to_index: HashMap<Author, usize>,
     storage: Arc<dyn DAGStorage>,
+    /// Round the DAG starts from, nodes below it are pruned on recovery
+    initial_round: Round,
 }
 
 impl Dag {
-    pub fn new(epoch_state: Arc<EpochState>, storage: Arc<dyn DAGStorage>) -> Self {
+    pub fn new(
+        epoch_state: Arc<EpochState>,
+        storage: Arc<dyn DAGStorage>,
+        initial_round: Round,
+    ) -> Self {
         let epoch = epoch_state.epoch;
         let author_to_index = epoch_state.verifier.address_to_validator_index().clone();
         let num_validators = author_to_index.len();
@@ -56,7 +62,9 @@ impl Dag {
         let mut expired = vec![];
         let mut nodes_by_round = BTreeMap::new();
         for (digest, certified_node) in all_nodes {
-            if certified_node.metadata().epoch() == epoch {
+            if certified_node.metadata().epoch() == epoch
+                && certified_node.metadata().round() >= initial_round
+            {
                 let arc_node = Arc::new(certified_node);
                 let index = *author_to_index
                     .get(arc_node.metadata().author())
@@ -77,6 +85,7 @@ impl Dag {
             nodes_by_round,
             author_to_index,
             storage,
+            initial_round,
         }
     }
 
@@ -85,7 +94,7 @@ impl Dag {
             .nodes_by_round
             .first_key_value()
             .map(|(round, _)| round)
-            .unwrap_or(&0)
+            .unwrap_or(&self.initial_round)
     }
 
     pub fn highest_round(&self) -> Round {
@@ -93,7 +102,7 @@ impl Dag {
             .nodes_by_round
             .last_key_value()
             .map(|(round, _)| round)
-            .unwrap_or(&0)
+            .unwrap_or(&self.initial_round)
     }
 
     pub fn add_node(&mut self, node: CertifiedNode) -> anyhow::Result<()> {
@@ -107,7 +116,11 @@ impl Dag {
         ensure!(round >= self.lowest_round(), "round too low");
         ensure!(round <= self.highest_round() + 1, "round too high");
         for parent in node.parents() {
-            ensure!(self.exists(parent.metadata()), "parent not exist");
+            // parents below the initial round have been pruned
+            ensure!(
+                parent.metadata().round() < self.initial_round || self.exists(parent.metadata()),
+                "parent not exist"
+            );
         }
         let round_ref = self
             .nodes_by_round
@@ -3,7 +3,10 @@
 
 use super::dag_store::NodeStatus;
 use crate::dag::{
-    anchor_election::AnchorElection, dag_store::Dag, types::NodeMetadata, CertifiedNode,
+    anchor_election::{AnchorElection, CommitEvent},
+    dag_store::Dag,
+    types::NodeMetadata,
+    CertifiedNode,
 };
 use aptos_consensus_types::common::Round;
 use aptos_crypto::HashValue;
@@ -124,7 +127,7 @@ impl OrderRule {
 
     /// Finalize the ordering with the given anchor node, update anchor election and construct blocks for execution.
     pub fn finalize_order(&mut self, anchor: Arc<CertifiedNode>) {
-        let _failed_anchors: Vec<_> = (self.lowest_unordered_anchor_round..anchor.round())
+        let failed_anchors: Vec<_> = (self.lowest_unordered_anchor_round..anchor.round())
             .step_by(2)
             .map(|failed_round| self.anchor_election.get_anchor(failed_round))
             .collect();
@@ -133,6 +136,16 @@ impl OrderRule {
             anchor.round(),
         ));
         self.lowest_unordered_anchor_round = anchor.round() + 1;
+        self.anchor_election.commit(CommitEvent::new(
+            anchor.round(),
+            *anchor.author(),
+            anchor
+                .parents()
+                .iter()
+                .map(|parent| *parent.metadata().author())
+                .collect(),
+            failed_anchors,
+        ));
 
         let mut dag_writer = self.dag.write();
         let ordered_nodes: Vec<_> = dag_writer
@@ -114,14 +114,20 @@ impl NodeBroadcastHandler {
     ) -> Self {
         let epoch = epoch_state.epoch;
         let votes_by_round_peer = read_votes_from_storage(&storage, epoch);
+        let lowest_round = dag.read().lowest_round();
 
-        Self {
+        let mut handler = Self {
             dag,
             votes_by_round_peer,
             signer,
             epoch_state,
             storage,
+        };
+        // votes for rounds pruned from the dag are no longer needed
+        if let Err(err) = handler.gc_before_round(lowest_round) {
+            error!("Error deleting expired votes: {:?}", err);
         }
+        handler
     }
 
     pub fn gc_before_round(&mut self, min_round: Round) -> anyhow::Result<()> {
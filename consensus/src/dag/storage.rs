@@ -13,7 +13,10 @@ use std::collections::HashMap;
 pub trait DAGStorage: Send + Sync {
     fn save_node(&self, node: &Node) -> anyhow::Result<()>;
 
-    fn delete_node(&self, digest: HashValue) -> anyhow::Result<()>;
+    /// Returns our own nodes, the most recent of which may not be certified yet
+    fn get_nodes(&self) -> anyhow::Result<HashMap<HashValue, Node>>;
+
+    fn delete_nodes(&self, digests: Vec<HashValue>) -> anyhow::Result<()>;
 
     fn save_vote(&self, node_id: &NodeId, vote: &Vote) -> anyhow::Result<()>;
 
@@ -33,8 +36,12 @@ impl DAGStorage for ConsensusDB {
         Ok(self.save_node(node)?)
     }
 
-    fn delete_node(&self, digest: HashValue) -> anyhow::Result<()> {
-        Ok(self.delete_node(digest)?)
+    fn get_nodes(&self) -> anyhow::Result<HashMap<HashValue, Node>> {
+        Ok(self.get_nodes()?)
+    }
+
+    fn delete_nodes(&self, digests: Vec<HashValue>) -> anyhow::Result<()> {
+        Ok(self.delete_nodes(digests)?)
     }
 
     fn save_vote(&self, node_id: &NodeId, vote: &Vote) -> anyhow::Result<()> {
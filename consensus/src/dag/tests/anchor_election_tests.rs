@@ -0,0 +1,78 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::dag::anchor_election::{AnchorElection, CommitEvent, ReputationAnchorElection};
+use aptos_config::config::DagReputationConfig;
+use aptos_consensus_types::common::Author;
+use aptos_types::{epoch_state::EpochState, validator_verifier::random_validator_verifier};
+
+fn new_epoch_state() -> EpochState {
+    let (_, validator_verifier) = random_validator_verifier(4, None, false);
+    EpochState {
+        epoch: 1,
+        verifier: validator_verifier,
+    }
+}
+
+/// Anchors of validators 0 to 2 committed at every other round up to the given one, with
+/// validator 3 failing to produce its anchor and never voting
+fn commit_anchors(election: &mut ReputationAnchorElection, validators: &[Author], until: u64) {
+    for round in (0..=until).step_by(2) {
+        election.commit(CommitEvent::new(
+            round,
+            validators[(round / 2) as usize % 3],
+            validators[0..3].to_vec(),
+            vec![validators[3]],
+        ));
+    }
+}
+
+#[test]
+fn test_reputation_anchor_election_independent_of_commit_progress() {
+    let epoch_state = new_epoch_state();
+    let validators = epoch_state.verifier.get_ordered_account_addresses();
+    let config = DagReputationConfig {
+        failed_weight: 0,
+        exclude_round: 10,
+        ..DagReputationConfig::default()
+    };
+    let mut behind = ReputationAnchorElection::new(&epoch_state, config.clone());
+    let mut ahead = ReputationAnchorElection::new(&epoch_state, config);
+
+    commit_anchors(&mut behind, &validators, 20);
+    commit_anchors(&mut ahead, &validators, 20);
+    // Only the validator ahead has seen validator 0 failing its anchors since
+    for round in (22..=30).step_by(2) {
+        ahead.commit(CommitEvent::new(
+            round,
+            validators[1],
+            validators[1..4].to_vec(),
+            vec![validators[0]],
+        ));
+    }
+    assert!((42..142).all(|round| ahead.get_anchor(round) != validators[0]));
+
+    // Rounds the validator behind still has to order agree with the one ahead, as long as the
+    // anchors it is missing are within the excluded rounds
+    for round in 21..=31 {
+        assert_eq!(behind.get_anchor(round), ahead.get_anchor(round));
+    }
+}
+
+#[test]
+fn test_reputation_anchor_election_penalizes_failed_anchors() {
+    let epoch_state = new_epoch_state();
+    let validators = epoch_state.verifier.get_ordered_account_addresses();
+    let config = DagReputationConfig {
+        failed_weight: 0,
+        ..DagReputationConfig::default()
+    };
+    let mut election = ReputationAnchorElection::new(&epoch_state, config);
+
+    commit_anchors(&mut election, &validators, 18);
+
+    // Only the rounds far enough after the commits see the reputation
+    for round in 60..160 {
+        assert_ne!(election.get_anchor(round), validators[3]);
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    dag::{
+        bootstrap::bootstrap_dag,
+        dag_driver::DagDriver,
+        dag_network::DAGNetworkSender,
+        dag_store::Dag,
+        reliable_broadcast::ReliableBroadcast,
+        storage::DAGStorage,
+        tests::{
+            dag_test::MockStorage,
+            helpers::{new_certified_node, new_node},
+        },
+        types::{DAGMessage, Node, NodeCertificate},
+    },
+    network::TConsensusMsg,
+    network_interface::ConsensusMsg,
+    test_utils::MockPayloadManager,
+    util::mock_time_service::SimulatedTimeService,
+};
+use aptos_channels::{aptos_channel, message_queues::QueueStyle};
+use aptos_config::config::DagAnchorElectionConfig;
+use aptos_consensus_types::common::Author;
+use aptos_crypto::HashValue;
+use aptos_infallible::{Mutex, RwLock};
+use aptos_types::{
+    block_info::BlockInfo, epoch_state::EpochState, ledger_info::LedgerInfo,
+    validator_signer::ValidatorSigner, validator_verifier::random_validator_verifier,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::Duration};
+
+/// Records every node it is asked to broadcast and never responds, so a broadcast stays pending
+struct PendingDAGSender {
+    received: Mutex<Vec<Node>>,
+}
+
+impl PendingDAGSender {
+    fn new() -> Self {
+        Self {
+            received: Mutex::new(vec![]),
+        }
+    }
+}
+
+#[async_trait]
+impl DAGNetworkSender for PendingDAGSender {
+    async fn send_rpc(
+        &self,
+        _receiver: Author,
+        message: ConsensusMsg,
+        _timeout: Duration,
+    ) -> anyhow::Result<ConsensusMsg> {
+        let message: DAGMessage = TConsensusMsg::from_network_message(message)?;
+        if let DAGMessage::NodeMsg(node) = message {
+            self.received.lock().push(node);
+        }
+        futures::future::pending().await
+    }
+
+    async fn send_rpc_with_fallbacks(
+        &self,
+        _responders: Vec<Author>,
+        _message: ConsensusMsg,
+        _timeout: Duration,
+    ) -> anyhow::Result<ConsensusMsg> {
+        unimplemented!();
+    }
+}
+
+fn new_driver(
+    signer: &ValidatorSigner,
+    epoch_state: Arc<EpochState>,
+    dag: Arc<RwLock<Dag>>,
+    sender: Arc<PendingDAGSender>,
+    storage: Arc<dyn DAGStorage>,
+) -> DagDriver {
+    let validators = epoch_state.verifier.get_ordered_account_addresses();
+    let current_round = dag.read().highest_round();
+    DagDriver::new(
+        signer.author(),
+        epoch_state,
+        dag,
+        Arc::new(MockPayloadManager::new(None)),
+        Arc::new(ReliableBroadcast::new(validators, sender)),
+        current_round,
+        Arc::new(SimulatedTimeService::new()),
+        storage,
+    )
+    .unwrap()
+}
+
+/// Returns a DAG with the certified nodes of the first three validators up to round 3, and
+/// the strong links to enter round 4 with
+fn new_dag_until_round_3(
+    signers: &[ValidatorSigner],
+    epoch_state: Arc<EpochState>,
+    storage: Arc<dyn DAGStorage>,
+) -> (Arc<RwLock<Dag>>, Vec<NodeCertificate>) {
+    let mut dag = Dag::new(epoch_state.clone(), storage, 0);
+    for round in 1..4 {
+        let parents = dag
+            .get_strong_links_for_round(round - 1, &epoch_state.verifier)
+            .unwrap_or_default();
+        for signer in &signers[0..3] {
+            let node = new_certified_node(round, signer.author(), parents.clone());
+            assert!(dag.add_node(node).is_ok());
+        }
+    }
+    let strong_links = dag
+        .get_strong_links_for_round(3, &epoch_state.verifier)
+        .unwrap();
+    (Arc::new(RwLock::new(dag)), strong_links)
+}
+
+async fn wait_for_broadcast(sender: &PendingDAGSender) -> Node {
+    loop {
+        if let Some(node) = sender.received.lock().first() {
+            return node.clone();
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_dag_driver_rebroadcast_after_restart() {
+    let (signers, validator_verifier) = random_validator_verifier(4, None, false);
+    let epoch_state = Arc::new(EpochState {
+        epoch: 1,
+        verifier: validator_verifier,
+    });
+    let storage = Arc::new(MockStorage::new());
+    let (dag, strong_links) = new_dag_until_round_3(&signers, epoch_state.clone(), storage.clone());
+
+    let sender = Arc::new(PendingDAGSender::new());
+    let mut driver = new_driver(
+        &signers[0],
+        epoch_state.clone(),
+        dag,
+        sender.clone(),
+        storage.clone(),
+    );
+    driver.enter_new_round(strong_links);
+    let pending_node = wait_for_broadcast(&sender).await;
+    assert_eq!(pending_node.metadata().round(), 4);
+    assert!(storage
+        .get_nodes()
+        .unwrap()
+        .contains_key(&pending_node.digest()));
+
+    // crash before the node is certified and restart from the committed round
+    drop(driver);
+    let committed_ledger_info = LedgerInfo::new(
+        BlockInfo::new(1, 2, HashValue::zero(), HashValue::zero(), 0, 0, None),
+        HashValue::zero(),
+    );
+    let (_dag_rpc_tx, dag_rpc_rx) = aptos_channel::new(QueueStyle::FIFO, 16, None);
+    let sender = Arc::new(PendingDAGSender::new());
+    let _bootstrap_unit = bootstrap_dag(
+        signers[0].clone(),
+        epoch_state,
+        committed_ledger_info,
+        storage.clone(),
+        sender.clone(),
+        dag_rpc_rx,
+        Arc::new(MockPayloadManager::new(None)),
+        Arc::new(SimulatedTimeService::new()),
+        &DagAnchorElectionConfig::RoundRobin,
+    )
+    .unwrap();
+    assert!(storage
+        .get_certified_nodes()
+        .unwrap()
+        .values()
+        .all(|node| node.metadata().round() >= 2));
+
+    let rebroadcast_node = wait_for_broadcast(&sender).await;
+    assert_eq!(rebroadcast_node.digest(), pending_node.digest());
+}
+
+#[tokio::test]
+async fn test_dag_driver_rebroadcast_ignores_previous_epochs() {
+    let (signers, validator_verifier) = random_validator_verifier(4, None, false);
+    let epoch_state = Arc::new(EpochState {
+        epoch: 1,
+        verifier: validator_verifier,
+    });
+    let storage = Arc::new(MockStorage::new());
+    let (dag, strong_links) = new_dag_until_round_3(&signers, epoch_state.clone(), storage.clone());
+
+    let sender = Arc::new(PendingDAGSender::new());
+    let mut driver = new_driver(
+        &signers[0],
+        epoch_state.clone(),
+        dag.clone(),
+        sender.clone(),
+        storage.clone(),
+    );
+    driver.enter_new_round(strong_links);
+    let pending_node = wait_for_broadcast(&sender).await;
+    assert_eq!(pending_node.metadata().round(), 4);
+
+    // our node of the previous epoch, which got to a higher round than this one so far
+    let stale_node = new_node(10, 0, signers[0].author(), vec![]);
+    assert_eq!(stale_node.metadata().epoch(), 0);
+    storage.save_node(&stale_node).unwrap();
+
+    // crash before the node is certified and restart
+    drop(driver);
+    let sender = Arc::new(PendingDAGSender::new());
+    let _driver = new_driver(
+        &signers[0],
+        epoch_state,
+        dag,
+        sender.clone(),
+        storage.clone(),
+    );
+
+    let rebroadcast_node = wait_for_broadcast(&sender).await;
+    assert_eq!(rebroadcast_node.digest(), pending_node.digest());
+    assert_eq!(
+        storage.get_nodes().unwrap().into_keys().collect::<Vec<_>>(),
+        vec![pending_node.digest()]
+    );
+}
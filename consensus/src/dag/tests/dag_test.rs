@@ -39,8 +39,14 @@ impl DAGStorage for MockStorage {
         Ok(())
     }
 
-    fn delete_node(&self, digest: HashValue) -> anyhow::Result<()> {
-        self.node_data.lock().remove(&digest);
+    fn get_nodes(&self) -> anyhow::Result<HashMap<HashValue, Node>> {
+        Ok(self.node_data.lock().clone())
+    }
+
+    fn delete_nodes(&self, digests: Vec<HashValue>) -> anyhow::Result<()> {
+        for digest in digests {
+            self.node_data.lock().remove(&digest);
+        }
         Ok(())
     }
 
@@ -86,7 +92,7 @@ fn setup() -> (Vec<ValidatorSigner>, Arc<EpochState>, Dag, Arc<MockStorage>) {
         verifier: validator_verifier,
     });
     let storage = Arc::new(MockStorage::new());
-    let dag = Dag::new(epoch_state.clone(), storage.clone());
+    let dag = Dag::new(epoch_state.clone(), storage.clone(), 0);
     (signers, epoch_state, dag, storage)
 }
 
@@ -174,7 +180,7 @@ fn test_dag_recover_from_storage() {
             assert!(dag.add_node(node).is_ok());
         }
     }
-    let new_dag = Dag::new(epoch_state.clone(), storage.clone());
+    let new_dag = Dag::new(epoch_state.clone(), storage.clone(), 0);
 
     for metadata in &metadatas {
         assert!(new_dag.exists(metadata));
@@ -185,7 +191,7 @@ fn test_dag_recover_from_storage() {
         verifier: epoch_state.verifier.clone(),
     });
 
-    let _new_epoch_dag = Dag::new(new_epoch_state, storage.clone());
+    let _new_epoch_dag = Dag::new(new_epoch_state, storage.clone(), 0);
     assert!(storage.certified_node_data.lock().is_empty());
 }
 
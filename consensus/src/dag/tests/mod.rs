@@ -1,6 +1,8 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+mod anchor_election_tests;
+mod dag_driver_tests;
 mod dag_test;
 mod fetcher_test;
 mod helpers;
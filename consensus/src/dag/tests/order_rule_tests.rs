@@ -3,7 +3,7 @@
 
 use crate::{
     dag::{
-        anchor_election::RoundRobinAnchorElection,
+        anchor_election::{AnchorElection, ReputationAnchorElection, RoundRobinAnchorElection},
         dag_store::Dag,
         order_rule::OrderRule,
         tests::{dag_test::MockStorage, helpers::new_certified_node},
@@ -12,6 +12,7 @@ use crate::{
     },
     test_utils::placeholder_ledger_info,
 };
+use aptos_config::config::DagReputationConfig;
 use aptos_consensus_types::common::Author;
 use aptos_infallible::{Mutex, RwLock};
 use aptos_types::{
@@ -120,11 +121,9 @@ fn generate_dag_nodes(
 fn create_order_rule(
     epoch_state: Arc<EpochState>,
     dag: Arc<RwLock<Dag>>,
+    anchor_election: Box<dyn AnchorElection>,
 ) -> (OrderRule, UnboundedReceiver<Vec<Arc<CertifiedNode>>>) {
     let ledger_info = placeholder_ledger_info();
-    let anchor_election = Box::new(RoundRobinAnchorElection::new(
-        epoch_state.verifier.get_ordered_account_addresses(),
-    ));
     let (tx, rx) = unbounded();
     (
         OrderRule::new(epoch_state, ledger_info, dag, anchor_election, tx),
@@ -136,6 +135,30 @@ const NUM_HOLES: usize = 1;
 const NUM_VALIDATORS: usize = 4;
 const NUM_ROUNDS: u64 = 50;
 const NUM_PERMUTATION: usize = 100;
+const NUM_DELIVERY_ORDERS: usize = 20;
+
+/// Adds the nodes to the DAG in the order they are delivered, holding back each node until its
+/// parents are there, and passes every node added to the order rule.
+fn deliver_nodes(
+    order_rule: &mut OrderRule,
+    dag: &RwLock<Dag>,
+    nodes: &[CertifiedNode],
+    delivery_order: &[usize],
+) {
+    let mut pending: Vec<&CertifiedNode> = vec![];
+    for idx in delivery_order {
+        pending.push(&nodes[*idx]);
+        while let Some(pos) = pending
+            .iter()
+            .position(|node| dag.read().all_exists(node.parents_metadata()))
+        {
+            let node = pending.swap_remove(pos);
+            dag.write().add_node(node.clone()).unwrap();
+            order_rule.process_new_node(node);
+        }
+    }
+    assert!(pending.is_empty());
+}
 
 proptest! {
     #[test]
@@ -153,7 +176,7 @@ proptest! {
             epoch: 1,
             verifier: validator_verifier,
         });
-        let mut dag = Dag::new(epoch_state.clone(), Arc::new(MockStorage::new()));
+        let mut dag = Dag::new(epoch_state.clone(), Arc::new(MockStorage::new()), 0);
         for round_nodes in &nodes {
             for node in round_nodes.iter().flatten() {
                 dag.add_node(node.clone()).unwrap();
@@ -165,7 +188,8 @@ proptest! {
             for seq in sequences {
                 s.spawn(|_| {
                     let dag = Arc::new(RwLock::new(dag.clone()));
-                    let (mut order_rule, mut receiver) = create_order_rule(epoch_state.clone(), dag);
+                    let anchor_election = Box::new(RoundRobinAnchorElection::new(validators.clone()));
+                    let (mut order_rule, mut receiver) = create_order_rule(epoch_state.clone(), dag, anchor_election);
                     for idx in seq {
                         order_rule.process_new_node(&flatten_nodes[idx]);
                     }
@@ -187,3 +211,68 @@ proptest! {
         }
     }
 }
+
+proptest! {
+    #[test]
+    fn test_order_rule_with_reputation_anchor_election(
+        mut dag_with_holes in generate_virtual_dag(NUM_VALIDATORS, NUM_HOLES, NUM_ROUNDS),
+        mut dag in generate_virtual_dag(NUM_VALIDATORS, 0, NUM_ROUNDS),
+        delivery_orders in generate_permutations(
+            NUM_DELIVERY_ORDERS,
+            (2 * NUM_VALIDATORS - NUM_HOLES) * NUM_ROUNDS as usize
+        )
+    ) {
+        let (_, validator_verifier) = random_validator_verifier(NUM_VALIDATORS, None, false);
+        let validators = validator_verifier.get_ordered_account_addresses();
+        let author_indexes = validator_verifier.address_to_validator_index().clone();
+        dag.append(&mut dag_with_holes);
+        let nodes: Vec<_> = generate_dag_nodes(&dag, &validators)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .collect();
+        let epoch_state = Arc::new(EpochState {
+            epoch: 1,
+            verifier: validator_verifier,
+        });
+        // A short window and exclusion, so the reputation changes the anchors within the test
+        let config = DagReputationConfig {
+            window_num_validators_multiplier: 2,
+            exclude_round: 4,
+            ..DagReputationConfig::default()
+        };
+
+        // Every validator builds its own DAG from the nodes in the order it receives them
+        let all_ordered = Arc::new(Mutex::new(vec![]));
+        rayon::scope(|s| {
+            for delivery_order in &delivery_orders {
+                s.spawn(|_| {
+                    let dag = Arc::new(RwLock::new(Dag::new(
+                        epoch_state.clone(),
+                        Arc::new(MockStorage::new()),
+                        0,
+                    )));
+                    let anchor_election =
+                        Box::new(ReputationAnchorElection::new(&epoch_state, config.clone()));
+                    let (mut order_rule, mut receiver) =
+                        create_order_rule(epoch_state.clone(), dag.clone(), anchor_election);
+                    deliver_nodes(&mut order_rule, &dag, &nodes, delivery_order);
+                    let mut ordered = vec![];
+                    while let Ok(Some(ordered_nodes)) = receiver.try_next() {
+                        // the anchor is the first of the ordered nodes
+                        ordered.push(ordered_nodes[0].clone());
+                    }
+                    all_ordered.lock().push(ordered);
+                });
+            }
+        });
+        let display = |node: &Arc<CertifiedNode>| {
+            (node.metadata().round(), *author_indexes.get(node.metadata().author()).unwrap())
+        };
+        let longest: Vec<_> = all_ordered.lock().iter().max_by(|v1, v2| v1.len().cmp(&v2.len())).unwrap().iter().map(display).collect();
+        for ordered in all_ordered.lock().iter() {
+            let anchors: Vec<_> = ordered.iter().map(display).collect();
+            assert_eq!(anchors, longest[..anchors.len()]);
+        }
+    }
+}
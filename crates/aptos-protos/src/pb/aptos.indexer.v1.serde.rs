@@ -18,6 +18,9 @@ impl serde::Serialize for GetTransactionsRequest {
         if self.batch_size.is_some() {
             len += 1;
         }
+        if self.filter.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aptos.indexer.v1.GetTransactionsRequest", len)?;
         if let Some(v) = self.starting_version.as_ref() {
             struct_ser.serialize_field("startingVersion", ToString::to_string(&v).as_str())?;
@@ -28,6 +31,9 @@ impl serde::Serialize for GetTransactionsRequest {
         if let Some(v) = self.batch_size.as_ref() {
             struct_ser.serialize_field("batchSize", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.filter.as_ref() {
+            struct_ser.serialize_field("filter", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -44,6 +50,7 @@ impl<'de> serde::Deserialize<'de> for GetTransactionsRequest {
             "transactionsCount",
             "batch_size",
             "batchSize",
+            "filter",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -51,6 +58,7 @@ impl<'de> serde::Deserialize<'de> for GetTransactionsRequest {
             StartingVersion,
             TransactionsCount,
             BatchSize,
+            Filter,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -75,6 +83,7 @@ impl<'de> serde::Deserialize<'de> for GetTransactionsRequest {
                             "startingVersion" | "starting_version" => Ok(GeneratedField::StartingVersion),
                             "transactionsCount" | "transactions_count" => Ok(GeneratedField::TransactionsCount),
                             "batchSize" | "batch_size" => Ok(GeneratedField::BatchSize),
+                            "filter" => Ok(GeneratedField::Filter),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -97,6 +106,7 @@ impl<'de> serde::Deserialize<'de> for GetTransactionsRequest {
                 let mut starting_version__ = None;
                 let mut transactions_count__ = None;
                 let mut batch_size__ = None;
+                let mut filter__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::StartingVersion => {
@@ -123,18 +133,189 @@ impl<'de> serde::Deserialize<'de> for GetTransactionsRequest {
                                 map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::Filter => {
+                            if filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("filter"));
+                            }
+                            filter__ = map.next_value()?;
+                        }
                     }
                 }
                 Ok(GetTransactionsRequest {
                     starting_version: starting_version__,
                     transactions_count: transactions_count__,
                     batch_size: batch_size__,
+                    filter: filter__,
                 })
             }
         }
         deserializer.deserialize_struct("aptos.indexer.v1.GetTransactionsRequest", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TransactionsFilter {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.sender_addresses.is_empty() {
+            len += 1;
+        }
+        if !self.entry_functions.is_empty() {
+            len += 1;
+        }
+        if !self.event_types.is_empty() {
+            len += 1;
+        }
+        if !self.write_resource_types.is_empty() {
+            len += 1;
+        }
+        if self.success_only {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aptos.indexer.v1.TransactionsFilter", len)?;
+        if !self.sender_addresses.is_empty() {
+            struct_ser.serialize_field("senderAddresses", &self.sender_addresses)?;
+        }
+        if !self.entry_functions.is_empty() {
+            struct_ser.serialize_field("entryFunctions", &self.entry_functions)?;
+        }
+        if !self.event_types.is_empty() {
+            struct_ser.serialize_field("eventTypes", &self.event_types)?;
+        }
+        if !self.write_resource_types.is_empty() {
+            struct_ser.serialize_field("writeResourceTypes", &self.write_resource_types)?;
+        }
+        if self.success_only {
+            struct_ser.serialize_field("successOnly", &self.success_only)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionsFilter {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "sender_addresses",
+            "senderAddresses",
+            "entry_functions",
+            "entryFunctions",
+            "event_types",
+            "eventTypes",
+            "write_resource_types",
+            "writeResourceTypes",
+            "success_only",
+            "successOnly",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SenderAddresses,
+            EntryFunctions,
+            EventTypes,
+            WriteResourceTypes,
+            SuccessOnly,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "senderAddresses" | "sender_addresses" => Ok(GeneratedField::SenderAddresses),
+                            "entryFunctions" | "entry_functions" => Ok(GeneratedField::EntryFunctions),
+                            "eventTypes" | "event_types" => Ok(GeneratedField::EventTypes),
+                            "writeResourceTypes" | "write_resource_types" => Ok(GeneratedField::WriteResourceTypes),
+                            "successOnly" | "success_only" => Ok(GeneratedField::SuccessOnly),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionsFilter;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aptos.indexer.v1.TransactionsFilter")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<TransactionsFilter, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut sender_addresses__ = None;
+                let mut entry_functions__ = None;
+                let mut event_types__ = None;
+                let mut write_resource_types__ = None;
+                let mut success_only__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::SenderAddresses => {
+                            if sender_addresses__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("senderAddresses"));
+                            }
+                            sender_addresses__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EntryFunctions => {
+                            if entry_functions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entryFunctions"));
+                            }
+                            entry_functions__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::EventTypes => {
+                            if event_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("eventTypes"));
+                            }
+                            event_types__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::WriteResourceTypes => {
+                            if write_resource_types__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("writeResourceTypes"));
+                            }
+                            write_resource_types__ = Some(map.next_value()?);
+                        }
+                        GeneratedField::SuccessOnly => {
+                            if success_only__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("successOnly"));
+                            }
+                            success_only__ = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(TransactionsFilter {
+                    sender_addresses: sender_addresses__.unwrap_or_default(),
+                    entry_functions: entry_functions__.unwrap_or_default(),
+                    event_types: event_types__.unwrap_or_default(),
+                    write_resource_types: write_resource_types__.unwrap_or_default(),
+                    success_only: success_only__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aptos.indexer.v1.TransactionsFilter", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TransactionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -149,6 +330,9 @@ impl serde::Serialize for TransactionsResponse {
         if self.chain_id.is_some() {
             len += 1;
         }
+        if self.end_version.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aptos.indexer.v1.TransactionsResponse", len)?;
         if !self.transactions.is_empty() {
             struct_ser.serialize_field("transactions", &self.transactions)?;
@@ -156,6 +340,9 @@ impl serde::Serialize for TransactionsResponse {
         if let Some(v) = self.chain_id.as_ref() {
             struct_ser.serialize_field("chainId", ToString::to_string(&v).as_str())?;
         }
+        if let Some(v) = self.end_version.as_ref() {
+            struct_ser.serialize_field("endVersion", ToString::to_string(&v).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -169,12 +356,15 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
             "transactions",
             "chain_id",
             "chainId",
+            "end_version",
+            "endVersion",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Transactions,
             ChainId,
+            EndVersion,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -198,6 +388,7 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
                         match value {
                             "transactions" => Ok(GeneratedField::Transactions),
                             "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "endVersion" | "end_version" => Ok(GeneratedField::EndVersion),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -219,6 +410,7 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
             {
                 let mut transactions__ = None;
                 let mut chain_id__ = None;
+                let mut end_version__ = None;
                 while let Some(k) = map.next_key()? {
                     match k {
                         GeneratedField::Transactions => {
@@ -235,11 +427,20 @@ impl<'de> serde::Deserialize<'de> for TransactionsResponse {
                                 map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
                             ;
                         }
+                        GeneratedField::EndVersion => {
+                            if end_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endVersion"));
+                            }
+                            end_version__ =
+                                map.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
                     }
                 }
                 Ok(TransactionsResponse {
                     transactions: transactions__.unwrap_or_default(),
                     chain_id: chain_id__,
+                    end_version: end_version__,
                 })
             }
         }
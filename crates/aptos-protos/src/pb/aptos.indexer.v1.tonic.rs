@@ -71,7 +71,7 @@ pub mod raw_data_client {
             self.inner = self.inner.accept_compressed(encoding);
             self
         }
-        /** Get transactions batch without any filtering from starting version and end if transaction count is present.
+        /** Get transactions batch from starting version and end if transaction count is present, keeping only the transactions matching the filter if one is present.
 */
         pub async fn get_transactions(
             &mut self,
@@ -110,7 +110,7 @@ pub mod raw_data_server {
             >
             + Send
             + 'static;
-        /** Get transactions batch without any filtering from starting version and end if transaction count is present.
+        /** Get transactions batch from starting version and end if transaction count is present, keeping only the transactions matching the filter if one is present.
 */
         async fn get_transactions(
             &self,
@@ -10,7 +10,8 @@ use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue},
     Client as ReqwestClient, ClientBuilder as ReqwestClientBuilder,
 };
-use std::{str::FromStr, time::Duration};
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tokio::sync::OnceCell;
 use url::Url;
 
 pub enum AptosBaseUrl {
@@ -96,6 +97,7 @@ impl ClientBuilder {
                 .unwrap(),
             base_url: self.base_url,
             version_path_base,
+            wait_by_hash_enabled: Arc::new(OnceCell::new()),
         }
     }
 }
@@ -28,7 +28,7 @@ use aptos_api_types::{
     mime_types::{BCS, BCS_SIGNED_TRANSACTION as BCS_CONTENT_TYPE, JSON},
     AptosError, BcsBlock, Block, GasEstimation, HexEncodedBytes, IndexResponse, MoveModuleId,
     TransactionData, TransactionOnChainData, TransactionsBatchSubmissionResult, UserTransaction,
-    VersionedEvent, ViewRequest,
+    VersionedEvent, ViewBatchRequest, ViewBatchResult, ViewRequest,
 };
 use aptos_crypto::HashValue;
 use aptos_logger::{debug, info, sample, sample::SampleRate};
@@ -47,8 +47,8 @@ use reqwest::{
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::{json, Value};
 pub use state::State;
-use std::{collections::BTreeMap, future::Future, time::Duration};
-use tokio::time::Instant;
+use std::{collections::BTreeMap, future::Future, sync::Arc, time::Duration};
+use tokio::{sync::OnceCell, time::Instant};
 pub use types::{deserialize_from_prefixed_hex_string, Account, Resource};
 use url::Url;
 
@@ -69,6 +69,8 @@ pub struct Client {
     inner: ReqwestClient,
     base_url: Url,
     version_path_base: String,
+    /// Whether the server advertises `transactions/wait_by_hash`, once known
+    wait_by_hash_enabled: Arc<OnceCell<bool>>,
 }
 
 impl Client {
@@ -307,6 +309,26 @@ impl Client {
         self.json(response).await
     }
 
+    /// Executes several view functions in a single request, each at its own version. A function
+    /// failing doesn't fail the request, its result holds the error instead.
+    pub async fn view_batch(
+        &self,
+        requests: &[ViewBatchRequest],
+    ) -> AptosResult<Response<Vec<ViewBatchResult>>> {
+        let requests = serde_json::to_string(requests)?;
+        let url = self.build_path("view/batch")?;
+
+        let response = self
+            .inner
+            .post(url)
+            .header(CONTENT_TYPE, JSON)
+            .body(requests)
+            .send()
+            .await?;
+
+        self.json(response).await
+    }
+
     pub async fn simulate(
         &self,
         txn: &SignedTransaction,
@@ -563,15 +585,17 @@ impl Client {
         fetch: F,
     ) -> AptosResult<Response<T>>
     where
-        F: Fn(HashValue) -> Fut,
+        F: Fn(HashValue, bool) -> Fut,
         Fut: Future<Output = AptosResult<WaitForTransactionResult<T>>>,
     {
         const DEFAULT_DELAY: Duration = Duration::from_millis(500);
         let mut reached_mempool = false;
         let start = std::time::Instant::now();
+        let wait_on_server = self.wait_by_hash_enabled().await;
         loop {
             let mut chain_timestamp_usecs = None;
-            match fetch(hash).await {
+            let fetch_start = std::time::Instant::now();
+            match fetch(hash, wait_on_server).await {
                 Ok(WaitForTransactionResult::Success(result)) => {
                     return Ok(result);
                 },
@@ -671,7 +695,26 @@ impl Client {
                 );
             }
 
-            tokio::time::sleep(DEFAULT_DELAY).await;
+            // Polls are at least DEFAULT_DELAY apart, whether or not the server held the
+            // request, which it doesn't when too many requests are already waiting on it
+            tokio::time::sleep(DEFAULT_DELAY.saturating_sub(fetch_start.elapsed())).await;
+        }
+    }
+
+    /// Returns whether the server supports waiting for transactions with
+    /// `transactions/wait_by_hash`, as advertised by its index
+    async fn wait_by_hash_enabled(&self) -> bool {
+        if let Some(enabled) = self.wait_by_hash_enabled.get() {
+            return *enabled;
+        }
+        match self.get_index().await {
+            Ok(response) => {
+                let enabled = response.inner().wait_by_hash_enabled.unwrap_or(false);
+                let _ = self.wait_by_hash_enabled.set(enabled);
+                enabled
+            },
+            // Don't cache the result, the server may just be unavailable right now
+            Err(_) => false,
         }
     }
 
@@ -687,8 +730,12 @@ impl Client {
             expiration_timestamp_secs,
             max_server_lag_wait,
             timeout_from_call,
-            |hash| async move {
-                let resp = self.get_transaction_by_hash_inner(hash).await?;
+            |hash, wait_on_server| async move {
+                let resp = if wait_on_server {
+                    self.wait_transaction_by_hash_inner(hash).await?
+                } else {
+                    self.get_transaction_by_hash_inner(hash).await?
+                };
                 if resp.status() != StatusCode::NOT_FOUND {
                     let txn_resp: Response<Transaction> = self.json(resp).await?;
                     let (transaction, state) = txn_resp.into_parts();
@@ -728,8 +775,12 @@ impl Client {
             expiration_timestamp_secs,
             max_server_lag_wait,
             timeout_from_call,
-            |hash| async move {
-                let resp = self.get_transaction_by_hash_bcs_inner(hash).await?;
+            |hash, wait_on_server| async move {
+                let resp = if wait_on_server {
+                    self.wait_transaction_by_hash_bcs_inner(hash).await?
+                } else {
+                    self.get_transaction_by_hash_bcs_inner(hash).await?
+                };
                 if resp.status() != StatusCode::NOT_FOUND {
                     let resp = self.check_and_parse_bcs_response(resp).await?;
                     let resp = resp.and_then(|bytes| bcs::from_bytes(&bytes))?;
@@ -847,6 +898,28 @@ impl Client {
         Ok(self.inner.get(url).send().await?)
     }
 
+    async fn wait_transaction_by_hash_inner(
+        &self,
+        hash: HashValue,
+    ) -> AptosResult<reqwest::Response> {
+        let url = self.build_path(&format!(
+            "transactions/wait_by_hash/{}",
+            hash.to_hex_literal()
+        ))?;
+        Ok(self.inner.get(url).send().await?)
+    }
+
+    async fn wait_transaction_by_hash_bcs_inner(
+        &self,
+        hash: HashValue,
+    ) -> AptosResult<reqwest::Response> {
+        let url = self.build_path(&format!(
+            "transactions/wait_by_hash/{}",
+            hash.to_hex_literal()
+        ))?;
+        Ok(self.inner.get(url).header(ACCEPT, BCS).send().await?)
+    }
+
     pub async fn get_transaction_by_version(
         &self,
         version: u64,
@@ -1590,6 +1663,7 @@ impl From<(ReqwestClient, Url)> for Client {
             inner,
             base_url,
             version_path_base: DEFAULT_VERSION_PATH_BASE.to_string(),
+            wait_by_hash_enabled: Arc::new(OnceCell::new()),
         }
     }
 }
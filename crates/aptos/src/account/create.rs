@@ -1,7 +1,9 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::common::types::{CliCommand, CliTypedResult, TransactionOptions, TransactionSummary};
+use crate::common::types::{
+    CliCommand, CliTypedResult, TransactionOptions, TransactionOutcome, TransactionSummary,
+};
 use aptos_cached_packages::aptos_stdlib;
 use aptos_types::account_address::AccountAddress;
 use async_trait::async_trait;
@@ -26,16 +28,17 @@ pub struct CreateAccount {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for CreateAccount {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for CreateAccount {
     fn command_name(&self) -> &'static str {
         "CreateAccount"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let address = self.account;
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::aptos_account_create_account(address))
-            .await
-            .map(TransactionSummary::from)
+            .await?
+            .map(TransactionSummary::from))
     }
 }
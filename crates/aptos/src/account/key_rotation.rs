@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::common::{
+    keystore::prompt_new_password,
     types::{
         account_address_from_auth_key, account_address_from_public_key,
         AuthenticationKeyInputOptions, CliCommand, CliConfig, CliError, CliTypedResult,
@@ -93,6 +94,13 @@ impl CliCommand<RotateSummary> for RotateKey {
     }
 
     async fn execute(self) -> CliTypedResult<RotateSummary> {
+        // The rotation proof has to be signed with the current private key anyway
+        if self.txn_options.unsigned_output_file.is_some() {
+            return Err(CliError::CommandArgumentError(
+                "--unsigned-output-file can't be used to rotate a key".to_string(),
+            ));
+        }
+
         let new_private_key = self
             .extract_private_key(self.txn_options.encoding_options.encoding)?
             .ok_or_else(|| {
@@ -142,7 +150,8 @@ impl CliCommand<RotateSummary> for RotateKey {
                     .to_vec(),
                 rotation_proof_signed_by_new_private_key.to_bytes().to_vec(),
             ))
-            .await
+            .await?
+            .into_submitted()
             .map(TransactionSummary::from)?;
 
         let string = serde_json::to_string_pretty(&txn_summary)
@@ -217,11 +226,17 @@ impl CliCommand<RotateSummary> for RotateKey {
         }
 
         let mut profile_config = ProfileConfig {
-            private_key: Some(new_private_key.clone()),
             public_key: Some(new_private_key.public_key()),
             account: Some(sender_address),
             ..self.txn_options.profile_options.profile()?
         };
+        // Don't save the new key in plaintext if the old one was encrypted
+        let password = if profile_config.encrypted_private_key.is_some() {
+            Some(prompt_new_password()?)
+        } else {
+            None
+        };
+        profile_config.set_private_key(new_private_key, password.as_deref().map(String::as_str))?;
 
         if let Some(url) = self.txn_options.rest_options.url {
             profile_config.rest_url = Some(url.into());
@@ -4,7 +4,8 @@
 use crate::common::{
     types::{
         CliCommand, CliError, CliTypedResult, EntryFunctionArguments, MultisigAccount,
-        MultisigAccountWithSequenceNumber, TransactionOptions, TransactionSummary,
+        MultisigAccountWithSequenceNumber, TransactionOptions, TransactionOutcome,
+        TransactionSummary,
     },
     utils::view_json_option_str,
 };
@@ -86,13 +87,14 @@ impl From<Transaction> for CreateSummary {
 }
 
 #[async_trait]
-impl CliCommand<CreateSummary> for Create {
+impl CliCommand<TransactionOutcome<CreateSummary>> for Create {
     fn command_name(&self) -> &'static str {
         "CreateMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<CreateSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<CreateSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::multisig_account_create_with_owners(
                 self.additional_owners,
                 self.num_signatures_required,
@@ -100,8 +102,8 @@ impl CliCommand<CreateSummary> for Create {
                 vec![],
                 vec![],
             ))
-            .await
-            .map(CreateSummary::from)
+            .await?
+            .map(CreateSummary::from))
     }
 }
 
@@ -124,12 +126,12 @@ pub struct CreateTransaction {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for CreateTransaction {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for CreateTransaction {
     fn command_name(&self) -> &'static str {
         "CreateTransactionMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let multisig_transaction_payload_bytes =
             to_bytes::<MultisigTransactionPayload>(&self.entry_function_args.try_into()?)?;
         let transaction_payload = if self.store_hash_only {
@@ -143,10 +145,11 @@ impl CliCommand<TransactionSummary> for CreateTransaction {
                 multisig_transaction_payload_bytes,
             )
         };
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(transaction_payload)
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -187,6 +190,7 @@ impl CliCommand<serde_json::Value> for VerifyProposal {
                             .to_string(),
                     ),
                 ],
+                state_overrides: None,
             })
             .await?[0];
         // Get expected multisig transaction payload hash hex from provided entry function.
@@ -239,21 +243,22 @@ pub struct Approve {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for Approve {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for Approve {
     fn command_name(&self) -> &'static str {
         "ApproveMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::multisig_account_approve_transaction(
                 self.multisig_account_with_sequence_number
                     .multisig_account
                     .multisig_address,
                 self.multisig_account_with_sequence_number.sequence_number,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -271,21 +276,22 @@ pub struct Reject {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for Reject {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for Reject {
     fn command_name(&self) -> &'static str {
         "RejectMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::multisig_account_reject_transaction(
                 self.multisig_account_with_sequence_number
                     .multisig_account
                     .multisig_address,
                 self.multisig_account_with_sequence_number.sequence_number,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -299,19 +305,20 @@ pub struct Execute {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for Execute {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for Execute {
     fn command_name(&self) -> &'static str {
         "ExecuteMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(TransactionPayload::Multisig(Multisig {
                 multisig_address: self.multisig_account.multisig_address,
                 transaction_payload: None,
             }))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -325,20 +332,21 @@ pub struct ExecuteWithPayload {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for ExecuteWithPayload {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for ExecuteWithPayload {
     fn command_name(&self) -> &'static str {
         "ExecuteWithPayloadMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
-        self.execute
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
+        Ok(self
+            .execute
             .txn_options
             .submit_transaction(TransactionPayload::Multisig(Multisig {
                 multisig_address: self.execute.multisig_account.multisig_address,
                 transaction_payload: Some(self.entry_function_args.try_into()?),
             }))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -355,17 +363,18 @@ pub struct ExecuteReject {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for ExecuteReject {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for ExecuteReject {
     fn command_name(&self) -> &'static str {
         "ExecuteRejectMultisig"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::multisig_account_execute_rejected_transaction(
                 self.multisig_account.multisig_address,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::common::types::{CliCommand, CliTypedResult, TransactionOptions};
+use crate::common::types::{CliCommand, CliTypedResult, TransactionOptions, TransactionOutcome};
 use aptos_cached_packages::aptos_stdlib;
 use aptos_rest_client::{
     aptos_api_types::{HashValue, WriteResource, WriteSetChange},
@@ -32,19 +32,20 @@ pub struct TransferCoins {
 }
 
 #[async_trait]
-impl CliCommand<TransferSummary> for TransferCoins {
+impl CliCommand<TransactionOutcome<TransferSummary>> for TransferCoins {
     fn command_name(&self) -> &'static str {
         "TransferCoins"
     }
 
-    async fn execute(self) -> CliTypedResult<TransferSummary> {
-        self.txn_options
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransferSummary>> {
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::aptos_account_transfer(
                 self.account,
                 self.amount,
             ))
-            .await
-            .map(TransferSummary::from)
+            .await?
+            .map(TransferSummary::from))
     }
 }
 
@@ -4,6 +4,7 @@
 use crate::{
     account::key_rotation::lookup_address,
     common::{
+        keystore::prompt_new_password,
         types::{
             account_address_from_public_key, CliCommand, CliConfig, CliError, CliTypedResult,
             ConfigSearchMode, EncodingOptions, PrivateKeyInputOptions, ProfileConfig,
@@ -49,6 +50,13 @@ pub struct InitTool {
     #[clap(long)]
     pub skip_faucet: bool,
 
+    /// Whether to encrypt the private key with a password, instead of saving it in plaintext
+    ///
+    /// The password is then prompted for whenever the private key is used.  Profiles which
+    /// already have an encrypted private key stay encrypted.
+    #[clap(long)]
+    pub encrypt_private_key: bool,
+
     #[clap(flatten)]
     pub rng_args: RngArgs,
     #[clap(flatten)]
@@ -139,11 +147,11 @@ impl CliCommand<()> for InitTool {
             eprintln!("Using command line argument for private key");
             private_key
         } else {
-            eprintln!("Enter your private key as a hex literal (0x...) [Current: {} | No input: Generate new key (or keep one if present)]", profile_config.private_key.as_ref().map(|_| "Redacted").unwrap_or("None"));
+            eprintln!("Enter your private key as a hex literal (0x...) [Current: {} | No input: Generate new key (or keep one if present)]", if profile_config.has_private_key() { "Redacted" } else { "None" });
             let input = read_line("Private key")?;
             let input = input.trim();
             if input.is_empty() {
-                if let Some(private_key) = profile_config.private_key {
+                if let Some(private_key) = profile_config.load_private_key()? {
                     eprintln!("No key given, keeping existing key...");
                     private_key
                 } else {
@@ -158,6 +166,12 @@ impl CliCommand<()> for InitTool {
             }
         };
         let public_key = private_key.public_key();
+        let password = if self.encrypt_private_key || profile_config.encrypted_private_key.is_some()
+        {
+            Some(prompt_new_password()?)
+        } else {
+            None
+        };
 
         let client = aptos_rest_client::Client::new(
             Url::parse(
@@ -174,7 +188,7 @@ impl CliCommand<()> for InitTool {
         let derived_address = account_address_from_public_key(&public_key);
         let address = lookup_address(&client, derived_address, false).await?;
 
-        profile_config.private_key = Some(private_key);
+        profile_config.set_private_key(private_key, password.as_deref().map(String::as_str))?;
         profile_config.public_key = Some(public_key);
         profile_config.account = Some(address);
 
@@ -0,0 +1,232 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Password based encryption of the private keys of profiles
+//!
+//! An encryption key is derived from the password with Argon2id, and the private key is
+//! encrypted with it using AES-256-GCM. Only the encrypted private key is written to the config,
+//! it's decrypted in memory when a command needs to sign with it.  The password, the derived key
+//! and the plaintext bytes are zeroed once they're dropped.
+
+use crate::common::types::{CliError, CliTypedResult};
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm, Key, Nonce,
+};
+use aptos_crypto::ed25519::Ed25519PrivateKey;
+use argon2::{Algorithm, Argon2, Params, Version};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Mutex};
+use zeroize::Zeroizing;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+
+/// Private keys decrypted by this command, by ciphertext, so the password is only prompted for once
+static DECRYPTED_PRIVATE_KEYS: Lazy<Mutex<HashMap<String, Ed25519PrivateKey>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A private key encrypted with a password
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EncryptedPrivateKey {
+    pub kdf_params: KdfParams,
+    /// Hex encoded salt of the key derivation
+    pub salt: String,
+    /// Hex encoded AES-256-GCM nonce
+    pub nonce: String,
+    /// Hex encoded encrypted private key, followed by the authentication tag
+    pub ciphertext: String,
+}
+
+/// Argon2id parameters the encryption key is derived with
+///
+/// Stored along with the encrypted key, so that the defaults can be raised without breaking
+/// existing configs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct KdfParams {
+    /// Memory size, in KiB
+    pub m_cost: u32,
+    /// Number of iterations
+    pub t_cost: u32,
+    /// Degree of parallelism
+    pub p_cost: u32,
+}
+
+impl Default for KdfParams {
+    /// The minimum recommended by OWASP for Argon2id
+    fn default() -> Self {
+        Self {
+            m_cost: 19 * 1024,
+            t_cost: 2,
+            p_cost: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    fn derive_key(
+        &self,
+        password: &str,
+        salt: &[u8],
+    ) -> CliTypedResult<Zeroizing<[u8; KEY_LENGTH]>> {
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_LENGTH)).map_err(
+            |err| CliError::UnexpectedError(format!("Invalid key derivation parameters: {}", err)),
+        )?;
+        let mut key = Zeroizing::new([0u8; KEY_LENGTH]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(password.as_bytes(), salt, &mut *key)
+            .map_err(|err| {
+                CliError::UnexpectedError(format!("Failed to derive encryption key: {}", err))
+            })?;
+        Ok(key)
+    }
+}
+
+impl EncryptedPrivateKey {
+    pub fn encrypt(private_key: &Ed25519PrivateKey, password: &str) -> CliTypedResult<Self> {
+        Self::encrypt_with_params(private_key, password, KdfParams::default())
+    }
+
+    fn encrypt_with_params(
+        private_key: &Ed25519PrivateKey,
+        password: &str,
+        kdf_params: KdfParams,
+    ) -> CliTypedResult<Self> {
+        let salt: [u8; SALT_LENGTH] = rand::random();
+        let nonce: [u8; NONCE_LENGTH] = rand::random();
+        let key = kdf_params.derive_key(password, &salt)?;
+        let plaintext = Zeroizing::new(private_key.to_bytes());
+        let ciphertext = Aes256Gcm::new(Key::from_slice(key.as_slice()))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| CliError::UnexpectedError("Failed to encrypt private key".to_string()))?;
+
+        Ok(Self {
+            kdf_params,
+            salt: hex::encode(salt),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    pub fn decrypt(&self, password: &str) -> CliTypedResult<Ed25519PrivateKey> {
+        let salt = decode_hex("salt", &self.salt)?;
+        let nonce = decode_hex("nonce", &self.nonce)?;
+        let ciphertext = decode_hex("ciphertext", &self.ciphertext)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(CliError::UnableToParse(
+                "EncryptedPrivateKey",
+                format!("nonce must be {} bytes", NONCE_LENGTH),
+            ));
+        }
+
+        let key = self.kdf_params.derive_key(password, &salt)?;
+        let bytes = Aes256Gcm::new(Key::from_slice(key.as_slice()))
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map(Zeroizing::new)
+            .map_err(|_| {
+                CliError::CommandArgumentError(
+                    "Failed to decrypt private key, the password is incorrect".to_string(),
+                )
+            })?;
+        Ed25519PrivateKey::try_from(bytes.as_slice())
+            .map_err(|err| CliError::UnableToParse("Ed25519PrivateKey", err.to_string()))
+    }
+
+    /// Decrypts the private key, prompting for the password the first time it's used by the
+    /// command
+    pub fn decrypt_with_prompt(&self) -> CliTypedResult<Ed25519PrivateKey> {
+        let mut decrypted_private_keys = DECRYPTED_PRIVATE_KEYS
+            .lock()
+            .expect("Decrypted private keys lock is poisoned");
+        if let Some(private_key) = decrypted_private_keys.get(&self.ciphertext) {
+            return Ok(private_key.clone());
+        }
+
+        let password = prompt_password("Enter the password of the private key")?;
+        let private_key = self.decrypt(&password)?;
+        decrypted_private_keys.insert(self.ciphertext.clone(), private_key.clone());
+        Ok(private_key)
+    }
+}
+
+fn decode_hex(name: &'static str, value: &str) -> CliTypedResult<Vec<u8>> {
+    hex::decode(value).map_err(|err| CliError::UnableToParse(name, err.to_string()))
+}
+
+/// Prompts for a password without echoing it
+pub fn prompt_password(prompt: &str) -> CliTypedResult<Zeroizing<String>> {
+    rpassword::prompt_password(format!("{}: ", prompt))
+        .map(Zeroizing::new)
+        .map_err(|err| CliError::IO("password".to_string(), err))
+}
+
+/// Prompts for a new password, twice to catch typos
+pub fn prompt_new_password() -> CliTypedResult<Zeroizing<String>> {
+    let password = prompt_password("Enter a password to encrypt the private key with")?;
+    if password.is_empty() {
+        return Err(CliError::CommandArgumentError(
+            "Password must not be empty".to_string(),
+        ));
+    }
+    if *prompt_password("Confirm the password")? != *password {
+        return Err(CliError::CommandArgumentError(
+            "Passwords don't match".to_string(),
+        ));
+    }
+    Ok(password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_keygen::KeyGen;
+
+    // Cheap parameters, the defaults are slow without optimizations.
+    const TEST_KDF_PARAMS: KdfParams = KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let private_key = KeyGen::from_seed([0; 32]).generate_ed25519_private_key();
+        let encrypted =
+            EncryptedPrivateKey::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS)
+                .unwrap();
+        assert_ne!(encrypted.ciphertext, hex::encode(private_key.to_bytes()));
+
+        let decrypted = encrypted.decrypt("password").unwrap();
+        assert_eq!(decrypted, private_key);
+        assert!(encrypted.decrypt("wrong password").is_err());
+    }
+
+    #[test]
+    fn test_serialized_config_round_trip() {
+        let private_key = KeyGen::from_seed([1; 32]).generate_ed25519_private_key();
+        let encrypted =
+            EncryptedPrivateKey::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS)
+                .unwrap();
+        let yaml = serde_yaml::to_string(&encrypted).unwrap();
+        let deserialized: EncryptedPrivateKey = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(deserialized, encrypted);
+        assert_eq!(deserialized.decrypt("password").unwrap(), private_key);
+    }
+
+    #[test]
+    fn test_decrypted_private_key_is_cached() {
+        let private_key = KeyGen::from_seed([2; 32]).generate_ed25519_private_key();
+        let encrypted =
+            EncryptedPrivateKey::encrypt_with_params(&private_key, "password", TEST_KDF_PARAMS)
+                .unwrap();
+        DECRYPTED_PRIVATE_KEYS
+            .lock()
+            .unwrap()
+            .insert(encrypted.ciphertext.clone(), private_key.clone());
+
+        // Doesn't prompt for the password, which would fail without a terminal
+        assert_eq!(encrypted.decrypt_with_prompt().unwrap(), private_key);
+    }
+}
@@ -4,6 +4,7 @@
 use crate::{
     common::{
         init::Network,
+        keystore::EncryptedPrivateKey,
         utils::{
             check_if_file_exists, create_dir_if_not_exist, dir_default_to_current,
             get_account_with_state, get_auth_key, get_sequence_number, parse_json_file,
@@ -35,8 +36,9 @@ use aptos_sdk::{transaction_builder::TransactionFactory, types::LocalAccount};
 use aptos_types::{
     chain_id::ChainId,
     transaction::{
-        authenticator::AuthenticationKey, EntryFunction, MultisigTransactionPayload, Script,
-        SignedTransaction, TransactionArgument, TransactionPayload, TransactionStatus,
+        authenticator::AuthenticationKey, EntryFunction, MultisigTransactionPayload,
+        RawTransaction, Script, SignedTransaction, TransactionArgument, TransactionPayload,
+        TransactionStatus,
     },
 };
 use async_trait::async_trait;
@@ -209,6 +211,9 @@ pub struct ProfileConfig {
     /// Private key for commands.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub private_key: Option<Ed25519PrivateKey>,
+    /// Private key for commands, encrypted with a password, in place of `private_key`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_private_key: Option<EncryptedPrivateKey>,
     /// Public key for commands
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<Ed25519PublicKey>,
@@ -223,10 +228,43 @@ pub struct ProfileConfig {
     pub faucet_url: Option<String>,
 }
 
+impl ProfileConfig {
+    pub fn has_private_key(&self) -> bool {
+        self.private_key.is_some() || self.encrypted_private_key.is_some()
+    }
+
+    /// Loads the private key, prompting for the password the first time if it is encrypted
+    pub fn load_private_key(&self) -> CliTypedResult<Option<Ed25519PrivateKey>> {
+        if let Some(ref encrypted_private_key) = self.encrypted_private_key {
+            encrypted_private_key.decrypt_with_prompt().map(Some)
+        } else {
+            Ok(self.private_key.clone())
+        }
+    }
+
+    /// Sets the private key, encrypted with `password` if one is given
+    pub fn set_private_key(
+        &mut self,
+        private_key: Ed25519PrivateKey,
+        password: Option<&str>,
+    ) -> CliTypedResult<()> {
+        if let Some(password) = password {
+            self.encrypted_private_key =
+                Some(EncryptedPrivateKey::encrypt(&private_key, password)?);
+            self.private_key = None;
+        } else {
+            self.encrypted_private_key = None;
+            self.private_key = Some(private_key);
+        }
+        Ok(())
+    }
+}
+
 /// ProfileConfig but without the private parts
 #[derive(Debug, Serialize)]
 pub struct ProfileSummary {
     pub has_private_key: bool,
+    pub private_key_encrypted: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key: Option<Ed25519PublicKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -240,7 +278,8 @@ pub struct ProfileSummary {
 impl From<&ProfileConfig> for ProfileSummary {
     fn from(config: &ProfileConfig) -> Self {
         ProfileSummary {
-            has_private_key: config.private_key.is_some(),
+            has_private_key: config.has_private_key(),
+            private_key_encrypted: config.encrypted_private_key.is_some(),
             public_key: config.public_key.clone(),
             account: config.account,
             rest_url: config.rest_url.clone(),
@@ -792,7 +831,8 @@ impl PrivateKeyInputOptions {
             profile.profile_name(),
             ConfigSearchMode::CurrentDirAndParents,
         )?
-        .map(|p| (p.private_key, p.account))
+        .map(|p| p.load_private_key().map(|key| (key, p.account)))
+        .transpose()?
         {
             match (maybe_address, maybe_config_address) {
                 (Some(address), _) => Ok((key, address)),
@@ -821,7 +861,8 @@ impl PrivateKeyInputOptions {
             profile.profile_name(),
             ConfigSearchMode::CurrentDirAndParents,
         )?
-        .map(|p| p.private_key)
+        .map(|p| p.load_private_key())
+        .transpose()?
         {
             Ok(private_key)
         } else {
@@ -850,6 +891,19 @@ impl ExtractPublicKey for PrivateKeyInputOptions {
         encoding: EncodingType,
         profile: &ProfileOptions,
     ) -> CliTypedResult<Ed25519PublicKey> {
+        // An encrypted private key isn't needed for its public key, which is stored along with it
+        if self.extract_private_key_cli(encoding)?.is_none() {
+            if let Some(ProfileConfig {
+                encrypted_private_key: Some(_),
+                public_key: Some(public_key),
+                ..
+            }) = CliConfig::load_profile(
+                profile.profile_name(),
+                ConfigSearchMode::CurrentDirAndParents,
+            )? {
+                return Ok(public_key);
+            }
+        }
         self.extract_private_key(encoding, profile)
             .map(|private_key| private_key.public_key())
     }
@@ -1049,11 +1103,10 @@ pub fn load_account_arg(str: &str) -> Result<AccountAddress, CliError> {
             .map(|p| p.account)
     {
         Ok(account_address)
-    } else if let Some(Some(private_key)) =
+    } else if let Some(Some(public_key)) =
         CliConfig::load_profile(Some(str), ConfigSearchMode::CurrentDirAndParents)?
-            .map(|p| p.private_key)
+            .map(|p| p.private_key.map(|key| key.public_key()).or(p.public_key))
     {
-        let public_key = private_key.public_key();
         Ok(account_address_from_public_key(&public_key))
     } else {
         Err(CliError::CommandArgumentError(
@@ -1090,11 +1143,10 @@ pub fn load_manifest_account_arg(str: &str) -> Result<Option<AccountAddress>, Cl
             })
     } else if let Ok(account_address) = AccountAddress::from_str(str) {
         Ok(Some(account_address))
-    } else if let Some(Some(private_key)) =
+    } else if let Some(Some(public_key)) =
         CliConfig::load_profile(Some(str), ConfigSearchMode::CurrentDirAndParents)?
-            .map(|p| p.private_key)
+            .map(|p| p.private_key.map(|key| key.public_key()).or(p.public_key))
     {
-        let public_key = private_key.public_key();
         Ok(Some(account_address_from_public_key(&public_key)))
     } else {
         Err(CliError::CommandArgumentError(
@@ -1142,6 +1194,65 @@ pub trait CliCommand<T: Serialize + Send>: Sized + Send {
     }
 }
 
+/// Outcome of sending a transaction, which is only submitted if it's not to be saved unsigned
+///
+/// It's serialized as its content, so commands output the same as before when submitting.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum TransactionOutcome<T = Transaction> {
+    Submitted(T),
+    SavedUnsigned(UnsignedTransactionSummary),
+}
+
+impl<T> TransactionOutcome<T> {
+    /// Converts the output of the submitted transaction
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> TransactionOutcome<U> {
+        match self {
+            TransactionOutcome::Submitted(inner) => TransactionOutcome::Submitted(f(inner)),
+            TransactionOutcome::SavedUnsigned(summary) => {
+                TransactionOutcome::SavedUnsigned(summary)
+            },
+        }
+    }
+
+    /// Returns the output of the submitted transaction, failing if it was saved unsigned
+    pub fn into_submitted(self) -> CliTypedResult<T> {
+        match self {
+            TransactionOutcome::Submitted(inner) => Ok(inner),
+            TransactionOutcome::SavedUnsigned(summary) => Err(CliError::UnexpectedError(format!(
+                "Transaction not submitted, saved unsigned to {}",
+                summary.unsigned_transaction_file.display()
+            ))),
+        }
+    }
+}
+
+/// Output of commands which save a transaction unsigned instead of submitting it
+#[derive(Clone, Debug, Serialize)]
+pub struct UnsignedTransactionSummary {
+    pub unsigned_transaction_file: PathBuf,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub chain_id: u8,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    pub expiration_timestamp_secs: u64,
+}
+
+impl UnsignedTransactionSummary {
+    pub fn new(raw_transaction: &RawTransaction, unsigned_transaction_file: PathBuf) -> Self {
+        UnsignedTransactionSummary {
+            unsigned_transaction_file,
+            sender: raw_transaction.sender(),
+            sequence_number: raw_transaction.sequence_number(),
+            chain_id: raw_transaction.chain_id().id(),
+            max_gas_amount: raw_transaction.max_gas_amount(),
+            gas_unit_price: raw_transaction.gas_unit_price(),
+            expiration_timestamp_secs: raw_transaction.expiration_timestamp_secs(),
+        }
+    }
+}
+
 /// A shortened transaction output
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TransactionSummary {
@@ -1358,6 +1469,15 @@ pub struct TransactionOptions {
     /// flamegraphs that reflect the gas usage.
     #[clap(long)]
     pub(crate) profile_gas: bool,
+
+    /// Instead of signing and submitting the transaction, save it unsigned to this file
+    ///
+    /// The file can be signed on another machine, which doesn't need network access, with
+    /// `aptos transaction sign` and the signed transaction submitted with
+    /// `aptos transaction submit`. No private key is needed, but `--max-gas` is, and
+    /// `--expiration-secs` should leave enough time to go through these steps.
+    #[clap(long, value_parser, conflicts_with = "profile_gas")]
+    pub(crate) unsigned_output_file: Option<PathBuf>,
 }
 
 impl TransactionOptions {
@@ -1376,10 +1496,40 @@ impl TransactionOptions {
         )
     }
 
+    /// Retrieves the sender address, in the same order as `get_key_and_address`, but without
+    /// loading the private key of the profile, which may have to be decrypted
     pub fn sender_address(&self) -> CliTypedResult<AccountAddress> {
+        if let Some(address) = self.sender_account {
+            return Ok(address);
+        }
+        if let Some(key) = self
+            .private_key_options
+            .extract_private_key_cli(self.encoding_options.encoding)?
+        {
+            return Ok(account_address_from_public_key(&key.public_key()));
+        }
+        if let Ok(address) = self.profile_options.account_address() {
+            return Ok(address);
+        }
+        if let Ok(public_key) = self.profile_options.public_key() {
+            return Ok(account_address_from_public_key(&public_key));
+        }
+
         Ok(self.get_key_and_address()?.1)
     }
 
+    /// Fails if transactions are to be saved unsigned and there are more than one of them, as
+    /// they would all be saved to the same file
+    pub fn ensure_single_transaction(&self, num_transactions: usize) -> CliTypedResult<()> {
+        if self.unsigned_output_file.is_some() && num_transactions > 1 {
+            return Err(CliError::CommandArgumentError(format!(
+                "--unsigned-output-file can't be used for {} transactions",
+                num_transactions
+            )));
+        }
+        Ok(())
+    }
+
     /// Gets the auth key by account address. We need to fetch the auth key from Rest API rather than creating an
     /// auth key out of the public key.
     pub(crate) async fn auth_key(
@@ -1400,11 +1550,18 @@ impl TransactionOptions {
         Ok(client.view(&payload, None).await?.into_inner())
     }
 
-    /// Submit a transaction
+    /// Submit a transaction, or save it unsigned if `--unsigned-output-file` is given
     pub async fn submit_transaction(
         &self,
         payload: TransactionPayload,
-    ) -> CliTypedResult<Transaction> {
+    ) -> CliTypedResult<TransactionOutcome> {
+        if let Some(unsigned_output_file) = &self.unsigned_output_file {
+            return self
+                .save_unsigned_transaction(payload, unsigned_output_file)
+                .await
+                .map(TransactionOutcome::SavedUnsigned);
+        }
+
         let client = self.rest_client()?;
         let (sender_key, sender_address) = self.get_key_and_address()?;
 
@@ -1509,7 +1666,48 @@ impl TransactionOptions {
             .await
             .map_err(|err| CliError::ApiError(err.to_string()))?;
 
-        Ok(response.into_inner())
+        Ok(TransactionOutcome::Submitted(response.into_inner()))
+    }
+
+    /// Builds the transaction without signing it, and saves it BCS encoded to `path`.
+    async fn save_unsigned_transaction(
+        &self,
+        payload: TransactionPayload,
+        path: &Path,
+    ) -> CliTypedResult<UnsignedTransactionSummary> {
+        let client = self.rest_client()?;
+        let sender_address = self.sender_address()?;
+        // Gas can't be estimated by simulation, since that requires the public key.
+        let max_gas = self.gas_options.max_gas.ok_or_else(|| {
+            CliError::CommandArgumentError(
+                "--max-gas is required with --unsigned-output-file".to_string(),
+            )
+        })?;
+        let gas_unit_price = match self.gas_options.gas_unit_price {
+            Some(gas_unit_price) => gas_unit_price,
+            None => client.estimate_gas_price().await?.into_inner().gas_estimate,
+        };
+        let (account, state) = get_account_with_state(&client, sender_address).await?;
+
+        let raw_transaction = TransactionFactory::new(ChainId::new(state.chain_id))
+            .with_gas_unit_price(gas_unit_price)
+            .with_max_gas_amount(max_gas)
+            .with_transaction_expiration_time(self.gas_options.expiration_secs)
+            .payload(payload)
+            .sender(sender_address)
+            .sequence_number(account.sequence_number)
+            .build();
+        check_if_file_exists(path, self.prompt_options)?;
+        write_to_file(
+            path,
+            &path.display().to_string(),
+            &bcs::to_bytes(&raw_transaction)?,
+        )?;
+
+        Ok(UnsignedTransactionSummary::new(
+            &raw_transaction,
+            path.to_path_buf(),
+        ))
     }
 
     /// Simulate the transaction locally using the debugger, with the gas profiler enabled.
@@ -1931,6 +2129,7 @@ impl TryInto<ViewRequest> for EntryFunctionArguments {
             },
             type_arguments: entry_function_args.type_arg_vec.type_args,
             arguments: entry_function_args.arg_vec.try_into()?,
+            state_overrides: None,
         })
     }
 }
@@ -4,7 +4,7 @@
 use crate::{
     common::types::{
         account_address_from_public_key, CliError, CliTypedResult, PromptOptions,
-        TransactionOptions, TransactionSummary,
+        TransactionOptions, TransactionOutcome, TransactionSummary,
     },
     config::GlobalConfig,
     CliResult,
@@ -470,16 +470,19 @@ pub fn start_logger(level: Level) {
 pub async fn profile_or_submit(
     payload: TransactionPayload,
     txn_options_ref: &TransactionOptions,
-) -> CliTypedResult<TransactionSummary> {
+) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
     // Profile gas if needed.
     if txn_options_ref.profile_gas {
-        txn_options_ref.profile_gas(payload).await
+        txn_options_ref
+            .profile_gas(payload)
+            .await
+            .map(TransactionOutcome::Submitted)
     } else {
         // Otherwise submit the transaction.
-        txn_options_ref
+        Ok(txn_options_ref
             .submit_transaction(payload)
-            .await
-            .map(TransactionSummary::from)
+            .await?
+            .map(TransactionSummary::from))
     }
 }
 
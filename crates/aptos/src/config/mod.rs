@@ -3,9 +3,10 @@
 
 use crate::{
     common::{
+        keystore::{prompt_new_password, prompt_password},
         types::{
             CliCommand, CliConfig, CliError, CliResult, CliTypedResult, ConfigSearchMode,
-            ProfileSummary, CONFIG_FOLDER,
+            ProfileConfig, ProfileSummary, CONFIG_FOLDER,
         },
         utils::{create_dir_if_not_exist, current_dir, read_from_file, write_to_user_only_file},
     },
@@ -25,6 +26,8 @@ use std::{collections::BTreeMap, fmt::Formatter, path::PathBuf, str::FromStr};
 /// default configuration, and user specific settings.
 #[derive(Parser)]
 pub enum ConfigTool {
+    ChangePassword(ChangePassword),
+    EncryptProfiles(EncryptProfiles),
     GenerateShellCompletions(GenerateShellCompletions),
     SetGlobalConfig(SetGlobalConfig),
     ShowGlobalConfig(ShowGlobalConfig),
@@ -34,6 +37,8 @@ pub enum ConfigTool {
 impl ConfigTool {
     pub async fn execute(self) -> CliResult {
         match self {
+            ConfigTool::ChangePassword(tool) => tool.execute_serialized().await,
+            ConfigTool::EncryptProfiles(tool) => tool.execute_serialized().await,
             ConfigTool::GenerateShellCompletions(tool) => tool.execute_serialized_success().await,
             ConfigTool::SetGlobalConfig(tool) => tool.execute_serialized().await,
             ConfigTool::ShowGlobalConfig(tool) => tool.execute_serialized().await,
@@ -150,6 +155,126 @@ impl CliCommand<BTreeMap<String, ProfileSummary>> for ShowProfiles {
     }
 }
 
+/// Encrypts the private keys of profiles saved in plaintext
+///
+/// The private keys are encrypted with a password, which is prompted for whenever one of them
+/// is used afterwards.  All the profiles encrypted at once share the same password.
+#[derive(Parser, Debug)]
+pub struct EncryptProfiles {
+    /// Which profile to encrypt
+    ///
+    /// If not provided, all profiles with a plaintext private key are encrypted
+    #[clap(long)]
+    profile: Option<String>,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for EncryptProfiles {
+    fn command_name(&self) -> &'static str {
+        "EncryptProfiles"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        let mut config = CliConfig::load(ConfigSearchMode::CurrentDir)?;
+        let names = selected_profiles(&config, self.profile.as_deref(), |profile| {
+            profile.private_key.is_some()
+        })?;
+        if names.is_empty() {
+            return Err(CliError::CommandArgumentError(
+                "No profile with a plaintext private key to encrypt".to_string(),
+            ));
+        }
+
+        let password = prompt_new_password()?;
+        for (name, profile) in config.profiles.iter_mut().flatten() {
+            if names.contains(name) {
+                if let Some(private_key) = profile.private_key.take() {
+                    profile.set_private_key(private_key, Some(&password))?;
+                }
+            }
+        }
+        config.save()?;
+        Ok(names)
+    }
+}
+
+/// Changes the password of profiles with an encrypted private key
+///
+/// All the selected profiles must currently be encrypted with the same password.
+#[derive(Parser, Debug)]
+pub struct ChangePassword {
+    /// Which profile to change the password of
+    ///
+    /// If not provided, the password of all profiles with an encrypted private key is changed
+    #[clap(long)]
+    profile: Option<String>,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for ChangePassword {
+    fn command_name(&self) -> &'static str {
+        "ChangePassword"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        let mut config = CliConfig::load(ConfigSearchMode::CurrentDir)?;
+        let names = selected_profiles(&config, self.profile.as_deref(), |profile| {
+            profile.encrypted_private_key.is_some()
+        })?;
+        if names.is_empty() {
+            return Err(CliError::CommandArgumentError(
+                "No profile with an encrypted private key".to_string(),
+            ));
+        }
+
+        // Decrypt all the keys before changing any, so a wrong password leaves the config as is
+        let current_password = prompt_password("Enter the current password")?;
+        let mut private_keys = BTreeMap::new();
+        for (name, profile) in config.profiles.iter().flatten() {
+            match profile.encrypted_private_key {
+                Some(ref encrypted_private_key) if names.contains(name) => {
+                    let private_key = encrypted_private_key.decrypt(&current_password)?;
+                    private_keys.insert(name.clone(), private_key);
+                },
+                _ => {},
+            }
+        }
+
+        let password = prompt_new_password()?;
+        for (name, profile) in config.profiles.iter_mut().flatten() {
+            if let Some(private_key) = private_keys.remove(name) {
+                profile.set_private_key(private_key, Some(&password))?;
+            }
+        }
+        config.save()?;
+        Ok(names)
+    }
+}
+
+/// Names of the profiles matching `filter`, among the one named `profile` if given, or all of them
+fn selected_profiles(
+    config: &CliConfig,
+    profile: Option<&str>,
+    filter: impl Fn(&ProfileConfig) -> bool,
+) -> CliTypedResult<Vec<String>> {
+    let profiles = config.profiles.iter().flatten();
+    if let Some(profile) = profile {
+        if !profiles.clone().any(|(name, _)| name == profile) {
+            return Err(CliError::CommandArgumentError(format!(
+                "Profile {} not found",
+                profile
+            )));
+        }
+    }
+
+    Ok(profiles
+        .filter(|(name, profile_config)| {
+            profile.map_or(true, |profile| name.as_str() == profile) && filter(profile_config)
+        })
+        .map(|(name, _)| name.clone())
+        .collect())
+}
+
 /// Shows the properties in the global config
 #[derive(Parser, Debug)]
 pub struct ShowGlobalConfig {}
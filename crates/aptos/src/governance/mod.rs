@@ -7,7 +7,7 @@ use crate::{
     common::{
         types::{
             CliError, CliTypedResult, MovePackageDir, PoolAddressArgs, ProfileOptions,
-            PromptOptions, RestOptions, TransactionOptions, TransactionSummary,
+            PromptOptions, RestOptions, TransactionOptions, TransactionOutcome, TransactionSummary,
         },
         utils::prompt_yes_with_override,
     },
@@ -300,12 +300,12 @@ pub struct SubmitProposal {
 }
 
 #[async_trait]
-impl CliCommand<ProposalSubmissionSummary> for SubmitProposal {
+impl CliCommand<TransactionOutcome<ProposalSubmissionSummary>> for SubmitProposal {
     fn command_name(&self) -> &'static str {
         "SubmitProposal"
     }
 
-    async fn execute(mut self) -> CliTypedResult<ProposalSubmissionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<ProposalSubmissionSummary>> {
         let (_bytecode, script_hash) = self
             .compile_proposal_args
             .compile("SubmitProposal", self.txn_options.prompt_options)?;
@@ -322,7 +322,7 @@ impl CliCommand<ProposalSubmissionSummary> for SubmitProposal {
             self.txn_options.prompt_options,
         )?;
 
-        let txn: Transaction = if self.is_multi_step {
+        let outcome = if self.is_multi_step {
             self.txn_options
                 .submit_transaction(aptos_stdlib::aptos_governance_create_proposal_v2(
                     self.pool_address_args.pool_address,
@@ -342,6 +342,12 @@ impl CliCommand<ProposalSubmissionSummary> for SubmitProposal {
                 ))
                 .await?
         };
+        let txn = match outcome {
+            TransactionOutcome::Submitted(txn) => txn,
+            TransactionOutcome::SavedUnsigned(summary) => {
+                return Ok(TransactionOutcome::SavedUnsigned(summary))
+            },
+        };
         let txn_summary = TransactionSummary::from(&txn);
         if let Transaction::UserTransaction(inner) = txn {
             // Find event with proposal id
@@ -360,10 +366,10 @@ impl CliCommand<ProposalSubmissionSummary> for SubmitProposal {
                 None
             };
 
-            return Ok(ProposalSubmissionSummary {
+            return Ok(TransactionOutcome::Submitted(ProposalSubmissionSummary {
                 proposal_id,
                 transaction: txn_summary,
-            });
+            }));
         }
         Err(CliError::UnexpectedError(
             "Unable to find parse proposal transaction output".to_string(),
@@ -472,12 +478,12 @@ pub struct SubmitVote {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for SubmitVote {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for SubmitVote {
     fn command_name(&self) -> &'static str {
         "SubmitVote"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let (vote_str, vote) = match (self.yes, self.no) {
             (true, false) => ("Yes", true),
             (false, true) => ("No", false),
@@ -503,7 +509,9 @@ impl CliCommand<Vec<TransactionSummary>> for SubmitVote {
             .into_inner()
             .votes;
 
-        let mut summaries: Vec<TransactionSummary> = vec![];
+        self.txn_options
+            .ensure_single_transaction(self.pool_addresses.len())?;
+        let mut summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
         for pool_address in self.pool_addresses {
             let voting_record = client
                 .get_table_item(
@@ -547,8 +555,8 @@ impl CliCommand<Vec<TransactionSummary>> for SubmitVote {
                         proposal_id,
                         vote,
                     ))
-                    .await
-                    .map(TransactionSummary::from)?,
+                    .await?
+                    .map(TransactionSummary::from),
             );
         }
         Ok(summaries)
@@ -568,19 +576,19 @@ pub struct ApproveExecutionHash {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for ApproveExecutionHash {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for ApproveExecutionHash {
     fn command_name(&self) -> &'static str {
         "ApproveExecutionHash"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         Ok(self
             .txn_options
             .submit_transaction(
                 aptos_stdlib::aptos_governance_add_approved_script_hash_script(self.proposal_id),
             )
-            .await
-            .map(TransactionSummary::from)?)
+            .await?
+            .map(TransactionSummary::from))
     }
 }
 
@@ -702,12 +710,12 @@ pub struct ExecuteProposal {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for ExecuteProposal {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for ExecuteProposal {
     fn command_name(&self) -> &'static str {
         "ExecuteProposal"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let (bytecode, _script_hash) = self
             .compile_proposal_args
             .compile("ExecuteProposal", self.txn_options.prompt_options)?;
@@ -716,10 +724,11 @@ impl CliCommand<TransactionSummary> for ExecuteProposal {
         let args = vec![TransactionArgument::U64(self.proposal_id)];
         let txn = TransactionPayload::Script(Script::new(bytecode, vec![], args));
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(txn)
-            .await
-            .map(TransactionSummary::from)
+            .await?
+            .map(TransactionSummary::from))
     }
 }
 
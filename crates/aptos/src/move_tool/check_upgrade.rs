@@ -0,0 +1,699 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    common::types::{
+        CliCommand, CliError, CliTypedResult, MovePackageDir, ProfileOptions, RestOptions,
+    },
+    move_tool::{CachedPackageRegistry, IncludedArtifacts},
+};
+use aptos_framework::{
+    natives::code::{PackageDep, PackageRegistry, UpgradePolicy},
+    BuiltPackage,
+};
+use aptos_rest_client::{
+    aptos_api_types::{AptosError, AptosErrorCode},
+    error::{AptosErrorResponse, RestError},
+    Client,
+};
+use aptos_types::{
+    account_address::AccountAddress,
+    account_config::CORE_CODE_ADDRESS,
+    on_chain_config::{FeatureFlag, Features},
+};
+use async_trait::async_trait;
+use clap::Parser;
+use move_binary_format::{
+    compatibility::Compatibility,
+    file_format::{AbilitySet, Visibility},
+    file_format_common::VERSION_5,
+    normalized, CompiledModule,
+};
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// Checks whether a package can be published as an upgrade of the one on chain
+///
+/// The package is built locally and checked against the published package of the same name,
+/// with the same upgrade policy, dependency and bytecode compatibility checks as publishing it
+/// would run. Nothing is submitted.
+#[derive(Parser)]
+pub struct CheckUpgrade {
+    /// Address of the account the package is published at
+    #[clap(long, value_parser = crate::common::types::load_account_arg)]
+    pub(crate) account: AccountAddress,
+
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpgradeCheckReport {
+    pub package: String,
+    /// Whether publishing the package would pass all the checks
+    pub upgradable: bool,
+    /// Violations of the upgrade policy, by the package or its dependencies, independent of the
+    /// contents of the modules
+    pub policy_errors: Vec<String>,
+    pub modules: Vec<ModuleUpgradeReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleUpgradeReport {
+    pub name: String,
+    pub status: ModuleUpgradeStatus,
+    pub breaking_changes: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleUpgradeStatus {
+    Added,
+    Unchanged,
+    Compatible,
+    Incompatible,
+    Removed,
+}
+
+#[async_trait]
+impl CliCommand<UpgradeCheckReport> for CheckUpgrade {
+    fn command_name(&self) -> &'static str {
+        "CheckUpgrade"
+    }
+
+    async fn execute(self) -> CliTypedResult<UpgradeCheckReport> {
+        let build_options = IncludedArtifacts::None.build_options(
+            self.move_options.dev,
+            self.move_options.skip_fetch_latest_git_deps,
+            self.move_options.named_addresses(),
+            self.move_options.bytecode_version,
+        );
+        let pack = BuiltPackage::build(self.move_options.get_package_path()?, build_options)
+            .map_err(|e| CliError::MoveCompilationError(format!("{:#}", e)))?;
+        let new_metadata = pack.extract_metadata()?;
+
+        let url = self.rest_options.url(&self.profile_options)?;
+        let client = Client::new(url.clone());
+        let registry = CachedPackageRegistry::create(url.clone(), self.account).await?;
+        let old_package = registry
+            .get_package(pack.name())
+            .await
+            .map_err(|e| CliError::CommandArgumentError(e.to_string()))?;
+        let features = client
+            .get_account_resource_bcs::<Features>(CORE_CODE_ADDRESS, "0x1::features::Features")
+            .await?
+            .into_inner();
+        // Same configuration the VM publishes modules with.
+        let check_friend_linking = !features.is_enabled(FeatureFlag::TREAT_FRIEND_AS_PRIVATE);
+
+        // The checks `code::publish_package` runs on the package metadata.
+        let old_policy = old_package.upgrade_policy();
+        let mut policy_errors = vec![];
+        if old_policy == UpgradePolicy::immutable() {
+            policy_errors.push("The published package is immutable.".to_string());
+        }
+        if old_policy.policy > new_metadata.upgrade_policy.policy {
+            policy_errors.push(format!(
+                "The upgrade policy cannot be weakened from `{}` to `{}`.",
+                old_policy, new_metadata.upgrade_policy
+            ));
+        }
+        let new_module_names: BTreeSet<_> = pack
+            .modules()
+            .map(|module| module.self_id().name().to_string())
+            .collect();
+        for package_name in registry.package_names() {
+            if package_name == pack.name() {
+                continue;
+            }
+            let other_package = registry.get_package(package_name).await?;
+            for module_name in other_package.module_names() {
+                if new_module_names.contains(module_name) {
+                    policy_errors.push(format!(
+                        "Module `{}` is already published in package `{}`.",
+                        module_name, package_name
+                    ));
+                }
+            }
+        }
+
+        // The checks `code::check_dependencies` runs on the dependencies of the package.
+        for dep in &new_metadata.deps {
+            if is_policy_exempted_address(dep.account) {
+                continue;
+            }
+            let dep_policy = get_package_registry(&client, dep.account)
+                .await?
+                .and_then(|dep_registry| {
+                    dep_registry
+                        .packages
+                        .into_iter()
+                        .find(|package| package.name == dep.package_name)
+                })
+                .map(|package| package.upgrade_policy);
+            policy_errors.extend(check_dependency(
+                self.account,
+                new_metadata.upgrade_policy,
+                dep,
+                dep_policy,
+            ));
+        }
+
+        let mut modules = vec![];
+        let old_module_names = old_package.module_names();
+        for name in &old_module_names {
+            if !new_module_names.contains(*name) {
+                modules.push(ModuleUpgradeReport {
+                    name: name.to_string(),
+                    status: ModuleUpgradeStatus::Removed,
+                    breaking_changes: vec!["Modules cannot be removed from a package.".to_string()],
+                });
+            }
+        }
+        for new_module in pack.modules() {
+            let name = new_module.self_id().name().to_string();
+            if !old_module_names.contains(&name.as_str()) {
+                modules.push(ModuleUpgradeReport {
+                    name,
+                    status: ModuleUpgradeStatus::Added,
+                    breaking_changes: vec![],
+                });
+                continue;
+            }
+            let old_bytes = client
+                .get_account_module_bcs(self.account, &name)
+                .await?
+                .into_inner();
+            let old_module = CompiledModule::deserialize(&old_bytes).map_err(|e| {
+                CliError::UnexpectedError(format!(
+                    "Failed to deserialize published module `{}`: {}",
+                    name, e
+                ))
+            })?;
+            modules.push(check_module(
+                check_friend_linking,
+                name,
+                &old_module,
+                new_module,
+            ));
+        }
+
+        Ok(UpgradeCheckReport {
+            package: pack.name().to_string(),
+            upgradable: policy_errors.is_empty()
+                && modules.iter().all(|module| {
+                    !matches!(
+                        module.status,
+                        ModuleUpgradeStatus::Incompatible | ModuleUpgradeStatus::Removed
+                    )
+                }),
+            policy_errors,
+            modules,
+        })
+    }
+}
+
+/// Returns the package registry of the account, if it has published any package.
+async fn get_package_registry(
+    client: &Client,
+    account: AccountAddress,
+) -> CliTypedResult<Option<PackageRegistry>> {
+    match client
+        .get_account_resource_bcs::<PackageRegistry>(account, "0x1::code::PackageRegistry")
+        .await
+    {
+        Ok(response) => Ok(Some(response.into_inner())),
+        Err(RestError::Api(AptosErrorResponse {
+            error:
+                AptosError {
+                    error_code: AptosErrorCode::ResourceNotFound | AptosErrorCode::AccountNotFound,
+                    ..
+                },
+            ..
+        })) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Whether the address is one of the core addresses `0x1` to `0xa`, whose packages may be
+/// depended on regardless of their upgrade policy.
+fn is_policy_exempted_address(account: AccountAddress) -> bool {
+    let (last, rest) = account.as_ref().split_last().unwrap();
+    rest.iter().all(|byte| *byte == 0) && (1..=10).contains(last)
+}
+
+/// Checks a dependency of a package published at `publish_address` with `policy`, given the
+/// upgrade policy of the dependency, if it is published at all.
+fn check_dependency(
+    publish_address: AccountAddress,
+    policy: UpgradePolicy,
+    dep: &PackageDep,
+    dep_policy: Option<UpgradePolicy>,
+) -> Option<String> {
+    let Some(dep_policy) = dep_policy else {
+        return Some(format!(
+            "The dependency `{}` is not published at `{}`.",
+            dep.package_name, dep.account
+        ));
+    };
+    if dep_policy.policy < policy.policy {
+        Some(format!(
+            "The dependency `{}` has the weaker upgrade policy `{}`.",
+            dep.package_name, dep_policy
+        ))
+    } else if dep_policy == UpgradePolicy::arbitrary() && dep.account != publish_address {
+        Some(format!(
+            "The dependency `{}` has the upgrade policy `{}` but is published at another address.",
+            dep.package_name, dep_policy
+        ))
+    } else {
+        None
+    }
+}
+
+fn check_module(
+    check_friend_linking: bool,
+    name: String,
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+) -> ModuleUpgradeReport {
+    if old_module == new_module {
+        return ModuleUpgradeReport {
+            name,
+            status: ModuleUpgradeStatus::Unchanged,
+            breaking_changes: vec![],
+        };
+    }
+
+    let old = normalized::Module::new(old_module);
+    let new = normalized::Module::new(new_module);
+    if Compatibility::new(true, true, check_friend_linking)
+        .check(&old, &new)
+        .is_ok()
+    {
+        return ModuleUpgradeReport {
+            name,
+            status: ModuleUpgradeStatus::Compatible,
+            breaking_changes: vec![],
+        };
+    }
+
+    let mut breaking_changes = breaking_changes(&old, &new, check_friend_linking);
+    if breaking_changes.is_empty() {
+        breaking_changes.push("The module is not backward compatible.".to_string());
+    }
+    ModuleUpgradeReport {
+        name,
+        status: ModuleUpgradeStatus::Incompatible,
+        breaking_changes,
+    }
+}
+
+/// Lists what makes `new` incompatible with `old`, following the rules of
+/// `Compatibility::check`, which only tells whether it is.
+fn breaking_changes(
+    old: &normalized::Module,
+    new: &normalized::Module,
+    check_friend_linking: bool,
+) -> Vec<String> {
+    let mut changes = vec![];
+
+    for (name, old_struct) in &old.structs {
+        let Some(new_struct) = new.structs.get(name) else {
+            changes.push(format!("Struct `{}` was removed.", name));
+            continue;
+        };
+        if !old_struct.abilities.is_subset(new_struct.abilities) {
+            changes.push(format!("Struct `{}` lost abilities.", name));
+        }
+        let type_parameters_compatible = old_struct.type_parameters.len()
+            == new_struct.type_parameters.len()
+            && old_struct
+                .type_parameters
+                .iter()
+                .zip(&new_struct.type_parameters)
+                .all(|(old_param, new_param)| {
+                    (!old_param.is_phantom || new_param.is_phantom)
+                        && new_param.constraints.is_subset(old_param.constraints)
+                });
+        if !type_parameters_compatible {
+            changes.push(format!("Type parameters of struct `{}` changed.", name));
+        }
+        if old_struct.fields != new_struct.fields {
+            changes.push(format!(
+                "Fields of struct `{}` changed, values already stored could not be read.",
+                name
+            ));
+        }
+    }
+
+    for (name, old_func) in &old.exposed_functions {
+        let kind = match old_func.visibility {
+            Visibility::Friend if !check_friend_linking => continue,
+            Visibility::Friend => "friend function",
+            Visibility::Public => "public function",
+            Visibility::Private => "entry function",
+        };
+        let Some(new_func) = new.exposed_functions.get(name) else {
+            changes.push(format!("The {} `{}` was removed.", kind, name));
+            continue;
+        };
+        let visibility_compatible = match old_func.visibility {
+            Visibility::Public => new_func.visibility == Visibility::Public,
+            Visibility::Friend => new_func.visibility != Visibility::Private,
+            Visibility::Private => true,
+        };
+        if !visibility_compatible {
+            changes.push(format!("The {} `{}` became less visible.", kind, name));
+        }
+        let entry_compatible =
+            if old.file_format_version < VERSION_5 && new.file_format_version < VERSION_5 {
+                old_func.is_entry == new_func.is_entry
+            } else {
+                !old_func.is_entry || new_func.is_entry
+            };
+        if !entry_compatible {
+            changes.push(format!("The {} `{}` is no longer an entry.", kind, name));
+        }
+        if old_func.parameters != new_func.parameters
+            || old_func.return_ != new_func.return_
+            || !function_type_parameters_compatible(
+                &old_func.type_parameters,
+                &new_func.type_parameters,
+            )
+        {
+            changes.push(format!("The signature of the {} `{}` changed.", kind, name));
+        }
+    }
+
+    if check_friend_linking {
+        for friend in &old.friends {
+            if !new.friends.contains(friend) {
+                changes.push(format!("Friend declaration of `{}` was removed.", friend));
+            }
+        }
+    }
+
+    changes
+}
+
+fn function_type_parameters_compatible(old: &[AbilitySet], new: &[AbilitySet]) -> bool {
+    old.len() == new.len()
+        && old
+            .iter()
+            .zip(new)
+            .all(|(old_constraints, new_constraints)| new_constraints.is_subset(*old_constraints))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_binary_format::{
+        file_format::{Ability, StructTypeParameter},
+        file_format_common::VERSION_6,
+        normalized::{Field, Function, Module, Struct, Type},
+    };
+    use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+
+    fn ident(name: &str) -> Identifier {
+        Identifier::new(name).unwrap()
+    }
+
+    fn function(visibility: Visibility, is_entry: bool, parameters: Vec<Type>) -> Function {
+        Function {
+            visibility,
+            is_entry,
+            type_parameters: vec![AbilitySet::EMPTY],
+            parameters,
+            return_: vec![],
+        }
+    }
+
+    /// A module with a struct, a function of each kind and a friend.
+    fn old_module() -> Module {
+        let resource = Struct {
+            abilities: AbilitySet::EMPTY.add(Ability::Key).add(Ability::Store),
+            type_parameters: vec![StructTypeParameter {
+                constraints: AbilitySet::EMPTY,
+                is_phantom: true,
+            }],
+            fields: vec![Field {
+                name: ident("value"),
+                type_: Type::U64,
+            }],
+        };
+        Module {
+            file_format_version: VERSION_6,
+            address: AccountAddress::ONE,
+            name: ident("upgraded"),
+            friends: vec![ModuleId::new(AccountAddress::ONE, ident("friendly"))],
+            structs: [(ident("Resource"), resource)].into_iter().collect(),
+            exposed_functions: [
+                (
+                    ident("public_function"),
+                    function(Visibility::Public, false, vec![Type::U64]),
+                ),
+                (
+                    ident("friend_function"),
+                    function(Visibility::Friend, false, vec![Type::U64]),
+                ),
+                (
+                    ident("entry_function"),
+                    function(Visibility::Private, true, vec![Type::U64]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    /// Checks that the change breaks compatibility and is the only one reported.
+    fn assert_breaking_change(change: impl FnOnce(&mut Module), expected: &str) {
+        let old = old_module();
+        let mut new = old_module();
+        change(&mut new);
+        assert!(Compatibility::new(true, true, true)
+            .check(&old, &new)
+            .is_err());
+        let expected = vec![expected.to_string()];
+        assert_eq!(breaking_changes(&old, &new, true), expected);
+    }
+
+    #[test]
+    fn test_compatible_changes() {
+        let old = old_module();
+        let mut new = old_module();
+        new.structs.get_mut(&ident("Resource")).unwrap().abilities = AbilitySet::ALL;
+        let added = Struct {
+            abilities: AbilitySet::EMPTY,
+            type_parameters: vec![],
+            fields: vec![],
+        };
+        new.structs.insert(ident("Added"), added);
+        new.exposed_functions.insert(
+            ident("added_function"),
+            function(Visibility::Public, true, vec![]),
+        );
+        new.exposed_functions
+            .get_mut(&ident("friend_function"))
+            .unwrap()
+            .visibility = Visibility::Public;
+        new.friends
+            .push(ModuleId::new(AccountAddress::ONE, ident("added_friend")));
+
+        assert!(Compatibility::new(true, true, true)
+            .check(&old, &new)
+            .is_ok());
+        assert!(breaking_changes(&old, &new, true).is_empty());
+    }
+
+    #[test]
+    fn test_struct_removed() {
+        assert_breaking_change(
+            |new| {
+                new.structs.remove(&ident("Resource"));
+            },
+            "Struct `Resource` was removed.",
+        );
+    }
+
+    #[test]
+    fn test_struct_lost_abilities() {
+        assert_breaking_change(
+            |new| {
+                new.structs.get_mut(&ident("Resource")).unwrap().abilities =
+                    AbilitySet::EMPTY.add(Ability::Key);
+            },
+            "Struct `Resource` lost abilities.",
+        );
+    }
+
+    #[test]
+    fn test_struct_type_parameters_changed() {
+        assert_breaking_change(
+            |new| {
+                new.structs
+                    .get_mut(&ident("Resource"))
+                    .unwrap()
+                    .type_parameters[0]
+                    .is_phantom = false;
+            },
+            "Type parameters of struct `Resource` changed.",
+        );
+        assert_breaking_change(
+            |new| {
+                new.structs
+                    .get_mut(&ident("Resource"))
+                    .unwrap()
+                    .type_parameters[0]
+                    .constraints = AbilitySet::EMPTY.add(Ability::Copy);
+            },
+            "Type parameters of struct `Resource` changed.",
+        );
+    }
+
+    #[test]
+    fn test_struct_fields_changed() {
+        assert_breaking_change(
+            |new| {
+                new.structs.get_mut(&ident("Resource")).unwrap().fields[0].type_ = Type::U128;
+            },
+            "Fields of struct `Resource` changed, values already stored could not be read.",
+        );
+    }
+
+    #[test]
+    fn test_function_removed() {
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions.remove(&ident("public_function"));
+            },
+            "The public function `public_function` was removed.",
+        );
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions.remove(&ident("entry_function"));
+            },
+            "The entry function `entry_function` was removed.",
+        );
+    }
+
+    #[test]
+    fn test_function_less_visible() {
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions
+                    .get_mut(&ident("public_function"))
+                    .unwrap()
+                    .visibility = Visibility::Friend;
+            },
+            "The public function `public_function` became less visible.",
+        );
+    }
+
+    #[test]
+    fn test_function_no_longer_entry() {
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions
+                    .get_mut(&ident("entry_function"))
+                    .unwrap()
+                    .is_entry = false;
+            },
+            "The entry function `entry_function` is no longer an entry.",
+        );
+    }
+
+    #[test]
+    fn test_function_signature_changed() {
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions
+                    .get_mut(&ident("public_function"))
+                    .unwrap()
+                    .parameters = vec![Type::Bool];
+            },
+            "The signature of the public function `public_function` changed.",
+        );
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions
+                    .get_mut(&ident("public_function"))
+                    .unwrap()
+                    .type_parameters = vec![AbilitySet::EMPTY.add(Ability::Drop)];
+            },
+            "The signature of the public function `public_function` changed.",
+        );
+    }
+
+    #[test]
+    fn test_friend_linking() {
+        assert_breaking_change(
+            |new| {
+                new.exposed_functions.remove(&ident("friend_function"));
+            },
+            "The friend function `friend_function` was removed.",
+        );
+        let friend = ModuleId::new(AccountAddress::ONE, ident("friendly"));
+        assert_breaking_change(
+            |new| new.friends.clear(),
+            &format!("Friend declaration of `{}` was removed.", friend),
+        );
+
+        // Friends are treated as private when friend linking isn't checked
+        let old = old_module();
+        let mut new = old_module();
+        new.exposed_functions.remove(&ident("friend_function"));
+        new.friends.clear();
+        assert!(Compatibility::new(true, true, false)
+            .check(&old, &new)
+            .is_ok());
+        assert!(breaking_changes(&old, &new, false).is_empty());
+    }
+
+    #[test]
+    fn test_check_dependency() {
+        let publisher = AccountAddress::from_hex_literal("0xcafe").unwrap();
+        let other = AccountAddress::from_hex_literal("0xbeef").unwrap();
+        let dep = |account| PackageDep {
+            account,
+            package_name: "Dependency".to_string(),
+        };
+        let compat = UpgradePolicy::compat();
+
+        assert!(check_dependency(publisher, compat, &dep(other), Some(compat)).is_none());
+        assert!(check_dependency(
+            publisher,
+            compat,
+            &dep(other),
+            Some(UpgradePolicy::immutable())
+        )
+        .is_none());
+        assert!(check_dependency(publisher, compat, &dep(other), None).is_some());
+        assert!(check_dependency(
+            publisher,
+            UpgradePolicy::immutable(),
+            &dep(other),
+            Some(compat)
+        )
+        .is_some());
+
+        // Packages with an arbitrary policy can only be depended on from the same address
+        let arbitrary = UpgradePolicy::arbitrary();
+        assert!(check_dependency(publisher, arbitrary, &dep(publisher), Some(arbitrary)).is_none());
+        assert!(check_dependency(publisher, arbitrary, &dep(other), Some(arbitrary)).is_some());
+
+        assert!(is_policy_exempted_address(AccountAddress::ONE));
+        assert!(is_policy_exempted_address(
+            AccountAddress::from_hex_literal("0xa").unwrap()
+        ));
+        assert!(!is_policy_exempted_address(
+            AccountAddress::from_hex_literal("0xb").unwrap()
+        ));
+        assert!(!is_policy_exempted_address(other));
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod aptos_debug_natives;
+pub mod check_upgrade;
 pub mod coverage;
 mod disassembler;
 mod manifest;
@@ -17,7 +18,8 @@ use crate::{
             load_account_arg, ArgWithTypeJSON, CliConfig, CliError, CliTypedResult,
             ConfigSearchMode, EntryFunctionArguments, EntryFunctionArgumentsJSON,
             MoveManifestAccountWrapper, MovePackageDir, ProfileOptions, PromptOptions, RestOptions,
-            SaveFile, ScriptFunctionArguments, TransactionOptions, TransactionSummary,
+            SaveFile, ScriptFunctionArguments, TransactionOptions, TransactionOutcome,
+            TransactionSummary,
         },
         utils::{
             check_if_file_exists, create_dir_if_not_exist, dir_default_to_current,
@@ -26,6 +28,7 @@ use crate::{
     },
     governance::CompileScriptFunction,
     move_tool::{
+        check_upgrade::CheckUpgrade,
         coverage::SummaryCoverage,
         disassembler::Disassemble,
         manifest::{Dependency, ManifestNamedAddress, MovePackageManifest, PackageInfo},
@@ -79,6 +82,7 @@ use transactional_tests_runner::TransactionalTestOpts;
 #[derive(Subcommand)]
 pub enum MoveTool {
     BuildPublishPayload(BuildPublishPayload),
+    CheckUpgrade(CheckUpgrade),
     Clean(CleanPackage),
     Compile(CompilePackage),
     CompileScript(CompileScript),
@@ -106,6 +110,7 @@ impl MoveTool {
     pub async fn execute(self) -> CliResult {
         match self {
             MoveTool::BuildPublishPayload(tool) => tool.execute_serialized().await,
+            MoveTool::CheckUpgrade(tool) => tool.execute_serialized().await,
             MoveTool::Clean(tool) => tool.execute_serialized().await,
             MoveTool::Compile(tool) => tool.execute_serialized().await,
             MoveTool::CompileScript(tool) => tool.execute_serialized().await,
@@ -792,12 +797,12 @@ impl IncludedArtifacts {
 pub const MAX_PUBLISH_PACKAGE_SIZE: usize = 60_000;
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for PublishPackage {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for PublishPackage {
     fn command_name(&self) -> &'static str {
         "PublishPackage"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let package_publication_data: PackagePublicationData = (&self).try_into()?;
         profile_or_submit(package_publication_data.payload, &self.txn_options).await
     }
@@ -886,12 +891,12 @@ pub struct CreateResourceAccountAndPublishPackage {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for CreateResourceAccountAndPublishPackage {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for CreateResourceAccountAndPublishPackage {
     fn command_name(&self) -> &'static str {
         "ResourceAccountPublishPackage"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let CreateResourceAccountAndPublishPackage {
             address_name,
             mut move_options,
@@ -952,10 +957,10 @@ impl CliCommand<TransactionSummary> for CreateResourceAccountAndPublishPackage {
                 MAX_PUBLISH_PACKAGE_SIZE, size
             )));
         }
-        txn_options
+        Ok(txn_options
             .submit_transaction(payload)
-            .await
-            .map(TransactionSummary::from)
+            .await?
+            .map(TransactionSummary::from))
     }
 }
 
@@ -1205,12 +1210,12 @@ pub struct RunFunction {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for RunFunction {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for RunFunction {
     fn command_name(&self) -> &'static str {
         "RunFunction"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         profile_or_submit(
             TransactionPayload::EntryFunction(self.entry_function_args.try_into()?),
             &self.txn_options,
@@ -1253,12 +1258,12 @@ pub struct RunScript {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for RunScript {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for RunScript {
     fn command_name(&self) -> &'static str {
         "RunScript"
     }
 
-    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let (bytecode, _script_hash) = self
             .compile_proposal_args
             .compile("RunScript", self.txn_options.prompt_options)?;
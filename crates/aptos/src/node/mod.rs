@@ -8,7 +8,7 @@ use crate::{
         types::{
             CliCommand, CliError, CliResult, CliTypedResult, ConfigSearchMode,
             OptionalPoolAddressArgs, PoolAddressArgs, ProfileOptions, PromptOptions, RestOptions,
-            TransactionOptions, TransactionSummary,
+            TransactionOptions, TransactionOutcome, TransactionSummary,
         },
         utils::{prompt_yes_with_override, read_from_file},
     },
@@ -607,12 +607,12 @@ pub struct InitializeValidator {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for InitializeValidator {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for InitializeValidator {
     fn command_name(&self) -> &'static str {
         "InitializeValidator"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let operator_config = self.operator_config_file_args.load()?;
         let consensus_public_key = self
             .validator_consensus_key_args
@@ -642,7 +642,8 @@ impl CliCommand<TransactionSummary> for InitializeValidator {
                 },
             };
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_initialize_validator(
                 consensus_public_key.to_bytes().to_vec(),
                 consensus_proof_of_possession.to_bytes().to_vec(),
@@ -650,8 +651,8 @@ impl CliCommand<TransactionSummary> for InitializeValidator {
                 bcs::to_bytes(&validator_network_addresses)?,
                 bcs::to_bytes(&full_node_network_addresses)?,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -699,20 +700,21 @@ pub struct JoinValidatorSet {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for JoinValidatorSet {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for JoinValidatorSet {
     fn command_name(&self) -> &'static str {
         "JoinValidatorSet"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let address = self
             .operator_args
             .address_fallback_to_txn(&self.txn_options)?;
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_join_validator_set(address))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -729,20 +731,21 @@ pub struct LeaveValidatorSet {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for LeaveValidatorSet {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for LeaveValidatorSet {
     fn command_name(&self) -> &'static str {
         "LeaveValidatorSet"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let address = self
             .operator_args
             .address_fallback_to_txn(&self.txn_options)?;
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_leave_validator_set(address))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -1256,12 +1259,12 @@ pub struct UpdateConsensusKey {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for UpdateConsensusKey {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for UpdateConsensusKey {
     fn command_name(&self) -> &'static str {
         "UpdateConsensusKey"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let address = self
             .operator_args
             .address_fallback_to_txn(&self.txn_options)?;
@@ -1273,14 +1276,15 @@ impl CliCommand<TransactionSummary> for UpdateConsensusKey {
         let consensus_proof_of_possession = self
             .validator_consensus_key_args
             .get_consensus_proof_of_possession(&operator_config)?;
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_rotate_consensus_key(
                 address,
                 consensus_public_key.to_bytes().to_vec(),
                 consensus_proof_of_possession.to_bytes().to_vec(),
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -1300,12 +1304,12 @@ pub struct UpdateValidatorNetworkAddresses {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for UpdateValidatorNetworkAddresses {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for UpdateValidatorNetworkAddresses {
     fn command_name(&self) -> &'static str {
         "UpdateValidatorNetworkAddresses"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let address = self
             .operator_args
             .address_fallback_to_txn(&self.txn_options)?;
@@ -1333,15 +1337,16 @@ impl CliCommand<TransactionSummary> for UpdateValidatorNetworkAddresses {
                 },
             };
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_update_network_and_fullnode_addresses(
                 address,
                 // BCS encode, so that we can hide the original type
                 bcs::to_bytes(&validator_network_addresses)?,
                 bcs::to_bytes(&full_node_network_addresses)?,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
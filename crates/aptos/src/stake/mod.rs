@@ -4,7 +4,8 @@
 use crate::{
     common::{
         types::{
-            CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions, TransactionSummary,
+            CliCommand, CliError, CliResult, CliTypedResult, TransactionOptions,
+            TransactionOutcome, TransactionSummary,
         },
         utils::prompt_yes_with_override,
     },
@@ -70,29 +71,31 @@ pub struct AddStake {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for AddStake {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for AddStake {
     fn command_name(&self) -> &'static str {
         "AddStake"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .txn_options
             .rest_options
             .client(&self.txn_options.profile_options)?;
         let amount = self.amount;
         let owner_address = self.txn_options.sender_address()?;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.txn_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
                     transaction_summaries.push(
                         self.txn_options
                             .submit_transaction(aptos_stdlib::stake_add_stake(amount))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -102,8 +105,8 @@ impl CliCommand<Vec<TransactionSummary>> for AddStake {
                                 stake_pool.operator_address,
                                 amount,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -131,29 +134,31 @@ pub struct UnlockStake {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for UnlockStake {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for UnlockStake {
     fn command_name(&self) -> &'static str {
         "UnlockStake"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .txn_options
             .rest_options
             .client(&self.txn_options.profile_options)?;
         let amount = self.amount;
         let owner_address = self.txn_options.sender_address()?;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.txn_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
                     transaction_summaries.push(
                         self.txn_options
                             .submit_transaction(aptos_stdlib::stake_unlock(amount))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -163,8 +168,8 @@ impl CliCommand<Vec<TransactionSummary>> for UnlockStake {
                                 stake_pool.operator_address,
                                 amount,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -195,29 +200,31 @@ pub struct WithdrawStake {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for WithdrawStake {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for WithdrawStake {
     fn command_name(&self) -> &'static str {
         "WithdrawStake"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .node_op_options
             .rest_options
             .client(&self.node_op_options.profile_options)?;
         let amount = self.amount;
         let owner_address = self.node_op_options.sender_address()?;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.node_op_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
                     transaction_summaries.push(
                         self.node_op_options
                             .submit_transaction(aptos_stdlib::stake_withdraw(amount))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -227,8 +234,8 @@ impl CliCommand<Vec<TransactionSummary>> for WithdrawStake {
                                 owner_address,
                                 stake_pool.operator_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -253,28 +260,30 @@ pub struct IncreaseLockup {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for IncreaseLockup {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for IncreaseLockup {
     fn command_name(&self) -> &'static str {
         "IncreaseLockup"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .txn_options
             .rest_options
             .client(&self.txn_options.profile_options)?;
         let owner_address = self.txn_options.sender_address()?;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.txn_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
                     transaction_summaries.push(
                         self.txn_options
                             .submit_transaction(aptos_stdlib::stake_increase_lockup())
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -283,8 +292,8 @@ impl CliCommand<Vec<TransactionSummary>> for IncreaseLockup {
                             .submit_transaction(aptos_stdlib::staking_contract_reset_lockup(
                                 stake_pool.operator_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -293,8 +302,8 @@ impl CliCommand<Vec<TransactionSummary>> for IncreaseLockup {
                             .submit_transaction(aptos_stdlib::vesting_reset_lockup(
                                 stake_pool.vesting_contract.unwrap(),
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
             }
@@ -326,21 +335,22 @@ pub struct InitializeStakeOwner {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for InitializeStakeOwner {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for InitializeStakeOwner {
     fn command_name(&self) -> &'static str {
         "InitializeStakeOwner"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let owner_address = self.txn_options.sender_address()?;
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::stake_initialize_stake_owner(
                 self.initial_stake_amount,
                 self.operator_address.unwrap_or(owner_address),
                 self.voter_address.unwrap_or(owner_address),
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -361,21 +371,23 @@ pub struct SetOperator {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for SetOperator {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for SetOperator {
     fn command_name(&self) -> &'static str {
         "SetOperator"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .txn_options
             .rest_options
             .client(&self.txn_options.profile_options)?;
         let owner_address = self.txn_options.sender_address()?;
         let new_operator_address = self.operator_address;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.txn_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
@@ -384,8 +396,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetOperator {
                             .submit_transaction(aptos_stdlib::stake_set_operator(
                                 new_operator_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -397,8 +409,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetOperator {
                                     new_operator_address,
                                 ),
                             )
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -410,8 +422,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetOperator {
                                     new_operator_address,
                                 ),
                             )
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
             }
@@ -437,21 +449,23 @@ pub struct SetDelegatedVoter {
 }
 
 #[async_trait]
-impl CliCommand<Vec<TransactionSummary>> for SetDelegatedVoter {
+impl CliCommand<Vec<TransactionOutcome<TransactionSummary>>> for SetDelegatedVoter {
     fn command_name(&self) -> &'static str {
         "SetDelegatedVoter"
     }
 
-    async fn execute(mut self) -> CliTypedResult<Vec<TransactionSummary>> {
+    async fn execute(mut self) -> CliTypedResult<Vec<TransactionOutcome<TransactionSummary>>> {
         let client = self
             .txn_options
             .rest_options
             .client(&self.txn_options.profile_options)?;
         let owner_address = self.txn_options.sender_address()?;
         let new_voter_address = self.voter_address;
-        let mut transaction_summaries: Vec<TransactionSummary> = vec![];
+        let mut transaction_summaries: Vec<TransactionOutcome<TransactionSummary>> = vec![];
 
         let stake_pool_results = get_stake_pools(&client, owner_address).await?;
+        self.txn_options
+            .ensure_single_transaction(stake_pool_results.len())?;
         for stake_pool in stake_pool_results {
             match stake_pool.pool_type {
                 StakePoolType::Direct => {
@@ -460,8 +474,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetDelegatedVoter {
                             .submit_transaction(aptos_stdlib::stake_set_delegated_voter(
                                 new_voter_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::StakingContract => {
@@ -471,8 +485,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetDelegatedVoter {
                                 stake_pool.operator_address,
                                 new_voter_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
                 StakePoolType::Vesting => {
@@ -482,8 +496,8 @@ impl CliCommand<Vec<TransactionSummary>> for SetDelegatedVoter {
                                 stake_pool.vesting_contract.unwrap(),
                                 new_voter_address,
                             ))
-                            .await
-                            .map(|inner| inner.into())?,
+                            .await?
+                            .map(|inner| inner.into()),
                     );
                 },
             }
@@ -518,12 +532,12 @@ pub struct CreateStakingContract {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for CreateStakingContract {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for CreateStakingContract {
     fn command_name(&self) -> &'static str {
         "CreateStakingContract"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let pool_address = default_stake_pool_address(
             self.txn_options.profile_options.account_address()?,
             self.operator,
@@ -536,7 +550,8 @@ impl CliCommand<TransactionSummary> for CreateStakingContract {
             self.txn_options.prompt_options,
         )?;
 
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::staking_contract_create_staking_contract(
                 self.operator,
                 self.voter,
@@ -544,8 +559,8 @@ impl CliCommand<TransactionSummary> for CreateStakingContract {
                 self.commission_percentage,
                 vec![],
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -564,17 +579,18 @@ pub struct DistributeVestedCoins {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for DistributeVestedCoins {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for DistributeVestedCoins {
     fn command_name(&self) -> &'static str {
         "DistributeVestedCoins"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let vesting_contract_address = create_vesting_contract_address(self.admin_address, 0, &[]);
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::vesting_distribute(vesting_contract_address))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -597,17 +613,18 @@ pub struct UnlockVestedCoins {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for UnlockVestedCoins {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for UnlockVestedCoins {
     fn command_name(&self) -> &'static str {
         "UnlockVestedCoins"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let vesting_contract_address = create_vesting_contract_address(self.admin_address, 0, &[]);
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::vesting_vest(vesting_contract_address))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
 
@@ -631,12 +648,12 @@ pub struct RequestCommission {
 }
 
 #[async_trait]
-impl CliCommand<TransactionSummary> for RequestCommission {
+impl CliCommand<TransactionOutcome<TransactionSummary>> for RequestCommission {
     fn command_name(&self) -> &'static str {
         "RequestCommission"
     }
 
-    async fn execute(mut self) -> CliTypedResult<TransactionSummary> {
+    async fn execute(mut self) -> CliTypedResult<TransactionOutcome<TransactionSummary>> {
         let client = self
             .txn_options
             .rest_options
@@ -657,12 +674,13 @@ impl CliCommand<TransactionSummary> for RequestCommission {
         } else {
             self.owner_address
         };
-        self.txn_options
+        Ok(self
+            .txn_options
             .submit_transaction(aptos_stdlib::staking_contract_request_commission(
                 staker_address,
                 self.operator_address,
             ))
-            .await
-            .map(|inner| inner.into())
+            .await?
+            .map(|inner| inner.into()))
     }
 }
@@ -17,7 +17,8 @@ use crate::{
             EntryFunctionArguments, FaucetOptions, GasOptions, KeyType, MoveManifestAccountWrapper,
             MovePackageDir, OptionalPoolAddressArgs, PoolAddressArgs, PrivateKeyInputOptions,
             PromptOptions, PublicKeyInputOptions, RestOptions, RngArgs, SaveFile,
-            ScriptFunctionArguments, TransactionOptions, TransactionSummary, TypeArgVec,
+            ScriptFunctionArguments, TransactionOptions, TransactionOutcome, TransactionSummary,
+            TypeArgVec,
         },
         utils::write_to_file,
     },
@@ -26,6 +27,7 @@ use crate::{
         VerifyProposal, VerifyProposalResponse,
     },
     move_tool::{
+        check_upgrade::{CheckUpgrade, UpgradeCheckReport},
         ArgWithType, CompilePackage, DownloadPackage, FrameworkPackageArgs, IncludedArtifacts,
         IncludedArtifactsArgs, InitPackage, MemberId, PublishPackage, RunFunction, RunScript,
         TestPackage,
@@ -42,6 +44,7 @@ use crate::{
         AddStake, IncreaseLockup, InitializeStakeOwner, SetDelegatedVoter, SetOperator,
         UnlockStake, WithdrawStake,
     },
+    transaction::{SignTransaction, SignedTransactionSummary, SubmitTransaction},
     CliCommand,
 };
 use aptos_config::config::Peer;
@@ -301,6 +304,67 @@ impl CliTestFramework {
             amount,
         }
         .execute()
+        .await?
+        .into_submitted()
+    }
+
+    /// Saves the transfer unsigned instead of submitting it. The private key isn't used.
+    pub async fn transfer_coins_unsigned(
+        &self,
+        sender_index: usize,
+        receiver_index: usize,
+        amount: u64,
+        max_gas: u64,
+        unsigned_output_file: PathBuf,
+    ) -> CliTypedResult<TransactionOutcome<TransferSummary>> {
+        TransferCoins {
+            txn_options: TransactionOptions {
+                sender_account: Some(self.account_id(sender_index)),
+                rest_options: self.rest_options(),
+                gas_options: GasOptions {
+                    max_gas: Some(max_gas),
+                    ..Default::default()
+                },
+                prompt_options: PromptOptions::yes(),
+                unsigned_output_file: Some(unsigned_output_file),
+                ..Default::default()
+            },
+            account: self.account_id(receiver_index),
+            amount,
+        }
+        .execute()
+        .await
+    }
+
+    pub async fn sign_transaction(
+        &self,
+        index: usize,
+        unsigned_transaction_file: PathBuf,
+        output_file: PathBuf,
+    ) -> CliTypedResult<SignedTransactionSummary> {
+        SignTransaction {
+            unsigned_transaction_file,
+            output_file,
+            ledger_index: None,
+            private_key_options: PrivateKeyInputOptions::from_private_key(self.private_key(index))?,
+            encoding_options: Default::default(),
+            profile_options: Default::default(),
+            prompt_options: PromptOptions::yes(),
+        }
+        .execute()
+        .await
+    }
+
+    pub async fn submit_transaction(
+        &self,
+        signed_transaction_file: PathBuf,
+    ) -> CliTypedResult<TransactionSummary> {
+        SubmitTransaction {
+            signed_transaction_file,
+            rest_options: self.rest_options(),
+            profile_options: Default::default(),
+        }
+        .execute()
         .await
     }
 
@@ -335,7 +399,8 @@ impl CliTestFramework {
             txn_options: self.transaction_options(sender_index, gas_options),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn show_validator_config(
@@ -397,7 +462,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn add_stake(
@@ -410,7 +476,10 @@ impl CliTestFramework {
             amount,
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     pub async fn unlock_stake(
@@ -423,7 +492,10 @@ impl CliTestFramework {
             amount,
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     pub async fn withdraw_stake(
@@ -436,7 +508,10 @@ impl CliTestFramework {
             amount,
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     pub async fn increase_lockup(&self, index: usize) -> CliTypedResult<Vec<TransactionSummary>> {
@@ -444,7 +519,10 @@ impl CliTestFramework {
             txn_options: self.transaction_options(index, None),
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     pub async fn join_validator_set(
@@ -457,7 +535,8 @@ impl CliTestFramework {
             operator_args: self.operator_args(pool_index),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn leave_validator_set(
@@ -470,7 +549,8 @@ impl CliTestFramework {
             operator_args: self.operator_args(pool_index),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn update_validator_network_addresses(
@@ -494,7 +574,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn analyze_validator_performance(
@@ -533,7 +614,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn init(&self, private_key: &Ed25519PrivateKey) -> CliTypedResult<()> {
@@ -547,6 +629,7 @@ impl CliTestFramework {
             prompt_options: PromptOptions::yes(),
             encoding_options: EncodingOptions::default(),
             skip_faucet: false,
+            encrypt_private_key: false,
         }
         .execute()
         .await
@@ -579,7 +662,8 @@ impl CliTestFramework {
             voter_address: voter_index.map(|idx| self.account_id(idx)),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn create_stake_pool(
@@ -610,7 +694,8 @@ impl CliTestFramework {
             txn_options: self.transaction_options(owner_index, None),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn set_operator(
@@ -623,7 +708,10 @@ impl CliTestFramework {
             operator_address: self.account_id(operator_index),
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     pub async fn set_delegated_voter(
@@ -636,7 +724,10 @@ impl CliTestFramework {
             voter_address: self.account_id(voter_index),
         }
         .execute()
-        .await
+        .await?
+        .into_iter()
+        .map(TransactionOutcome::into_submitted)
+        .collect()
     }
 
     /// Wait for an account to exist
@@ -871,7 +962,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn download_package(
@@ -891,6 +983,21 @@ impl CliTestFramework {
         .await
     }
 
+    pub async fn check_upgrade(
+        &self,
+        index: usize,
+        account_strs: BTreeMap<&str, &str>,
+    ) -> CliTypedResult<UpgradeCheckReport> {
+        CheckUpgrade {
+            account: self.account_id(index),
+            move_options: self.move_options(account_strs),
+            rest_options: self.rest_options(),
+            profile_options: Default::default(),
+        }
+        .execute()
+        .await
+    }
+
     pub async fn run_function(
         &self,
         index: usize,
@@ -927,7 +1034,8 @@ impl CliTestFramework {
             txn_options: self.transaction_options(index, gas_options),
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     /// Runs the given script contents using the local aptos_framework directory.
@@ -993,7 +1101,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn run_script_with_script_path(
@@ -1022,7 +1131,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     fn aptos_framework_dir() -> PathBuf {
@@ -1149,7 +1259,8 @@ impl CliTestFramework {
             },
         }
         .execute()
-        .await
+        .await?
+        .into_submitted()
     }
 
     pub async fn vote(
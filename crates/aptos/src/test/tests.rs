@@ -24,6 +24,8 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "account", "transfer", "--help"]).await;
 
     assert_cmd_not_panic(&["aptos", "config"]).await;
+    assert_cmd_not_panic(&["aptos", "config", "change-password", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "config", "encrypt-profiles", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "generate-shell-completions", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "init", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "config", "set-global-config", "--help"]).await;
@@ -53,6 +55,7 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "key", "extract-peer", "--help"]).await;
 
     assert_cmd_not_panic(&["aptos", "move"]).await;
+    assert_cmd_not_panic(&["aptos", "move", "check-upgrade", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "move", "clean", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "move", "compile", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "move", "compile-script", "--help"]).await;
@@ -96,6 +99,10 @@ async fn ensure_every_command_args_work() {
     assert_cmd_not_panic(&["aptos", "stake", "set-operator", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "stake", "unlock-stake", "--help"]).await;
     assert_cmd_not_panic(&["aptos", "stake", "withdraw-stake", "--help"]).await;
+
+    assert_cmd_not_panic(&["aptos", "transaction"]).await;
+    assert_cmd_not_panic(&["aptos", "transaction", "sign", "--help"]).await;
+    assert_cmd_not_panic(&["aptos", "transaction", "submit", "--help"]).await;
 }
 
 /// Ensure we can parse URLs for args
@@ -0,0 +1,182 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    types::{
+        CliCommand, CliError, CliResult, CliTypedResult, EncodingOptions, PrivateKeyInputOptions,
+        ProfileOptions, PromptOptions, RestOptions, TransactionSummary,
+    },
+    utils::{check_if_file_exists, prompt_yes_with_override, read_from_file, write_to_file},
+};
+use aptos_crypto::{ed25519::Ed25519Signature, PrivateKey};
+use aptos_rest_client::aptos_api_types::HashValue;
+use aptos_types::{
+    account_address::AccountAddress,
+    transaction::{RawTransaction, SignedTransaction},
+};
+use async_trait::async_trait;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// Tool for signing and submitting transactions separately
+///
+/// Any command submitting a transaction can save it unsigned instead, with
+/// `--unsigned-output-file`. It can then be signed offline, e.g. on an air-gapped machine, and
+/// submitted from a machine with network access.
+#[derive(Subcommand)]
+pub enum TransactionTool {
+    Sign(SignTransaction),
+    Submit(SubmitTransaction),
+}
+
+impl TransactionTool {
+    pub async fn execute(self) -> CliResult {
+        match self {
+            TransactionTool::Sign(tool) => tool.execute_serialized().await,
+            TransactionTool::Submit(tool) => tool.execute_serialized().await,
+        }
+    }
+}
+
+/// Sign a transaction saved unsigned
+///
+/// This doesn't require network access. The transaction is shown, and needs to be confirmed,
+/// before it is signed.
+#[derive(Parser)]
+pub struct SignTransaction {
+    /// File with the unsigned transaction, as saved with `--unsigned-output-file`
+    #[clap(long, value_parser)]
+    pub(crate) unsigned_transaction_file: PathBuf,
+
+    /// File to save the signed transaction to
+    #[clap(long, value_parser)]
+    pub(crate) output_file: PathBuf,
+
+    /// Index of the account on a connected Ledger device to sign with, instead of a private key
+    #[clap(long, conflicts_with = "private_key_input")]
+    pub(crate) ledger_index: Option<u32>,
+
+    #[clap(flatten)]
+    pub(crate) private_key_options: PrivateKeyInputOptions,
+    #[clap(flatten)]
+    pub(crate) encoding_options: EncodingOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+    #[clap(flatten)]
+    pub(crate) prompt_options: PromptOptions,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SignedTransactionSummary {
+    pub signed_transaction_file: PathBuf,
+    pub transaction_hash: HashValue,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+}
+
+#[async_trait]
+impl CliCommand<SignedTransactionSummary> for SignTransaction {
+    fn command_name(&self) -> &'static str {
+        "SignTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<SignedTransactionSummary> {
+        let raw_transaction: RawTransaction =
+            bcs::from_bytes(&read_from_file(&self.unsigned_transaction_file)?)
+                .map_err(|err| CliError::BCS("unsigned transaction", err))?;
+
+        eprintln!(
+            "{}",
+            raw_transaction.format_for_client(|code| format!("script 0x{}", hex::encode(code)))
+        );
+        prompt_yes_with_override("Do you want to sign this transaction?", self.prompt_options)?;
+
+        let signed_transaction = match self.ledger_index {
+            Some(index) => sign_with_ledger(raw_transaction, index)?,
+            None => {
+                let private_key = self
+                    .private_key_options
+                    .extract_private_key(self.encoding_options.encoding, &self.profile_options)?;
+                raw_transaction
+                    .sign(&private_key, private_key.public_key())
+                    .map_err(|err| CliError::UnexpectedError(err.to_string()))?
+                    .into_inner()
+            },
+        };
+
+        check_if_file_exists(&self.output_file, self.prompt_options)?;
+        write_to_file(
+            &self.output_file,
+            &self.output_file.display().to_string(),
+            &bcs::to_bytes(&signed_transaction)?,
+        )?;
+
+        Ok(SignedTransactionSummary {
+            signed_transaction_file: self.output_file,
+            sender: signed_transaction.sender(),
+            sequence_number: signed_transaction.sequence_number(),
+            transaction_hash: signed_transaction.committed_hash().into(),
+        })
+    }
+}
+
+fn sign_with_ledger(
+    raw_transaction: RawTransaction,
+    index: u32,
+) -> CliTypedResult<SignedTransaction> {
+    let ledger_error = |err: aptos_ledger::AptosLedgerError| {
+        CliError::UnexpectedError(format!("Failed to sign with the Ledger device: {}", err))
+    };
+    let path = format!("m/44'/637'/{}'/0'/0'", index);
+
+    let public_key = aptos_ledger::get_public_key(&path, false).map_err(ledger_error)?;
+    let signing_message = raw_transaction
+        .signing_message()
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+    eprintln!("Confirm the transaction on the Ledger device");
+    let signature = aptos_ledger::sign_txn(&path, signing_message).map_err(ledger_error)?;
+    let signature = Ed25519Signature::try_from(signature.as_slice())
+        .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+
+    // Catch a signature which wouldn't be accepted before it makes it to the network
+    let signed_transaction = SignedTransaction::new(raw_transaction, public_key, signature);
+    signed_transaction
+        .clone()
+        .check_signature()
+        .map_err(|err| CliError::UnexpectedError(format!("Invalid signature: {}", err)))?;
+    Ok(signed_transaction)
+}
+
+/// Submit a transaction signed with `aptos transaction sign`
+#[derive(Parser)]
+pub struct SubmitTransaction {
+    /// File with the signed transaction
+    #[clap(long, value_parser)]
+    pub(crate) signed_transaction_file: PathBuf,
+
+    #[clap(flatten)]
+    pub(crate) rest_options: RestOptions,
+    #[clap(flatten)]
+    pub(crate) profile_options: ProfileOptions,
+}
+
+#[async_trait]
+impl CliCommand<TransactionSummary> for SubmitTransaction {
+    fn command_name(&self) -> &'static str {
+        "SubmitTransaction"
+    }
+
+    async fn execute(self) -> CliTypedResult<TransactionSummary> {
+        let signed_transaction: SignedTransaction =
+            bcs::from_bytes(&read_from_file(&self.signed_transaction_file)?)
+                .map_err(|err| CliError::BCS("signed transaction", err))?;
+
+        let client = self.rest_options.client(&self.profile_options)?;
+        client
+            .submit_and_wait(&signed_transaction)
+            .await
+            .map(|response| TransactionSummary::from(response.into_inner()))
+            .map_err(|err| CliError::ApiError(err.to_string()))
+    }
+}
@@ -0,0 +1,365 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, ensure, format_err, Result};
+use aptos_protos::{
+    indexer::v1::TransactionsFilter,
+    transaction::v1::{
+        multisig_transaction_payload, transaction::TxnData, transaction_payload,
+        write_set_change::Change, Event, Transaction,
+    },
+};
+use std::collections::HashSet;
+
+/// The `TransactionsFilter` of a request, validated and with all the addresses in it rewritten to
+/// the short form used in the transactions, e.g. `0x1`, so that matching is plain string
+/// comparison.
+#[derive(Debug, Default)]
+pub struct TransactionFilter {
+    sender_addresses: HashSet<String>,
+    entry_functions: Vec<String>,
+    event_types: Vec<String>,
+    write_resource_types: Vec<String>,
+    success_only: bool,
+}
+
+impl TransactionFilter {
+    pub fn new(filter: &TransactionsFilter) -> Result<Self> {
+        Ok(Self {
+            sender_addresses: filter
+                .sender_addresses
+                .iter()
+                .map(|address| normalize_address(address))
+                .collect::<Result<_>>()?,
+            entry_functions: filter
+                .entry_functions
+                .iter()
+                .map(|function| normalize_entry_function(function))
+                .collect::<Result<_>>()?,
+            event_types: filter
+                .event_types
+                .iter()
+                .map(|typ| normalize_type(typ))
+                .collect::<Result<_>>()?,
+            write_resource_types: filter
+                .write_resource_types
+                .iter()
+                .map(|typ| normalize_type(typ))
+                .collect::<Result<_>>()?,
+            success_only: filter.success_only,
+        })
+    }
+
+    pub fn matches(&self, transaction: &Transaction) -> bool {
+        if self.success_only && !transaction.info.as_ref().map_or(false, |info| info.success) {
+            return false;
+        }
+
+        let request = match &transaction.txn_data {
+            Some(TxnData::User(user)) => user.request.as_ref(),
+            _ => None,
+        };
+        if !self.sender_addresses.is_empty()
+            && !request.map_or(false, |request| {
+                self.sender_addresses.contains(&request.sender)
+            })
+        {
+            return false;
+        }
+        if !self.entry_functions.is_empty() {
+            let function = request
+                .and_then(|request| request.payload.as_ref())
+                .and_then(|payload| entry_function_id(&payload.payload));
+            if !function.map_or(false, |function| {
+                self.entry_functions
+                    .iter()
+                    .any(|pattern| entry_function_matches(pattern, function))
+            }) {
+                return false;
+            }
+        }
+        if !self.event_types.is_empty()
+            && !events(transaction).iter().any(|event| {
+                self.event_types
+                    .iter()
+                    .any(|pattern| type_matches(pattern, &event.type_str))
+            })
+        {
+            return false;
+        }
+        if !self.write_resource_types.is_empty()
+            && !transaction.info.as_ref().map_or(false, |info| {
+                info.changes.iter().any(|change| match &change.change {
+                    Some(Change::WriteResource(resource)) => self
+                        .write_resource_types
+                        .iter()
+                        .any(|pattern| type_matches(pattern, &resource.type_str)),
+                    _ => false,
+                })
+            })
+        {
+            return false;
+        }
+        true
+    }
+}
+
+/// Returns the entry function called, directly or through a multisig account.
+fn entry_function_id(payload: &Option<transaction_payload::Payload>) -> Option<&str> {
+    match payload {
+        Some(transaction_payload::Payload::EntryFunctionPayload(payload)) => {
+            Some(payload.entry_function_id_str.as_str())
+        },
+        Some(transaction_payload::Payload::MultisigPayload(multisig)) => multisig
+            .transaction_payload
+            .as_ref()
+            .and_then(|payload| match &payload.payload {
+                Some(multisig_transaction_payload::Payload::EntryFunctionPayload(payload)) => {
+                    Some(payload.entry_function_id_str.as_str())
+                },
+                None => None,
+            }),
+        _ => None,
+    }
+}
+
+fn events(transaction: &Transaction) -> &[Event] {
+    match &transaction.txn_data {
+        Some(TxnData::User(user)) => &user.events,
+        Some(TxnData::BlockMetadata(block_metadata)) => &block_metadata.events,
+        Some(TxnData::Genesis(genesis)) => &genesis.events,
+        Some(TxnData::StateCheckpoint(_)) | None => &[],
+    }
+}
+
+/// `pattern` is either a module, matching all its functions, or a function.
+fn entry_function_matches(pattern: &str, function: &str) -> bool {
+    function
+        .strip_prefix(pattern)
+        .map_or(false, |rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+/// A `pattern` without type arguments matches all instantiations of the type.
+fn type_matches(pattern: &str, typ: &str) -> bool {
+    typ.strip_prefix(pattern)
+        .map_or(false, |rest| rest.is_empty() || rest.starts_with('<'))
+}
+
+fn normalize_address(address: &str) -> Result<String> {
+    let trimmed = address.trim();
+    let hex = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+    ensure!(
+        !hex.is_empty() && hex.len() <= 64 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        "Invalid address: {:?}",
+        address
+    );
+    let hex = hex.trim_start_matches('0').to_ascii_lowercase();
+    Ok(format!("0x{}", if hex.is_empty() { "0" } else { &hex }))
+}
+
+fn normalize_entry_function(function: &str) -> Result<String> {
+    let parts = function.trim().split("::").collect::<Vec<_>>();
+    if !(2..=3).contains(&parts.len()) || !parts[1..].iter().all(|part| is_identifier(part)) {
+        bail!(
+            "Invalid entry function {:?}, expected <address>::<module>[::<function>].",
+            function
+        );
+    }
+    Ok(format!(
+        "{}::{}",
+        normalize_address(parts[0])?,
+        parts[1..].join("::")
+    ))
+}
+
+/// Rewrites the addresses in a struct type, e.g. `0x01::coin::CoinStore<0x1::aptos_coin::AptosCoin>`,
+/// and spaces the type arguments the way the transactions do.
+fn normalize_type(typ: &str) -> Result<String> {
+    let invalid = || format_err!("Invalid type {:?}.", typ);
+    let typ = typ
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+    let (address, rest) = typ.split_once("::").ok_or_else(invalid)?;
+
+    let mut normalized = normalize_address(address)?;
+    normalized.push_str("::");
+    let mut token = String::new();
+    for c in rest.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            token.push(c);
+            continue;
+        }
+        normalized.push_str(&normalize_token(&token)?);
+        token.clear();
+        match c {
+            ',' => normalized.push_str(", "),
+            ':' | '<' | '>' => normalized.push(c),
+            _ => return Err(invalid()),
+        }
+    }
+    if token.is_empty() && !typ.ends_with('>') {
+        return Err(invalid());
+    }
+    normalized.push_str(&normalize_token(&token)?);
+    Ok(normalized)
+}
+
+/// Addresses in type arguments need the `0x` prefix to be told apart from identifiers.
+fn normalize_token(token: &str) -> Result<String> {
+    if token.starts_with("0x") {
+        normalize_address(token)
+    } else {
+        Ok(token.to_string())
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    s.chars()
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_protos::transaction::v1::{
+        EntryFunctionPayload, TransactionInfo, TransactionPayload, UserTransaction,
+        UserTransactionRequest, WriteResource, WriteSetChange,
+    };
+
+    fn user_transaction(sender: &str, function: &str, success: bool) -> Transaction {
+        Transaction {
+            info: Some(TransactionInfo {
+                success,
+                changes: vec![WriteSetChange {
+                    change: Some(Change::WriteResource(WriteResource {
+                        type_str: "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>".to_string(),
+                        ..WriteResource::default()
+                    })),
+                    ..WriteSetChange::default()
+                }],
+                ..TransactionInfo::default()
+            }),
+            txn_data: Some(TxnData::User(UserTransaction {
+                request: Some(UserTransactionRequest {
+                    sender: sender.to_string(),
+                    payload: Some(TransactionPayload {
+                        payload: Some(transaction_payload::Payload::EntryFunctionPayload(
+                            EntryFunctionPayload {
+                                entry_function_id_str: function.to_string(),
+                                ..EntryFunctionPayload::default()
+                            },
+                        )),
+                        ..TransactionPayload::default()
+                    }),
+                    ..UserTransactionRequest::default()
+                }),
+                events: vec![Event {
+                    type_str: "0x1::coin::DepositEvent".to_string(),
+                    ..Event::default()
+                }],
+            })),
+            ..Transaction::default()
+        }
+    }
+
+    fn new_filter(filter: TransactionsFilter) -> TransactionFilter {
+        TransactionFilter::new(&filter).unwrap()
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = new_filter(TransactionsFilter::default());
+        assert!(filter.matches(&Transaction::default()));
+        assert!(filter.matches(&user_transaction("0xa", "0x1::coin::transfer", false)));
+    }
+
+    #[test]
+    fn test_match() {
+        let transaction = user_transaction("0xa", "0x1::coin::transfer", true);
+
+        for (sender_addresses, matches) in [
+            (vec!["0xA"], true),
+            (vec!["0x000a"], true),
+            (vec!["a"], true),
+            (vec!["0xb", "0xa"], true),
+            (vec!["0xb"], false),
+        ] {
+            let filter = new_filter(TransactionsFilter {
+                sender_addresses: sender_addresses.into_iter().map(str::to_string).collect(),
+                ..TransactionsFilter::default()
+            });
+            assert_eq!(filter.matches(&transaction), matches);
+        }
+
+        for (entry_function, matches) in [
+            ("0x1::coin::transfer", true),
+            ("0x01::coin", true),
+            ("0x1::coin::transfer_all", false),
+            ("0x1::coi", false),
+            ("0x1::aptos_account", false),
+        ] {
+            let filter = new_filter(TransactionsFilter {
+                entry_functions: vec![entry_function.to_string()],
+                ..TransactionsFilter::default()
+            });
+            assert_eq!(filter.matches(&transaction), matches, "{}", entry_function);
+        }
+
+        for (typ, matches) in [
+            ("0x1::coin::CoinStore", true),
+            ("0x1::coin::CoinStore<0x01::aptos_coin::AptosCoin>", true),
+            ("0x1::coin::CoinStore<0x1::coin::Fake>", false),
+            ("0x1::coin::Coin", false),
+        ] {
+            let filter = new_filter(TransactionsFilter {
+                write_resource_types: vec![typ.to_string()],
+                ..TransactionsFilter::default()
+            });
+            assert_eq!(filter.matches(&transaction), matches, "{}", typ);
+        }
+
+        let filter = new_filter(TransactionsFilter {
+            event_types: vec!["0x1::coin::WithdrawEvent".to_string()],
+            ..TransactionsFilter::default()
+        });
+        assert!(!filter.matches(&transaction));
+    }
+
+    #[test]
+    fn test_all_criteria_must_match() {
+        let filter = new_filter(TransactionsFilter {
+            sender_addresses: vec!["0xa".to_string()],
+            event_types: vec!["0x1::coin::DepositEvent".to_string()],
+            success_only: true,
+            ..TransactionsFilter::default()
+        });
+        assert!(filter.matches(&user_transaction("0xa", "0x1::coin::transfer", true)));
+        assert!(!filter.matches(&user_transaction("0xa", "0x1::coin::transfer", false)));
+        assert!(!filter.matches(&user_transaction("0xb", "0x1::coin::transfer", true)));
+        // Not a user transaction, so it has no sender.
+        assert!(!filter.matches(&Transaction::default()));
+    }
+
+    #[test]
+    fn test_invalid_filter() {
+        for filter in [
+            TransactionsFilter {
+                sender_addresses: vec!["0xg".to_string()],
+                ..TransactionsFilter::default()
+            },
+            TransactionsFilter {
+                entry_functions: vec!["0x1".to_string()],
+                ..TransactionsFilter::default()
+            },
+            TransactionsFilter {
+                event_types: vec!["0x1::coin::Coin<".to_string()],
+                ..TransactionsFilter::default()
+            },
+        ] {
+            assert!(TransactionFilter::new(&filter).is_err());
+        }
+    }
+}
@@ -1,10 +1,13 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::metrics::{
-    CONNECTION_COUNT, ERROR_COUNT, LATEST_PROCESSED_VERSION, PROCESSED_BATCH_SIZE,
-    PROCESSED_LATENCY_IN_SECS, PROCESSED_LATENCY_IN_SECS_ALL, PROCESSED_VERSIONS_COUNT,
-    SHORT_CONNECTION_COUNT,
+use crate::{
+    filter::TransactionFilter,
+    metrics::{
+        CONNECTION_COUNT, ERROR_COUNT, LATEST_PROCESSED_VERSION, PROCESSED_BATCH_SIZE,
+        PROCESSED_LATENCY_IN_SECS, PROCESSED_LATENCY_IN_SECS_ALL, PROCESSED_VERSIONS_COUNT,
+        SHORT_CONNECTION_COUNT,
+    },
 };
 use aptos_indexer_grpc_utils::{
     build_protobuf_encoded_transaction_wrappers,
@@ -96,6 +99,8 @@ impl RawData for RawDataServerWrapper {
     ///    1.4  If error happens, retry after a short sleep.
     /// 2. Push data into channel to stream to the client.
     ///    2.1. If the channel is full, do not fetch and retry after a short sleep.
+    ///    2.2. If the request has a filter, only the matching transactions are pushed, and batches
+    ///         without any are skipped.
     async fn get_transactions(
         &self,
         req: Request<GetTransactionsRequest>,
@@ -109,6 +114,11 @@ impl RawData for RawDataServerWrapper {
         let request = req.into_inner();
 
         let transactions_count = request.transactions_count;
+        let filter = match request.filter.as_ref().map(TransactionFilter::new) {
+            Some(Ok(filter)) => Some(filter),
+            Some(Err(e)) => return Result::Err(Status::invalid_argument(e.to_string())),
+            None => None,
+        };
 
         // Response channel to stream the data to the client.
         let (tx, rx) = channel(MAX_RESPONSE_CHANNEL_SIZE);
@@ -249,17 +259,21 @@ impl RawData for RawDataServerWrapper {
                         }
                     };
                     // 2. Push the data to the response channel, i.e. stream the data to the client.
-                    let resp_item =
-                        get_transactions_response_builder(transaction_data, chain_id as u32);
+                    // A batch without any transaction matching the filter is still sent, so that
+                    // the client can tell how far the stream has progressed.
+                    let end_of_batch_version = transaction_data.last().unwrap().1;
+                    let batch_versions_count = transaction_data.len();
+                    let resp_item = get_transactions_response_builder(
+                        transaction_data,
+                        chain_id as u32,
+                        end_of_batch_version,
+                        filter.as_ref(),
+                    );
                     let current_batch_size = resp_item.transactions.as_slice().len();
-                    let end_of_batch_version =
-                        resp_item.transactions.as_slice().last().unwrap().version;
                     let data_latency_in_secs = resp_item
                         .transactions
                         .first()
-                        .unwrap()
-                        .timestamp
-                        .as_ref()
+                        .and_then(|transaction| transaction.timestamp.as_ref())
                         .map(time_diff_since_pb_timestamp_in_secs);
                     match tx
                         .send_timeout(
@@ -286,11 +300,11 @@ impl RawData for RawDataServerWrapper {
                                     request_metadata.request_token.as_str(),
                                     request_metadata.request_name.as_str(),
                                 ])
-                                .inc_by(current_batch_size as u64);
+                                .inc_by(batch_versions_count as u64);
                             if let Some(data_latency_in_secs) = data_latency_in_secs {
                                 // If it's a partial batch, we record the latency because it usually means
                                 // the data is the latest.
-                                if current_batch_size % BLOB_STORAGE_SIZE != 0 {
+                                if batch_versions_count % BLOB_STORAGE_SIZE != 0 {
                                     PROCESSED_LATENCY_IN_SECS
                                         .with_label_values(&[
                                             request_metadata.request_token.as_str(),
@@ -315,7 +329,7 @@ impl RawData for RawDataServerWrapper {
                         },
                     }
                     // 3. Update the current version and record current tps.
-                    tps_calculator.tick_now(current_batch_size as u64);
+                    tps_calculator.tick_now(batch_versions_count as u64);
                     current_version = end_of_batch_version + 1;
                     info!(
                         current_version = current_version,
@@ -343,12 +357,16 @@ impl RawData for RawDataServerWrapper {
 }
 
 /// Builds the response for the get transactions request. Partial batch is ok, i.e., a batch with transactions < 1000.
+/// Transactions not matching the filter, if any, are left out.
 fn get_transactions_response_builder(
     data: Vec<EncodedTransactionWithVersion>,
     chain_id: u32,
+    end_version: u64,
+    filter: Option<&TransactionFilter>,
 ) -> TransactionsResponse {
     TransactionsResponse {
         chain_id: Some(chain_id as u64),
+        end_version: Some(end_version),
         transactions: data
             .into_iter()
             .map(|(encoded, _)| {
@@ -356,6 +374,7 @@ fn get_transactions_response_builder(
                 let transaction = Transaction::decode(&*decoded_transaction);
                 transaction.unwrap()
             })
+            .filter(|transaction| filter.map_or(true, |filter| filter.matches(transaction)))
             .collect(),
     }
 }
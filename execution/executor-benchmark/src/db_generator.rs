@@ -5,7 +5,7 @@
 use crate::{add_accounts_impl, PipelineConfig};
 use aptos_config::{
     config::{
-        PrunerConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+        ColdStorageConfig, PrunerConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
         DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
     },
     utils::get_genesis_txn,
@@ -88,8 +88,10 @@ fn bootstrap_with_genesis(
             NO_OP_STORAGE_PRUNER_CONFIG,
             rocksdb_configs,
             false, /* indexer */
+            false, /* event type index */
             BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            ColdStorageConfig::default(),
         )
         .expect("DB should open."),
     );
@@ -61,8 +61,10 @@ where
             config.storage.storage_pruner_config,
             config.storage.rocksdb_configs,
             false,
+            false,
             config.storage.buffered_state_target_items,
             config.storage.max_num_nodes_per_lru_cache_shard,
+            config.storage.cold_storage_config.clone(),
         )
         .expect("DB should open."),
     );
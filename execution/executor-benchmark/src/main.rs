@@ -64,6 +64,7 @@ impl PrunerOpt {
                 enable: self.enable_state_pruner,
                 prune_window: self.state_prune_window,
                 batch_size: self.state_pruning_batch_size,
+                retention: None,
             },
             epoch_snapshot_pruner_config: EpochSnapshotPrunerConfig {
                 enable: self.enable_epoch_snapshot_pruner,
@@ -75,6 +76,7 @@ impl PrunerOpt {
                 prune_window: self.ledger_prune_window,
                 batch_size: self.ledger_pruning_batch_size,
                 user_pruning_window_offset: 0,
+                retention: None,
             },
         }
     }
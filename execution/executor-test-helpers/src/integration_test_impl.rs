@@ -31,9 +31,17 @@ use aptos_types::{
 };
 use aptos_vm::AptosVM;
 use rand::SeedableRng;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 pub fn test_execution_with_storage_impl() -> Arc<AptosDB> {
+    let path = aptos_temppath::TempPath::new();
+    path.create_as_dir().unwrap();
+    test_execution_with_storage_impl_inner(path.path())
+}
+
+/// Same as `test_execution_with_storage_impl`, but creates the DB in the existing directory
+/// `db_path`, for the caller to open it again.
+pub fn test_execution_with_storage_impl_inner(db_path: &Path) -> Arc<AptosDB> {
     const B: u64 = 1_000_000_000;
 
     let (genesis, validators) = aptos_vm_genesis::test_genesis_change_set_and_validators(Some(1));
@@ -45,9 +53,7 @@ pub fn test_execution_with_storage_impl() -> Arc<AptosDB> {
         0,
     );
 
-    let path = aptos_temppath::TempPath::new();
-    path.create_as_dir().unwrap();
-    let (aptos_db, db, executor, waypoint) = create_db_and_executor(path.path(), &genesis_txn);
+    let (aptos_db, db, executor, waypoint) = create_db_and_executor(db_path, &genesis_txn);
 
     let parent_block_id = executor.committed_block_id();
     let signer = aptos_types::validator_signer::ValidatorSigner::new(
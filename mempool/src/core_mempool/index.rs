@@ -7,7 +7,7 @@ use crate::core_mempool::transaction::{MempoolTransaction, SequenceInfo, Timelin
 use crate::{
     counters,
     logging::{LogEntry, LogSchema},
-    shared_mempool::types::MultiBucketTimelineIndexIds,
+    shared_mempool::types::{MultiBucketTimelineIndexIds, PendingTransactionsCursor},
 };
 use aptos_consensus_types::common::TransactionSummary;
 use aptos_logger::prelude::*;
@@ -15,7 +15,10 @@ use aptos_types::account_address::AccountAddress;
 use rand::seq::SliceRandom;
 use std::{
     cmp::Ordering,
-    collections::{btree_set::Iter, BTreeMap, BTreeSet, HashMap},
+    collections::{
+        btree_set::{Iter, Range},
+        BTreeMap, BTreeSet, HashMap,
+    },
     iter::Rev,
     ops::Bound,
     time::Duration,
@@ -67,6 +70,30 @@ impl PriorityIndex {
         self.data.iter().rev()
     }
 
+    /// Iterates in priority order from the position of `start`, whether or not the transaction
+    /// it points at is still in the index.
+    pub(crate) fn iter_from(
+        &self,
+        start: Option<&PendingTransactionsCursor>,
+    ) -> Rev<Range<OrderedQueueKey>> {
+        match start {
+            Some(start) => {
+                let key = OrderedQueueKey {
+                    gas_ranking_score: start.ranking_score,
+                    expiration_time: start.expiration_time,
+                    address: start.sender,
+                    // Keys are ordered by the transaction sequence number only
+                    sequence_number: SequenceInfo {
+                        transaction_sequence_number: start.sequence_number,
+                        account_sequence_number: 0,
+                    },
+                };
+                self.data.range(..=key).rev()
+            },
+            None => self.data.range(..).rev(),
+        }
+    }
+
     pub(crate) fn size(&self) -> usize {
         self.data.len()
     }
@@ -493,6 +520,28 @@ impl From<&MempoolTransaction> for TxnPointer {
     }
 }
 
+impl From<&OrderedQueueKey> for PendingTransactionsCursor {
+    fn from(key: &OrderedQueueKey) -> Self {
+        Self {
+            ranking_score: key.gas_ranking_score,
+            expiration_time: key.expiration_time,
+            sender: key.address,
+            sequence_number: key.sequence_number.transaction_sequence_number,
+        }
+    }
+}
+
+impl From<&MempoolTransaction> for PendingTransactionsCursor {
+    fn from(txn: &MempoolTransaction) -> Self {
+        Self {
+            ranking_score: txn.ranking_score,
+            expiration_time: txn.expiration_time,
+            sender: txn.get_sender(),
+            sequence_number: txn.sequence_info.transaction_sequence_number,
+        }
+    }
+}
+
 impl From<&OrderedQueueKey> for TxnPointer {
     fn from(key: &OrderedQueueKey) -> Self {
         Self {
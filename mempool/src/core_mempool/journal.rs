@@ -0,0 +1,116 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! On-disk journal of the transactions held in mempool, so that pending transactions survive a
+//! node restart.
+//!
+//! Serialized transactions identified by committed hash.
+//! ```text
+//! |<-----key----->|<----value---->|
+//! | committed_hash |  transaction  |
+//! ```
+
+use crate::{
+    counters,
+    logging::{LogEntry, LogSchema},
+};
+use anyhow::Result;
+use aptos_crypto::HashValue;
+use aptos_logger::prelude::*;
+use aptos_schemadb::{
+    define_schema,
+    schema::{KeyCodec, ValueCodec},
+    ColumnFamilyName, Options, ReadOptions, SchemaBatch, DB, DEFAULT_COLUMN_FAMILY_NAME,
+};
+use aptos_types::transaction::SignedTransaction;
+use std::{path::Path, time::Instant};
+
+/// The name of the mempool journal db file
+pub const MEMPOOL_JOURNAL_NAME: &str = "mempool_journal";
+
+pub const JOURNAL_TRANSACTION_CF_NAME: ColumnFamilyName = "transaction";
+
+define_schema!(
+    JournalTransactionSchema,
+    HashValue,
+    SignedTransaction,
+    JOURNAL_TRANSACTION_CF_NAME
+);
+
+impl KeyCodec<JournalTransactionSchema> for HashValue {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        Ok(self.to_vec())
+    }
+
+    fn decode_key(data: &[u8]) -> Result<Self> {
+        Ok(HashValue::from_slice(data)?)
+    }
+}
+
+impl ValueCodec<JournalTransactionSchema> for SignedTransaction {
+    fn encode_value(&self) -> Result<Vec<u8>> {
+        Ok(bcs::to_bytes(&self)?)
+    }
+
+    fn decode_value(data: &[u8]) -> Result<Self> {
+        Ok(bcs::from_bytes(data)?)
+    }
+}
+
+pub struct MempoolJournal {
+    db: DB,
+}
+
+impl MempoolJournal {
+    pub fn new<P: AsRef<Path>>(db_root_path: P) -> Self {
+        let column_families = vec![
+            /* UNUSED CF = */ DEFAULT_COLUMN_FAMILY_NAME,
+            JOURNAL_TRANSACTION_CF_NAME,
+        ];
+
+        let path = db_root_path.as_ref().join(MEMPOOL_JOURNAL_NAME);
+        let instant = Instant::now();
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+        let db = DB::open(path.clone(), "mempool_journal", column_families, &opts)
+            .expect("MempoolJournal open failed; unable to continue");
+
+        info!(
+            "Opened MempoolJournal at {:?} in {} ms",
+            path,
+            instant.elapsed().as_millis()
+        );
+
+        Self { db }
+    }
+
+    /// Records a transaction that was accepted into mempool.
+    pub fn insert(&self, hash: &HashValue, txn: &SignedTransaction) {
+        if let Err(e) = self.db.put::<JournalTransactionSchema>(hash, txn) {
+            error!(LogSchema::new(LogEntry::Journal).error(&e));
+            counters::MEMPOOL_JOURNAL_ERROR.inc();
+        }
+    }
+
+    /// Forgets a transaction that left mempool.
+    pub fn remove(&self, hash: &HashValue) {
+        let batch = SchemaBatch::new();
+        let result = batch
+            .delete::<JournalTransactionSchema>(hash)
+            .and_then(|_| self.db.write_schemas(batch));
+        if let Err(e) = result {
+            error!(LogSchema::new(LogEntry::Journal).error(&e));
+            counters::MEMPOOL_JOURNAL_ERROR.inc();
+        }
+    }
+
+    /// Returns all the journaled transactions, to be replayed into mempool.
+    pub fn get_all(&self) -> Result<Vec<SignedTransaction>> {
+        let mut iter = self
+            .db
+            .iter::<JournalTransactionSchema>(ReadOptions::default())?;
+        iter.seek_to_first();
+        iter.map(|entry| entry.map(|(_, txn)| txn)).collect()
+    }
+}
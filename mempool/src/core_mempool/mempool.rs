@@ -7,12 +7,16 @@
 use crate::{
     core_mempool::{
         index::TxnPointer,
+        journal::MempoolJournal,
         transaction::{InsertionInfo, MempoolTransaction, TimelineState},
         transaction_store::TransactionStore,
     },
     counters,
     logging::{LogEntry, LogSchema, TxnsLog},
-    shared_mempool::types::MultiBucketTimelineIndexIds,
+    shared_mempool::types::{
+        MultiBucketTimelineIndexIds, PendingTransactionInfo, PendingTransactionsCursor,
+        PendingTransactionsQuery,
+    },
 };
 use aptos_config::config::NodeConfig;
 use aptos_consensus_types::common::TransactionInProgress;
@@ -39,7 +43,13 @@ pub struct Mempool {
 impl Mempool {
     pub fn new(config: &NodeConfig) -> Self {
         Mempool {
-            transactions: TransactionStore::new(&config.mempool),
+            transactions: TransactionStore::new(
+                &config.mempool,
+                config
+                    .mempool
+                    .journal_enabled
+                    .then(|| MempoolJournal::new(config.storage.dir())),
+            ),
             system_transaction_timeout: Duration::from_secs(
                 config.mempool.system_transaction_timeout_secs,
             ),
@@ -169,6 +179,17 @@ impl Mempool {
         self.transactions.get_by_hash(hash)
     }
 
+    pub(crate) fn get_pending_transactions(
+        &self,
+        query: &PendingTransactionsQuery,
+        limit: usize,
+    ) -> (
+        Vec<PendingTransactionInfo>,
+        Option<PendingTransactionsCursor>,
+    ) {
+        self.transactions.get_pending_transactions(query, limit)
+    }
+
     /// Used to add a transaction to the Mempool.
     /// Performs basic validation: checks account's sequence number.
     pub(crate) fn add_txn(
@@ -405,6 +426,17 @@ impl Mempool {
         self.transactions.gen_snapshot()
     }
 
+    /// Returns the transactions journaled before the last shutdown. They must go through
+    /// validation again to re-enter mempool, and stay journaled until then.
+    pub(crate) fn get_journaled_transactions(&self) -> Vec<SignedTransaction> {
+        self.transactions.get_journaled_transactions()
+    }
+
+    /// Forgets the replayed transactions that were not accepted back into mempool.
+    pub(crate) fn prune_journal(&self, replayed: &[HashValue]) {
+        self.transactions.prune_journal(replayed)
+    }
+
     #[cfg(test)]
     pub fn get_parking_lot_size(&self) -> usize {
         self.transactions.get_parking_lot_size()
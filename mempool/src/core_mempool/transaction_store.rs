@@ -8,6 +8,7 @@ use crate::{
             AccountTransactions, MultiBucketTimelineIndex, ParkingLotIndex, PriorityIndex,
             PriorityQueueIter, TTLIndex,
         },
+        journal::MempoolJournal,
         mempool::Mempool,
         transaction::{InsertionInfo, MempoolTransaction, TimelineState},
         TxnPointer,
@@ -15,7 +16,10 @@ use crate::{
     counters,
     counters::{BROADCAST_BATCHED_LABEL, BROADCAST_READY_LABEL, CONSENSUS_READY_LABEL},
     logging::{LogEntry, LogEvent, LogSchema, TxnsLog},
-    shared_mempool::types::MultiBucketTimelineIndexIds,
+    shared_mempool::types::{
+        MultiBucketTimelineIndexIds, PendingTransactionInfo, PendingTransactionsCursor,
+        PendingTransactionsQuery,
+    },
 };
 use aptos_config::config::MempoolConfig;
 use aptos_crypto::HashValue;
@@ -39,6 +43,10 @@ pub const TXN_INDEX_ESTIMATED_BYTES: usize = size_of::<crate::core_mempool::inde
     + (size_of::<u64>() * 3 + size_of::<AccountAddress>()) // timeline_index
     + (size_of::<HashValue>() + size_of::<u64>() + size_of::<AccountAddress>()); // hash_index
 
+/// Maximum number of transactions returned by a single pending transactions listing, so a
+/// listing never holds the mempool lock for long.
+pub const MAX_PENDING_TRANSACTIONS_PAGE_SIZE: usize = 1000;
+
 /// TransactionStore is in-memory storage for all transactions in mempool.
 pub struct TransactionStore {
     // main DS
@@ -79,10 +87,13 @@ pub struct TransactionStore {
     // eager expiration
     eager_expire_threshold: Option<Duration>,
     eager_expire_time: Duration,
+
+    // on-disk copy of the transactions, replayed on restart
+    journal: Option<MempoolJournal>,
 }
 
 impl TransactionStore {
-    pub(crate) fn new(config: &MempoolConfig) -> Self {
+    pub(crate) fn new(config: &MempoolConfig, journal: Option<MempoolJournal>) -> Self {
         Self {
             // main DS
             transactions: HashMap::new(),
@@ -111,6 +122,8 @@ impl TransactionStore {
             // eager expiration
             eager_expire_threshold: config.eager_expire_threshold_ms.map(Duration::from_millis),
             eager_expire_time: Duration::from_millis(config.eager_expire_time_ms),
+
+            journal,
         }
     }
 
@@ -156,6 +169,68 @@ impl TransactionStore {
         }
     }
 
+    /// Lists at most `limit` transactions matching the query, capped at
+    /// `MAX_PENDING_TRANSACTIONS_PAGE_SIZE`, along with the cursor of the next matching
+    /// transaction, if there is one.
+    pub(crate) fn get_pending_transactions(
+        &self,
+        query: &PendingTransactionsQuery,
+        limit: usize,
+    ) -> (
+        Vec<PendingTransactionInfo>,
+        Option<PendingTransactionsCursor>,
+    ) {
+        let limit = limit.min(MAX_PENDING_TRANSACTIONS_PAGE_SIZE);
+        match query {
+            PendingTransactionsQuery::Ready(start) => {
+                let mut keys = self.priority_index.iter_from(start.as_ref());
+                let txns = keys
+                    .by_ref()
+                    .take(limit)
+                    .filter_map(|key| {
+                        self.get_mempool_txn(
+                            &key.address,
+                            key.sequence_number.transaction_sequence_number,
+                        )
+                    })
+                    .map(|txn| self.pending_transaction_info(txn))
+                    .collect();
+                (txns, keys.next().map(PendingTransactionsCursor::from))
+            },
+            PendingTransactionsQuery::Account(address, start) => {
+                let Some(account_txns) = self.transactions.get(address) else {
+                    return (vec![], None);
+                };
+                let start_sequence_number = start.as_ref().map_or(0, |start| start.sequence_number);
+                let mut account_txns = account_txns
+                    .range(start_sequence_number..)
+                    .map(|(_, txn)| txn);
+                let txns = account_txns
+                    .by_ref()
+                    .take(limit)
+                    .map(|txn| self.pending_transaction_info(txn))
+                    .collect();
+                (
+                    txns,
+                    account_txns.next().map(PendingTransactionsCursor::from),
+                )
+            },
+        }
+    }
+
+    fn pending_transaction_info(&self, txn: &MempoolTransaction) -> PendingTransactionInfo {
+        PendingTransactionInfo {
+            transaction: txn.txn.clone(),
+            ranking_score: txn.ranking_score,
+            insertion_time: txn.insertion_info.insertion_time,
+            bucket: self.get_bucket(txn.ranking_score).to_string(),
+            parked: self.parking_lot_index.contains(
+                &txn.get_sender(),
+                &txn.sequence_info.transaction_sequence_number,
+            ),
+        }
+    }
+
     pub(crate) fn get_insertion_info_and_bucket(
         &self,
         address: &AccountAddress,
@@ -277,6 +352,9 @@ impl TransactionStore {
                 self.gas_upgraded_index
                     .insert(TxnPointer::from(&txn), txn.get_gas_price());
             }
+            if let Some(journal) = &self.journal {
+                journal.insert(&txn.get_committed_hash(), &txn.txn);
+            }
             txns.insert(txn_seq_num, txn);
             self.track_indices();
         }
@@ -556,6 +634,9 @@ impl TransactionStore {
         self.hash_index.remove(&txn.get_committed_hash());
         self.size_bytes -= txn.get_estimated_bytes();
         self.gas_upgraded_index.remove(&TxnPointer::from(txn));
+        if let Some(journal) = &self.journal {
+            journal.remove(&txn.get_committed_hash());
+        }
 
         // Remove account datastructures if there are no more transactions for the account.
         let address = &txn.get_sender();
@@ -779,6 +860,32 @@ impl TransactionStore {
         txns_log
     }
 
+    /// Returns the transactions left in the journal by a previous run, so they can be
+    /// re-validated and inserted again. They stay journaled until `prune_journal` is called.
+    pub(crate) fn get_journaled_transactions(&self) -> Vec<SignedTransaction> {
+        match self.journal.as_ref().map(|journal| journal.get_all()) {
+            Some(Ok(txns)) => txns,
+            Some(Err(e)) => {
+                error!(LogSchema::new(LogEntry::Journal).error(&e));
+                counters::MEMPOOL_JOURNAL_ERROR.inc();
+                vec![]
+            },
+            None => vec![],
+        }
+    }
+
+    /// Removes the journal entries of replayed transactions that didn't make it back into
+    /// mempool. The ones that did were journaled again when inserted.
+    pub(crate) fn prune_journal(&self, replayed: &[HashValue]) {
+        if let Some(journal) = &self.journal {
+            for hash in replayed {
+                if !self.hash_index.contains_key(hash) {
+                    journal.remove(hash);
+                }
+            }
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn get_parking_lot_size(&self) -> usize {
         self.parking_lot_index.size()
@@ -63,6 +63,7 @@ pub const SUCCESS_LABEL: &str = "success";
 // Bounded executor task labels
 pub const CLIENT_EVENT_LABEL: &str = "client_event";
 pub const CLIENT_EVENT_GET_TXN_LABEL: &str = "client_event_get_txn";
+pub const CLIENT_EVENT_GET_PENDING_TXNS_LABEL: &str = "client_event_get_pending_txns";
 pub const RECONFIG_EVENT_LABEL: &str = "reconfig";
 pub const PEER_BROADCAST_EVENT_LABEL: &str = "peer_broadcast";
 
@@ -589,6 +590,14 @@ pub static DB_ERROR: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+pub static MEMPOOL_JOURNAL_ERROR: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_mempool_journal_error_count",
+        "Number of times writing to the on-disk mempool journal failed"
+    )
+    .unwrap()
+});
+
 /// Counter for the current number of active upstream peers mempool can
 /// broadcast to, summed across each of its networks
 static ACTIVE_UPSTREAM_PEERS_COUNT: Lazy<IntGaugeVec> = Lazy::new(|| {
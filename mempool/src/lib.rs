@@ -62,7 +62,8 @@ pub use shared_mempool::{
     bootstrap, network,
     network::MempoolSyncMsg,
     types::{
-        MempoolClientRequest, MempoolClientSender, MempoolEventsReceiver, QuorumStoreRequest,
+        MempoolClientRequest, MempoolClientSender, MempoolEventsReceiver, PendingTransactionInfo,
+        PendingTransactionsCursor, PendingTransactionsQuery, QuorumStoreRequest,
         QuorumStoreResponse, SubmissionStatus,
     },
 };
@@ -157,6 +157,7 @@ pub enum LogEntry {
     ReconfigUpdate,
     JsonRpc,
     GetTransaction,
+    GetPendingTransactions,
     GetBlock,
     QuorumStore,
     StateSyncCommit,
@@ -172,6 +173,7 @@ pub enum LogEntry {
     CleanRejectedTxn,
     ProcessReadyTxns,
     DBError,
+    Journal,
     UnexpectedNetworkMsg,
     MempoolSnapshot,
 }
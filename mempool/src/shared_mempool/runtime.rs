@@ -7,6 +7,7 @@ use crate::{
     network::MempoolSyncMsg,
     shared_mempool::{
         coordinator::{coordinator, gc_coordinator, snapshot_job},
+        tasks::process_journaled_transactions,
         types::{MempoolEventsReceiver, SharedMempool, SharedMempoolNotification},
     },
     QuorumStoreRequest,
@@ -58,6 +59,7 @@ pub(crate) fn start_shared_mempool<TransactionValidator>(
             subscribers,
             config.base.role,
         );
+    process_journaled_transactions(&smp);
 
     executor.spawn(coordinator(
         smp,
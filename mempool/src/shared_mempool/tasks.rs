@@ -9,8 +9,9 @@ use crate::{
     logging::{LogEntry, LogEvent, LogSchema},
     network::{BroadcastError, MempoolSyncMsg},
     shared_mempool::types::{
-        notify_subscribers, MultiBatchId, ScheduledBroadcast, SharedMempool,
-        SharedMempoolNotification, SubmissionStatusBundle,
+        notify_subscribers, MultiBatchId, PendingTransactionInfo, PendingTransactionsCursor,
+        PendingTransactionsQuery, ScheduledBroadcast, SharedMempool, SharedMempoolNotification,
+        SubmissionStatusBundle,
     },
     thread_pool::IO_POOL,
     QuorumStoreRequest, QuorumStoreResponse, SubmissionStatus,
@@ -154,6 +155,86 @@ pub(crate) async fn process_client_get_transaction<NetworkClient, TransactionVal
     }
 }
 
+/// Processes pending transactions request by client.
+pub(crate) async fn process_client_get_pending_transactions<NetworkClient, TransactionValidator>(
+    smp: SharedMempool<NetworkClient, TransactionValidator>,
+    query: PendingTransactionsQuery,
+    limit: usize,
+    callback: oneshot::Sender<(
+        Vec<PendingTransactionInfo>,
+        Option<PendingTransactionsCursor>,
+    )>,
+    timer: HistogramTimer,
+) where
+    NetworkClient: NetworkClientInterface<MempoolSyncMsg>,
+    TransactionValidator: TransactionValidation,
+{
+    timer.stop_and_record();
+    let page = smp.mempool.lock().get_pending_transactions(&query, limit);
+
+    if callback.send(page).is_err() {
+        warn!(LogSchema::event_log(
+            LogEntry::GetPendingTransactions,
+            LogEvent::CallbackFail
+        ));
+        counters::CLIENT_CALLBACK_FAIL.inc();
+    }
+}
+
+/// Replays the transactions journaled before a restart. They go through the same validation as
+/// client submissions, so already committed transactions are dropped. They are only removed from
+/// the journal once the accepted ones are back in mempool, so a crash during the replay loses
+/// none of them.
+pub(crate) fn process_journaled_transactions<NetworkClient, TransactionValidator>(
+    smp: &SharedMempool<NetworkClient, TransactionValidator>,
+) where
+    NetworkClient: NetworkClientInterface<MempoolSyncMsg>,
+    TransactionValidator: TransactionValidation,
+{
+    let transactions = smp.mempool.lock().get_journaled_transactions();
+    if transactions.is_empty() {
+        return;
+    }
+    let num_journaled = transactions.len();
+    let replayed: Vec<_> = transactions
+        .iter()
+        .map(|txn| txn.clone().committed_hash())
+        .collect();
+
+    // Expired transactions would be rejected by the VM anyways
+    let now = aptos_infallible::duration_since_epoch();
+    let transactions: Vec<_> = transactions
+        .into_iter()
+        .filter(|txn| Duration::from_secs(txn.expiration_timestamp_secs()) > now)
+        .collect();
+    let num_expired = num_journaled - transactions.len();
+
+    let ineligible_for_broadcast =
+        smp.network_interface.is_validator() && !smp.broadcast_within_validator_network();
+    let timeline_state = if ineligible_for_broadcast {
+        TimelineState::NonQualified
+    } else {
+        TimelineState::NotReady
+    };
+    let statuses = if transactions.is_empty() {
+        vec![]
+    } else {
+        process_incoming_transactions(smp, transactions, timeline_state, true)
+    };
+    let num_accepted = statuses
+        .iter()
+        .filter(|(_, (status, _))| status.code == MempoolStatusCode::Accepted)
+        .count();
+    smp.mempool.lock().prune_journal(&replayed);
+    info!(
+        LogSchema::new(LogEntry::Journal),
+        num_journaled = num_journaled,
+        num_expired = num_expired,
+        num_accepted = num_accepted,
+        "Replayed mempool journal"
+    );
+}
+
 /// Processes transactions from other nodes.
 pub(crate) async fn process_transaction_broadcast<NetworkClient, TransactionValidator>(
     smp: SharedMempool<NetworkClient, TransactionValidator>,
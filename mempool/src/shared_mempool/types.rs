@@ -20,7 +20,8 @@ use aptos_network::{
 };
 use aptos_storage_interface::DbReader;
 use aptos_types::{
-    mempool_status::MempoolStatus, transaction::SignedTransaction, vm_status::DiscardedVMStatus,
+    account_address::AccountAddress, mempool_status::MempoolStatus, transaction::SignedTransaction,
+    vm_status::DiscardedVMStatus,
 };
 use aptos_vm_validator::vm_validator::TransactionValidation;
 use futures::{
@@ -36,7 +37,7 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::Waker,
-    time::{Instant, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 use tokio::runtime::Handle;
 
@@ -227,9 +228,50 @@ pub type SubmissionStatus = (MempoolStatus, Option<DiscardedVMStatus>);
 
 pub type SubmissionStatusBundle = (SignedTransaction, SubmissionStatus);
 
+/// A transaction waiting in mempool along with how mempool is tracking it
+#[derive(Clone, Debug)]
+pub struct PendingTransactionInfo {
+    pub transaction: SignedTransaction,
+    pub ranking_score: u64,
+    pub insertion_time: SystemTime,
+    /// Broadcast bucket the transaction falls in, based on its ranking score
+    pub bucket: String,
+    /// Whether the transaction is parked, i.e. waiting on a lower sequence number of its account
+    pub parked: bool,
+}
+
+/// Position of a pending transaction, used to resume listing the priority queue or an account
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PendingTransactionsCursor {
+    pub ranking_score: u64,
+    pub expiration_time: Duration,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+}
+
+/// Which pending transactions to list
+#[derive(Clone, Debug)]
+pub enum PendingTransactionsQuery {
+    /// Ready transactions in priority order, starting at the cursor if one is given
+    Ready(Option<PendingTransactionsCursor>),
+    /// Transactions sent by the account, parked ones included, in sequence number order, starting
+    /// at the sequence number of the cursor if one is given
+    Account(AccountAddress, Option<PendingTransactionsCursor>),
+}
+
 pub enum MempoolClientRequest {
     SubmitTransaction(SignedTransaction, oneshot::Sender<Result<SubmissionStatus>>),
     GetTransactionByHash(HashValue, oneshot::Sender<Option<SignedTransaction>>),
+    /// Lists at most `limit` pending transactions, along with the cursor of the next matching
+    /// transaction if more are left
+    GetPendingTransactions(
+        PendingTransactionsQuery,
+        usize,
+        oneshot::Sender<(
+            Vec<PendingTransactionInfo>,
+            Option<PendingTransactionsCursor>,
+        )>,
+    ),
 }
 
 pub type MempoolClientSender = mpsc::Sender<MempoolClientRequest>;
@@ -4,6 +4,7 @@
 
 use crate::{
     core_mempool::{CoreMempool, MempoolTransaction, SubmittedBy, TimelineState},
+    shared_mempool::types::{PendingTransactionInfo, PendingTransactionsQuery},
     tests::common::{
         add_signed_txn, add_txn, add_txns_to_mempool, setup_mempool,
         setup_mempool_with_broadcast_buckets, TestTransaction,
@@ -16,7 +17,10 @@ use aptos_types::{
     mempool_status::MempoolStatusCode, transaction::SignedTransaction, vm_status::DiscardedVMStatus,
 };
 use itertools::Itertools;
-use std::time::{Duration, SystemTime};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
 
 #[test]
 fn test_transaction_ordering_only_seqnos() {
@@ -917,3 +921,119 @@ fn test_include_gas_upgraded() {
     let batch = pool.get_batch(10, 10240, true, true, vec![high_gas_txn, low_gas_txn]);
     assert_eq!(batch.len(), 0);
 }
+
+#[test]
+fn test_journal_replay_after_restart() {
+    let temp_path = aptos_temppath::TempPath::new();
+    temp_path.create_as_dir().unwrap();
+    let mut config = NodeConfig::generate_random_config();
+    config.mempool.journal_enabled = true;
+    config.storage.dir = temp_path.path().to_path_buf();
+
+    let mut pool = CoreMempool::new(&config);
+    let txns = add_txns_to_mempool(&mut pool, vec![
+        TestTransaction::new(0, 0, 1),
+        TestTransaction::new(0, 1, 1),
+        TestTransaction::new(1, 0, 1),
+    ]);
+    // committed transactions are dropped from the journal
+    pool.commit_transaction(&TestTransaction::get_address(0), 0);
+    drop(pool);
+
+    let journaled_hashes = |pool: &CoreMempool| -> HashSet<_> {
+        pool.get_journaled_transactions()
+            .into_iter()
+            .map(|txn| txn.committed_hash())
+            .collect()
+    };
+    let expected: HashSet<_> = txns[1..]
+        .iter()
+        .map(|txn| txn.clone().committed_hash())
+        .collect();
+
+    // a crash before the replay is over leaves the journal as it was
+    let pool = CoreMempool::new(&config);
+    assert_eq!(journaled_hashes(&pool), expected);
+    drop(pool);
+
+    // only the transactions accepted back into mempool stay journaled after the replay
+    let mut pool = CoreMempool::new(&config);
+    let journaled = pool.get_journaled_transactions();
+    assert_eq!(journaled.len(), 2);
+    let accepted = journaled
+        .iter()
+        .find(|txn| txn.sender() == TestTransaction::get_address(1))
+        .unwrap()
+        .clone();
+    add_signed_txn(&mut pool, accepted.clone()).unwrap();
+    let replayed: Vec<_> = journaled
+        .into_iter()
+        .map(|txn| txn.committed_hash())
+        .collect();
+    pool.prune_journal(&replayed);
+    drop(pool);
+
+    let pool = CoreMempool::new(&config);
+    assert_eq!(
+        journaled_hashes(&pool),
+        HashSet::from([accepted.committed_hash()])
+    );
+}
+
+#[test]
+fn test_get_pending_transactions() {
+    let (mut pool, _) = setup_mempool();
+    let txns = add_txns_to_mempool(&mut pool, vec![
+        TestTransaction::new(0, 0, 3),
+        TestTransaction::new(1, 0, 5),
+        TestTransaction::new(2, 0, 1),
+        // parked, as sequence number 1 is missing
+        TestTransaction::new(1, 2, 4),
+    ]);
+    let hashes = |page: &[PendingTransactionInfo]| -> Vec<HashValue> {
+        page.iter()
+            .map(|info| info.transaction.clone().committed_hash())
+            .collect()
+    };
+    let hash = |index: usize| txns[index].clone().committed_hash();
+
+    // ready transactions are listed in priority order, one page at a time
+    let (page, cursor) = pool.get_pending_transactions(&PendingTransactionsQuery::Ready(None), 2);
+    assert_eq!(hashes(&page), vec![hash(1), hash(0)]);
+    let cursor = cursor.unwrap();
+    assert_eq!(cursor.sender, TestTransaction::get_address(2));
+
+    // the cursor still points at the right position once transactions before it are gone
+    pool.commit_transaction(&TestTransaction::get_address(0), 0);
+    let (page, cursor) =
+        pool.get_pending_transactions(&PendingTransactionsQuery::Ready(Some(cursor)), 2);
+    assert_eq!(hashes(&page), vec![hash(2)]);
+    assert!(cursor.is_none());
+
+    // parked transactions only show up in the listing of their account
+    let (page, cursor) = pool.get_pending_transactions(
+        &PendingTransactionsQuery::Account(TestTransaction::get_address(1), None),
+        10,
+    );
+    assert_eq!(hashes(&page), vec![hash(1), hash(3)]);
+    assert_eq!(
+        page.iter().map(|info| info.parked).collect::<Vec<_>>(),
+        vec![false, true]
+    );
+    assert!(cursor.is_none());
+
+    // transactions of an account are listed one page at a time as well
+    let (page, cursor) = pool.get_pending_transactions(
+        &PendingTransactionsQuery::Account(TestTransaction::get_address(1), None),
+        1,
+    );
+    assert_eq!(hashes(&page), vec![hash(1)]);
+    let cursor = cursor.unwrap();
+    assert_eq!(cursor.sequence_number, 2);
+    let (page, cursor) = pool.get_pending_transactions(
+        &PendingTransactionsQuery::Account(TestTransaction::get_address(1), Some(cursor)),
+        1,
+    );
+    assert_eq!(hashes(&page), vec![hash(3)]);
+    assert!(cursor.is_none());
+}
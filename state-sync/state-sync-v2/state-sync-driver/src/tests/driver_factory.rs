@@ -4,8 +4,8 @@
 use crate::{driver_factory::DriverFactory, metadata_storage::PersistentMetadataStorage};
 use aptos_config::{
     config::{
-        RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
-        NO_OP_STORAGE_PRUNER_CONFIG,
+        ColdStorageConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
     },
     utils::get_genesis_txn,
 };
@@ -39,8 +39,10 @@ fn test_new_initialized_configs() {
         NO_OP_STORAGE_PRUNER_CONFIG,
         RocksdbConfigs::default(),
         false,
+        false,
         BUFFERED_STATE_TARGET_ITEMS,
         DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        ColdStorageConfig::default(),
     )
     .unwrap();
     let (_, db_rw) = DbReaderWriter::wrap(db);
@@ -5,15 +5,19 @@ use crate::{
     get_first_seq_num_and_limit,
     pruner::{LedgerPrunerManager, StateMerklePrunerManager},
     test_helper,
-    test_helper::{arb_blocks_to_commit, put_as_state_root, put_transaction_info},
-    AptosDB, PrunerManager, StaleNodeIndexSchema,
+    test_helper::{
+        arb_blocks_to_commit, arb_blocks_to_commit_with_block_nums, put_as_state_root,
+        put_transaction_info,
+    },
+    AptosDB, PrunerManager, StaleNodeIndexSchema, TransactionStore,
 };
 use aptos_config::config::{
-    EpochSnapshotPrunerConfig, LedgerPrunerConfig, PrunerConfig, RocksdbConfigs,
-    StateMerklePrunerConfig, BUFFERED_STATE_TARGET_ITEMS,
-    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    ColdStorageConfig, EpochSnapshotPrunerConfig, LedgerPrunerConfig, PrunerConfig, RocksdbConfig,
+    RocksdbConfigs, StateMerklePrunerConfig, BUFFERED_STATE_TARGET_ITEMS,
+    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
 };
 use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_db_indexer::Indexer;
 use aptos_storage_interface::{DbReader, DbWriter, ExecutedTrees, Order};
 use aptos_temppath::TempPath;
 use aptos_types::{
@@ -25,7 +29,11 @@ use aptos_types::{
     transaction::{ExecutionStatus, TransactionInfo, TransactionToCommit, Version},
 };
 use proptest::prelude::*;
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use test_helper::{test_save_blocks_impl, test_sync_transactions_impl};
 
 proptest! {
@@ -91,22 +99,28 @@ fn test_pruner_config() {
     for enable in [false, true] {
         let state_merkle_pruner = StateMerklePrunerManager::<StaleNodeIndexSchema>::new(
             Arc::clone(&aptos_db.state_merkle_db),
+            Arc::clone(&aptos_db.ledger_db),
             StateMerklePrunerConfig {
                 enable,
                 prune_window: 20,
                 batch_size: 1,
+                retention: None,
             },
         );
         assert_eq!(state_merkle_pruner.is_pruner_enabled(), enable);
         assert_eq!(state_merkle_pruner.get_prune_window(), 20);
 
-        let ledger_pruner =
-            LedgerPrunerManager::new(Arc::clone(&aptos_db.ledger_db), LedgerPrunerConfig {
+        let ledger_pruner = LedgerPrunerManager::new(
+            Arc::clone(&aptos_db.ledger_db),
+            LedgerPrunerConfig {
                 enable,
                 prune_window: 100,
                 batch_size: 1,
                 user_pruning_window_offset: 0,
-            });
+                retention: None,
+            },
+            None,
+        );
         assert_eq!(ledger_pruner.is_pruner_enabled(), enable);
         assert_eq!(ledger_pruner.get_prune_window(), 100);
     }
@@ -138,6 +152,33 @@ fn test_error_if_version_pruned() {
     assert!(db.error_if_ledger_pruned("Transaction", 10).is_ok());
 }
 
+#[test]
+fn test_open_pruned_db_with_event_type_index() {
+    let tmp_dir = TempPath::new();
+    {
+        let db = AptosDB::new_for_test(&tmp_dir);
+        db.ledger_pruner.save_min_readable_version(10).unwrap();
+    }
+
+    // The events before the min readable version are gone, so the event type index starts there.
+    let db = AptosDB::new_for_test_with_indexer(&tmp_dir);
+    assert!(db.event_type_index_enabled());
+    assert_eq!(
+        db.indexer.as_ref().unwrap().event_type_index_next_version(),
+        Some(10)
+    );
+    drop(db);
+
+    // And it doesn't go back to 0 after a restart.
+    let indexer = Indexer::open(
+        &tmp_dir,
+        RocksdbConfig::default(),
+        true, /* enable_event_type_index */
+    )
+    .unwrap();
+    assert_eq!(indexer.event_type_index_next_version(), Some(10));
+}
+
 #[test]
 fn test_get_latest_executed_trees() {
     let tmp_dir = TempPath::new();
@@ -187,11 +228,13 @@ pub fn test_state_merkle_pruning_impl(
                 prune_window: 10,
                 batch_size: 1,
                 user_pruning_window_offset: 0,
+                retention: None,
             },
             state_merkle_pruner_config: StateMerklePrunerConfig {
                 enable: true,
                 prune_window: 5,
                 batch_size: 1,
+                retention: None,
             },
             epoch_snapshot_pruner_config: EpochSnapshotPrunerConfig {
                 enable: true,
@@ -201,8 +244,10 @@ pub fn test_state_merkle_pruning_impl(
         },
         RocksdbConfigs::default(),
         false, /* enable_indexer */
+        false, /* enable_event_type_index */
         BUFFERED_STATE_TARGET_ITEMS,
         DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        ColdStorageConfig::default(),
     )
     .unwrap();
 
@@ -289,3 +334,112 @@ proptest! {
         test_state_merkle_pruning_impl(input);
     }
 }
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_read_migrated_ledger_data(input in arb_blocks_to_commit_with_block_nums(10, 20)) {
+        test_read_migrated_ledger_data_impl(input);
+    }
+}
+
+fn test_read_migrated_ledger_data_impl(
+    input: Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
+) {
+    const HOT_WINDOW: u64 = 5;
+    const SEGMENT_SIZE: u64 = 4;
+
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::open(
+        &tmp_dir,
+        false, /* is_read_only */
+        NO_OP_STORAGE_PRUNER_CONFIG,
+        RocksdbConfigs::default(),
+        false, /* enable_indexer */
+        false, /* enable_event_type_index */
+        BUFFERED_STATE_TARGET_ITEMS,
+        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        ColdStorageConfig {
+            enable: true,
+            hot_window: HOT_WINDOW,
+            segment_size: SEGMENT_SIZE,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let mut in_memory_state = db
+        .state_store
+        .buffered_state()
+        .lock()
+        .current_state()
+        .clone();
+    let _ancester = in_memory_state.current.clone();
+    let mut next_ver: Version = 0;
+    for (txns_to_commit, ledger_info_with_sigs) in input.iter() {
+        test_helper::update_in_memory_state(&mut in_memory_state, txns_to_commit.as_slice());
+        db.save_transactions(
+            txns_to_commit,
+            next_ver,                /* first_version */
+            next_ver.checked_sub(1), /* base_state_version */
+            Some(ledger_info_with_sigs),
+            true, /* sync_commit */
+            in_memory_state.clone(),
+        )
+        .unwrap();
+        next_ver += txns_to_commit.len() as u64;
+    }
+
+    // The whole segments out of the hot window end up deleted from RocksDB.
+    let num_migrated = next_ver.saturating_sub(HOT_WINDOW) / SEGMENT_SIZE * SEGMENT_SIZE;
+    assert!(num_migrated > 0);
+    let hot_transaction_store = TransactionStore::new(Arc::clone(&db.ledger_db));
+    let start = Instant::now();
+    while hot_transaction_store
+        .get_transaction(num_migrated - 1)
+        .is_ok()
+    {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Timed out waiting for the migration."
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    let all_txns_to_commit: Vec<_> = input
+        .iter()
+        .flat_map(|(txns_to_commit, _)| txns_to_commit.iter().cloned())
+        .collect();
+    let latest_ledger_info = &input.last().unwrap().1;
+    test_helper::verify_committed_transactions(
+        &db,
+        &all_txns_to_commit,
+        0, /* first_version */
+        latest_ledger_info,
+        true, /* is_latest */
+    );
+
+    let txn_list_with_proof = db
+        .get_transactions(0, next_ver, next_ver - 1, true /* fetch_events */)
+        .unwrap();
+    txn_list_with_proof
+        .verify(latest_ledger_info.ledger_info(), Some(0))
+        .unwrap();
+
+    let backup_txns = db
+        .get_backup_handler()
+        .get_transaction_iter(0, next_ver as usize)
+        .unwrap()
+        .collect::<anyhow::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(backup_txns.len(), all_txns_to_commit.len());
+    for ((txn, txn_info, events, write_set), txn_to_commit) in
+        backup_txns.into_iter().zip(&all_txns_to_commit)
+    {
+        assert_eq!(&txn, txn_to_commit.transaction());
+        assert_eq!(&txn_info, txn_to_commit.transaction_info());
+        assert_eq!(events, txn_to_commit.events());
+        assert_eq!(&write_set, txn_to_commit.write_set());
+    }
+}
@@ -5,6 +5,7 @@
 pub mod backup_handler;
 pub mod restore_handler;
 pub mod restore_utils;
+pub mod state_snapshot_increment_restore;
 
 #[cfg(test)]
 mod test;
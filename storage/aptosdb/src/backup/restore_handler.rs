@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    backup::restore_utils,
+    backup::{restore_utils, state_snapshot_increment_restore::StateSnapshotIncrementRestore},
     db_metadata::{DbMetadataKey, DbMetadataSchema},
     event_store::EventStore,
     ledger_store::LedgerStore,
@@ -68,6 +68,22 @@ impl RestoreHandler {
         )
     }
 
+    /// Returns the receiver of the changes of a state snapshot increment taken over the snapshot
+    /// at `base_version`. Nothing is written unless `commit` is set.
+    pub fn get_state_snapshot_increment_receiver(
+        &self,
+        base_version: Version,
+        version: Version,
+        commit: bool,
+    ) -> Result<StateSnapshotIncrementRestore> {
+        StateSnapshotIncrementRestore::new(
+            Arc::clone(&self.state_store),
+            base_version,
+            version,
+            commit,
+        )
+    }
+
     pub fn reset_state_store(&self) {
         self.state_store.reset();
     }
@@ -0,0 +1,246 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    new_sharded_kv_schema_batch,
+    schema::{
+        stale_state_value_index::StaleStateValueIndexSchema, version_data::VersionDataSchema,
+    },
+    state_merkle_db::Node,
+    state_store::StateStore,
+    NUM_STATE_SHARDS,
+};
+use anyhow::{ensure, Result};
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_jellyfish_merkle::JellyfishMerkleTree;
+use aptos_schemadb::SchemaBatch;
+use aptos_storage_interface::DbReader;
+use aptos_types::{
+    state_store::{
+        create_empty_sharded_state_updates,
+        state_key::StateKey,
+        state_storage_usage::StateStorageUsage,
+        state_value::{StaleStateValueIndex, StateValue},
+    },
+    transaction::Version,
+};
+use std::sync::Arc;
+
+/// Applies the changes of a state snapshot increment, i.e. the state keys changed between the
+/// snapshots at `base_version` and `version`, fed in chunks in the order of the key hashes.
+///
+/// The state values are written a chunk at a time, and the tree a shard at a time, holding only
+/// the key and value hashes of the shard. Nothing is written unless `commit` is set, so that the
+/// resulting root hash can be checked before going through the changes again to write them.
+pub struct StateSnapshotIncrementRestore {
+    state_store: Arc<StateStore>,
+    base_version: Version,
+    version: Version,
+    commit: bool,
+    /// The versions of the shard roots in the tree at `base_version`, `None` if empty.
+    shard_persisted_versions: [Option<Version>; NUM_STATE_SHARDS],
+    previous_key_hash: Option<HashValue>,
+    /// The tree shard the next changes go to, and its changes so far.
+    shard_id: u8,
+    shard_value_set: Vec<(HashValue, Option<(HashValue, StateKey)>)>,
+    shard_root_nodes: Vec<Node>,
+    items_delta: i64,
+    bytes_delta: i64,
+}
+
+impl StateSnapshotIncrementRestore {
+    pub(crate) fn new(
+        state_store: Arc<StateStore>,
+        base_version: Version,
+        version: Version,
+        commit: bool,
+    ) -> Result<Self> {
+        ensure!(
+            base_version < version,
+            "Increment to version {} can't be based on version {}.",
+            version,
+            base_version,
+        );
+        let shard_persisted_versions = state_store
+            .state_merkle_db
+            .get_shard_persisted_versions(Some(base_version))?;
+        Ok(Self {
+            state_store,
+            base_version,
+            version,
+            commit,
+            shard_persisted_versions,
+            previous_key_hash: None,
+            shard_id: 0,
+            shard_value_set: Vec::new(),
+            shard_root_nodes: Vec::new(),
+            items_delta: 0,
+            bytes_delta: 0,
+        })
+    }
+
+    pub fn add_chunk(&mut self, chunk: Vec<(StateKey, Option<StateValue>)>) -> Result<()> {
+        for (key, value) in &chunk {
+            let key_hash = key.hash();
+            ensure!(
+                self.previous_key_hash.map_or(true, |hash| hash < key_hash),
+                "State key hash {} out of order, previous one: {:?}.",
+                key_hash,
+                self.previous_key_hash,
+            );
+            self.previous_key_hash = Some(key_hash);
+
+            // Later changes all belong to this shard or the following ones.
+            self.merklize_shards_before(key_hash.nibble(0))?;
+            self.shard_value_set.push((
+                key_hash,
+                value.as_ref().map(|value| (value.hash(), key.clone())),
+            ));
+        }
+
+        if self.commit {
+            self.write_state_values(chunk)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the root hash of the state tree at `version`.
+    pub fn finish(mut self) -> Result<HashValue> {
+        self.merklize_shards_before(NUM_STATE_SHARDS as u8)?;
+        let state_merkle_db = &self.state_store.state_merkle_db;
+        if !self.commit {
+            let (root_hash, _) = JellyfishMerkleTree::new(state_merkle_db.as_ref())
+                .put_top_levels_nodes(
+                    self.shard_root_nodes,
+                    Some(self.base_version),
+                    self.version,
+                )?;
+            return Ok(root_hash);
+        }
+
+        let base_usage = self.state_store.get_usage(Some(self.base_version))?;
+        if !base_usage.is_untracked() {
+            let usage = StateStorageUsage::new(
+                (base_usage.items() as i64 + self.items_delta) as usize,
+                (base_usage.bytes() as i64 + self.bytes_delta) as usize,
+            );
+            self.state_store
+                .ledger_db
+                .metadata_db()
+                .put::<VersionDataSchema>(&self.version, &usage.into())?;
+        }
+        // The root node goes last, as it makes the snapshot visible.
+        let (root_hash, top_levels_batch) = state_merkle_db.calculate_top_levels(
+            self.shard_root_nodes,
+            self.version,
+            Some(self.base_version),
+            /*previous_epoch_ending_version=*/ None,
+        )?;
+        state_merkle_db
+            .metadata_db()
+            .write_schemas(top_levels_batch)?;
+        Ok(root_hash)
+    }
+
+    /// Updates the tree shards before `shard_id`, as there are no changes left for them.
+    fn merklize_shards_before(&mut self, shard_id: u8) -> Result<()> {
+        while self.shard_id < shard_id {
+            let shard_value_set = std::mem::take(&mut self.shard_value_set);
+            let value_set = shard_value_set
+                .iter()
+                .map(|(key_hash, value)| (*key_hash, value.as_ref()))
+                .collect();
+            let state_merkle_db = &self.state_store.state_merkle_db;
+            let shard_root_node = if self.commit {
+                let (shard_root_node, batch) = state_merkle_db.merklize_value_set_for_shard(
+                    self.shard_id,
+                    value_set,
+                    /*node_hashes=*/ None,
+                    self.version,
+                    Some(self.base_version),
+                    self.shard_persisted_versions[self.shard_id as usize],
+                    /*previous_epoch_ending_version=*/ None,
+                )?;
+                state_merkle_db
+                    .db_shard(self.shard_id)
+                    .write_schemas(batch)?;
+                shard_root_node
+            } else {
+                state_merkle_db
+                    .batch_put_value_set_for_shard(
+                        self.shard_id,
+                        value_set,
+                        /*node_hashes=*/ None,
+                        self.shard_persisted_versions[self.shard_id as usize],
+                        self.version,
+                    )?
+                    .0
+            };
+            self.shard_root_nodes.push(shard_root_node);
+            self.shard_id += 1;
+        }
+        Ok(())
+    }
+
+    /// Writes the values at `version`, marking the ones they replace as stale since then. The
+    /// stale indices are based on `base_version`, as the versions in between are skipped.
+    fn write_state_values(&mut self, chunk: Vec<(StateKey, Option<StateValue>)>) -> Result<()> {
+        let mut sharded_updates = create_empty_sharded_state_updates();
+        for (key, value) in chunk {
+            sharded_updates[key.get_shard_id() as usize].insert(key, value);
+        }
+
+        let sharded_state_kv_batches = new_sharded_kv_schema_batch();
+        for (updates, batch) in sharded_updates.iter().zip(sharded_state_kv_batches.iter()) {
+            for (key, value) in updates {
+                if let Some(value) = value {
+                    self.items_delta += 1;
+                    self.bytes_delta += (key.size() + value.size()) as i64;
+                } else {
+                    batch.put::<StaleStateValueIndexSchema>(
+                        &StaleStateValueIndex {
+                            stale_since_version: self.version,
+                            version: self.version,
+                            state_key: key.clone(),
+                        },
+                        &(),
+                    )?;
+                }
+                if let Some((old_version, old_value)) = self
+                    .state_store
+                    .state_db
+                    .get_state_value_with_version_by_version(key, self.base_version)?
+                {
+                    self.items_delta -= 1;
+                    self.bytes_delta -= (key.size() + old_value.size()) as i64;
+                    batch.put::<StaleStateValueIndexSchema>(
+                        &StaleStateValueIndex {
+                            stale_since_version: self.version,
+                            version: old_version,
+                            state_key: key.clone(),
+                        },
+                        &(),
+                    )?;
+                }
+            }
+        }
+
+        let state_kv_metadata_batch = SchemaBatch::new();
+        self.state_store.put_state_values(
+            vec![&sharded_updates],
+            self.version,
+            &sharded_state_kv_batches,
+            &state_kv_metadata_batch,
+            self.state_store.state_kv_db.enabled_sharding(),
+        )?;
+        for (shard_id, batch) in sharded_state_kv_batches.into_iter().enumerate() {
+            self.state_store
+                .state_kv_db
+                .db_shard(shard_id as u8)
+                .write_schemas(batch)?;
+        }
+        self.state_store
+            .state_kv_db
+            .commit_raw_batch(state_kv_metadata_batch)
+    }
+}
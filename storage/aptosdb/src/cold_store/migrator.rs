@@ -0,0 +1,192 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    cold_store::{ColdLedgerData, ColdStore},
+    ledger_db::LedgerDb,
+    pruner::pruner_utils,
+    schema::event::EventSchema,
+    EventStore, TransactionStore,
+};
+use anyhow::{format_err, Result};
+use aptos_config::config::ColdStorageConfig;
+use aptos_logger::{
+    error, info,
+    prelude::{sample, SampleRate},
+};
+use aptos_schemadb::SchemaBatch;
+use aptos_types::transaction::{AtomicVersion, Version};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{sleep, JoinHandle},
+    time::Duration,
+};
+
+/// Runs a thread migrating the ledger data out of the hot window to the cold store, one segment
+/// at a time. The data is only deleted from RocksDB once its segment is complete, and the reads
+/// go to the cold store from then on.
+pub(crate) struct ColdStoreMigrator {
+    worker_thread: Option<JoinHandle<()>>,
+    inner: Arc<ColdStoreMigratorInner>,
+}
+
+struct ColdStoreMigratorInner {
+    ledger_db: Arc<LedgerDb>,
+    cold_store: Arc<ColdStore>,
+    transaction_store: TransactionStore,
+    event_store: EventStore,
+    hot_window: Version,
+    segment_size: Version,
+    latest_version: AtomicVersion,
+    quit_worker: AtomicBool,
+}
+
+impl ColdStoreMigratorInner {
+    fn work(&self) {
+        const IDLE_INTERVAL: Duration = Duration::from_millis(if cfg!(test) { 10 } else { 1000 });
+
+        while !self.quit_worker.load(Ordering::SeqCst) {
+            match self.migrate_next_segment() {
+                Ok(true) => (),
+                Ok(false) => sleep(IDLE_INTERVAL),
+                Err(err) => {
+                    sample!(
+                        SampleRate::Duration(Duration::from_secs(1)),
+                        error!(error = ?err, "Cold store migration has error.")
+                    );
+                    sleep(IDLE_INTERVAL);
+                },
+            }
+        }
+    }
+
+    /// Migrates the next segment if it's out of the hot window, returns whether it did.
+    fn migrate_next_segment(&self) -> Result<bool> {
+        let first_version = match self.cold_store.versions() {
+            Some(versions) => versions.end,
+            None => pruner_utils::get_ledger_pruner_progress(&self.ledger_db)?,
+        };
+        let end_version = first_version + self.segment_size;
+        let latest_version = self.latest_version.load(Ordering::SeqCst);
+        if end_version.saturating_add(self.hot_window) > latest_version.saturating_add(1) {
+            return Ok(false);
+        }
+
+        let data = (first_version..end_version)
+            .map(|version| {
+                Ok(ColdLedgerData {
+                    transaction: self.transaction_store.get_transaction(version)?,
+                    write_set: self.transaction_store.get_write_set(version)?,
+                    events: self.event_store.get_events_by_version(version)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.cold_store.append(first_version, &data)?;
+        self.delete_hot_data(first_version, &data)?;
+
+        info!(
+            first_version = first_version,
+            end_version = end_version,
+            "Migrated ledger data to the cold store."
+        );
+        Ok(true)
+    }
+
+    /// Deletes the data now in the cold store from RocksDB, keeping the indexes. Deleting it
+    /// again is harmless.
+    fn delete_hot_data(&self, first_version: Version, data: &[ColdLedgerData]) -> Result<()> {
+        let end_version = first_version + data.len() as Version;
+
+        let transaction_batch = SchemaBatch::new();
+        self.transaction_store.prune_transaction_schema(
+            first_version,
+            end_version,
+            &transaction_batch,
+        )?;
+        let write_set_batch = SchemaBatch::new();
+        self.transaction_store
+            .prune_write_set(first_version, end_version, &write_set_batch)?;
+        let event_batch = SchemaBatch::new();
+        for (version, version_data) in (first_version..).zip(data) {
+            for idx in 0..version_data.events.len() {
+                event_batch.delete::<EventSchema>(&(version, idx as u64))?;
+            }
+        }
+
+        self.ledger_db
+            .transaction_db()
+            .write_schemas(transaction_batch)?;
+        self.ledger_db
+            .write_set_db()
+            .write_schemas(write_set_batch)?;
+        self.ledger_db.event_db().write_schemas(event_batch)
+    }
+}
+
+impl ColdStoreMigrator {
+    pub fn new(
+        ledger_db: Arc<LedgerDb>,
+        cold_store: Arc<ColdStore>,
+        config: &ColdStorageConfig,
+    ) -> Result<Self> {
+        let inner = Arc::new(ColdStoreMigratorInner {
+            transaction_store: TransactionStore::new(Arc::clone(&ledger_db)),
+            event_store: EventStore::new(ledger_db.event_db_arc()),
+            ledger_db,
+            cold_store,
+            hot_window: config.hot_window,
+            segment_size: config.segment_size,
+            latest_version: AtomicVersion::new(0),
+            quit_worker: AtomicBool::new(false),
+        });
+
+        // The process might have stopped after the last segment was written, before its data was
+        // deleted from RocksDB.
+        if let Some(versions) = inner.cold_store.versions() {
+            let first_version = versions
+                .end
+                .saturating_sub(inner.segment_size)
+                .max(versions.start);
+            let data = (first_version..versions.end)
+                .map(|version| {
+                    inner.cold_store.get(version)?.ok_or_else(|| {
+                        format_err!("Version {} missing from the cold store.", version)
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            inner.delete_hot_data(first_version, &data)?;
+        }
+
+        let inner_cloned = Arc::clone(&inner);
+        let worker_thread = std::thread::Builder::new()
+            .name("cold_store_migrator".into())
+            .spawn(move || inner_cloned.work())
+            .expect("Creating cold store migrator thread should succeed.");
+
+        Ok(Self {
+            worker_thread: Some(worker_thread),
+            inner,
+        })
+    }
+
+    /// Lets the migrator know about newly committed versions.
+    pub fn set_latest_version(&self, latest_version: Version) {
+        self.inner
+            .latest_version
+            .fetch_max(latest_version, Ordering::SeqCst);
+    }
+}
+
+impl Drop for ColdStoreMigrator {
+    fn drop(&mut self) {
+        self.inner.quit_worker.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.worker_thread.take() {
+            handle
+                .join()
+                .unwrap_or_else(|_| panic!("Cold store migrator thread should join peacefully."));
+        }
+    }
+}
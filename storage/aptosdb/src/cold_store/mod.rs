@@ -0,0 +1,254 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the cold tier of the ledger data, for archive nodes.
+//!
+//! The transactions, write sets and events older than the hot window are moved out of RocksDB by
+//! the `ColdStoreMigrator`, into immutable segment files of a fixed number of versions each. The
+//! indexes, transaction infos and accumulators stay in RocksDB, so only reading the data itself
+//! goes through the `ColdStore`, which the `TransactionStore` and the `EventStore` of the DB do.
+
+mod migrator;
+mod segment;
+
+#[cfg(test)]
+mod test;
+
+pub(crate) use migrator::ColdStoreMigrator;
+
+use crate::{cold_store::segment::Segment, metrics::COLD_STORE_NEXT_VERSION};
+use anyhow::{ensure, format_err, Result};
+use aptos_infallible::RwLock;
+use aptos_logger::info;
+use aptos_types::{
+    contract_event::ContractEvent,
+    transaction::{Transaction, Version},
+    write_set::WriteSet,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Everything the cold tier holds about a version.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct ColdLedgerData {
+    pub transaction: Transaction,
+    pub write_set: WriteSet,
+    pub events: Vec<ContractEvent>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ColdStore {
+    dir: PathBuf,
+    /// Segments by their first version. They cover a contiguous range of versions.
+    segments: RwLock<BTreeMap<Version, Arc<Segment>>>,
+}
+
+impl ColdStore {
+    pub fn open(dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(dir)?;
+
+        let mut segments = BTreeMap::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if Segment::is_tmp_file(&path) {
+                // Left behind by a crash while migrating, the versions are still in RocksDB.
+                std::fs::remove_file(&path)?;
+            } else if Segment::is_segment_file(&path) {
+                let segment = Segment::open(&path)?;
+                segments.insert(segment.versions().start, Arc::new(segment));
+            }
+        }
+        let mut next_version = None;
+        for segment in segments.values() {
+            let versions = segment.versions();
+            if let Some(next_version) = next_version {
+                ensure!(
+                    versions.start == next_version,
+                    "Gap in the cold store before version {}.",
+                    versions.start
+                );
+            }
+            next_version = Some(versions.end);
+        }
+
+        let myself = Self {
+            dir: dir.to_path_buf(),
+            segments: RwLock::new(segments),
+        };
+        let versions = myself.versions();
+        info!(
+            dir = ?dir,
+            versions = ?versions,
+            "Opened the cold store."
+        );
+        if let Some(versions) = versions {
+            COLD_STORE_NEXT_VERSION.set(versions.end as i64);
+        }
+        Ok(myself)
+    }
+
+    /// The versions in the cold store, `None` if it's empty.
+    pub fn versions(&self) -> Option<Range<Version>> {
+        let segments = self.segments.read();
+        let first = segments.values().next()?;
+        let last = segments.values().next_back()?;
+        Some(first.versions().start..last.versions().end)
+    }
+
+    /// Whether the data of `version` is in the cold store.
+    pub fn contains(&self, version: Version) -> bool {
+        self.versions()
+            .map_or(false, |versions| versions.contains(&version))
+    }
+
+    /// Reads the data of `version` if it's in the cold store.
+    pub fn get(&self, version: Version) -> Result<Option<ColdLedgerData>> {
+        let segment = match self.segments.read().range(..=version).next_back() {
+            Some((_, segment)) if segment.versions().contains(&version) => Arc::clone(segment),
+            _ => return Ok(None),
+        };
+        segment.get(version).map(Some)
+    }
+
+    /// Adds the data of the versions from `first_version`, which must follow the versions already
+    /// in the cold store, as a new segment.
+    pub fn append(&self, first_version: Version, data: &[ColdLedgerData]) -> Result<()> {
+        if let Some(versions) = self.versions() {
+            ensure!(
+                first_version == versions.end,
+                "Expected version {} to be migrated next, got {}.",
+                versions.end,
+                first_version
+            );
+        }
+
+        let path = Segment::write(&self.dir, first_version, data)?;
+        let segment = Segment::open(&path)?;
+        let next_version = segment.versions().end;
+        self.segments
+            .write()
+            .insert(first_version, Arc::new(segment));
+        COLD_STORE_NEXT_VERSION.set(next_version as i64);
+        Ok(())
+    }
+}
+
+/// Reads the data of `version` from RocksDB with `read_hot`, unless it's in the cold store, in
+/// which case it's taken from the data there with `read_cold`.
+pub(crate) fn read_ledger_data<T>(
+    cold_store: Option<&ColdStore>,
+    version: Version,
+    read_hot: impl FnOnce() -> Result<T>,
+    read_cold: impl FnOnce(ColdLedgerData) -> Result<T>,
+) -> Result<T> {
+    let Some(cold_store) = cold_store else {
+        return read_hot();
+    };
+    if let Some(data) = cold_store.get(version)? {
+        return read_cold(data);
+    }
+
+    // The version is deleted from RocksDB once migrated, which can happen while it's being read,
+    // so the read is only complete if the version still isn't in the cold store afterwards.
+    let hot = read_hot();
+    match cold_store.get(version)? {
+        Some(data) => read_cold(data),
+        None => hot,
+    }
+}
+
+/// Iterates over the data of consecutive versions, read from the cold store for the versions
+/// there, and from a RocksDB iterator created with `new_hot_iter` for the rest.
+pub(crate) struct ColdOrHotIter<'a, T, I, F> {
+    cold_store: Option<&'a ColdStore>,
+    next_version: Version,
+    end_version: Version,
+    from_cold: fn(ColdLedgerData) -> T,
+    new_hot_iter: F,
+    hot_iter: Option<I>,
+}
+
+impl<'a, T, I, F> ColdOrHotIter<'a, T, I, F>
+where
+    I: Iterator<Item = Result<T>>,
+    F: FnMut(Version, usize) -> Result<I>,
+{
+    pub fn new(
+        cold_store: Option<&'a ColdStore>,
+        start_version: Version,
+        num_versions: usize,
+        from_cold: fn(ColdLedgerData) -> T,
+        new_hot_iter: F,
+    ) -> Result<Self> {
+        Ok(Self {
+            cold_store,
+            next_version: start_version,
+            end_version: start_version
+                .checked_add(num_versions as u64)
+                .ok_or_else(|| format_err!("Too many versions requested."))?,
+            from_cold,
+            new_hot_iter,
+            hot_iter: None,
+        })
+    }
+
+    fn next_impl(&mut self) -> Result<Option<T>> {
+        if self.next_version >= self.end_version {
+            return Ok(None);
+        }
+
+        if self.hot_iter.is_none() {
+            if let Some(data) = self.get_cold(self.next_version)? {
+                self.next_version += 1;
+                return Ok(Some((self.from_cold)(data)));
+            }
+            let hot_iter = (self.new_hot_iter)(
+                self.next_version,
+                (self.end_version - self.next_version) as usize,
+            )?;
+            // The iterator reads a snapshot of RocksDB, which lacks the versions migrated before
+            // it was created. They're migrated in order, so if the next version still isn't in
+            // the cold store, the snapshot has it and all the following ones.
+            if self
+                .cold_store
+                .map_or(false, |cold_store| cold_store.contains(self.next_version))
+            {
+                return self.next_impl();
+            }
+            self.hot_iter = Some(hot_iter);
+        }
+
+        let item = self
+            .hot_iter
+            .as_mut()
+            .expect("Created above.")
+            .next()
+            .transpose()?;
+        self.next_version += 1;
+        Ok(item)
+    }
+
+    fn get_cold(&self, version: Version) -> Result<Option<ColdLedgerData>> {
+        match self.cold_store {
+            Some(cold_store) => cold_store.get(version),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a, T, I, F> Iterator for ColdOrHotIter<'a, T, I, F>
+where
+    I: Iterator<Item = Result<T>>,
+    F: FnMut(Version, usize) -> Result<I>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_impl().transpose()
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! An immutable segment file, holding the ledger data of a range of versions.
+//!
+//! The data of each version is BCS encoded and LZ4 compressed separately, so that it can be read
+//! without decompressing the whole segment. The layout is:
+//!
+//! ```text
+//! | data of first_version | ... | data of first_version + num_versions - 1 |
+//! | offsets of the data of each version, and of the end of the data, u64 each |
+//! | first_version u64 | num_versions u64 | MAGIC u64 |
+//! ```
+//!
+//! All the integers are little endian.
+
+use crate::cold_store::ColdLedgerData;
+use anyhow::{ensure, format_err, Result};
+use aptos_infallible::Mutex;
+use aptos_types::transaction::Version;
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::{
+    fs::File,
+    io::{BufWriter, Cursor, Read, Seek, SeekFrom, Write},
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+const MAGIC: u64 = 0x4150_544F_5345_474D;
+const FOOTER_SIZE: u64 = 24;
+const EXTENSION: &str = "seg";
+const TMP_EXTENSION: &str = "seg.tmp";
+
+#[derive(Debug)]
+pub(crate) struct Segment {
+    file: Mutex<File>,
+    first_version: Version,
+    /// Offsets of the data of each version in the file, followed by the end of the data.
+    offsets: Vec<u64>,
+}
+
+impl Segment {
+    /// Writes the data of the versions from `first_version` to a new segment file in `dir`. The
+    /// file only shows up under its final name once complete, so that a crash leaves no partial
+    /// segment behind.
+    pub fn write(dir: &Path, first_version: Version, data: &[ColdLedgerData]) -> Result<PathBuf> {
+        ensure!(!data.is_empty(), "Empty segment.");
+
+        let path = Self::path(dir, first_version);
+        let tmp_path = path.with_extension(TMP_EXTENSION);
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        let mut offsets = Vec::with_capacity(data.len() + 1);
+        let mut offset = 0u64;
+        for version_data in data {
+            offsets.push(offset);
+            let compressed = lz4::block::compress(&bcs::to_bytes(version_data)?, None, true)?;
+            writer.write_all(&compressed)?;
+            offset += compressed.len() as u64;
+        }
+        offsets.push(offset);
+
+        for offset in offsets {
+            writer.write_u64::<LittleEndian>(offset)?;
+        }
+        writer.write_u64::<LittleEndian>(first_version)?;
+        writer.write_u64::<LittleEndian>(data.len() as u64)?;
+        writer.write_u64::<LittleEndian>(MAGIC)?;
+        writer
+            .into_inner()
+            .map_err(|err| err.into_error())?
+            .sync_all()?;
+
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(path)
+    }
+
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut file = File::open(path)?;
+        let file_size = file.metadata()?.len();
+        ensure!(
+            file_size >= FOOTER_SIZE,
+            "Segment {} is truncated.",
+            path.display()
+        );
+
+        file.seek(SeekFrom::Start(file_size - FOOTER_SIZE))?;
+        let first_version = file.read_u64::<LittleEndian>()?;
+        let num_versions = file.read_u64::<LittleEndian>()?;
+        let magic = file.read_u64::<LittleEndian>()?;
+        ensure!(magic == MAGIC, "{} is not a segment file.", path.display());
+
+        let index_size = (num_versions + 1)
+            .checked_mul(8)
+            .filter(|size| size + FOOTER_SIZE <= file_size)
+            .ok_or_else(|| format_err!("Segment {} is truncated.", path.display()))?;
+        let mut index = vec![0u8; index_size as usize];
+        file.seek(SeekFrom::Start(file_size - FOOTER_SIZE - index_size))?;
+        file.read_exact(&mut index)?;
+        let mut cursor = Cursor::new(index);
+        let offsets = (0..=num_versions)
+            .map(|_| cursor.read_u64::<LittleEndian>())
+            .collect::<std::io::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            file: Mutex::new(file),
+            first_version,
+            offsets,
+        })
+    }
+
+    pub fn versions(&self) -> Range<Version> {
+        self.first_version..self.first_version + self.offsets.len() as u64 - 1
+    }
+
+    /// Reads the data of `version`, which must be in the segment.
+    pub fn get(&self, version: Version) -> Result<ColdLedgerData> {
+        ensure!(
+            self.versions().contains(&version),
+            "Version {} is not in segment {:?}.",
+            version,
+            self.versions(),
+        );
+        let idx = (version - self.first_version) as usize;
+        let (begin, end) = (self.offsets[idx], self.offsets[idx + 1]);
+
+        let mut compressed = vec![0u8; (end - begin) as usize];
+        {
+            let mut file = self.file.lock();
+            file.seek(SeekFrom::Start(begin))?;
+            file.read_exact(&mut compressed)?;
+        }
+        Ok(bcs::from_bytes(&lz4::block::decompress(
+            &compressed,
+            None,
+        )?)?)
+    }
+
+    fn path(dir: &Path, first_version: Version) -> PathBuf {
+        // Zero padded, so that the segments are listed in order.
+        dir.join(format!("{:020}.{}", first_version, EXTENSION))
+    }
+
+    pub fn is_segment_file(path: &Path) -> bool {
+        path.extension().map_or(false, |ext| ext == EXTENSION)
+    }
+
+    /// Whether `path` is a segment that wasn't completely written.
+    pub fn is_tmp_file(path: &Path) -> bool {
+        path.to_str()
+            .map_or(false, |path| path.ends_with(TMP_EXTENSION))
+    }
+}
@@ -0,0 +1,274 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::{AptosDB, EventStore, TransactionStore};
+use aptos_config::config::ColdStorageConfig;
+use aptos_crypto::HashValue;
+use aptos_schemadb::SchemaBatch;
+use aptos_temppath::TempPath;
+use aptos_types::{account_address::AccountAddress, event::EventKey};
+use move_core_types::language_storage::TypeTag;
+use std::time::{Duration, Instant};
+
+fn make_data(num_versions: usize) -> Vec<ColdLedgerData> {
+    (0..num_versions)
+        .map(|version| ColdLedgerData {
+            transaction: Transaction::StateCheckpoint(HashValue::random()),
+            write_set: WriteSet::default(),
+            events: vec![ContractEvent::new(
+                EventKey::new(0, AccountAddress::ONE),
+                version as u64,
+                TypeTag::Bool,
+                vec![],
+            )],
+        })
+        .collect()
+}
+
+fn put_hot_data(db: &AptosDB, data: &[ColdLedgerData]) {
+    let transaction_batch = SchemaBatch::new();
+    let write_set_batch = SchemaBatch::new();
+    let event_batch = SchemaBatch::new();
+    for (version, version_data) in (0..).zip(data) {
+        db.transaction_store
+            .put_transaction(
+                version,
+                &version_data.transaction,
+                /*skip_index=*/ false,
+                &transaction_batch,
+            )
+            .unwrap();
+        db.transaction_store
+            .put_write_set(version, &version_data.write_set, &write_set_batch)
+            .unwrap();
+        db.event_store
+            .put_events(
+                version,
+                &version_data.events,
+                /*skip_index=*/ false,
+                &event_batch,
+            )
+            .unwrap();
+    }
+    db.ledger_db
+        .transaction_db()
+        .write_schemas(transaction_batch)
+        .unwrap();
+    db.ledger_db
+        .write_set_db()
+        .write_schemas(write_set_batch)
+        .unwrap();
+    db.ledger_db.event_db().write_schemas(event_batch).unwrap();
+}
+
+/// The stores of a DB opened for test don't know about the cold store, they only read RocksDB.
+fn verify_hot_data(db: &AptosDB, data: &[ColdLedgerData], versions: Range<Version>, in_db: bool) {
+    for version in versions {
+        let version_data = &data[version as usize];
+        if in_db {
+            assert_eq!(
+                db.transaction_store.get_transaction(version).unwrap(),
+                version_data.transaction
+            );
+            assert_eq!(
+                db.transaction_store.get_write_set(version).unwrap(),
+                version_data.write_set
+            );
+            assert_eq!(
+                db.event_store.get_events_by_version(version).unwrap(),
+                version_data.events
+            );
+        } else {
+            assert!(db.transaction_store.get_transaction(version).is_err());
+            assert!(db.transaction_store.get_write_set(version).is_err());
+            assert!(db
+                .event_store
+                .get_events_by_version(version)
+                .unwrap()
+                .is_empty());
+        }
+    }
+}
+
+fn wait_for_migration(cold_store: &ColdStore, end_version: Version) {
+    let start = Instant::now();
+    while cold_store.versions().map_or(0, |versions| versions.end) < end_version {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "Timed out waiting for the migration."
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+}
+
+fn cold_storage_config() -> ColdStorageConfig {
+    ColdStorageConfig {
+        enable: true,
+        hot_window: 5,
+        segment_size: 10,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_segment_round_trip() {
+    let tmp_dir = TempPath::new();
+    tmp_dir.create_as_dir().unwrap();
+    let data = make_data(10);
+
+    let path = Segment::write(tmp_dir.path(), 100, &data).unwrap();
+    assert!(Segment::is_segment_file(&path));
+    let segment = Segment::open(&path).unwrap();
+    assert_eq!(segment.versions(), 100..110);
+    for (version, version_data) in (100..).zip(&data) {
+        assert_eq!(&segment.get(version).unwrap(), version_data);
+    }
+    assert!(segment.get(99).is_err());
+    assert!(segment.get(110).is_err());
+}
+
+#[test]
+fn test_cold_store_append_and_reopen() {
+    let tmp_dir = TempPath::new();
+    let data = make_data(30);
+
+    {
+        let store = ColdStore::open(tmp_dir.path()).unwrap();
+        assert_eq!(store.versions(), None);
+        assert_eq!(store.get(0).unwrap(), None);
+
+        store.append(0, &data[..10]).unwrap();
+        store.append(10, &data[10..20]).unwrap();
+        // Not following the versions already in the store.
+        assert!(store.append(30, &data[20..]).is_err());
+        assert_eq!(store.versions(), Some(0..20));
+    }
+
+    // A segment left half written.
+    std::fs::write(
+        tmp_dir.path().join("00000000000000000020.seg.tmp"),
+        b"garbage",
+    )
+    .unwrap();
+
+    let store = ColdStore::open(tmp_dir.path()).unwrap();
+    assert_eq!(store.versions(), Some(0..20));
+    for (version, version_data) in (0..20).zip(&data) {
+        assert_eq!(store.get(version).unwrap().as_ref(), Some(version_data));
+    }
+    assert_eq!(store.get(20).unwrap(), None);
+    store.append(20, &data[20..]).unwrap();
+    assert_eq!(store.get(25).unwrap().as_ref(), Some(&data[25]));
+}
+
+#[test]
+fn test_migrator() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let data = make_data(30);
+    put_hot_data(&db, &data);
+    let cold_store = Arc::new(ColdStore::open(&tmp_dir.path().join("cold")).unwrap());
+
+    let migrator = ColdStoreMigrator::new(
+        Arc::clone(&db.ledger_db),
+        Arc::clone(&cold_store),
+        &cold_storage_config(),
+    )
+    .unwrap();
+    migrator.set_latest_version(29);
+    wait_for_migration(&cold_store, 20);
+    // Stopping the migrator lets it finish deleting the last segment from RocksDB.
+    drop(migrator);
+
+    // The last segment isn't entirely out of the hot window.
+    assert_eq!(cold_store.versions(), Some(0..20));
+    verify_hot_data(&db, &data, 0..20, /*in_db=*/ false);
+    verify_hot_data(&db, &data, 20..30, /*in_db=*/ true);
+
+    let transaction_store = TransactionStore::new_with_cold_store(
+        Arc::clone(&db.ledger_db),
+        Some(Arc::clone(&cold_store)),
+    );
+    let event_store =
+        EventStore::new_with_cold_store(db.ledger_db.event_db_arc(), Some(cold_store));
+    for (version, version_data) in (0..).zip(&data) {
+        assert_eq!(
+            transaction_store.get_transaction(version).unwrap(),
+            version_data.transaction
+        );
+        assert_eq!(
+            transaction_store.get_write_set(version).unwrap(),
+            version_data.write_set
+        );
+        assert_eq!(
+            event_store
+                .get_event_by_version_and_index(version, 0)
+                .unwrap(),
+            version_data.events[0]
+        );
+    }
+    // Across the two tiers.
+    assert_eq!(
+        transaction_store
+            .get_transaction_iter(15, 10)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        data[15..25]
+            .iter()
+            .map(|version_data| version_data.transaction.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        transaction_store
+            .get_write_set_iter(15, 10)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        data[15..25]
+            .iter()
+            .map(|version_data| version_data.write_set.clone())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        event_store
+            .get_events_by_version_iter(15, 10)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        data[15..25]
+            .iter()
+            .map(|version_data| version_data.events.clone())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_migrator_resumes_deletion() {
+    let tmp_dir = TempPath::new();
+    let db = AptosDB::new_for_test(&tmp_dir);
+    let data = make_data(20);
+    put_hot_data(&db, &data);
+    let cold_dir = tmp_dir.path().join("cold");
+
+    // The process stopped right after writing the segment, before deleting its data from RocksDB.
+    ColdStore::open(&cold_dir)
+        .unwrap()
+        .append(0, &data[..10])
+        .unwrap();
+    verify_hot_data(&db, &data, 0..20, /*in_db=*/ true);
+
+    let cold_store = Arc::new(ColdStore::open(&cold_dir).unwrap());
+    let migrator = ColdStoreMigrator::new(
+        Arc::clone(&db.ledger_db),
+        Arc::clone(&cold_store),
+        &cold_storage_config(),
+    )
+    .unwrap();
+    drop(migrator);
+
+    assert_eq!(cold_store.versions(), Some(0..10));
+    verify_hot_data(&db, &data, 0..10, /*in_db=*/ false);
+    verify_hot_data(&db, &data, 10..20, /*in_db=*/ true);
+}
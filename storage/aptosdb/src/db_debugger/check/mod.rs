@@ -0,0 +1,892 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    cold_store::{ColdLedgerData, ColdStore},
+    event_store::EventStore,
+    ledger_db::LedgerDb,
+    pruner::pruner_utils::{get_ledger_pruner_progress, get_state_kv_pruner_progress},
+    schema::{
+        db_metadata::DbMetadataKey, event::EventSchema, event_accumulator::EventAccumulatorSchema,
+        event_by_key::EventByKeySchema, event_by_version::EventByVersionSchema,
+        state_value::StateValueSchema, transaction::TransactionSchema,
+        transaction_accumulator::TransactionAccumulatorSchema,
+        transaction_by_account::TransactionByAccountSchema,
+        transaction_by_hash::TransactionByHashSchema, transaction_info::TransactionInfoSchema,
+        write_set::WriteSetSchema,
+    },
+    state_kv_db::StateKvDb,
+    state_merkle_db::StateMerkleDb,
+    transaction_store::TransactionStore,
+    utils::{
+        get_progress,
+        truncation_helper::{
+            get_ledger_commit_progress, get_overall_commit_progress, get_state_kv_commit_progress,
+            get_state_merkle_commit_progress,
+        },
+    },
+    AptosDB,
+};
+use anyhow::{ensure, Result};
+use aptos_config::config::RocksdbConfigs;
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_jellyfish_merkle::JellyfishMerkleTree;
+use aptos_schemadb::{schema::Schema, ReadOptions, DB};
+use aptos_types::{
+    contract_event::ContractEvent,
+    proof::{
+        accumulator::InMemoryAccumulator, definition::EventAccumulatorHasher, position::Position,
+    },
+    state_store::{state_key::StateKey, state_value::StateValue},
+    transaction::{Transaction, TransactionInfo, Version},
+    write_set::{TransactionWrite, WriteSet},
+};
+use clap::Parser;
+use serde::Serialize;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+#[derive(Parser)]
+#[clap(
+    about = "Cross check the ledger data, the state and the indexes in a version range, \
+reporting every inconsistency found."
+)]
+pub struct Cmd {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+
+    #[clap(long)]
+    split_ledger_db: bool,
+
+    /// Cold store to read the ledger data migrated out of RocksDB from, if the node has one.
+    #[clap(long, value_parser)]
+    cold_store_dir: Option<PathBuf>,
+
+    /// The node runs with `skip_index_and_usage`, so the account and event indexes are not
+    /// expected.
+    #[clap(long)]
+    skip_index_and_usage: bool,
+
+    /// Also go through the whole transaction and event indexes for entries left below the pruner
+    /// progress. They are not ordered by version, so this is slow on a large DB.
+    #[clap(long)]
+    check_pruned_indexes: bool,
+
+    /// File to write the JSON report to, printed to stdout if not specified.
+    #[clap(long, value_parser)]
+    report_file: Option<PathBuf>,
+
+    start_version: Version,
+
+    num_versions: usize,
+}
+
+/// Beyond this many keys written since the last state snapshot, e.g. when the snapshots are
+/// pruned, the writes are dropped rather than checked against the state tree.
+const MAX_PENDING_WRITES: usize = 1_000_000;
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Check {
+    LedgerData,
+    StateValue,
+    StateTree,
+    EventAccumulator,
+    TransactionByHash,
+    TransactionByAccount,
+    EventByKey,
+    EventByVersion,
+    PrunerProgress,
+    ColdStore,
+}
+
+#[derive(Serialize)]
+struct Inconsistency {
+    check: Check,
+    version: Option<Version>,
+    details: String,
+}
+
+#[derive(Serialize)]
+struct Report {
+    first_version: Version,
+    /// Exclusive.
+    end_version: Version,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl Cmd {
+    pub fn run(self) -> Result<()> {
+        let rocksdb_config = RocksdbConfigs {
+            split_ledger_db: self.split_ledger_db,
+            ..Default::default()
+        };
+        let (ledger_db, state_merkle_db, state_kv_db) = AptosDB::open_dbs(
+            &self.db_dir,
+            rocksdb_config,
+            /*readonly=*/ true,
+            /*max_num_nodes_per_lru_cache_shard=*/ 0,
+        )?;
+        let ledger_db = Arc::new(ledger_db);
+        let cold_store = self
+            .cold_store_dir
+            .as_deref()
+            .map(ColdStore::open)
+            .transpose()?;
+        let mut checker = Checker {
+            transaction_store: TransactionStore::new(Arc::clone(&ledger_db)),
+            event_store: EventStore::new(ledger_db.event_db_arc()),
+            ledger_db,
+            state_merkle_db,
+            state_kv_db,
+            cold_store,
+            skip_index_and_usage: self.skip_index_and_usage,
+            check_pruned_indexes: self.check_pruned_indexes,
+            inconsistencies: Vec::new(),
+        };
+
+        println!("Checking pruner progress...");
+        checker.check_pruner_progress()?;
+        let ledger_pruner_progress = get_ledger_pruner_progress(&checker.ledger_db)?;
+        let state_kv_pruner_progress = get_state_kv_pruner_progress(&checker.state_kv_db)?;
+        let next_version =
+            get_overall_commit_progress(checker.ledger_db.metadata_db())?.map_or(0, |v| v + 1);
+
+        let first_version = self.start_version.max(ledger_pruner_progress);
+        let end_version = (self.start_version + self.num_versions as Version).min(next_version);
+        if first_version > self.start_version {
+            println!(
+                "Versions before {} are pruned, starting from there.",
+                first_version
+            );
+        }
+
+        println!("Checking versions [{}, {})...", first_version, end_version);
+        // Latest writes since the last state snapshot checked against the state tree.
+        let mut pending_writes = HashMap::new();
+        for version in first_version..end_version {
+            checker.check_version(version, state_kv_pruner_progress, &mut pending_writes)?;
+            if version % 10_000 == 0 {
+                println!(
+                    "Checked until version {}, {} inconsistencies so far.",
+                    version,
+                    checker.inconsistencies.len()
+                );
+            }
+        }
+
+        let report = Report {
+            first_version,
+            end_version,
+            inconsistencies: checker.inconsistencies,
+        };
+        let report_json = serde_json::to_string_pretty(&report)?;
+        match &self.report_file {
+            Some(path) => std::fs::write(path, report_json)?,
+            None => println!("{}", report_json),
+        }
+        ensure!(
+            report.inconsistencies.is_empty(),
+            "Found {} inconsistencies.",
+            report.inconsistencies.len(),
+        );
+        println!("Done.");
+
+        Ok(())
+    }
+}
+
+struct Checker {
+    ledger_db: Arc<LedgerDb>,
+    state_merkle_db: StateMerkleDb,
+    state_kv_db: StateKvDb,
+    transaction_store: TransactionStore,
+    event_store: EventStore,
+    cold_store: Option<ColdStore>,
+    skip_index_and_usage: bool,
+    check_pruned_indexes: bool,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl Checker {
+    fn report(&mut self, check: Check, version: Option<Version>, details: String) {
+        self.inconsistencies.push(Inconsistency {
+            check,
+            version,
+            details,
+        });
+    }
+
+    fn check_version(
+        &mut self,
+        version: Version,
+        state_kv_pruner_progress: Version,
+        pending_writes: &mut HashMap<StateKey, (Version, Option<StateValue>)>,
+    ) -> Result<()> {
+        let txn_info = match self
+            .ledger_db
+            .transaction_info_db()
+            .get::<TransactionInfoSchema>(&version)?
+        {
+            Some(txn_info) => txn_info,
+            None => {
+                self.report(
+                    Check::LedgerData,
+                    Some(version),
+                    "TransactionInfo missing.".to_string(),
+                );
+                return Ok(());
+            },
+        };
+        let data = match self.get_ledger_data(version) {
+            Ok(data) => data,
+            Err(err) => {
+                self.report(Check::LedgerData, Some(version), err.to_string());
+                return Ok(());
+            },
+        };
+
+        if version >= state_kv_pruner_progress {
+            self.check_state_values(version, &data.write_set)?;
+        }
+        pending_writes.extend(
+            data.write_set
+                .iter()
+                .map(|(key, op)| (key.clone(), (version, op.as_state_value()))),
+        );
+        if let Some(state_root_hash) = txn_info.state_checkpoint_hash() {
+            if self.check_state_tree(version, state_root_hash, pending_writes)? {
+                pending_writes.clear();
+            }
+        }
+        if pending_writes.len() > MAX_PENDING_WRITES {
+            pending_writes.clear();
+        }
+        self.check_event_accumulator(version, &data.events, &txn_info);
+        self.check_transaction_indexes(version, &data.transaction)?;
+        if !self.skip_index_and_usage {
+            self.check_event_indexes(version, &data.events)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_ledger_data(&self, version: Version) -> Result<ColdLedgerData> {
+        if let Some(cold_store) = &self.cold_store {
+            if let Some(data) = cold_store.get(version)? {
+                return Ok(data);
+            }
+        }
+        Ok(ColdLedgerData {
+            transaction: self.transaction_store.get_transaction(version)?,
+            write_set: self.transaction_store.get_write_set(version)?,
+            events: self.event_store.get_events_by_version(version)?,
+        })
+    }
+
+    /// Every write in the write set is in `StateValueSchema` at the version.
+    fn check_state_values(&mut self, version: Version, write_set: &WriteSet) -> Result<()> {
+        for (key, op) in write_set {
+            let expected = op.as_state_value();
+            let stored = self
+                .state_kv_db
+                .db_shard(key.get_shard_id())
+                .get::<StateValueSchema>(&(key.clone(), version))?;
+            if stored.as_ref() != Some(&expected) {
+                self.report(
+                    Check::StateValue,
+                    Some(version),
+                    format!(
+                        "State key {:?} has value hash {:?} in the write set, {:?} in StateValueSchema.",
+                        key,
+                        expected.as_ref().map(CryptoHash::hash),
+                        stored.map(|value| value.as_ref().map(CryptoHash::hash)),
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The state tree at the version, if persisted, has the root hash in the `TransactionInfo`,
+    /// and leaves matching the latest writes. Returns whether the tree was there to check.
+    fn check_state_tree(
+        &mut self,
+        version: Version,
+        state_root_hash: HashValue,
+        pending_writes: &HashMap<StateKey, (Version, Option<StateValue>)>,
+    ) -> Result<bool> {
+        let Some(root_hash) =
+            JellyfishMerkleTree::new(&self.state_merkle_db).get_root_hash_option(version)?
+        else {
+            return Ok(false);
+        };
+        if root_hash != state_root_hash {
+            self.report(
+                Check::StateTree,
+                Some(version),
+                format!(
+                    "State tree root hash {} doesn't match the state checkpoint hash {}.",
+                    root_hash, state_root_hash,
+                ),
+            );
+        }
+
+        for (key, (value_version, value)) in pending_writes {
+            let expected_leaf = value
+                .as_ref()
+                .map(|value| (value.hash(), (key.clone(), *value_version)));
+            match self.state_merkle_db.get_with_proof_ext(key, version) {
+                Ok((leaf, _)) if leaf == expected_leaf => (),
+                Ok((leaf, _)) => self.report(
+                    Check::StateTree,
+                    Some(version),
+                    format!(
+                        "State key {:?} is expected to have leaf {:?}, the state tree has {:?}.",
+                        key, expected_leaf, leaf,
+                    ),
+                ),
+                Err(err) => self.report(
+                    Check::StateTree,
+                    Some(version),
+                    format!("Failed to get the leaf of state key {:?}: {}", key, err),
+                ),
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn check_event_accumulator(
+        &mut self,
+        version: Version,
+        events: &[ContractEvent],
+        txn_info: &TransactionInfo,
+    ) {
+        let event_hashes: Vec<_> = events.iter().map(ContractEvent::hash).collect();
+        let event_root_hash =
+            InMemoryAccumulator::<EventAccumulatorHasher>::from_leaves(&event_hashes).root_hash();
+        if event_root_hash != txn_info.event_root_hash() {
+            self.report(
+                Check::EventAccumulator,
+                Some(version),
+                format!(
+                    "Root hash {} of the {} events doesn't match the event root hash {} in the TransactionInfo.",
+                    event_root_hash,
+                    events.len(),
+                    txn_info.event_root_hash(),
+                ),
+            );
+        }
+    }
+
+    fn check_transaction_indexes(
+        &mut self,
+        version: Version,
+        transaction: &Transaction,
+    ) -> Result<()> {
+        let hash = transaction.hash();
+        let indexed = self
+            .ledger_db
+            .transaction_db()
+            .get::<TransactionByHashSchema>(&hash)?;
+        if indexed != Some(version) {
+            self.report(
+                Check::TransactionByHash,
+                Some(version),
+                format!("Transaction {} is indexed at version {:?}.", hash, indexed),
+            );
+        }
+
+        if let Some(txn) = transaction.try_as_signed_user_txn() {
+            if !self.skip_index_and_usage {
+                let indexed = self
+                    .ledger_db
+                    .transaction_db()
+                    .get::<TransactionByAccountSchema>(&(txn.sender(), txn.sequence_number()))?;
+                if indexed != Some(version) {
+                    self.report(
+                        Check::TransactionByAccount,
+                        Some(version),
+                        format!(
+                            "Transaction {} of account {} is indexed at version {:?}.",
+                            txn.sequence_number(),
+                            txn.sender(),
+                            indexed,
+                        ),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_event_indexes(&mut self, version: Version, events: &[ContractEvent]) -> Result<()> {
+        for (idx, event) in events.iter().enumerate() {
+            let idx = idx as u64;
+            let indexed = self
+                .ledger_db
+                .event_db()
+                .get::<EventByKeySchema>(&(*event.key(), event.sequence_number()))?;
+            if indexed != Some((version, idx)) {
+                self.report(
+                    Check::EventByKey,
+                    Some(version),
+                    format!(
+                        "Event {} of key {} is indexed at {:?}, expected {:?}.",
+                        event.sequence_number(),
+                        event.key(),
+                        indexed,
+                        (version, idx),
+                    ),
+                );
+            }
+
+            let indexed = self.ledger_db.event_db().get::<EventByVersionSchema>(&(
+                *event.key(),
+                version,
+                event.sequence_number(),
+            ))?;
+            if indexed != Some(idx) {
+                self.report(
+                    Check::EventByVersion,
+                    Some(version),
+                    format!(
+                        "Event {} of key {} is indexed at index {:?}, expected {}.",
+                        event.sequence_number(),
+                        event.key(),
+                        indexed,
+                        idx,
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// No pruner is ahead of what's committed, the ledger sub pruners are not ahead of the ledger
+    /// pruner, and the data and its indexes are gone below where each of them has pruned to.
+    fn check_pruner_progress(&mut self) -> Result<()> {
+        let ledger_pruner_progress = get_ledger_pruner_progress(&self.ledger_db)?;
+        let ledger_commit_progress = get_ledger_commit_progress(self.ledger_db.metadata_db())?;
+        let pruners = [
+            ("Ledger", ledger_pruner_progress, ledger_commit_progress),
+            (
+                "StateKv",
+                get_state_kv_pruner_progress(&self.state_kv_db)?,
+                get_state_kv_commit_progress(&self.state_kv_db)?,
+            ),
+            (
+                "StateMerkle",
+                get_progress(
+                    self.state_merkle_db.metadata_db(),
+                    &DbMetadataKey::StateMerklePrunerProgress,
+                )?
+                .unwrap_or(0),
+                get_state_merkle_commit_progress(&self.state_merkle_db)?,
+            ),
+        ];
+        for (name, pruner_progress, commit_progress) in pruners {
+            let next_version = commit_progress.map_or(0, |v| v + 1);
+            if pruner_progress > next_version {
+                self.report(
+                    Check::PrunerProgress,
+                    None,
+                    format!(
+                        "{} pruner progress {} is ahead of the commit progress {:?}.",
+                        name, pruner_progress, commit_progress,
+                    ),
+                );
+            }
+        }
+
+        let ledger_db = Arc::clone(&self.ledger_db);
+        let progress = self.check_sub_pruner(
+            "Transaction",
+            ledger_db.transaction_db(),
+            DbMetadataKey::TransactionPrunerProgress,
+            ledger_pruner_progress,
+        )?;
+        self.check_pruned::<TransactionSchema>(
+            Check::PrunerProgress,
+            "Transaction",
+            ledger_db.transaction_db(),
+            progress,
+            |version| *version,
+        )?;
+        self.check_pruned_index::<TransactionByHashSchema>(
+            "TransactionByHash",
+            ledger_db.transaction_db(),
+            progress,
+            |_, version| *version,
+        )?;
+        self.check_pruned_index::<TransactionByAccountSchema>(
+            "TransactionByAccount",
+            ledger_db.transaction_db(),
+            progress,
+            |_, version| *version,
+        )?;
+
+        let progress = self.check_sub_pruner(
+            "TransactionInfo",
+            ledger_db.transaction_info_db(),
+            DbMetadataKey::TransactionInfoPrunerProgress,
+            ledger_pruner_progress,
+        )?;
+        self.check_pruned::<TransactionInfoSchema>(
+            Check::PrunerProgress,
+            "TransactionInfo",
+            ledger_db.transaction_info_db(),
+            progress,
+            |version| *version,
+        )?;
+
+        let progress = self.check_sub_pruner(
+            "WriteSet",
+            ledger_db.write_set_db(),
+            DbMetadataKey::WriteSetPrunerProgress,
+            ledger_pruner_progress,
+        )?;
+        self.check_pruned::<WriteSetSchema>(
+            Check::PrunerProgress,
+            "WriteSet",
+            ledger_db.write_set_db(),
+            progress,
+            |version| *version,
+        )?;
+
+        let progress = self.check_sub_pruner(
+            "Event",
+            ledger_db.event_db(),
+            DbMetadataKey::EventPrunerProgress,
+            ledger_pruner_progress,
+        )?;
+        self.check_pruned::<EventSchema>(
+            Check::PrunerProgress,
+            "Event",
+            ledger_db.event_db(),
+            progress,
+            |(version, _)| *version,
+        )?;
+        self.check_pruned::<EventAccumulatorSchema>(
+            Check::PrunerProgress,
+            "EventAccumulator",
+            ledger_db.event_db(),
+            progress,
+            |(version, _)| *version,
+        )?;
+        self.check_pruned_index::<EventByKeySchema>(
+            "EventByKey",
+            ledger_db.event_db(),
+            progress,
+            |_, (version, _)| *version,
+        )?;
+        self.check_pruned_index::<EventByVersionSchema>(
+            "EventByVersion",
+            ledger_db.event_db(),
+            progress,
+            |(_, version, _), _| *version,
+        )?;
+
+        let progress = self.check_sub_pruner(
+            "TransactionAccumulator",
+            ledger_db.transaction_accumulator_db(),
+            DbMetadataKey::TransactionAccumulatorPrunerProgress,
+            ledger_pruner_progress,
+        )?;
+        // The leaves are pruned in pairs, along with the nodes not needed by the frozen subtrees
+        // on their right.
+        let num_pruned_leaves = progress / 2 * 2;
+        if num_pruned_leaves > 0
+            && ledger_db
+                .transaction_accumulator_db()
+                .get::<TransactionAccumulatorSchema>(&Position::from_leaf_index(
+                    num_pruned_leaves - 1,
+                ))?
+                .is_some()
+        {
+            self.report(
+                Check::PrunerProgress,
+                Some(num_pruned_leaves - 1),
+                format!(
+                    "TransactionAccumulator leaf is still there below the pruner progress {}.",
+                    progress,
+                ),
+            );
+        }
+
+        self.check_cold_store_progress(ledger_pruner_progress, ledger_commit_progress)
+    }
+
+    /// Returns the progress of the ledger sub pruner, which is not ahead of the ledger pruner.
+    fn check_sub_pruner(
+        &mut self,
+        name: &str,
+        db: &DB,
+        progress_key: DbMetadataKey,
+        ledger_pruner_progress: Version,
+    ) -> Result<Version> {
+        let progress = get_progress(db, &progress_key)?.unwrap_or(ledger_pruner_progress);
+        if progress > ledger_pruner_progress {
+            self.report(
+                Check::PrunerProgress,
+                None,
+                format!(
+                    "{} pruner progress {} is ahead of the ledger pruner progress {}.",
+                    name, progress, ledger_pruner_progress,
+                ),
+            );
+        }
+        Ok(progress)
+    }
+
+    /// The data in the schema, ordered by version, starts at `first_version` or later.
+    fn check_pruned<S: Schema>(
+        &mut self,
+        check: Check,
+        name: &str,
+        db: &DB,
+        first_version: Version,
+        version_of: fn(&S::Key) -> Version,
+    ) -> Result<()> {
+        let mut iter = db.iter::<S>(ReadOptions::default())?;
+        iter.seek_to_first();
+        if let Some((key, _)) = iter.next().transpose()? {
+            let version = version_of(&key);
+            if version < first_version {
+                self.report(
+                    check,
+                    Some(version),
+                    format!(
+                        "{} is still there, expected from version {} on.",
+                        name, first_version,
+                    ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// No entry of the index points below the pruner progress. Goes through the whole index, so
+    /// only if asked to and anything is pruned.
+    fn check_pruned_index<S: Schema>(
+        &mut self,
+        name: &str,
+        db: &DB,
+        progress: Version,
+        version_of: fn(&S::Key, &S::Value) -> Version,
+    ) -> Result<()> {
+        if !self.check_pruned_indexes || progress == 0 {
+            return Ok(());
+        }
+        let mut iter = db.iter::<S>(ReadOptions::default())?;
+        iter.seek_to_first();
+        let mut num_stale_entries = 0;
+        let mut min_stale_version = Version::MAX;
+        for item in iter {
+            let (key, value) = item?;
+            let version = version_of(&key, &value);
+            if version < progress {
+                num_stale_entries += 1;
+                min_stale_version = min_stale_version.min(version);
+            }
+        }
+        if num_stale_entries > 0 {
+            self.report(
+                Check::PrunerProgress,
+                Some(min_stale_version),
+                format!(
+                    "{} has {} entries below the pruner progress {}.",
+                    name, num_stale_entries, progress,
+                ),
+            );
+        }
+        Ok(())
+    }
+
+    /// The cold store continues where the ledger pruner stopped, has nothing that isn't
+    /// committed, and what it has is gone from RocksDB, apart from the indexes.
+    fn check_cold_store_progress(
+        &mut self,
+        ledger_pruner_progress: Version,
+        ledger_commit_progress: Option<Version>,
+    ) -> Result<()> {
+        let Some(versions) = self.cold_store.as_ref().and_then(ColdStore::versions) else {
+            return Ok(());
+        };
+        if versions.start != ledger_pruner_progress {
+            self.report(
+                Check::ColdStore,
+                None,
+                format!(
+                    "Cold store starts at version {}, the ledger pruner progress is {}.",
+                    versions.start, ledger_pruner_progress,
+                ),
+            );
+        }
+        if versions.end > ledger_commit_progress.map_or(0, |v| v + 1) {
+            self.report(
+                Check::ColdStore,
+                None,
+                format!(
+                    "Cold store has versions until {}, ahead of the ledger commit progress {:?}.",
+                    versions.end, ledger_commit_progress,
+                ),
+            );
+        }
+
+        let ledger_db = Arc::clone(&self.ledger_db);
+        self.check_pruned::<TransactionSchema>(
+            Check::ColdStore,
+            "Transaction",
+            ledger_db.transaction_db(),
+            versions.end,
+            |version| *version,
+        )?;
+        self.check_pruned::<WriteSetSchema>(
+            Check::ColdStore,
+            "WriteSet",
+            ledger_db.write_set_db(),
+            versions.end,
+            |version| *version,
+        )?;
+        self.check_pruned::<EventSchema>(
+            Check::ColdStore,
+            "Event",
+            ledger_db.event_db(),
+            versions.end,
+            |(version, _)| *version,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        schema::db_metadata::{DbMetadataSchema, DbMetadataValue},
+        test_helper::{arb_blocks_to_commit_with_block_nums, update_in_memory_state},
+    };
+    use aptos_schemadb::SchemaBatch;
+    use aptos_storage_interface::DbWriter;
+    use aptos_temppath::TempPath;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    /// Runs the check over all the versions, returns its result and the checks in the report.
+    fn run_check(
+        db_dir: &TempPath,
+        cold_store_dir: Option<&TempPath>,
+        num_versions: usize,
+    ) -> (Result<()>, HashSet<String>) {
+        let report_file = TempPath::new();
+        let cmd = Cmd {
+            db_dir: db_dir.path().to_path_buf(),
+            split_ledger_db: false,
+            cold_store_dir: cold_store_dir.map(|dir| dir.path().to_path_buf()),
+            skip_index_and_usage: false,
+            check_pruned_indexes: true,
+            report_file: Some(report_file.path().to_path_buf()),
+            start_version: 0,
+            num_versions,
+        };
+        let result = cmd.run();
+
+        let report: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(report_file.path()).unwrap()).unwrap();
+        let checks = report["inconsistencies"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|inconsistency| inconsistency["check"].as_str().unwrap().to_string())
+            .collect();
+        (result, checks)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5))]
+
+        #[test]
+        fn test_check(input in arb_blocks_to_commit_with_block_nums(30, 50)) {
+            let tmp_dir = TempPath::new();
+            let db = AptosDB::new_for_test(&tmp_dir);
+            let mut in_memory_state = db.state_store.buffered_state().lock().current_state().clone();
+            let mut version = 0;
+            for (txns_to_commit, ledger_info_with_sigs) in input.iter() {
+                update_in_memory_state(&mut in_memory_state, txns_to_commit.as_slice());
+                db.save_transactions(txns_to_commit, version, version.checked_sub(1), Some(ledger_info_with_sigs), true, in_memory_state.clone())
+                    .unwrap();
+                version += txns_to_commit.len() as u64;
+            }
+            drop(db);
+            let num_versions = version as usize;
+
+            let (result, checks) = run_check(&tmp_dir, None, num_versions);
+            prop_assert!(result.is_ok());
+            prop_assert!(checks.is_empty());
+
+            let txns_to_commit: Vec<_> = input.iter().flat_map(|(txns, _)| txns).collect();
+            let (ledger_db, state_merkle_db, state_kv_db) = AptosDB::open_dbs(
+                tmp_dir.path().to_path_buf(),
+                RocksdbConfigs::default(),
+                /*readonly=*/ false,
+                /*max_num_nodes_per_lru_cache_shard=*/ 0,
+            ).unwrap();
+
+            let batch = SchemaBatch::new();
+            batch.delete::<TransactionByHashSchema>(&txns_to_commit[0].transaction().hash()).unwrap();
+            ledger_db.transaction_db().write_schemas(batch).unwrap();
+
+            let (state_value_version, state_key) = txns_to_commit
+                .iter()
+                .enumerate()
+                .find_map(|(version, txn)| {
+                    txn.write_set().iter().next().map(|(key, _)| (version as Version, key.clone()))
+                })
+                .unwrap();
+            state_kv_db.db_shard(state_key.get_shard_id()).put::<StateValueSchema>(
+                &(state_key, state_value_version),
+                &Some(StateValue::new_legacy(b"corrupted".to_vec())),
+            ).unwrap();
+
+            let (event_version, event_idx) = txns_to_commit
+                .iter()
+                .enumerate()
+                .find_map(|(version, txn)| {
+                    (!txn.events().is_empty()).then(|| (version as Version, txn.events().len() as u64 - 1))
+                })
+                .unwrap();
+            let batch = SchemaBatch::new();
+            batch.delete::<EventSchema>(&(event_version, event_idx)).unwrap();
+            ledger_db.event_db().write_schemas(batch).unwrap();
+
+            ledger_db.event_db().put::<DbMetadataSchema>(
+                &DbMetadataKey::EventPrunerProgress,
+                &DbMetadataValue::Version(1),
+            ).unwrap();
+            drop((ledger_db, state_merkle_db, state_kv_db));
+
+            let (result, checks) = run_check(&tmp_dir, None, num_versions);
+            prop_assert!(result.is_err());
+            for check in ["transaction_by_hash", "state_value", "event_accumulator", "pruner_progress"] {
+                prop_assert!(checks.contains(check), "{} not reported.", check);
+            }
+
+            // Data migrated to the cold store without being deleted from RocksDB.
+            let cold_store_dir = TempPath::new();
+            let data: Vec<_> = txns_to_commit[..2]
+                .iter()
+                .map(|txn| ColdLedgerData {
+                    transaction: txn.transaction().clone(),
+                    write_set: txn.write_set().clone(),
+                    events: txn.events().to_vec(),
+                })
+                .collect();
+            ColdStore::open(cold_store_dir.path()).unwrap().append(0, &data).unwrap();
+
+            let (result, checks) = run_check(&tmp_dir, Some(&cold_store_dir), num_versions);
+            prop_assert!(result.is_err());
+            prop_assert!(checks.contains("cold_store"));
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod check;
 pub mod checkpoint;
 mod common;
 mod examine;
@@ -18,6 +19,8 @@ pub enum Cmd {
 
     Checkpoint(checkpoint::Cmd),
 
+    Check(check::Cmd),
+
     #[clap(subcommand)]
     Ledger(ledger::Cmd),
 
@@ -32,6 +35,7 @@ impl Cmd {
         match self {
             Cmd::StateTree(cmd) => cmd.run(),
             Cmd::Checkpoint(cmd) => cmd.run(),
+            Cmd::Check(cmd) => cmd.run(),
             Cmd::Ledger(cmd) => cmd.run(),
             Cmd::Truncate(cmd) => cmd.run(),
             Cmd::Examine(cmd) => cmd.run(),
@@ -8,6 +8,7 @@
 
 use super::AptosDB;
 use crate::{
+    cold_store::{self, ColdOrHotIter, ColdStore},
     errors::AptosDbError,
     schema::{
         event::EventSchema, event_accumulator::EventAccumulatorSchema,
@@ -40,47 +41,74 @@ use std::{
 #[derive(Debug)]
 pub struct EventStore {
     event_db: Arc<DB>,
+    /// Where the events out of the hot window are, if enabled. Their indexes stay in `event_db`.
+    cold_store: Option<Arc<ColdStore>>,
 }
 
 impl EventStore {
     pub fn new(event_db: Arc<DB>) -> Self {
-        Self { event_db }
+        Self::new_with_cold_store(event_db, None)
+    }
+
+    pub(crate) fn new_with_cold_store(
+        event_db: Arc<DB>,
+        cold_store: Option<Arc<ColdStore>>,
+    ) -> Self {
+        Self {
+            event_db,
+            cold_store,
+        }
     }
 
     /// Get all of the events given a transaction version.
     /// We don't need a proof for this because it's only used to get all events
     /// for a version which can be proved from the root hash of the event tree.
     pub fn get_events_by_version(&self, version: Version) -> Result<Vec<ContractEvent>> {
-        let mut events = vec![];
-
-        let mut iter = self.event_db.iter::<EventSchema>(ReadOptions::default())?;
-        // Grab the first event and then iterate until we get all events for this version.
-        iter.seek(&version)?;
-        while let Some(((ver, index), event)) = iter.next().transpose()? {
-            if ver != version {
-                break;
-            }
-            events.push(event);
-        }
+        cold_store::read_ledger_data(
+            self.cold_store.as_deref(),
+            version,
+            || {
+                let mut events = vec![];
+
+                let mut iter = self.event_db.iter::<EventSchema>(ReadOptions::default())?;
+                // Grab the first event and then iterate until we get all events for this version.
+                iter.seek(&version)?;
+                while let Some(((ver, index), event)) = iter.next().transpose()? {
+                    if ver != version {
+                        break;
+                    }
+                    events.push(event);
+                }
 
-        Ok(events)
+                Ok(events)
+            },
+            |data| Ok(data.events),
+        )
     }
 
     pub fn get_events_by_version_iter(
         &self,
         start_version: Version,
         num_versions: usize,
-    ) -> Result<EventsByVersionIter> {
-        let mut iter = self.event_db.iter::<EventSchema>(Default::default())?;
-        iter.seek(&start_version)?;
-
-        Ok(EventsByVersionIter::new(
-            iter,
+    ) -> Result<impl Iterator<Item = Result<Vec<ContractEvent>>> + '_> {
+        ColdOrHotIter::new(
+            self.cold_store.as_deref(),
             start_version,
-            start_version
-                .checked_add(num_versions as u64)
-                .ok_or_else(|| format_err!("Too many versions requested."))?,
-        ))
+            num_versions,
+            |data| data.events,
+            |start_version, num_versions| {
+                let mut iter = self.event_db.iter::<EventSchema>(Default::default())?;
+                iter.seek(&start_version)?;
+
+                Ok(EventsByVersionIter::new(
+                    iter,
+                    start_version,
+                    start_version
+                        .checked_add(num_versions as u64)
+                        .ok_or_else(|| format_err!("Too many versions requested."))?,
+                ))
+            },
+        )
     }
 
     pub fn get_event_by_version_and_index(
@@ -88,11 +116,26 @@ impl EventStore {
         version: Version,
         index: u64,
     ) -> Result<ContractEvent> {
-        self.event_db
-            .get::<EventSchema>(&(version, index))?
-            .ok_or_else(|| {
-                AptosDbError::NotFound(format!("Event {} of Txn {}", index, version)).into()
-            })
+        cold_store::read_ledger_data(
+            self.cold_store.as_deref(),
+            version,
+            || {
+                self.event_db
+                    .get::<EventSchema>(&(version, index))?
+                    .ok_or_else(|| {
+                        AptosDbError::NotFound(format!("Event {} of Txn {}", index, version)).into()
+                    })
+            },
+            |mut data| {
+                ensure!(
+                    index < data.events.len() as u64,
+                    "Event {} of Txn {} not found in the cold store.",
+                    index,
+                    version
+                );
+                Ok(data.events.swap_remove(index as usize))
+            },
+        )
     }
 
     pub fn get_txn_ver_by_seq_num(&self, event_key: &EventKey, seq_num: u64) -> Result<u64> {
@@ -362,18 +405,18 @@ impl EventStore {
         })
     }
 
-    /// Finds the first event sequence number in a specified stream on which `comp` returns false.
-    /// (assuming the whole stream is partitioned by `comp`)
+    /// Finds the first event sequence number, starting from `begin`, in a specified stream on
+    /// which `comp` returns false. (assuming the whole stream is partitioned by `comp`)
     fn search_for_event_lower_bound<C>(
         &self,
         event_key: &EventKey,
         mut comp: C,
+        mut begin: u64,
         ledger_version: Version,
     ) -> Result<Option<u64>>
     where
         C: FnMut(&ContractEvent) -> Result<bool>,
     {
-        let mut begin = 0u64;
         let mut end = match self.get_latest_sequence_number(ledger_version, event_key)? {
             Some(s) => s
                 .checked_add(1)
@@ -420,6 +463,7 @@ impl EventStore {
                 let new_block_event: NewBlockEvent = event.try_into()?;
                 Ok(new_block_event.proposed_time() < timestamp)
             },
+            0,
             ledger_version,
         )?.ok_or_else(|| format_err!(
             "No new block found beyond timestamp {}, so can't determine the last version before it.",
@@ -440,6 +484,41 @@ impl EventStore {
             .ok_or_else(|| format_err!("A block with non-zero seq num started at version 0."))
     }
 
+    /// Like `get_last_version_before_timestamp`, but only looks at the blocks started after
+    /// `min_version`, e.g. because the events before it are pruned. Returns `None` if the first of
+    /// them already started at or after timestamp, or if none did, in which case the answer is
+    /// unknown.
+    pub(crate) fn get_last_version_before_timestamp_since(
+        &self,
+        timestamp: u64,
+        min_version: Version,
+        ledger_version: Version,
+    ) -> Result<Option<Version>> {
+        let event_key = new_block_event_key();
+        let first_seq = match self.lookup_event_after_version(&event_key, min_version)? {
+            Some((version, _idx, seq)) if version <= ledger_version => seq,
+            _ => return Ok(None),
+        };
+        let seq_at_or_after_ts = self.search_for_event_lower_bound(
+            &event_key,
+            |event| {
+                let new_block_event: NewBlockEvent = event.try_into()?;
+                Ok(new_block_event.proposed_time() < timestamp)
+            },
+            first_seq,
+            ledger_version,
+        )?;
+
+        match seq_at_or_after_ts {
+            Some(seq) if seq > first_seq => {
+                let (version, _idx) = self.lookup_event_by_key(&event_key, seq, ledger_version)?;
+                // The block at `first_seq` started after `min_version`, so this can't be 0.
+                Ok(Some(version - 1))
+            },
+            _ => Ok(None),
+        }
+    }
+
     /// Prunes events by accumulator store for a range of version in [begin, end)
     fn prune_event_accumulator(
         &self,
@@ -333,6 +333,18 @@ fn test_get_last_version_before_timestamp_impl(new_block_events: Vec<(Version, C
                 .unwrap(),
             version - 1,
         );
+        // same, but ignoring the first block, as if pruned
+        let expected = if *version == new_block_events[1].0 {
+            None
+        } else {
+            Some(version - 1)
+        };
+        assert_eq!(
+            store
+                .get_last_version_before_timestamp_since(ts, *first_block_version, ledger_version)
+                .unwrap(),
+            expected,
+        );
 
         last_block_version = *version;
         last_block_ts = ts;
@@ -342,6 +354,16 @@ fn test_get_last_version_before_timestamp_impl(new_block_events: Vec<(Version, C
     assert!(store
         .get_last_version_before_timestamp(last_block_ts + 1, ledger_version)
         .is_err());
+    assert_eq!(
+        store
+            .get_last_version_before_timestamp_since(
+                last_block_ts + 1,
+                *first_block_version,
+                ledger_version
+            )
+            .unwrap(),
+        None,
+    );
 }
 
 proptest! {
@@ -49,7 +49,7 @@ use aptos_types::{
 use arc_swap::ArcSwapOption;
 use dashmap::DashMap;
 use itertools::zip_eq;
-use move_core_types::move_resource::MoveStructType;
+use move_core_types::{language_storage::TypeTag, move_resource::MoveStructType};
 use std::{borrow::Borrow, collections::HashMap, mem::swap, sync::Arc};
 
 /// Alternate implementation of [crate::state_store::buffered_state::BufferedState] for use with consensus-only-perf-test feature.
@@ -854,6 +854,22 @@ impl DbReader for FakeAptosDB {
         self.inner.indexer_enabled()
     }
 
+    fn get_events_by_type(
+        &self,
+        type_tag: &TypeTag,
+        start_version: Version,
+        end_version: Version,
+        limit: u64,
+        ledger_version: Version,
+    ) -> Result<Vec<EventWithVersion>> {
+        self.inner
+            .get_events_by_type(type_tag, start_version, end_version, limit, ledger_version)
+    }
+
+    fn event_type_index_enabled(&self) -> bool {
+        self.inner.event_type_index_enabled()
+    }
+
     fn get_state_storage_usage(&self, version: Option<Version>) -> Result<StateStorageUsage> {
         self.inner.get_state_storage_usage(version)
     }
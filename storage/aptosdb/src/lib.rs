@@ -23,6 +23,7 @@ pub mod schema;
 pub mod state_restore;
 pub mod utils;
 
+mod cold_store;
 mod db_options;
 mod event_store;
 mod ledger_db;
@@ -43,6 +44,7 @@ pub mod db_debugger;
 
 use crate::{
     backup::{backup_handler::BackupHandler, restore_handler::RestoreHandler, restore_utils},
+    cold_store::{ColdStore, ColdStoreMigrator},
     db_metadata::{DbMetadataKey, DbMetadataSchema, DbMetadataValue},
     db_options::{ledger_db_column_families, state_merkle_db_column_families},
     errors::AptosDbError,
@@ -64,7 +66,7 @@ use crate::{
 };
 use anyhow::{bail, ensure, Result};
 use aptos_config::config::{
-    PrunerConfig, RocksdbConfig, RocksdbConfigs, NO_OP_STORAGE_PRUNER_CONFIG,
+    ColdStorageConfig, PrunerConfig, RocksdbConfigs, NO_OP_STORAGE_PRUNER_CONFIG,
 };
 #[cfg(any(test, feature = "fuzzing"))]
 use aptos_config::config::{
@@ -111,6 +113,7 @@ use aptos_types::{
 };
 use aptos_vm::data_cache::AsMoveResolver;
 use arr_macro::arr;
+use move_core_types::language_storage::TypeTag;
 use move_resource_viewer::MoveValueAnnotator;
 use once_cell::sync::Lazy;
 use rayon::prelude::*;
@@ -280,7 +283,8 @@ pub struct AptosDB {
     ledger_pruner: LedgerPrunerManager,
     _rocksdb_property_reporter: RocksdbPropertyReporter,
     ledger_commit_lock: std::sync::Mutex<()>,
-    indexer: Option<Indexer>,
+    indexer: Option<Arc<Indexer>>,
+    cold_store_migrator: Option<ColdStoreMigrator>,
     skip_index_and_usage: bool,
 }
 
@@ -294,20 +298,27 @@ impl AptosDB {
         hack_for_tests: bool,
         empty_buffered_state_for_restore: bool,
         skip_index_and_usage: bool,
+        indexer: Option<Arc<Indexer>>,
+        cold_store: Option<Arc<ColdStore>>,
     ) -> Self {
         let ledger_db = Arc::new(ledger_db);
         let state_merkle_db = Arc::new(state_merkle_db);
         let state_kv_db = Arc::new(state_kv_db);
         let state_merkle_pruner = StateMerklePrunerManager::new(
             Arc::clone(&state_merkle_db),
+            Arc::clone(&ledger_db),
             pruner_config.state_merkle_pruner_config,
         );
         let epoch_snapshot_pruner = StateMerklePrunerManager::new(
             Arc::clone(&state_merkle_db),
+            Arc::clone(&ledger_db),
             pruner_config.epoch_snapshot_pruner_config.into(),
         );
-        let state_kv_pruner =
-            StateKvPrunerManager::new(Arc::clone(&state_kv_db), pruner_config.ledger_pruner_config);
+        let state_kv_pruner = StateKvPrunerManager::new(
+            Arc::clone(&state_kv_db),
+            Arc::clone(&ledger_db),
+            pruner_config.ledger_pruner_config,
+        );
         let state_store = Arc::new(StateStore::new(
             Arc::clone(&ledger_db),
             Arc::clone(&state_merkle_db),
@@ -321,17 +332,26 @@ impl AptosDB {
             skip_index_and_usage,
         ));
 
-        let ledger_pruner =
-            LedgerPrunerManager::new(Arc::clone(&ledger_db), pruner_config.ledger_pruner_config);
+        let ledger_pruner = LedgerPrunerManager::new(
+            Arc::clone(&ledger_db),
+            pruner_config.ledger_pruner_config,
+            indexer.clone(),
+        );
 
         AptosDB {
             ledger_db: Arc::clone(&ledger_db),
             state_merkle_db: Arc::clone(&state_merkle_db),
             state_kv_db: Arc::clone(&state_kv_db),
-            event_store: Arc::new(EventStore::new(ledger_db.event_db_arc())),
+            event_store: Arc::new(EventStore::new_with_cold_store(
+                ledger_db.event_db_arc(),
+                cold_store.clone(),
+            )),
             ledger_store: Arc::new(LedgerStore::new(Arc::clone(&ledger_db))),
             state_store,
-            transaction_store: Arc::new(TransactionStore::new(Arc::clone(&ledger_db))),
+            transaction_store: Arc::new(TransactionStore::new_with_cold_store(
+                Arc::clone(&ledger_db),
+                cold_store,
+            )),
             ledger_pruner,
             // TODO(grao): Include other DBs.
             _rocksdb_property_reporter: RocksdbPropertyReporter::new(
@@ -339,7 +359,8 @@ impl AptosDB {
                 Arc::clone(&state_merkle_db),
             ),
             ledger_commit_lock: std::sync::Mutex::new(()),
-            indexer: None,
+            indexer,
+            cold_store_migrator: None,
             skip_index_and_usage,
         }
     }
@@ -350,14 +371,21 @@ impl AptosDB {
         pruner_config: PrunerConfig,
         rocksdb_configs: RocksdbConfigs,
         enable_indexer: bool,
+        enable_event_type_index: bool,
         buffered_state_target_items: usize,
         max_num_nodes_per_lru_cache_shard: usize,
         empty_buffered_state_for_restore: bool,
+        cold_storage_config: ColdStorageConfig,
     ) -> Result<Self> {
         ensure!(
             pruner_config.eq(&NO_OP_STORAGE_PRUNER_CONFIG) || !readonly,
             "Do not set prune_window when opening readonly.",
         );
+        // The ledger pruner reads the events it prunes, which it can't once they're migrated.
+        ensure!(
+            !cold_storage_config.enable || !pruner_config.ledger_pruner_config.enable,
+            "Do not enable the ledger pruner along with the cold storage.",
+        );
 
         let (ledger_db, state_merkle_db, state_kv_db) = Self::open_dbs(
             db_root_path.as_ref(),
@@ -366,6 +394,25 @@ impl AptosDB {
             max_num_nodes_per_lru_cache_shard,
         )?;
 
+        // The indexer is opened first, as the ledger pruner prunes the event type index too.
+        let indexer = if !readonly && enable_indexer {
+            Some(Arc::new(Indexer::open(
+                &db_root_path,
+                rocksdb_configs.index_db_config,
+                enable_event_type_index,
+            )?))
+        } else {
+            None
+        };
+        // The cold store too, as all the reads of the ledger data go through it.
+        let cold_store = if cold_storage_config.enable {
+            Some(Arc::new(ColdStore::open(
+                &db_root_path.as_ref().join(&cold_storage_config.dir),
+            )?))
+        } else {
+            None
+        };
+
         let mut myself = Self::new_with_dbs(
             ledger_db,
             state_merkle_db,
@@ -375,10 +422,18 @@ impl AptosDB {
             readonly,
             empty_buffered_state_for_restore,
             rocksdb_configs.skip_index_and_usage,
+            indexer,
+            cold_store.clone(),
         );
 
-        if !readonly && enable_indexer {
-            myself.open_indexer(db_root_path, rocksdb_configs.index_db_config)?;
+        if let Some(cold_store) = cold_store {
+            if !readonly {
+                myself.start_cold_store_migrator(cold_store, &cold_storage_config)?;
+            }
+        }
+
+        if let Some(indexer) = &myself.indexer {
+            myself.catch_up_indexer(indexer)?;
         }
 
         Ok(myself)
@@ -390,8 +445,10 @@ impl AptosDB {
         pruner_config: PrunerConfig,
         rocksdb_configs: RocksdbConfigs,
         enable_indexer: bool,
+        enable_event_type_index: bool,
         buffered_state_target_items: usize,
         max_num_nodes_per_lru_cache_shard: usize,
+        cold_storage_config: ColdStorageConfig,
     ) -> Result<Self> {
         Self::open_internal(
             db_root_path,
@@ -399,9 +456,11 @@ impl AptosDB {
             pruner_config,
             rocksdb_configs,
             enable_indexer,
+            enable_event_type_index,
             buffered_state_target_items,
             max_num_nodes_per_lru_cache_shard,
             false,
+            cold_storage_config,
         )
     }
 
@@ -411,8 +470,10 @@ impl AptosDB {
         pruner_config: PrunerConfig,
         rocksdb_configs: RocksdbConfigs,
         enable_indexer: bool,
+        enable_event_type_index: bool,
         buffered_state_target_items: usize,
         max_num_nodes_per_lru_cache_shard: usize,
+        cold_storage_config: ColdStorageConfig,
     ) -> Result<Self> {
         Self::open_internal(
             db_root_path,
@@ -420,9 +481,11 @@ impl AptosDB {
             pruner_config,
             rocksdb_configs,
             enable_indexer,
+            enable_event_type_index,
             buffered_state_target_items,
             max_num_nodes_per_lru_cache_shard,
             true,
+            cold_storage_config,
         )
     }
 
@@ -449,12 +512,20 @@ impl AptosDB {
         Ok((ledger_db, state_merkle_db, state_kv_db))
     }
 
-    fn open_indexer(
+    fn start_cold_store_migrator(
         &mut self,
-        db_root_path: impl AsRef<Path>,
-        rocksdb_config: RocksdbConfig,
+        cold_store: Arc<ColdStore>,
+        config: &ColdStorageConfig,
     ) -> Result<()> {
-        let indexer = Indexer::open(&db_root_path, rocksdb_config)?;
+        let migrator = ColdStoreMigrator::new(Arc::clone(&self.ledger_db), cold_store, config)?;
+        if let Ok(latest_version) = self.get_latest_version() {
+            migrator.set_latest_version(latest_version);
+        }
+        self.cold_store_migrator = Some(migrator);
+        Ok(())
+    }
+
+    fn catch_up_indexer(&self, indexer: &Indexer) -> Result<()> {
         let ledger_next_version = self.get_latest_version().map_or(0, |v| v + 1);
         info!(
             indexer_next_version = indexer.next_version(),
@@ -486,7 +557,34 @@ impl AptosDB {
         }
         info!("AptosDB Indexer caught up.");
 
-        self.indexer = Some(indexer);
+        if let Some(event_type_index_next_version) = indexer.event_type_index_next_version() {
+            // Events already pruned can't be indexed anymore, and can't be served either. The
+            // progress is persisted before indexing anything, so the index never has a gap.
+            let mut next_version = std::cmp::max(
+                event_type_index_next_version,
+                self.ledger_pruner.get_min_readable_version(),
+            );
+            indexer.skip_event_type_index_to(next_version)?;
+            const EVENTS_BATCH_SIZE: Version = 10000;
+            while next_version < ledger_next_version {
+                info!(
+                    next_version = next_version,
+                    "AptosDB event type index catching up."
+                );
+                let num_versions =
+                    std::cmp::min(ledger_next_version - next_version, EVENTS_BATCH_SIZE);
+                let events = self
+                    .event_store
+                    .get_events_by_version_iter(next_version, num_versions as usize)?
+                    .collect::<Result<Vec<_>>>()?;
+                let events_ref: Vec<_> = events.iter().map(Vec::as_slice).collect();
+                indexer.index_events(next_version, &events_ref)?;
+
+                next_version += num_versions;
+            }
+            info!("AptosDB event type index caught up.");
+        }
+
         Ok(())
     }
 
@@ -504,8 +602,10 @@ impl AptosDB {
             NO_OP_STORAGE_PRUNER_CONFIG, /* pruner */
             RocksdbConfigs::default(),
             enable_indexer,
+            enable_indexer, /* enable_event_type_index */
             buffered_state_target_items,
             max_num_nodes_per_lru_cache_shard,
+            ColdStorageConfig::default(),
         )
         .expect("Unable to open AptosDB")
     }
@@ -1227,6 +1327,9 @@ impl AptosDB {
             self.state_store
                 .state_kv_pruner
                 .maybe_set_pruner_target_db_version(last_version);
+            if let Some(cold_store_migrator) = &self.cold_store_migrator {
+                cold_store_migrator.set_latest_version(last_version);
+            }
         }
 
         // Note: this must happen after txns have been saved to db because types can be newly
@@ -1240,6 +1343,11 @@ impl AptosDB {
                 .map(|txn| txn.borrow().write_set())
                 .collect();
             indexer.index(self.state_store.clone(), first_version, &write_sets)?;
+            let events: Vec<_> = txns_to_commit
+                .iter()
+                .map(|txn| txn.borrow().events())
+                .collect();
+            indexer.index_events(first_version, &events)?;
         }
 
         // Once everything is successfully persisted, update the latest in-memory ledger info.
@@ -1981,6 +2089,43 @@ impl DbReader for AptosDB {
         self.indexer.is_some()
     }
 
+    fn get_events_by_type(
+        &self,
+        type_tag: &TypeTag,
+        start_version: Version,
+        end_version: Version,
+        limit: u64,
+        ledger_version: Version,
+    ) -> Result<Vec<EventWithVersion>> {
+        gauged_api("get_events_by_type", || {
+            error_if_too_many_requested(limit, MAX_REQUEST_LIMIT)?;
+            self.error_if_ledger_pruned("Event", start_version)?;
+            let indexer = match &self.indexer {
+                Some(indexer) => indexer,
+                None => bail!("Indexer not enabled."),
+            };
+
+            let end_version = std::cmp::min(end_version, ledger_version + 1);
+            indexer
+                .get_events_by_type(type_tag, start_version, end_version, limit as usize)?
+                .into_iter()
+                .map(|(version, index)| {
+                    let event = self
+                        .event_store
+                        .get_event_by_version_and_index(version, index)?;
+                    Ok(EventWithVersion::new(version, event))
+                })
+                .collect()
+        })
+    }
+
+    /// Returns whether the event type index of the indexer DB has been enabled or not
+    fn event_type_index_enabled(&self) -> bool {
+        self.indexer.as_ref().map_or(false, |indexer| {
+            indexer.event_type_index_next_version().is_some()
+        })
+    }
+
     fn get_state_storage_usage(&self, version: Option<Version>) -> Result<StateStorageUsage> {
         gauged_api("get_state_storage_usage", || {
             if let Some(v) = version {
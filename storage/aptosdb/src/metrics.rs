@@ -103,6 +103,15 @@ pub static PRUNER_BATCH_SIZE: Lazy<IntGaugeVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// The versions before this one are in the cold tier of the ledger data.
+pub static COLD_STORE_NEXT_VERSION: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_storage_cold_store_next_version",
+        "Next version to migrate to the cold tier of the ledger data"
+    )
+    .unwrap()
+});
+
 pub static API_LATENCY_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
     register_histogram_vec!(
         // metric name
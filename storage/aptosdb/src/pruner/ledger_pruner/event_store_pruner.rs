@@ -7,6 +7,7 @@ use crate::{
     EventStore,
 };
 use anyhow::Result;
+use aptos_db_indexer::Indexer;
 use aptos_logger::info;
 use aptos_schemadb::{SchemaBatch, DB};
 use aptos_types::transaction::Version;
@@ -16,10 +17,26 @@ use std::sync::Arc;
 pub struct EventStorePruner {
     event_store: Arc<EventStore>,
     event_db: Arc<DB>,
+    indexer: Option<Arc<Indexer>>,
 }
 
 impl DBSubPruner for EventStorePruner {
     fn prune(&self, current_progress: Version, target_version: Version) -> Result<()> {
+        if let Some(indexer) = &self.indexer {
+            // The index lives in another DB, so it's pruned first, otherwise a crash in between
+            // would leave index entries of events that are already gone. Pruning it again after
+            // a restart is harmless.
+            let events = self
+                .event_store
+                .get_events_by_version_iter(
+                    current_progress,
+                    (target_version - current_progress) as usize,
+                )?
+                .collect::<Result<Vec<_>>>()?;
+            let events_ref: Vec<_> = events.iter().map(Vec::as_slice).collect();
+            indexer.prune_events(current_progress, &events_ref)?;
+        }
+
         let batch = SchemaBatch::new();
         self.event_store
             .prune_events(current_progress, target_version, &batch)?;
@@ -35,6 +52,7 @@ impl EventStorePruner {
     pub(in crate::pruner) fn new(
         event_store: Arc<EventStore>,
         event_db: Arc<DB>,
+        indexer: Option<Arc<Indexer>>,
         metadata_progress: Version,
     ) -> Result<Self> {
         let progress = get_or_initialize_subpruner_progress(
@@ -46,6 +64,7 @@ impl EventStorePruner {
         let myself = EventStorePruner {
             event_store,
             event_db,
+            indexer,
         };
 
         info!(
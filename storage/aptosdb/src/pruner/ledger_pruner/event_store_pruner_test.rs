@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{AptosDB, EventStore, LedgerPrunerManager, PrunerManager};
-use aptos_config::config::LedgerPrunerConfig;
+use aptos_config::config::{LedgerPrunerConfig, RocksdbConfig};
+use aptos_db_indexer::Indexer;
 use aptos_proptest_helpers::Index;
 use aptos_schemadb::SchemaBatch;
 use aptos_temppath::TempPath;
@@ -72,12 +73,28 @@ fn verify_event_store_pruner(events: Vec<Vec<ContractEvent>>) {
     }
     aptos_db.ledger_db.event_db().write_schemas(batch).unwrap();
 
-    let pruner = LedgerPrunerManager::new(Arc::clone(&aptos_db.ledger_db), LedgerPrunerConfig {
-        enable: true,
-        prune_window: 0,
-        batch_size: 1,
-        user_pruning_window_offset: 0,
-    });
+    let indexer = Arc::new(
+        Indexer::open(
+            &tmp_dir,
+            RocksdbConfig::default(),
+            true, /* enable_event_type_index */
+        )
+        .unwrap(),
+    );
+    let events_ref: Vec<_> = events.iter().map(Vec::as_slice).collect();
+    indexer.index_events(0, &events_ref).unwrap();
+
+    let pruner = LedgerPrunerManager::new(
+        Arc::clone(&aptos_db.ledger_db),
+        LedgerPrunerConfig {
+            enable: true,
+            prune_window: 0,
+            batch_size: 1,
+            user_pruning_window_offset: 0,
+            retention: None,
+        },
+        Some(Arc::clone(&indexer)),
+    );
     // start pruning events batches of size 2 and verify transactions have been pruned from DB
     for i in (0..=num_versions).step_by(2) {
         pruner
@@ -88,12 +105,14 @@ fn verify_event_store_pruner(events: Vec<Vec<ContractEvent>>) {
             verify_events_not_in_store(j as u64, event_store);
             verify_event_by_key_not_in_store(&events, j as u64, event_store);
             verify_event_by_version_not_in_store(&events, j as u64, event_store);
+            verify_event_by_type_not_in_index(&events, j as u64, &indexer);
         }
         // ensure all other events are valid in DB
         for j in i..num_versions {
             verify_events_in_store(&events, j as u64, event_store);
             verify_event_by_key_in_store(&events, j as u64, event_store);
             verify_event_by_version_in_store(&events, j as u64, event_store);
+            verify_event_by_type_in_index(&events, j as u64, &indexer);
         }
     }
 }
@@ -182,6 +201,32 @@ fn verify_event_by_version_in_store(
     }
 }
 
+fn verify_event_by_type_not_in_index(
+    events: &[Vec<ContractEvent>],
+    version: Version,
+    indexer: &Indexer,
+) {
+    for event in events.get(version as usize).unwrap() {
+        assert!(indexer
+            .get_events_by_type(event.type_tag(), version, version + 1, usize::MAX)
+            .unwrap()
+            .is_empty());
+    }
+}
+
+fn verify_event_by_type_in_index(
+    events: &[Vec<ContractEvent>],
+    version: Version,
+    indexer: &Indexer,
+) {
+    for (index, event) in events.get(version as usize).unwrap().iter().enumerate() {
+        assert!(indexer
+            .get_events_by_type(event.type_tag(), version, version + 1, usize::MAX)
+            .unwrap()
+            .contains(&(version, index as u64)));
+    }
+}
+
 fn verify_events_not_in_store(version: Version, event_store: &Arc<EventStore>) {
     assert!(event_store
         .get_events_by_version(version)
@@ -5,12 +5,16 @@ use crate::{
     ledger_db::LedgerDb,
     metrics::{PRUNER_BATCH_SIZE, PRUNER_VERSIONS, PRUNER_WINDOW},
     pruner::{
-        ledger_pruner::LedgerPruner, pruner_manager::PrunerManager, pruner_utils,
+        ledger_pruner::LedgerPruner,
+        pruner_manager::PrunerManager,
+        pruner_utils,
         pruner_worker::PrunerWorker,
+        retention::{self, RetentionResolver},
     },
 };
 use anyhow::Result;
 use aptos_config::config::LedgerPrunerConfig;
+use aptos_db_indexer::Indexer;
 use aptos_infallible::Mutex;
 use aptos_types::transaction::{AtomicVersion, Version};
 use std::sync::{atomic::Ordering, Arc};
@@ -31,6 +35,8 @@ pub(crate) struct LedgerPrunerManager {
     user_pruning_window_offset: u64,
     /// The minimal readable version for the ledger data.
     min_readable_version: AtomicVersion,
+    /// Keeps more than `prune_window` versions, if configured.
+    retention: Option<RetentionResolver>,
 }
 
 impl PrunerManager for LedgerPrunerManager {
@@ -54,7 +60,17 @@ impl PrunerManager for LedgerPrunerManager {
             let adjusted_window = self
                 .prune_window
                 .saturating_sub(self.user_pruning_window_offset);
-            let adjusted_cutoff = self.latest_version.lock().saturating_sub(adjusted_window);
+            let mut adjusted_cutoff = self.latest_version.lock().saturating_sub(adjusted_window);
+            if let Some(retained_version) = self
+                .retention
+                .as_ref()
+                .and_then(|retention| retention.last_resolved_version())
+            {
+                adjusted_cutoff = std::cmp::min(
+                    adjusted_cutoff,
+                    retained_version.saturating_add(self.user_pruning_window_offset),
+                );
+            }
             std::cmp::max(min_version, adjusted_cutoff)
         } else {
             min_version
@@ -104,11 +120,17 @@ impl PrunerManager for LedgerPrunerManager {
 
 impl LedgerPrunerManager {
     /// Creates a worker thread that waits on a channel for pruning commands.
-    pub fn new(ledger_db: Arc<LedgerDb>, ledger_pruner_config: LedgerPrunerConfig) -> Self {
+    /// `indexer`, if given, has its event type index pruned together with the events.
+    pub fn new(
+        ledger_db: Arc<LedgerDb>,
+        ledger_pruner_config: LedgerPrunerConfig,
+        indexer: Option<Arc<Indexer>>,
+    ) -> Self {
         let pruner_worker = if ledger_pruner_config.enable {
             Some(Self::init_pruner(
                 Arc::clone(&ledger_db),
                 ledger_pruner_config,
+                indexer,
             ))
         } else {
             None
@@ -116,6 +138,9 @@ impl LedgerPrunerManager {
 
         let min_readable_version =
             pruner_utils::get_ledger_pruner_progress(&ledger_db).expect("Must succeed.");
+        let retention = ledger_pruner_config.retention.map(|retention| {
+            RetentionResolver::new("ledger_pruner", retention, Arc::clone(&ledger_db))
+        });
 
         PRUNER_VERSIONS
             .with_label_values(&["ledger_pruner", "min_readable"])
@@ -129,15 +154,18 @@ impl LedgerPrunerManager {
             latest_version: Arc::new(Mutex::new(min_readable_version)),
             user_pruning_window_offset: ledger_pruner_config.user_pruning_window_offset,
             min_readable_version: AtomicVersion::new(min_readable_version),
+            retention,
         }
     }
 
     fn init_pruner(
         ledger_db: Arc<LedgerDb>,
         ledger_pruner_config: LedgerPrunerConfig,
+        indexer: Option<Arc<Indexer>>,
     ) -> PrunerWorker {
-        let pruner =
-            Arc::new(LedgerPruner::new(ledger_db).expect("Failed to create ledger pruner."));
+        let pruner = Arc::new(
+            LedgerPruner::new(ledger_db, indexer).expect("Failed to create ledger pruner."),
+        );
 
         PRUNER_WINDOW
             .with_label_values(&["ledger_pruner"])
@@ -152,7 +180,17 @@ impl LedgerPrunerManager {
 
     fn set_pruner_target_db_version(&self, latest_version: Version) {
         assert!(self.pruner_worker.is_some());
-        let min_readable_version = latest_version.saturating_sub(self.prune_window);
+        let Some(min_readable_version) = retention::get_target_min_readable_version(
+            self.retention.as_ref(),
+            latest_version,
+            self.prune_window,
+        ) else {
+            return;
+        };
+        // The retention can hold the pruner back for longer than the prune window.
+        if min_readable_version < self.get_min_readable_version() + self.pruning_batch_size as u64 {
+            return;
+        }
         self.min_readable_version
             .store(min_readable_version, Ordering::SeqCst);
         self.pruner_worker
@@ -25,6 +25,7 @@ use crate::{
     EventStore, TransactionStore,
 };
 use anyhow::Result;
+use aptos_db_indexer::Indexer;
 use aptos_logger::info;
 use aptos_types::transaction::{AtomicVersion, Version};
 use std::{
@@ -104,7 +105,7 @@ impl DBPruner for LedgerPruner {
 }
 
 impl LedgerPruner {
-    pub fn new(ledger_db: Arc<LedgerDb>) -> Result<Self> {
+    pub fn new(ledger_db: Arc<LedgerDb>, indexer: Option<Arc<Indexer>>) -> Result<Self> {
         info!(name = LEDGER_PRUNER_NAME, "Initializing...");
 
         let ledger_metadata_pruner = Box::new(
@@ -124,6 +125,7 @@ impl LedgerPruner {
         let event_store_pruner = Box::new(EventStorePruner::new(
             Arc::new(EventStore::new(ledger_db.event_db_arc())),
             ledger_db.event_db_arc(),
+            indexer,
             metadata_progress,
         )?);
         let transaction_accumulator_pruner = Box::new(TransactionAccumulatorPruner::new(
@@ -0,0 +1,303 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module resolves the retention policies of the pruners, which are in wall-clock time or
+//! epochs, to the versions the pruners prune up to.
+
+use crate::{
+    ledger_db::LedgerDb, metrics::PRUNER_VERSIONS, pruner::pruner_utils,
+    schema::epoch_by_version::EpochByVersionSchema, EventStore,
+};
+use anyhow::Result;
+use aptos_config::config::PrunerRetention;
+use aptos_infallible::Mutex;
+use aptos_logger::{
+    prelude::{sample, SampleRate},
+    warn,
+};
+use aptos_schemadb::ReadOptions;
+use aptos_types::transaction::Version;
+use std::{cmp::min, sync::Arc, time::Duration};
+
+/// Resolving a retention takes a few DB reads, so it's only redone once the latest version moved
+/// by this many versions.
+const RESOLUTION_INTERVAL: Version = 1_000;
+
+/// Resolves a `PrunerRetention` through the block timestamps and the epoch ending versions.
+pub(crate) struct RetentionResolver {
+    pruner_name: &'static str,
+    retention: PrunerRetention,
+    ledger_db: Arc<LedgerDb>,
+    event_store: EventStore,
+    /// The latest version at the last resolution, and the first version to keep it resolved to.
+    last_resolution: Mutex<Option<(Version, Version)>>,
+}
+
+impl RetentionResolver {
+    pub fn new(
+        pruner_name: &'static str,
+        retention: PrunerRetention,
+        ledger_db: Arc<LedgerDb>,
+    ) -> Self {
+        Self {
+            pruner_name,
+            retention,
+            event_store: EventStore::new(ledger_db.event_db_arc()),
+            ledger_db,
+            last_resolution: Mutex::new(None),
+        }
+    }
+
+    /// The first version to keep as of the last resolution, if any.
+    pub fn last_resolved_version(&self) -> Option<Version> {
+        self.last_resolution.lock().map(|(_, version)| version)
+    }
+
+    /// Returns the first version to keep as of `latest_version`, or `None` if the retention can't
+    /// be resolved, in which case nothing should be pruned.
+    pub fn first_version_to_keep(&self, latest_version: Version) -> Option<Version> {
+        let mut last_resolution = self.last_resolution.lock();
+        if let Some((resolved_at, version)) = *last_resolution {
+            if latest_version < resolved_at.saturating_add(RESOLUTION_INTERVAL) {
+                return Some(version);
+            }
+        }
+
+        match self.resolve(latest_version) {
+            Ok(version) => {
+                PRUNER_VERSIONS
+                    .with_label_values(&[self.pruner_name, "retention"])
+                    .set(version as i64);
+                *last_resolution = Some((latest_version, version));
+                Some(version)
+            },
+            Err(err) => {
+                sample!(
+                    SampleRate::Duration(Duration::from_secs(60)),
+                    warn!(
+                        pruner_name = self.pruner_name,
+                        error = ?err,
+                        "Failed to resolve the pruner retention, not pruning."
+                    )
+                );
+                None
+            },
+        }
+    }
+
+    fn resolve(&self, latest_version: Version) -> Result<Version> {
+        match self.retention {
+            PrunerRetention::DurationSecs(secs) => {
+                let (_, latest_block) = self.event_store.get_block_metadata(latest_version)?;
+                let timestamp = latest_block
+                    .proposed_time()
+                    .saturating_sub(secs.saturating_mul(1_000_000));
+                // The block events before the ledger pruner progress are gone. If the timestamp
+                // is before the first block left, everything left is kept.
+                let min_version = pruner_utils::get_ledger_pruner_progress(&self.ledger_db)?;
+                Ok(self
+                    .event_store
+                    .get_last_version_before_timestamp_since(
+                        timestamp,
+                        min_version,
+                        latest_version,
+                    )?
+                    .map_or(0, |version| version + 1))
+            },
+            PrunerRetention::Epochs(num_epochs) => {
+                let mut iter = self
+                    .ledger_db
+                    .metadata_db()
+                    .rev_iter::<EpochByVersionSchema>(ReadOptions::default())?;
+                // An epoch ending at the latest version is still the current one.
+                iter.seek_for_prev(&latest_version.saturating_sub(1))?;
+                // The end of the epoch right before the oldest one to keep.
+                let previous_epoch_end = iter.nth(num_epochs.saturating_sub(1) as usize);
+                Ok(match previous_epoch_end.transpose()? {
+                    Some((epoch_end_version, _epoch)) => epoch_end_version + 1,
+                    None => 0,
+                })
+            },
+        }
+    }
+}
+
+/// Returns the version a pruner is to prune up to: `prune_window` versions behind
+/// `latest_version`, or further behind to keep what the retention asks for. `None` if the
+/// retention can't be resolved.
+pub(crate) fn get_target_min_readable_version(
+    retention: Option<&RetentionResolver>,
+    latest_version: Version,
+    prune_window: Version,
+) -> Option<Version> {
+    let window_target = latest_version.saturating_sub(prune_window);
+    match retention {
+        Some(retention) => retention
+            .first_version_to_keep(latest_version)
+            .map(|version| min(version, window_target)),
+        None => Some(window_target),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AptosDB, PrunerManager, StateKvPrunerManager};
+    use aptos_config::config::LedgerPrunerConfig;
+    use aptos_schemadb::SchemaBatch;
+    use aptos_temppath::TempPath;
+    use aptos_types::{
+        account_address::AccountAddress,
+        account_config::{new_block_event_key, NewBlockEvent},
+        contract_event::ContractEvent,
+    };
+    use move_core_types::{language_storage::TypeTag, move_resource::MoveStructType};
+
+    fn put_epoch_endings(db: &AptosDB, epoch_endings: &[Version]) {
+        for (epoch, version) in epoch_endings.iter().enumerate() {
+            db.ledger_db
+                .metadata_db()
+                .put::<EpochByVersionSchema>(version, &(epoch as u64))
+                .unwrap();
+        }
+    }
+
+    /// Puts a block started at each of the versions, proposed at the given time in seconds.
+    fn put_blocks(db: &AptosDB, blocks: &[(Version, u64)]) {
+        let batch = SchemaBatch::new();
+        for (seq, (version, timestamp_secs)) in blocks.iter().enumerate() {
+            let new_block_event = NewBlockEvent::new(
+                AccountAddress::ZERO,
+                0,                   // epoch
+                seq as u64,          // round
+                seq as u64,          // height
+                vec![],              // prev block voters
+                AccountAddress::ONE, // proposer
+                Vec::new(),          // failed_proposers
+                timestamp_secs * 1_000_000,
+            );
+            let event = ContractEvent::new(
+                new_block_event_key(),
+                seq as u64,
+                TypeTag::Struct(Box::new(NewBlockEvent::struct_tag())),
+                bcs::to_bytes(&new_block_event).unwrap(),
+            );
+            db.event_store
+                .put_events(*version, &[event], /*skip_index=*/ false, &batch)
+                .unwrap();
+        }
+        db.ledger_db.event_db().write_schemas(batch).unwrap();
+    }
+
+    fn first_version_to_keep(
+        db: &AptosDB,
+        retention: PrunerRetention,
+        latest_version: Version,
+    ) -> Option<Version> {
+        RetentionResolver::new("test", retention, Arc::clone(&db.ledger_db))
+            .first_version_to_keep(latest_version)
+    }
+
+    #[test]
+    fn test_resolve_epochs() {
+        let tmp_dir = TempPath::new();
+        let db = AptosDB::new_for_test(&tmp_dir);
+        put_epoch_endings(&db, &[9, 19, 29]);
+
+        // The current epoch, started at version 30, hasn't ended yet.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(1), 35),
+            Some(30)
+        );
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(2), 35),
+            Some(20)
+        );
+        // An epoch ending at the latest version is still the current one.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(1), 29),
+            Some(20)
+        );
+        // Rejected by the config sanitizer, but the current epoch is kept nonetheless.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(0), 35),
+            Some(30)
+        );
+        // More epochs than there are, everything is kept.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(4), 35),
+            Some(0)
+        );
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::Epochs(100), 35),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_resolve_duration() {
+        let tmp_dir = TempPath::new();
+        let db = AptosDB::new_for_test(&tmp_dir);
+        put_blocks(&db, &[(10, 100), (20, 200), (30, 300)]);
+
+        // Keeps the blocks proposed at or after 150s.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::DurationSecs(150), 35),
+            Some(20)
+        );
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::DurationSecs(0), 35),
+            Some(30)
+        );
+        // The duration reaches before the first block, everything is kept.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::DurationSecs(250), 35),
+            Some(0)
+        );
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::DurationSecs(u64::MAX), 35),
+            Some(0)
+        );
+        // There's no block yet to count from, nothing is to be pruned.
+        assert_eq!(
+            first_version_to_keep(&db, PrunerRetention::DurationSecs(150), 5),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pruner_target_never_passes_retention() {
+        let tmp_dir = TempPath::new();
+        let db = AptosDB::new_for_test(&tmp_dir);
+        put_epoch_endings(&db, &[9, 19]);
+        let pruner = StateKvPrunerManager::new(
+            Arc::clone(&db.state_kv_db),
+            Arc::clone(&db.ledger_db),
+            LedgerPrunerConfig {
+                enable: true,
+                prune_window: 0,
+                batch_size: 1,
+                user_pruning_window_offset: 0,
+                retention: Some(PrunerRetention::Epochs(1)),
+            },
+        );
+
+        // The prune window alone would prune up to the latest version.
+        pruner
+            .wake_and_wait_pruner(25 /* latest_version */)
+            .unwrap();
+        assert_eq!(pruner.get_min_readable_version(), 20);
+
+        // No epoch ended since, so it stays there however far the latest version goes.
+        pruner
+            .wake_and_wait_pruner(25 + RESOLUTION_INTERVAL /* latest_version */)
+            .unwrap();
+        assert_eq!(pruner.get_min_readable_version(), 20);
+
+        put_epoch_endings(&db, &[9, 19, 1999]);
+        pruner
+            .wake_and_wait_pruner(25 + 3 * RESOLUTION_INTERVAL /* latest_version */)
+            .unwrap();
+        assert_eq!(pruner.get_min_readable_version(), 2000);
+    }
+}
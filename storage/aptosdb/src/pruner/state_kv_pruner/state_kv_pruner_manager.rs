@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    ledger_db::LedgerDb,
     metrics::{PRUNER_BATCH_SIZE, PRUNER_VERSIONS, PRUNER_WINDOW},
     pruner::{
-        pruner_manager::PrunerManager, pruner_utils, pruner_worker::PrunerWorker,
+        pruner_manager::PrunerManager,
+        pruner_utils,
+        pruner_worker::PrunerWorker,
+        retention::{self, RetentionResolver},
         state_kv_pruner::StateKvPruner,
     },
     state_kv_db::StateKvDb,
@@ -25,6 +29,8 @@ pub(crate) struct StateKvPrunerManager {
     pruning_batch_size: usize,
     /// The minimal readable version for the ledger data.
     min_readable_version: AtomicVersion,
+    /// Keeps more than `prune_window` versions, if configured.
+    retention: Option<RetentionResolver>,
 }
 
 impl PrunerManager for StateKvPrunerManager {
@@ -81,7 +87,11 @@ impl PrunerManager for StateKvPrunerManager {
 }
 
 impl StateKvPrunerManager {
-    pub fn new(state_kv_db: Arc<StateKvDb>, state_kv_pruner_config: LedgerPrunerConfig) -> Self {
+    pub fn new(
+        state_kv_db: Arc<StateKvDb>,
+        ledger_db: Arc<LedgerDb>,
+        state_kv_pruner_config: LedgerPrunerConfig,
+    ) -> Self {
         let pruner_worker = if state_kv_pruner_config.enable {
             Some(Self::init_pruner(
                 Arc::clone(&state_kv_db),
@@ -93,6 +103,9 @@ impl StateKvPrunerManager {
 
         let min_readable_version =
             pruner_utils::get_state_kv_pruner_progress(&state_kv_db).expect("Must succeed.");
+        let retention = state_kv_pruner_config
+            .retention
+            .map(|retention| RetentionResolver::new("state_kv_pruner", retention, ledger_db));
 
         PRUNER_VERSIONS
             .with_label_values(&["state_kv_pruner", "min_readable"])
@@ -104,6 +117,7 @@ impl StateKvPrunerManager {
             pruner_worker,
             pruning_batch_size: state_kv_pruner_config.batch_size,
             min_readable_version: AtomicVersion::new(min_readable_version),
+            retention,
         }
     }
 
@@ -127,7 +141,17 @@ impl StateKvPrunerManager {
 
     fn set_pruner_target_db_version(&self, latest_version: Version) {
         assert!(self.pruner_worker.is_some());
-        let min_readable_version = latest_version.saturating_sub(self.prune_window);
+        let Some(min_readable_version) = retention::get_target_min_readable_version(
+            self.retention.as_ref(),
+            latest_version,
+            self.prune_window,
+        ) else {
+            return;
+        };
+        // The retention can hold the pruner back for longer than the prune window.
+        if min_readable_version < self.get_min_readable_version() + self.pruning_batch_size as u64 {
+            return;
+        }
         self.min_readable_version
             .store(min_readable_version, Ordering::SeqCst);
         self.pruner_worker
@@ -5,11 +5,13 @@
 //! meant to be triggered by other threads as they commit new data to the DB.
 
 use crate::{
+    ledger_db::LedgerDb,
     metrics::{PRUNER_BATCH_SIZE, PRUNER_VERSIONS, PRUNER_WINDOW},
     pruner::{
         pruner_manager::PrunerManager,
         pruner_utils,
         pruner_worker::PrunerWorker,
+        retention::{self, RetentionResolver},
         state_merkle_pruner::{generics::StaleNodeIndexSchemaTrait, StateMerklePruner},
     },
     state_merkle_db::StateMerkleDb,
@@ -41,6 +43,8 @@ where
     pruner_worker: Option<PrunerWorker>,
     /// The minimal readable version for the state merkle data.
     min_readable_version: AtomicVersion,
+    /// Keeps more than `prune_window` versions, if configured.
+    retention: Option<RetentionResolver>,
 
     _phantom: PhantomData<S>,
 }
@@ -105,6 +109,7 @@ where
     /// Creates a worker thread that waits on a channel for pruning commands.
     pub fn new(
         state_merkle_db: Arc<StateMerkleDb>,
+        ledger_db: Arc<LedgerDb>,
         state_merkle_pruner_config: StateMerklePrunerConfig,
     ) -> Self {
         let pruner_worker = if state_merkle_pruner_config.enable {
@@ -118,6 +123,9 @@ where
 
         let min_readable_version = pruner_utils::get_state_merkle_pruner_progress(&state_merkle_db)
             .expect("Must succeed.");
+        let retention = state_merkle_pruner_config
+            .retention
+            .map(|retention| RetentionResolver::new(S::name(), retention, ledger_db));
 
         PRUNER_VERSIONS
             .with_label_values(&[S::name(), "min_readable"])
@@ -128,6 +136,7 @@ where
             prune_window: state_merkle_pruner_config.prune_window,
             pruner_worker,
             min_readable_version: AtomicVersion::new(min_readable_version),
+            retention,
             _phantom: PhantomData,
         }
     }
@@ -158,9 +167,15 @@ where
 
     fn set_pruner_target_db_version(&self, latest_version: Version) {
         assert!(self.pruner_worker.is_some());
-        self.pruner_worker
-            .as_ref()
-            .unwrap()
-            .set_target_db_version(latest_version.saturating_sub(self.prune_window));
+        if let Some(target_version) = retention::get_target_min_readable_version(
+            self.retention.as_ref(),
+            latest_version,
+            self.prune_window,
+        ) {
+            self.pruner_worker
+                .as_ref()
+                .unwrap()
+                .set_target_db_version(target_version);
+        }
     }
 }
@@ -437,14 +437,17 @@ impl StateStore {
 
         let state_merkle_pruner = StateMerklePrunerManager::new(
             Arc::clone(&state_merkle_db),
+            Arc::clone(&ledger_db),
             NO_OP_STORAGE_PRUNER_CONFIG.state_merkle_pruner_config,
         );
         let epoch_snapshot_pruner = StateMerklePrunerManager::new(
             Arc::clone(&state_merkle_db),
+            Arc::clone(&ledger_db),
             NO_OP_STORAGE_PRUNER_CONFIG.state_merkle_pruner_config,
         );
         let state_kv_pruner = StateKvPrunerManager::new(
             Arc::clone(&state_kv_db),
+            Arc::clone(&ledger_db),
             NO_OP_STORAGE_PRUNER_CONFIG.ledger_pruner_config,
         );
         let state_db = Arc::new(StateDb {
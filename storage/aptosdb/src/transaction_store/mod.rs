@@ -5,6 +5,7 @@
 //! This file defines transaction store APIs that are related to committed signed transactions.
 
 use crate::{
+    cold_store::{self, ColdOrHotIter, ColdStore},
     errors::AptosDbError,
     ledger_db::LedgerDb,
     schema::{
@@ -32,11 +33,23 @@ mod test;
 #[derive(Clone, Debug)]
 pub struct TransactionStore {
     ledger_db: Arc<LedgerDb>,
+    /// Where the transactions and write sets out of the hot window are, if enabled.
+    cold_store: Option<Arc<ColdStore>>,
 }
 
 impl TransactionStore {
     pub fn new(ledger_db: Arc<LedgerDb>) -> Self {
-        Self { ledger_db }
+        Self::new_with_cold_store(ledger_db, None)
+    }
+
+    pub(crate) fn new_with_cold_store(
+        ledger_db: Arc<LedgerDb>,
+        cold_store: Option<Arc<ColdStore>>,
+    ) -> Self {
+        Self {
+            ledger_db,
+            cold_store,
+        }
     }
 
     /// Gets the version of a transaction by the sender `address` and `sequence_number`.
@@ -107,10 +120,17 @@ impl TransactionStore {
 
     /// Get signed transaction given `version`
     pub fn get_transaction(&self, version: Version) -> Result<Transaction> {
-        self.ledger_db
-            .transaction_db()
-            .get::<TransactionSchema>(&version)?
-            .ok_or_else(|| AptosDbError::NotFound(format!("Txn {}", version)).into())
+        cold_store::read_ledger_data(
+            self.cold_store.as_deref(),
+            version,
+            || {
+                self.ledger_db
+                    .transaction_db()
+                    .get::<TransactionSchema>(&version)?
+                    .ok_or_else(|| AptosDbError::NotFound(format!("Txn {}", version)).into())
+            },
+            |data| Ok(data.transaction),
+        )
     }
 
     /// Gets an iterator that yields at most `num_transactions` transactions starting from `start_version`.
@@ -119,12 +139,20 @@ impl TransactionStore {
         start_version: Version,
         num_transactions: usize,
     ) -> Result<impl Iterator<Item = Result<Transaction>> + '_> {
-        let mut iter = self
-            .ledger_db
-            .transaction_db()
-            .iter::<TransactionSchema>(ReadOptions::default())?;
-        iter.seek(&start_version)?;
-        iter.expect_continuous_versions(start_version, num_transactions)
+        ColdOrHotIter::new(
+            self.cold_store.as_deref(),
+            start_version,
+            num_transactions,
+            |data| data.transaction,
+            |start_version, num_transactions| {
+                let mut iter = self
+                    .ledger_db
+                    .transaction_db()
+                    .iter::<TransactionSchema>(ReadOptions::default())?;
+                iter.seek(&start_version)?;
+                iter.expect_continuous_versions(start_version, num_transactions)
+            },
+        )
     }
 
     /// Gets an iterator that yields `num_transactions` write sets starting from `start_version`.
@@ -133,12 +161,20 @@ impl TransactionStore {
         start_version: Version,
         num_transactions: usize,
     ) -> Result<impl Iterator<Item = Result<WriteSet>> + '_> {
-        let mut iter = self
-            .ledger_db
-            .write_set_db()
-            .iter::<WriteSetSchema>(ReadOptions::default())?;
-        iter.seek(&start_version)?;
-        iter.expect_continuous_versions(start_version, num_transactions)
+        ColdOrHotIter::new(
+            self.cold_store.as_deref(),
+            start_version,
+            num_transactions,
+            |data| data.write_set,
+            |start_version, num_transactions| {
+                let mut iter = self
+                    .ledger_db
+                    .write_set_db()
+                    .iter::<WriteSetSchema>(ReadOptions::default())?;
+                iter.seek(&start_version)?;
+                iter.expect_continuous_versions(start_version, num_transactions)
+            },
+        )
     }
 
     /// Save signed transaction at `version`
@@ -165,12 +201,19 @@ impl TransactionStore {
 
     /// Get executed transaction vm output given `version`
     pub fn get_write_set(&self, version: Version) -> Result<WriteSet> {
-        self.ledger_db
-            .write_set_db()
-            .get::<WriteSetSchema>(&version)?
-            .ok_or_else(|| {
-                AptosDbError::NotFound(format!("WriteSet at version {}", version)).into()
-            })
+        cold_store::read_ledger_data(
+            self.cold_store.as_deref(),
+            version,
+            || {
+                self.ledger_db
+                    .write_set_db()
+                    .get::<WriteSetSchema>(&version)?
+                    .ok_or_else(|| {
+                        AptosDbError::NotFound(format!("WriteSet at version {}", version)).into()
+                    })
+            },
+            |data| Ok(data.write_set),
+        )
     }
 
     /// Get write sets in `[begin_version, end_version)` half-open range.
@@ -191,26 +234,15 @@ impl TransactionStore {
             end_version
         );
 
-        let mut iter = self
-            .ledger_db
-            .write_set_db()
-            .iter::<WriteSetSchema>(Default::default())?;
-        iter.seek(&begin_version)?;
-
-        let mut ret = Vec::with_capacity((end_version - begin_version) as usize);
-        for current_version in begin_version..end_version {
-            let (version, write_set) = iter
-                .next()
-                .transpose()?
-                .ok_or_else(|| format_err!("Write set missing for version {}", current_version))?;
-            ensure!(
-                version == current_version,
-                "Write set missing for version {}, got version {}",
-                current_version,
-                version,
-            );
-            ret.push(write_set);
-        }
+        let num_versions = (end_version - begin_version) as usize;
+        let ret = self
+            .get_write_set_iter(begin_version, num_versions)?
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(
+            ret.len() == num_versions,
+            "Write set missing for version {}",
+            begin_version + ret.len() as Version,
+        );
 
         Ok(ret)
     }
@@ -7,8 +7,12 @@ use crate::{
     metadata::Metadata,
     storage::{BackupHandleRef, BackupStorage, FileHandle, ShellSafeName},
     utils::{
-        backup_service_client::BackupServiceClient, read_record_bytes::ReadRecordBytes,
-        should_cut_chunk, storage_ext::BackupStorageExt, GlobalBackupOpt,
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, Codec},
+        read_record_bytes::ReadRecordBytes,
+        should_cut_chunk,
+        storage_ext::BackupStorageExt,
+        GlobalBackupOpt,
     },
 };
 use anyhow::{anyhow, ensure, Result};
@@ -35,6 +39,7 @@ pub struct EpochEndingBackupController {
     start_epoch: u64,
     end_epoch: u64,
     max_chunk_size: usize,
+    codec_opt: BackupCodecOpt,
     client: Arc<BackupServiceClient>,
     storage: Arc<dyn BackupStorage>,
 }
@@ -50,6 +55,7 @@ impl EpochEndingBackupController {
             start_epoch: opt.start_epoch,
             end_epoch: opt.end_epoch,
             max_chunk_size: global_opt.max_chunk_size,
+            codec_opt: global_opt.codec,
             client,
             storage,
         }
@@ -72,6 +78,7 @@ impl EpochEndingBackupController {
 
 impl EpochEndingBackupController {
     async fn run_impl(self) -> Result<FileHandle> {
+        let codec = self.codec_opt.init()?;
         let backup_handle = self
             .storage
             .create_backup_with_random_suffix(&self.backup_name())
@@ -96,6 +103,7 @@ impl EpochEndingBackupController {
                         &chunk_bytes,
                         chunk_first_epoch,
                         current_epoch - 1,
+                        &codec,
                     )
                     .await?;
                 chunks.push(chunk);
@@ -117,11 +125,13 @@ impl EpochEndingBackupController {
                 &chunk_bytes,
                 chunk_first_epoch,
                 current_epoch - 1,
+                &codec,
             )
             .await?;
         chunks.push(chunk);
 
-        self.write_manifest(&backup_handle, waypoints, chunks).await
+        self.write_manifest(&backup_handle, waypoints, chunks, &codec)
+            .await
     }
 
     fn backup_name(&self) -> String {
@@ -155,13 +165,17 @@ impl EpochEndingBackupController {
         chunk_bytes: &[u8],
         first_epoch: u64,
         last_epoch: u64,
+        codec: &Codec,
     ) -> Result<EpochEndingChunk> {
-        let (chunk_handle, mut chunk_file) = self
+        let chunk_handle = self
             .storage
-            .create_for_write(backup_handle, &Self::chunk_name(first_epoch))
+            .write_with_codec(
+                backup_handle,
+                &Self::chunk_name(first_epoch),
+                chunk_bytes,
+                codec,
+            )
             .await?;
-        chunk_file.write_all(chunk_bytes).await?;
-        chunk_file.shutdown().await?;
         Ok(EpochEndingChunk {
             first_epoch,
             last_epoch,
@@ -174,6 +188,7 @@ impl EpochEndingBackupController {
         backup_handle: &BackupHandleRef,
         waypoints: Vec<Waypoint>,
         chunks: Vec<EpochEndingChunk>,
+        codec: &Codec,
     ) -> Result<FileHandle> {
         let first_epoch = self.start_epoch;
         let last_epoch = self.end_epoch - 1;
@@ -183,6 +198,7 @@ impl EpochEndingBackupController {
             last_epoch,
             waypoints,
             chunks,
+            codec: codec.backup_codec(),
         };
         let (manifest_handle, mut manifest_file) = self
             .storage
@@ -10,7 +10,10 @@ use crate::{
     },
     storage::{BackupStorage, FileHandle, FileHandleRef},
     utils::{
-        read_record_bytes::ReadRecordBytes, storage_ext::BackupStorageExt, stream::StreamX,
+        codec::{Codec, EncryptionKey},
+        read_record_bytes::ReadRecordBytes,
+        storage_ext::BackupStorageExt,
+        stream::StreamX,
         GlobalRestoreOptions, RestoreRunMode,
     },
 };
@@ -38,6 +41,7 @@ pub struct EpochEndingRestoreController {
     manifest_handle: FileHandle,
     target_version: Version,
     trusted_waypoints: Arc<HashMap<Version, Waypoint>>,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl EpochEndingRestoreController {
@@ -52,6 +56,7 @@ impl EpochEndingRestoreController {
             manifest_handle: opt.manifest_handle,
             target_version: global_opt.target_version,
             trusted_waypoints: global_opt.trusted_waypoints,
+            encryption_key: global_opt.encryption_key,
         }
     }
 
@@ -82,6 +87,7 @@ impl EpochEndingRestoreController {
         let manifest: EpochEndingBackup =
             self.storage.load_json_file(&self.manifest_handle).await?;
         manifest.verify()?;
+        let codec = Codec::for_backup(&manifest.codec, self.encryption_key.as_ref())?;
 
         let mut next_epoch = manifest.first_epoch;
         let mut waypoint_iter = manifest.waypoints.iter();
@@ -95,7 +101,7 @@ impl EpochEndingRestoreController {
                 break;
             }
 
-            let lis = self.read_chunk(&chunk.ledger_infos).await?;
+            let lis = self.read_chunk(&chunk.ledger_infos, &codec).await?;
             ensure!(
                 chunk.first_epoch + lis.len() as u64 == chunk.last_epoch + 1,
                 "Number of items in chunks doesn't match that in manifest. \
@@ -161,8 +167,12 @@ impl EpochEndingRestoreController {
     async fn read_chunk(
         &self,
         file_handle: &FileHandleRef,
+        codec: &Codec,
     ) -> Result<Vec<LedgerInfoWithSignatures>> {
-        let mut file = self.storage.open_for_read(file_handle).await?;
+        let mut file = self
+            .storage
+            .open_for_read_with_codec(file_handle, codec)
+            .await?;
         let mut chunk = vec![];
 
         while let Some(record_bytes) = file.read_record_bytes().await? {
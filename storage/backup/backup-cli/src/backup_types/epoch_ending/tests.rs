@@ -11,7 +11,9 @@ use crate::{
     },
     storage::{local_fs::LocalFs, BackupStorage},
     utils::{
-        backup_service_client::BackupServiceClient, test_utils::tmp_db_with_random_content,
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, EncryptionKeyOpt},
+        test_utils::tmp_db_with_random_content,
         ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt,
         RocksdbOpt, TrustedWaypointOpt,
     },
@@ -68,6 +70,7 @@ fn end_to_end() {
                 },
                 GlobalBackupOpt {
                     max_chunk_size: 1024,
+                    codec: BackupCodecOpt::default(),
                 },
                 client,
                 Arc::clone(&store),
@@ -87,6 +90,7 @@ fn end_to_end() {
                 rocksdb_opt: RocksdbOpt::default(),
                 concurrent_downloads: ConcurrentDownloadsOpt::default(),
                 replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+                encryption_key: EncryptionKeyOpt::default(),
             }
             .try_into()
             .unwrap(),
@@ -198,6 +202,7 @@ async fn test_trusted_waypoints_impl(
             },
             GlobalBackupOpt {
                 max_chunk_size: 1024,
+                codec: BackupCodecOpt::default(),
             },
             client.clone(),
             Arc::clone(&store),
@@ -219,6 +224,7 @@ async fn test_trusted_waypoints_impl(
             rocksdb_opt: RocksdbOpt::default(),
             concurrent_downloads: ConcurrentDownloadsOpt::default(),
             replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+            encryption_key: EncryptionKeyOpt::default(),
         }
         .try_into()
         .unwrap(),
@@ -240,6 +246,7 @@ async fn test_trusted_waypoints_impl(
             rocksdb_opt: RocksdbOpt::default(),
             concurrent_downloads: ConcurrentDownloadsOpt::default(),
             replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+            encryption_key: EncryptionKeyOpt::default(),
         }
         .try_into()
         .unwrap(),
@@ -7,8 +7,12 @@ use crate::{
     metadata::Metadata,
     storage::{BackupHandleRef, BackupStorage, FileHandle, ShellSafeName},
     utils::{
-        backup_service_client::BackupServiceClient, read_record_bytes::ReadRecordBytes,
-        should_cut_chunk, storage_ext::BackupStorageExt, GlobalBackupOpt,
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, Codec},
+        read_record_bytes::ReadRecordBytes,
+        should_cut_chunk,
+        storage_ext::BackupStorageExt,
+        GlobalBackupOpt,
     },
 };
 use anyhow::{anyhow, Result};
@@ -24,7 +28,10 @@ use bytes::Bytes;
 use clap::Parser;
 use once_cell::sync::Lazy;
 use std::{convert::TryInto, str::FromStr, sync::Arc};
-use tokio::{io::AsyncWriteExt, time::Instant};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    time::Instant,
+};
 
 #[derive(Parser)]
 pub struct StateSnapshotBackupOpt {
@@ -39,6 +46,7 @@ pub struct StateSnapshotBackupController {
     epoch: u64,
     version: Option<Version>, // initialize before using
     max_chunk_size: usize,
+    codec_opt: BackupCodecOpt,
     client: Arc<BackupServiceClient>,
     storage: Arc<dyn BackupStorage>,
 }
@@ -54,6 +62,7 @@ impl StateSnapshotBackupController {
             epoch: opt.epoch,
             version: None,
             max_chunk_size: global_opt.max_chunk_size,
+            codec_opt: global_opt.codec,
             client,
             storage,
         }
@@ -70,6 +79,7 @@ impl StateSnapshotBackupController {
     }
 
     async fn run_impl(mut self) -> Result<FileHandle> {
+        let codec = self.codec_opt.init()?;
         self.version = Some(self.get_version_for_epoch_ending(self.epoch).await?);
         let backup_handle = self
             .storage
@@ -100,6 +110,7 @@ impl StateSnapshotBackupController {
                         current_idx,
                         chunk_first_key,
                         Self::parse_key(&prev_record_bytes)?,
+                        &codec,
                     )
                     .await?;
                 chunks.push(chunk);
@@ -130,11 +141,12 @@ impl StateSnapshotBackupController {
                 current_idx,
                 chunk_first_key,
                 Self::parse_key(&prev_record_bytes)?,
+                &codec,
             )
             .await?;
         chunks.push(chunk);
 
-        self.write_manifest(&backup_handle, chunks).await
+        self.write_manifest(&backup_handle, chunks, &codec).await
     }
 }
 
@@ -197,26 +209,32 @@ impl StateSnapshotBackupController {
         last_idx: usize,
         first_key: HashValue,
         last_key: HashValue,
+        codec: &Codec,
     ) -> Result<StateSnapshotChunk> {
-        let (chunk_handle, mut chunk_file) = self
+        let chunk_handle = self
             .storage
-            .create_for_write(backup_handle, &Self::chunk_name(first_idx))
+            .write_with_codec(
+                backup_handle,
+                &Self::chunk_name(first_idx),
+                chunk_bytes,
+                codec,
+            )
             .await?;
-        chunk_file.write_all(chunk_bytes).await?;
-        chunk_file.shutdown().await?;
-        let (proof_handle, mut proof_file) = self
+        let mut proof_bytes = Vec::new();
+        self.client
+            .get_account_range_proof(last_key, self.version())
+            .await?
+            .read_to_end(&mut proof_bytes)
+            .await?;
+        let proof_handle = self
             .storage
-            .create_for_write(backup_handle, &Self::chunk_proof_name(first_idx, last_idx))
+            .write_with_codec(
+                backup_handle,
+                &Self::chunk_proof_name(first_idx, last_idx),
+                &proof_bytes,
+                codec,
+            )
             .await?;
-        tokio::io::copy(
-            &mut self
-                .client
-                .get_account_range_proof(last_key, self.version())
-                .await?,
-            &mut proof_file,
-        )
-        .await?;
-        proof_file.shutdown().await?;
 
         Ok(StateSnapshotChunk {
             first_idx,
@@ -232,17 +250,16 @@ impl StateSnapshotBackupController {
         &self,
         backup_handle: &BackupHandleRef,
         chunks: Vec<StateSnapshotChunk>,
+        codec: &Codec,
     ) -> Result<FileHandle> {
         let proof_bytes = self.client.get_state_root_proof(self.version()).await?;
         let (txn_info, _): (TransactionInfoWithProof, LedgerInfoWithSignatures) =
             bcs::from_bytes(&proof_bytes)?;
 
-        let (proof_handle, mut proof_file) = self
+        let proof_handle = self
             .storage
-            .create_for_write(backup_handle, Self::proof_name())
+            .write_with_codec(backup_handle, Self::proof_name(), &proof_bytes, codec)
             .await?;
-        proof_file.write_all(&proof_bytes).await?;
-        proof_file.shutdown().await?;
 
         let manifest = StateSnapshotBackup {
             epoch: self.epoch,
@@ -250,6 +267,7 @@ impl StateSnapshotBackupController {
             root_hash: txn_info.transaction_info().ensure_state_checkpoint_hash()?,
             chunks,
             proof: proof_handle,
+            codec: codec.backup_codec(),
         };
 
         let (manifest_handle, mut manifest_file) = self
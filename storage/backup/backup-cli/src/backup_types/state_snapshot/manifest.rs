@@ -2,7 +2,7 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::storage::FileHandle;
+use crate::{storage::FileHandle, utils::codec::BackupCodec};
 use aptos_crypto::HashValue;
 use aptos_types::transaction::Version;
 use serde::{Deserialize, Serialize};
@@ -49,4 +49,7 @@ pub struct StateSnapshotBackup {
     /// `EpochStateBackup` recovered prior to this to the DB; Requiring it to be in the same epoch
     /// limits the requirement on such `EpochStateBackup` to no older than the same epoch.
     pub proof: FileHandle,
+    /// How the chunk files and `proof` are encoded.
+    #[serde(default)]
+    pub codec: BackupCodec,
 }
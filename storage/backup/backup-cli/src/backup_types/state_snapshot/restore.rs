@@ -18,7 +18,10 @@ use crate::{
     },
     storage::{BackupStorage, FileHandle},
     utils::{
-        read_record_bytes::ReadRecordBytes, storage_ext::BackupStorageExt, stream::StreamX,
+        codec::{Codec, EncryptionKey},
+        read_record_bytes::ReadRecordBytes,
+        storage_ext::BackupStorageExt,
+        stream::StreamX,
         GlobalRestoreOptions, RestoreRunMode,
     },
 };
@@ -71,6 +74,7 @@ pub struct StateSnapshotRestoreController {
     concurrent_downloads: usize,
     validate_modules: bool,
     restore_mode: StateSnapshotRestoreMode,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl StateSnapshotRestoreController {
@@ -90,6 +94,7 @@ impl StateSnapshotRestoreController {
             concurrent_downloads: global_opt.concurrent_downloads,
             validate_modules: opt.validate_modules,
             restore_mode: opt.restore_mode,
+            encryption_key: global_opt.encryption_key,
         }
     }
 
@@ -122,8 +127,11 @@ impl StateSnapshotRestoreController {
 
         let manifest: StateSnapshotBackup =
             self.storage.load_json_file(&self.manifest_handle).await?;
-        let (txn_info_with_proof, li): (TransactionInfoWithProof, LedgerInfoWithSignatures) =
-            self.storage.load_bcs_file(&manifest.proof).await?;
+        let codec = Codec::for_backup(&manifest.codec, self.encryption_key.as_ref())?;
+        let (txn_info_with_proof, li): (TransactionInfoWithProof, LedgerInfoWithSignatures) = self
+            .storage
+            .load_bcs_file_with_codec(&manifest.proof, &codec)
+            .await?;
         txn_info_with_proof.verify(li.ledger_info(), manifest.version)?;
         let state_root_hash = txn_info_with_proof
             .transaction_info()
@@ -186,10 +194,14 @@ impl StateSnapshotRestoreController {
         let storage = self.storage.clone();
         let futs_iter = chunks.into_iter().enumerate().map(|(chunk_idx, chunk)| {
             let storage = storage.clone();
+            let codec = codec.clone();
             async move {
                 tokio::spawn(async move {
-                    let blobs = Self::read_state_value(&storage, chunk.blobs.clone()).await?;
-                    let proof = storage.load_bcs_file(&chunk.proof).await?;
+                    let blobs =
+                        Self::read_state_value(&storage, chunk.blobs.clone(), &codec).await?;
+                    let proof = storage
+                        .load_bcs_file_with_codec(&chunk.proof, &codec)
+                        .await?;
                     Result::<_>::Ok((chunk_idx, chunk, blobs, proof))
                 })
                 .await?
@@ -256,8 +268,11 @@ impl StateSnapshotRestoreController {
     async fn read_state_value(
         storage: &Arc<dyn BackupStorage>,
         file_handle: FileHandle,
+        codec: &Codec,
     ) -> Result<Vec<(StateKey, StateValue)>> {
-        let mut file = storage.open_for_read(&file_handle).await?;
+        let mut file = storage
+            .open_for_read_with_codec(&file_handle, codec)
+            .await?;
 
         let mut chunk = vec![];
 
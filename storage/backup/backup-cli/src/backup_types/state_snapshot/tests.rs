@@ -10,6 +10,7 @@ use crate::{
     storage::{local_fs::LocalFs, BackupStorage},
     utils::{
         backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, EncryptionKeyOpt},
         test_utils::{start_local_backup_service, tmp_db_with_random_content},
         ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt,
         RocksdbOpt, TrustedWaypointOpt,
@@ -64,6 +65,7 @@ fn end_to_end() {
                 StateSnapshotBackupOpt { epoch },
                 GlobalBackupOpt {
                     max_chunk_size: 500,
+                    codec: BackupCodecOpt::default(),
                 },
                 client,
                 Arc::clone(&store),
@@ -88,6 +90,7 @@ fn end_to_end() {
                 rocksdb_opt: RocksdbOpt::default(),
                 concurrent_downloads: ConcurrentDownloadsOpt::default(),
                 replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+                encryption_key: EncryptionKeyOpt::default(),
             }
             .try_into()
             .unwrap(),
@@ -0,0 +1,437 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    backup_types::state_snapshot_increment::manifest::{
+        StateSnapshotIncrementBackup, StateSnapshotIncrementChunk,
+    },
+    metadata::Metadata,
+    storage::{BackupHandleRef, BackupStorage, FileHandle, ShellSafeName},
+    utils::{
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, Codec},
+        read_record_bytes::ReadRecordBytes,
+        should_cut_chunk,
+        storage_ext::BackupStorageExt,
+        GlobalBackupOpt,
+    },
+};
+use anyhow::{anyhow, ensure, Result};
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_logger::prelude::*;
+use aptos_temppath::TempPath;
+use aptos_types::{
+    contract_event::ContractEvent,
+    ledger_info::LedgerInfoWithSignatures,
+    proof::TransactionInfoWithProof,
+    state_store::{state_key::StateKey, state_value::StateValue},
+    transaction::{Transaction, TransactionInfo, Version},
+    write_set::{TransactionWrite, WriteSet},
+};
+use clap::Parser;
+use once_cell::sync::Lazy;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    convert::TryInto,
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+use tokio::{
+    fs::File,
+    io::{AsyncWriteExt, BufReader, BufWriter},
+};
+
+#[derive(Parser)]
+pub struct StateSnapshotIncrementBackupOpt {
+    #[clap(
+        long = "state-snapshot-epoch",
+        help = "Epoch at the end of which the state snapshot increment is to be taken."
+    )]
+    pub epoch: u64,
+    #[clap(
+        long = "state-snapshot-base-version",
+        help = "Version of the state snapshot, full or incremental, the increment is taken over."
+    )]
+    pub base_version: Version,
+    #[clap(
+        long = "sort-dir",
+        value_parser,
+        help = "Dir of the temporary files used to sort the state changes by key hash. \
+        [Defaults to temporary dir]"
+    )]
+    pub sort_dir: Option<PathBuf>,
+    #[clap(
+        long = "sort-buffer-size",
+        default_value_t = 268435456,
+        help = "Size in bytes of the state changes held in memory while sorting them, beyond \
+        which they are written to the sort dir."
+    )]
+    pub sort_buffer_size: usize,
+}
+
+pub struct StateSnapshotIncrementBackupController {
+    epoch: u64,
+    base_version: Version,
+    version: Option<Version>, // initialize before using
+    sort_dir: Option<PathBuf>,
+    sort_buffer_size: usize,
+    max_chunk_size: usize,
+    codec_opt: BackupCodecOpt,
+    client: Arc<BackupServiceClient>,
+    storage: Arc<dyn BackupStorage>,
+}
+
+impl StateSnapshotIncrementBackupController {
+    pub fn new(
+        opt: StateSnapshotIncrementBackupOpt,
+        global_opt: GlobalBackupOpt,
+        client: Arc<BackupServiceClient>,
+        storage: Arc<dyn BackupStorage>,
+    ) -> Self {
+        Self {
+            epoch: opt.epoch,
+            base_version: opt.base_version,
+            version: None,
+            sort_dir: opt.sort_dir,
+            sort_buffer_size: opt.sort_buffer_size,
+            max_chunk_size: global_opt.max_chunk_size,
+            codec_opt: global_opt.codec,
+            client,
+            storage,
+        }
+    }
+
+    pub async fn run(self) -> Result<FileHandle> {
+        info!(
+            "State snapshot increment backup started, for epoch {} over version {}.",
+            self.epoch, self.base_version,
+        );
+        let ret = self
+            .run_impl()
+            .await
+            .map_err(|e| anyhow!("State snapshot increment backup failed: {}", e))?;
+        info!(
+            "State snapshot increment backup succeeded. Manifest: {}",
+            ret
+        );
+        Ok(ret)
+    }
+
+    async fn run_impl(mut self) -> Result<FileHandle> {
+        let codec = self.codec_opt.init()?;
+        self.version = Some(self.get_version_for_epoch_ending(self.epoch).await?);
+        ensure!(
+            self.base_version < self.version(),
+            "Base version {} is not older than the state snapshot version {}.",
+            self.base_version,
+            self.version(),
+        );
+
+        let mut changes = self.collect_changes().await?;
+
+        let backup_handle = self
+            .storage
+            .create_backup_with_random_suffix(&self.backup_name())
+            .await?;
+
+        let mut chunks = vec![];
+        let mut chunk_bytes = vec![];
+        let mut chunk_first_idx: usize = 0;
+        let mut current_idx: usize = 0;
+        while let Some(change) = changes.next().await? {
+            let record_bytes = bcs::to_bytes(&change)?;
+            if should_cut_chunk(&chunk_bytes, &record_bytes, self.max_chunk_size) {
+                let chunk = self
+                    .write_chunk(
+                        &backup_handle,
+                        &chunk_bytes,
+                        chunk_first_idx,
+                        current_idx - 1,
+                        &codec,
+                    )
+                    .await?;
+                chunks.push(chunk);
+                chunk_bytes = vec![];
+                chunk_first_idx = current_idx;
+            }
+
+            chunk_bytes.extend((record_bytes.len() as u32).to_be_bytes());
+            chunk_bytes.extend(&record_bytes);
+            current_idx += 1;
+        }
+        if !chunk_bytes.is_empty() {
+            let chunk = self
+                .write_chunk(
+                    &backup_handle,
+                    &chunk_bytes,
+                    chunk_first_idx,
+                    current_idx - 1,
+                    &codec,
+                )
+                .await?;
+            chunks.push(chunk);
+        }
+        info!(num_changes = current_idx, "State changes written.");
+
+        self.write_manifest(&backup_handle, chunks, &codec).await
+    }
+}
+
+impl StateSnapshotIncrementBackupController {
+    fn version(&self) -> Version {
+        self.version.unwrap()
+    }
+
+    fn backup_name(&self) -> String {
+        format!("state_increment_{}-{}", self.base_version, self.version())
+    }
+
+    fn manifest_name() -> &'static ShellSafeName {
+        static NAME: Lazy<ShellSafeName> =
+            Lazy::new(|| ShellSafeName::from_str("state_increment.manifest").unwrap());
+        &NAME
+    }
+
+    fn proof_name() -> &'static ShellSafeName {
+        static NAME: Lazy<ShellSafeName> =
+            Lazy::new(|| ShellSafeName::from_str("state_increment.proof").unwrap());
+        &NAME
+    }
+
+    fn chunk_name(first_idx: usize) -> ShellSafeName {
+        format!("{}-.chunk", first_idx).try_into().unwrap()
+    }
+
+    /// Replays the write sets of the versions in (`base_version`, `version`], keeping the last
+    /// write to each state key, ordered by the key hash.
+    async fn collect_changes(&self) -> Result<SortedStateChanges> {
+        let first_version = self.base_version + 1;
+        let num_transactions = (self.version() - self.base_version) as usize;
+        let mut transactions_file = self
+            .client
+            .get_transactions(first_version, num_transactions)
+            .await?;
+
+        let mut sorter = StateChangeSorter::new(self.sort_dir.clone(), self.sort_buffer_size)?;
+        let mut next_version = first_version;
+        while let Some(record_bytes) = transactions_file.read_record_bytes().await? {
+            let (_, _, _, write_set): (Transaction, TransactionInfo, Vec<ContractEvent>, WriteSet) =
+                bcs::from_bytes(&record_bytes)?;
+            for (key, op) in &write_set {
+                sorter.insert(key.clone(), op.as_state_value()).await?;
+            }
+            next_version += 1;
+        }
+        ensure!(
+            next_version == self.version() + 1,
+            "Server did not return all transactions requested. Expecting last version {}, got {}",
+            self.version(),
+            next_version - 1,
+        );
+
+        sorter.finish().await
+    }
+
+    async fn get_version_for_epoch_ending(&self, epoch: u64) -> Result<u64> {
+        let ledger_info: LedgerInfoWithSignatures = bcs::from_bytes(
+            self.client
+                .get_epoch_ending_ledger_infos(epoch, epoch + 1)
+                .await?
+                .read_record_bytes()
+                .await?
+                .ok_or_else(|| {
+                    anyhow!("Failed to get epoch ending ledger info for epoch {}", epoch)
+                })?
+                .as_ref(),
+        )?;
+        Ok(ledger_info.ledger_info().version())
+    }
+
+    async fn write_chunk(
+        &self,
+        backup_handle: &BackupHandleRef,
+        chunk_bytes: &[u8],
+        first_idx: usize,
+        last_idx: usize,
+        codec: &Codec,
+    ) -> Result<StateSnapshotIncrementChunk> {
+        let chunk_handle = self
+            .storage
+            .write_with_codec(
+                backup_handle,
+                &Self::chunk_name(first_idx),
+                chunk_bytes,
+                codec,
+            )
+            .await?;
+
+        Ok(StateSnapshotIncrementChunk {
+            first_idx,
+            last_idx,
+            blobs: chunk_handle,
+        })
+    }
+
+    async fn write_manifest(
+        &self,
+        backup_handle: &BackupHandleRef,
+        chunks: Vec<StateSnapshotIncrementChunk>,
+        codec: &Codec,
+    ) -> Result<FileHandle> {
+        let proof_bytes = self.client.get_state_root_proof(self.version()).await?;
+        let (txn_info, _): (TransactionInfoWithProof, LedgerInfoWithSignatures) =
+            bcs::from_bytes(&proof_bytes)?;
+
+        let proof_handle = self
+            .storage
+            .write_with_codec(backup_handle, Self::proof_name(), &proof_bytes, codec)
+            .await?;
+
+        let manifest = StateSnapshotIncrementBackup {
+            base_version: self.base_version,
+            version: self.version(),
+            epoch: self.epoch,
+            root_hash: txn_info.transaction_info().ensure_state_checkpoint_hash()?,
+            chunks,
+            proof: proof_handle,
+            codec: codec.backup_codec(),
+        };
+
+        let (manifest_handle, mut manifest_file) = self
+            .storage
+            .create_for_write(backup_handle, Self::manifest_name())
+            .await?;
+        manifest_file
+            .write_all(&serde_json::to_vec(&manifest)?)
+            .await?;
+        manifest_file.shutdown().await?;
+
+        let metadata = Metadata::new_state_snapshot_increment_backup(
+            self.base_version,
+            self.epoch,
+            self.version(),
+            manifest_handle.clone(),
+        );
+        self.storage
+            .save_metadata_line(&metadata.name(), &metadata.to_text_line()?)
+            .await?;
+
+        Ok(manifest_handle)
+    }
+}
+
+/// Sorts the state changes by key hash, keeping the last one of each key, with a bounded amount
+/// of them in memory. Each time the buffer fills up, its content is written to a sorted run file
+/// in a temporary dir, and the runs are merged in the end.
+struct StateChangeSorter {
+    dir: TempPath,
+    buffer: BTreeMap<HashValue, (StateKey, Option<StateValue>)>,
+    buffer_size: usize,
+    max_buffer_size: usize,
+    runs: Vec<PathBuf>,
+}
+
+impl StateChangeSorter {
+    fn new(sort_dir: Option<PathBuf>, max_buffer_size: usize) -> Result<Self> {
+        let dir = TempPath::new_with_temp_dir(sort_dir.unwrap_or_else(std::env::temp_dir));
+        dir.create_as_dir()?;
+        Ok(Self {
+            dir,
+            buffer: BTreeMap::new(),
+            buffer_size: 0,
+            max_buffer_size,
+            runs: vec![],
+        })
+    }
+
+    async fn insert(&mut self, key: StateKey, value: Option<StateValue>) -> Result<()> {
+        self.buffer_size += key.size() + value.as_ref().map_or(0, StateValue::size);
+        self.buffer.insert(key.hash(), (key, value));
+        if self.buffer_size >= self.max_buffer_size {
+            self.write_run().await?;
+        }
+        Ok(())
+    }
+
+    async fn write_run(&mut self) -> Result<()> {
+        let path = self.dir.path().join(format!("{}.run", self.runs.len()));
+        let mut file = BufWriter::new(File::create(&path).await?);
+        for change in std::mem::take(&mut self.buffer) {
+            let record_bytes = bcs::to_bytes(&change)?;
+            file.write_all(&(record_bytes.len() as u32).to_be_bytes())
+                .await?;
+            file.write_all(&record_bytes).await?;
+        }
+        file.shutdown().await?;
+
+        self.buffer_size = 0;
+        self.runs.push(path);
+        Ok(())
+    }
+
+    async fn finish(mut self) -> Result<SortedStateChanges> {
+        if !self.buffer.is_empty() {
+            self.write_run().await?;
+        }
+        info!(num_runs = self.runs.len(), "State changes sorted.");
+
+        let mut changes = SortedStateChanges {
+            _dir: self.dir,
+            runs: vec![],
+            heads: BinaryHeap::new(),
+            head_changes: vec![],
+        };
+        for path in &self.runs {
+            changes.runs.push(BufReader::new(File::open(path).await?));
+            changes.head_changes.push(None);
+            changes.read_head(changes.runs.len() - 1).await?;
+        }
+        Ok(changes)
+    }
+}
+
+/// The state changes merged from the sorted runs.
+struct SortedStateChanges {
+    _dir: TempPath,
+    runs: Vec<BufReader<File>>,
+    /// The key hashes at the head of the runs, the smallest first, and for the same key the
+    /// latest run, which has the latest change to it, first.
+    heads: BinaryHeap<Reverse<(HashValue, Reverse<usize>)>>,
+    head_changes: Vec<Option<(StateKey, Option<StateValue>)>>,
+}
+
+impl SortedStateChanges {
+    async fn next(&mut self) -> Result<Option<(StateKey, Option<StateValue>)>> {
+        let Some(Reverse((key_hash, Reverse(run_idx)))) = self.heads.pop() else {
+            return Ok(None);
+        };
+        let change = self.head_changes[run_idx]
+            .take()
+            .expect("Every head has its change.");
+        self.read_head(run_idx).await?;
+
+        // Earlier changes to the same key are overwritten.
+        while let Some(&Reverse((next_key_hash, Reverse(next_run_idx)))) = self.heads.peek() {
+            if next_key_hash != key_hash {
+                break;
+            }
+            self.heads.pop();
+            self.read_head(next_run_idx).await?;
+        }
+        Ok(Some(change))
+    }
+
+    async fn read_head(&mut self, run_idx: usize) -> Result<()> {
+        self.head_changes[run_idx] = match self.runs[run_idx].read_record_bytes().await? {
+            Some(record_bytes) => {
+                let (key_hash, change) = bcs::from_bytes(&record_bytes)?;
+                self.heads.push(Reverse((key_hash, Reverse(run_idx))));
+                Some(change)
+            },
+            None => None,
+        };
+        Ok(())
+    }
+}
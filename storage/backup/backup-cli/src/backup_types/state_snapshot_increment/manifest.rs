@@ -0,0 +1,73 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{storage::FileHandle, utils::codec::BackupCodec};
+use anyhow::{ensure, Result};
+use aptos_crypto::HashValue;
+use aptos_types::transaction::Version;
+use serde::{Deserialize, Serialize};
+
+/// A chunk of a state snapshot increment manifest, representing the changes in the
+/// [`first_idx`, `last_idx`] range (right side inclusive).
+#[derive(Deserialize, Serialize)]
+pub struct StateSnapshotIncrementChunk {
+    /// index of the first change in this chunk over all changes.
+    pub first_idx: usize,
+    /// index of the last change in this chunk over all changes.
+    pub last_idx: usize,
+    /// Repeated `len(record) + record` where `record` is BCS serialized tuple
+    /// `(key, Option<state_value>)`, `None` meaning the key was deleted.
+    pub blobs: FileHandle,
+}
+
+/// State snapshot increment manifest, representing the state keys changed between the state
+/// snapshot at `base_version` and the one at `version`, in the order of their hashes.
+#[derive(Deserialize, Serialize)]
+pub struct StateSnapshotIncrementBackup {
+    /// Version of the state snapshot, full or incremental, the changes apply to.
+    pub base_version: Version,
+    /// Version at which this state snapshot is taken.
+    pub version: Version,
+    /// Epoch in which this state snapshot is taken.
+    pub epoch: u64,
+    /// Hash of the state tree root at `version`.
+    pub root_hash: HashValue,
+    /// All the changes in chunks.
+    pub chunks: Vec<StateSnapshotIncrementChunk>,
+    /// BCS serialized `Tuple(TransactionInfoWithProof, LedgerInfoWithSignatures)`, proving
+    /// `root_hash` like `StateSnapshotBackup::proof` does for a full state snapshot.
+    pub proof: FileHandle,
+    /// How the chunk files and `proof` are encoded.
+    #[serde(default)]
+    pub codec: BackupCodec,
+}
+
+impl StateSnapshotIncrementBackup {
+    pub fn verify(&self) -> Result<()> {
+        ensure!(
+            self.base_version < self.version,
+            "Bad version range: base version {}, version {}",
+            self.base_version,
+            self.version,
+        );
+
+        let mut next_idx = 0;
+        for chunk in &self.chunks {
+            ensure!(
+                chunk.first_idx == next_idx,
+                "Chunk ranges not continuous. Expected first index: {}, actual: {}.",
+                next_idx,
+                chunk.first_idx,
+            );
+            ensure!(
+                chunk.last_idx >= chunk.first_idx,
+                "Chunk range invalid. [{}, {}]",
+                chunk.first_idx,
+                chunk.last_idx,
+            );
+            next_idx = chunk.last_idx + 1;
+        }
+
+        Ok(())
+    }
+}
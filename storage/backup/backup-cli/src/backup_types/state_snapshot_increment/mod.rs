@@ -0,0 +1,13 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Incremental state snapshots, holding only the state keys changed since a base state snapshot,
+//! full or incremental. They're derived from the write sets of the versions in between, so
+//! taking one doesn't require going through the whole state.
+
+pub mod backup;
+pub mod manifest;
+pub mod restore;
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,291 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    backup_types::{
+        epoch_ending::restore::EpochHistory,
+        state_snapshot::{
+            manifest::StateSnapshotBackup,
+            restore::{StateSnapshotRestoreController, StateSnapshotRestoreOpt},
+        },
+        state_snapshot_increment::manifest::StateSnapshotIncrementBackup,
+    },
+    storage::{BackupStorage, FileHandle},
+    utils::{
+        codec::Codec, read_record_bytes::ReadRecordBytes, storage_ext::BackupStorageExt,
+        stream::StreamX, GlobalRestoreOptions, RestoreRunMode,
+    },
+};
+use anyhow::{anyhow, ensure, Result};
+use aptos_crypto::HashValue;
+use aptos_db::{
+    backup::state_snapshot_increment_restore::StateSnapshotIncrementRestore,
+    state_restore::StateSnapshotRestoreMode,
+};
+use aptos_logger::prelude::*;
+use aptos_types::{
+    ledger_info::LedgerInfoWithSignatures,
+    proof::TransactionInfoWithProof,
+    state_store::{state_key::StateKey, state_value::StateValue},
+};
+use clap::Parser;
+use futures::{stream, Stream, TryStreamExt};
+use std::sync::Arc;
+use tokio::time::Instant;
+
+#[derive(Parser)]
+pub struct StateSnapshotIncrementRestoreOpt {
+    #[clap(
+        long = "state-manifest",
+        help = "Manifest of the full state snapshot the increments are applied on top of."
+    )]
+    pub base_manifest_handle: FileHandle,
+    #[clap(
+        long = "state-increment-manifest",
+        required = true,
+        help = "(multiple) Manifests of the state snapshot increments, in the order they are to \
+        be applied. Each must be taken over the version of the previous one."
+    )]
+    pub increment_manifest_handles: Vec<FileHandle>,
+}
+
+pub struct StateSnapshotIncrementRestoreController {
+    storage: Arc<dyn BackupStorage>,
+    global_opt: GlobalRestoreOptions,
+    base_manifest_handle: FileHandle,
+    increment_manifest_handles: Vec<FileHandle>,
+    epoch_history: Option<Arc<EpochHistory>>,
+}
+
+impl StateSnapshotIncrementRestoreController {
+    pub fn new(
+        opt: StateSnapshotIncrementRestoreOpt,
+        global_opt: GlobalRestoreOptions,
+        storage: Arc<dyn BackupStorage>,
+        epoch_history: Option<Arc<EpochHistory>>,
+    ) -> Self {
+        Self {
+            storage,
+            global_opt,
+            base_manifest_handle: opt.base_manifest_handle,
+            increment_manifest_handles: opt.increment_manifest_handles,
+            epoch_history,
+        }
+    }
+
+    pub async fn run(self) -> Result<()> {
+        let name = self.name();
+        let start = Instant::now();
+        info!(
+            "{} started. Base manifest: {}, increments: {}",
+            name,
+            self.base_manifest_handle,
+            self.increment_manifest_handles.len(),
+        );
+        self.run_impl()
+            .await
+            .map_err(|e| anyhow!("{} failed: {}", name, e))?;
+        info!(time = start.elapsed().as_secs(), "{} succeeded.", name);
+        Ok(())
+    }
+}
+
+impl StateSnapshotIncrementRestoreController {
+    fn name(&self) -> String {
+        format!(
+            "state snapshot increment {}",
+            self.global_opt.run_mode.name()
+        )
+    }
+
+    async fn run_impl(self) -> Result<()> {
+        let base_manifest: StateSnapshotBackup = self
+            .storage
+            .load_json_file(&self.base_manifest_handle)
+            .await?;
+        let mut current_version = base_manifest.version;
+        StateSnapshotRestoreController::new(
+            StateSnapshotRestoreOpt {
+                manifest_handle: self.base_manifest_handle.clone(),
+                version: current_version,
+                validate_modules: false,
+                restore_mode: StateSnapshotRestoreMode::Default,
+            },
+            self.global_opt.clone(),
+            Arc::clone(&self.storage),
+            self.epoch_history.clone(),
+        )
+        .run()
+        .await?;
+
+        for manifest_handle in &self.increment_manifest_handles {
+            let manifest: StateSnapshotIncrementBackup =
+                self.storage.load_json_file(manifest_handle).await?;
+            manifest.verify()?;
+            ensure!(
+                manifest.base_version == current_version,
+                "State snapshot increment {} is taken over version {}, expected {}.",
+                manifest_handle,
+                manifest.base_version,
+                current_version,
+            );
+            if manifest.version > self.global_opt.target_version {
+                warn!(
+                    "State snapshot increment {} is at version {}, newer than the target version {}, stopping.",
+                    manifest_handle,
+                    manifest.version,
+                    self.global_opt.target_version,
+                );
+                break;
+            }
+
+            self.apply_increment(&manifest).await?;
+            current_version = manifest.version;
+        }
+
+        self.global_opt.run_mode.finish();
+        Ok(())
+    }
+
+    async fn apply_increment(&self, manifest: &StateSnapshotIncrementBackup) -> Result<()> {
+        let codec = Codec::for_backup(&manifest.codec, self.global_opt.encryption_key.as_ref())?;
+        let (txn_info_with_proof, li): (TransactionInfoWithProof, LedgerInfoWithSignatures) = self
+            .storage
+            .load_bcs_file_with_codec(&manifest.proof, &codec)
+            .await?;
+        txn_info_with_proof.verify(li.ledger_info(), manifest.version)?;
+        let state_root_hash = txn_info_with_proof
+            .transaction_info()
+            .ensure_state_checkpoint_hash()?;
+        ensure!(
+            state_root_hash == manifest.root_hash,
+            "Root hash mismatch with that in proof. root hash: {}, expected: {}",
+            manifest.root_hash,
+            state_root_hash,
+        );
+        if let Some(epoch_history) = self.epoch_history.as_ref() {
+            epoch_history.verify_ledger_info(&li)?;
+        }
+
+        if let RestoreRunMode::Restore { restore_handler } = self.global_opt.run_mode.as_ref() {
+            if restore_handler.get_state_snapshot_before(manifest.version + 1)?
+                == Some((manifest.version, manifest.root_hash))
+            {
+                info!(
+                    version = manifest.version,
+                    "State snapshot increment already applied, skipping."
+                );
+                return Ok(());
+            }
+        }
+
+        match self.global_opt.run_mode.as_ref() {
+            RestoreRunMode::Restore { restore_handler } => {
+                // The changes are gone through twice, first only to compute the resulting root
+                // hash, so that nothing is written from a bad increment.
+                let root_hash = self
+                    .add_changes(
+                        manifest,
+                        &codec,
+                        restore_handler.get_state_snapshot_increment_receiver(
+                            manifest.base_version,
+                            manifest.version,
+                            /*commit=*/ false,
+                        )?,
+                    )
+                    .await?;
+                ensure!(
+                    root_hash == manifest.root_hash,
+                    "Root hash mismatch after applying the increment. root hash: {}, expected: {}",
+                    root_hash,
+                    manifest.root_hash,
+                );
+                self.add_changes(
+                    manifest,
+                    &codec,
+                    restore_handler.get_state_snapshot_increment_receiver(
+                        manifest.base_version,
+                        manifest.version,
+                        /*commit=*/ true,
+                    )?,
+                )
+                .await?;
+                info!(
+                    base_version = manifest.base_version,
+                    version = manifest.version,
+                    "State snapshot increment applied."
+                );
+            },
+            RestoreRunMode::Verify => {
+                let mut num_changes = 0;
+                let mut chunks = self.read_chunks(manifest, &codec);
+                while let Some(chunk) = chunks.try_next().await? {
+                    num_changes += chunk.len();
+                }
+                info!(
+                    base_version = manifest.base_version,
+                    version = manifest.version,
+                    num_changes = num_changes,
+                    "State snapshot increment read and its proof verified."
+                );
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Feeds the changes to `receiver` chunk by chunk, returning the resulting root hash.
+    async fn add_changes(
+        &self,
+        manifest: &StateSnapshotIncrementBackup,
+        codec: &Codec,
+        mut receiver: StateSnapshotIncrementRestore,
+    ) -> Result<HashValue> {
+        let mut chunks = self.read_chunks(manifest, codec);
+        while let Some(chunk) = chunks.try_next().await? {
+            receiver = tokio::task::spawn_blocking(move || {
+                receiver.add_chunk(chunk)?;
+                Result::<_>::Ok(receiver)
+            })
+            .await??;
+        }
+        tokio::task::spawn_blocking(move || receiver.finish()).await?
+    }
+
+    fn read_chunks<'a>(
+        &'a self,
+        manifest: &'a StateSnapshotIncrementBackup,
+        codec: &Codec,
+    ) -> impl Stream<Item = Result<Vec<(StateKey, Option<StateValue>)>>> + Unpin + 'a {
+        let codec = codec.clone();
+        let futs_iter = manifest.chunks.iter().map(move |chunk| {
+            let storage = Arc::clone(&self.storage);
+            let blobs = chunk.blobs.clone();
+            let codec = codec.clone();
+            async move {
+                tokio::spawn(async move { Self::read_changes(&storage, blobs, &codec).await })
+                    .await?
+            }
+        });
+        let con = self.global_opt.concurrent_downloads;
+        stream::iter(futs_iter).buffered_x(con * 2, con)
+    }
+
+    async fn read_changes(
+        storage: &Arc<dyn BackupStorage>,
+        file_handle: FileHandle,
+        codec: &Codec,
+    ) -> Result<Vec<(StateKey, Option<StateValue>)>> {
+        let mut file = storage
+            .open_for_read_with_codec(&file_handle, codec)
+            .await?;
+
+        let mut chunk = vec![];
+
+        while let Some(record_bytes) = file.read_record_bytes().await? {
+            chunk.push(bcs::from_bytes(&record_bytes)?);
+        }
+
+        Ok(chunk)
+    }
+}
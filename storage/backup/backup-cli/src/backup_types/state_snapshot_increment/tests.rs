@@ -0,0 +1,132 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    backup_types::{
+        state_snapshot::backup::{StateSnapshotBackupController, StateSnapshotBackupOpt},
+        state_snapshot_increment::{
+            backup::{StateSnapshotIncrementBackupController, StateSnapshotIncrementBackupOpt},
+            restore::{StateSnapshotIncrementRestoreController, StateSnapshotIncrementRestoreOpt},
+        },
+    },
+    storage::{local_fs::LocalFs, BackupStorage},
+    utils::{
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, EncryptionKeyOpt},
+        test_utils::{start_local_backup_service, tmp_db_with_random_epochs},
+        ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt,
+        RocksdbOpt, TrustedWaypointOpt,
+    },
+};
+use aptos_db::AptosDB;
+use aptos_storage_interface::DbReader;
+use aptos_temppath::TempPath;
+use std::{convert::TryInto, sync::Arc};
+use tokio::time::Duration;
+
+#[test]
+fn end_to_end() {
+    // Epoch 0 ends at the first block, the increment is taken over it.
+    let (_src_db_dir, src_db, _blocks) = tmp_db_with_random_epochs(2);
+    let tgt_db_dir = TempPath::new();
+    tgt_db_dir.create_as_dir().unwrap();
+    let backup_dir = TempPath::new();
+    backup_dir.create_as_dir().unwrap();
+    let store: Arc<dyn BackupStorage> = Arc::new(LocalFs::new(backup_dir.path().to_path_buf()));
+
+    let epoch = src_db
+        .get_latest_ledger_info()
+        .unwrap()
+        .ledger_info()
+        .next_block_epoch()
+        - 1;
+    let mut epoch_ending_lis = src_db
+        .get_epoch_ending_ledger_infos(0, epoch + 1)
+        .unwrap()
+        .ledger_info_with_sigs;
+    let base_version = epoch_ending_lis[0].ledger_info().version();
+    let version = epoch_ending_lis.pop().unwrap().ledger_info().version();
+    let state_root_hash = src_db
+        .get_transactions(version, 1, version, false)
+        .unwrap()
+        .proof
+        .transaction_infos
+        .pop()
+        .unwrap()
+        .state_checkpoint_hash()
+        .unwrap();
+
+    let (rt, port) = start_local_backup_service(src_db);
+    let client = Arc::new(BackupServiceClient::new(format!(
+        "http://localhost:{}",
+        port
+    )));
+    let global_backup_opt = GlobalBackupOpt {
+        max_chunk_size: 500,
+        codec: BackupCodecOpt::default(),
+    };
+    let base_manifest_handle = rt
+        .block_on(
+            StateSnapshotBackupController::new(
+                StateSnapshotBackupOpt { epoch: 0 },
+                global_backup_opt.clone(),
+                Arc::clone(&client),
+                Arc::clone(&store),
+            )
+            .run(),
+        )
+        .unwrap();
+    let increment_manifest_handle = rt
+        .block_on(
+            StateSnapshotIncrementBackupController::new(
+                StateSnapshotIncrementBackupOpt {
+                    epoch,
+                    base_version,
+                    sort_dir: None,
+                    // Sorts the changes through several runs.
+                    sort_buffer_size: 100,
+                },
+                global_backup_opt,
+                client,
+                Arc::clone(&store),
+            )
+            .run(),
+        )
+        .unwrap();
+
+    rt.block_on(
+        StateSnapshotIncrementRestoreController::new(
+            StateSnapshotIncrementRestoreOpt {
+                base_manifest_handle,
+                increment_manifest_handles: vec![increment_manifest_handle],
+            },
+            GlobalRestoreOpt {
+                dry_run: false,
+                db_dir: Some(tgt_db_dir.path().to_path_buf()),
+                target_version: None, // max
+                trusted_waypoints: TrustedWaypointOpt::default(),
+                rocksdb_opt: RocksdbOpt::default(),
+                concurrent_downloads: ConcurrentDownloadsOpt::default(),
+                replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+                encryption_key: EncryptionKeyOpt::default(),
+            }
+            .try_into()
+            .unwrap(),
+            store,
+            None, /* epoch_history */
+        )
+        .run(),
+    )
+    .unwrap();
+
+    let tgt_db = AptosDB::new_readonly_for_test(&tgt_db_dir);
+    assert_eq!(
+        tgt_db
+            .get_state_snapshot_before(version + 1)
+            .unwrap()
+            .unwrap(),
+        (version, state_root_hash)
+    );
+
+    rt.shutdown_timeout(Duration::from_secs(1));
+}
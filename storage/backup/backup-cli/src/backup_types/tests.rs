@@ -15,7 +15,9 @@ use crate::{
     },
     storage::{local_fs::LocalFs, BackupStorage},
     utils::{
-        backup_service_client::BackupServiceClient, test_utils::start_local_backup_service,
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, EncryptionKeyOpt},
+        test_utils::start_local_backup_service,
         ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, GlobalRestoreOptions,
         ReplayConcurrencyLevelOpt, RocksdbOpt, TrustedWaypointOpt,
     },
@@ -88,6 +90,7 @@ fn test_end_to_end_impl(d: TestData) {
     // Backup
     let global_backup_opt = GlobalBackupOpt {
         max_chunk_size: 2048,
+        codec: BackupCodecOpt::default(),
     };
     let state_snapshot_manifest = d.state_snapshot_epoch.map(|epoch| {
         rt.block_on(
@@ -124,6 +127,7 @@ fn test_end_to_end_impl(d: TestData) {
         rocksdb_opt: RocksdbOpt::default(),
         concurrent_downloads: ConcurrentDownloadsOpt::default(),
         replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+        encryption_key: EncryptionKeyOpt::default(),
     }
     .try_into()
     .unwrap();
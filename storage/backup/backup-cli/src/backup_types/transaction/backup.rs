@@ -7,8 +7,12 @@ use crate::{
     metadata::Metadata,
     storage::{BackupHandleRef, BackupStorage, FileHandle, ShellSafeName},
     utils::{
-        backup_service_client::BackupServiceClient, read_record_bytes::ReadRecordBytes,
-        should_cut_chunk, storage_ext::BackupStorageExt, GlobalBackupOpt,
+        backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, Codec},
+        read_record_bytes::ReadRecordBytes,
+        should_cut_chunk,
+        storage_ext::BackupStorageExt,
+        GlobalBackupOpt,
     },
 };
 use anyhow::{anyhow, ensure, Result};
@@ -17,7 +21,7 @@ use aptos_types::transaction::Version;
 use clap::Parser;
 use once_cell::sync::Lazy;
 use std::{convert::TryInto, str::FromStr, sync::Arc};
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 #[derive(Parser)]
 pub struct TransactionBackupOpt {
@@ -32,6 +36,7 @@ pub struct TransactionBackupController {
     start_version: u64,
     num_transactions: usize,
     max_chunk_size: usize,
+    codec_opt: BackupCodecOpt,
     client: Arc<BackupServiceClient>,
     storage: Arc<dyn BackupStorage>,
 }
@@ -47,6 +52,7 @@ impl TransactionBackupController {
             start_version: opt.start_version,
             num_transactions: opt.num_transactions,
             max_chunk_size: global_opt.max_chunk_size,
+            codec_opt: global_opt.codec,
             client,
             storage,
         }
@@ -68,6 +74,7 @@ impl TransactionBackupController {
 
 impl TransactionBackupController {
     async fn run_impl(self) -> Result<FileHandle> {
+        let codec = self.codec_opt.init()?;
         let backup_handle = self
             .storage
             .create_backup_with_random_suffix(&self.backup_name())
@@ -91,6 +98,7 @@ impl TransactionBackupController {
                         &chunk_bytes,
                         chunk_first_ver,
                         current_ver - 1,
+                        &codec,
                     )
                     .await?;
                 chunks.push(chunk);
@@ -117,12 +125,19 @@ impl TransactionBackupController {
                 &chunk_bytes,
                 chunk_first_ver,
                 current_ver - 1,
+                &codec,
             )
             .await?;
         chunks.push(chunk);
 
-        self.write_manifest(&backup_handle, self.start_version, current_ver - 1, chunks)
-            .await
+        self.write_manifest(
+            &backup_handle,
+            self.start_version,
+            current_ver - 1,
+            chunks,
+            &codec,
+        )
+        .await
     }
 
     fn backup_name(&self) -> String {
@@ -151,30 +166,33 @@ impl TransactionBackupController {
         chunk_bytes: &[u8],
         first_version: u64,
         last_version: u64,
+        codec: &Codec,
     ) -> Result<TransactionChunk> {
-        let (proof_handle, mut proof_file) = self
+        let mut proof_bytes = Vec::new();
+        self.client
+            .get_transaction_range_proof(first_version, last_version)
+            .await?
+            .read_to_end(&mut proof_bytes)
+            .await?;
+        let proof_handle = self
             .storage
-            .create_for_write(
+            .write_with_codec(
                 backup_handle,
                 &Self::chunk_proof_name(first_version, last_version),
+                &proof_bytes,
+                codec,
             )
             .await?;
-        tokio::io::copy(
-            &mut self
-                .client
-                .get_transaction_range_proof(first_version, last_version)
-                .await?,
-            &mut proof_file,
-        )
-        .await?;
-        proof_file.shutdown().await?;
 
-        let (chunk_handle, mut chunk_file) = self
+        let chunk_handle = self
             .storage
-            .create_for_write(backup_handle, &Self::chunk_name(first_version))
+            .write_with_codec(
+                backup_handle,
+                &Self::chunk_name(first_version),
+                chunk_bytes,
+                codec,
+            )
             .await?;
-        chunk_file.write_all(chunk_bytes).await?;
-        chunk_file.shutdown().await?;
 
         Ok(TransactionChunk {
             first_version,
@@ -190,11 +208,13 @@ impl TransactionBackupController {
         first_version: Version,
         last_version: Version,
         chunks: Vec<TransactionChunk>,
+        codec: &Codec,
     ) -> Result<FileHandle> {
         let manifest = TransactionBackup {
             first_version,
             last_version,
             chunks,
+            codec: codec.backup_codec(),
         };
         let (manifest_handle, mut manifest_file) = self
             .storage
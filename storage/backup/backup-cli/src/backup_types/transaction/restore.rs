@@ -17,6 +17,7 @@ use crate::{
     },
     storage::{BackupStorage, FileHandle},
     utils::{
+        codec::Codec,
         error_notes::ErrorNotes,
         read_record_bytes::ReadRecordBytes,
         storage_ext::BackupStorageExt,
@@ -97,10 +98,15 @@ struct LoadedChunk {
 impl LoadedChunk {
     async fn load(
         manifest: TransactionChunk,
+        codec: &Codec,
         storage: &Arc<dyn BackupStorage>,
         epoch_history: Option<&Arc<EpochHistory>>,
     ) -> Result<Self> {
-        let mut file = BufReader::new(storage.open_for_read(&manifest.transactions).await?);
+        let mut file = BufReader::new(
+            storage
+                .open_for_read_with_codec(&manifest.transactions, codec)
+                .await?,
+        );
         let mut txns = Vec::new();
         let mut txn_infos = Vec::new();
         let mut event_vecs = Vec::new();
@@ -123,10 +129,11 @@ impl LoadedChunk {
             txns.len(),
         );
 
-        let (range_proof, ledger_info) = storage
-            .load_bcs_file::<(TransactionAccumulatorRangeProof, LedgerInfoWithSignatures)>(
-                &manifest.proof,
-            )
+        let (range_proof, ledger_info): (
+            TransactionAccumulatorRangeProof,
+            LedgerInfoWithSignatures,
+        ) = storage
+            .load_bcs_file_with_codec(&manifest.proof, codec)
             .await?;
         if let Some(epoch_history) = epoch_history {
             epoch_history.verify_ledger_info(&ledger_info)?;
@@ -325,17 +332,29 @@ impl TransactionRestoreBatchController {
             .buffered_x(con * 3, con)
             .and_then(|m: TransactionBackup| future::ready(m.verify().map(|_| m)));
 
+        let encryption_key = self.global_opt.encryption_key.clone();
         let target_version = self.global_opt.target_version;
         let first_version = self.first_version.unwrap_or(0);
         let chunk_manifest_stream = manifest_stream
-            .map_ok(|m| stream::iter(m.chunks.into_iter().map(Result::<_>::Ok)))
+            .and_then(move |m| {
+                future::ready(
+                    Codec::for_backup(&m.codec, encryption_key.as_ref()).map(|codec| (m, codec)),
+                )
+            })
+            .map_ok(|(m, codec)| {
+                stream::iter(
+                    m.chunks
+                        .into_iter()
+                        .map(move |c| Result::<_>::Ok((c, codec.clone()))),
+                )
+            })
             .try_flatten()
-            .try_filter(move |c| {
+            .try_filter(move |(c, _codec)| {
                 future::ready(c.first_version <= target_version && c.last_version >= first_version)
             })
             .scan(0, |last_chunk_last_version, chunk_res| {
                 let res = match &chunk_res {
-                    Ok(chunk) => {
+                    Ok((chunk, _codec)) => {
                         if *last_chunk_last_version != 0
                             && chunk.first_version != *last_chunk_last_version + 1
                         {
@@ -357,12 +376,12 @@ impl TransactionRestoreBatchController {
         let storage = self.storage.clone();
         let epoch_history = self.epoch_history.clone();
         chunk_manifest_stream
-            .and_then(move |chunk| {
+            .and_then(move |(chunk, codec)| {
                 let storage = storage.clone();
                 let epoch_history = epoch_history.clone();
                 future::ok(async move {
                     tokio::task::spawn(async move {
-                        LoadedChunk::load(chunk, &storage, epoch_history.as_ref()).await
+                        LoadedChunk::load(chunk, &codec, &storage, epoch_history.as_ref()).await
                     })
                     .err_into::<anyhow::Error>()
                     .await
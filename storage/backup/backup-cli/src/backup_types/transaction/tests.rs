@@ -10,6 +10,7 @@ use crate::{
     storage::{local_fs::LocalFs, BackupStorage},
     utils::{
         backup_service_client::BackupServiceClient,
+        codec::{BackupCodecOpt, Compression, EncryptionKeyOpt},
         test_utils::{start_local_backup_service, tmp_db_with_random_content},
         ConcurrentDownloadsOpt, GlobalBackupOpt, GlobalRestoreOpt, ReplayConcurrencyLevelOpt,
         RocksdbOpt, TrustedWaypointOpt,
@@ -54,6 +55,9 @@ fn end_to_end() {
         .unwrap() // biggest txn
         + 115 // size of a serialized TransactionInfo
         + size_of::<u32>(); // record len header
+    let encryption_key_file = TempPath::new();
+    std::fs::write(encryption_key_file.path(), hex::encode([7u8; 32])).unwrap();
+
     let first_ver_to_backup = (total_txns / 4) as Version;
     let num_txns_to_backup = total_txns - first_ver_to_backup as usize;
     let target_version = first_ver_to_backup + total_txns as Version / 2;
@@ -66,7 +70,10 @@ fn end_to_end() {
                         start_version: 0,
                         num_transactions: first_ver_to_backup as usize,
                     },
-                    GlobalBackupOpt { max_chunk_size },
+                    GlobalBackupOpt {
+                        max_chunk_size,
+                        codec: BackupCodecOpt::default(),
+                    },
                     client.clone(),
                     Arc::clone(&store),
                 )
@@ -83,7 +90,18 @@ fn end_to_end() {
                     start_version: first_ver_to_backup,
                     num_transactions: num_txns_to_backup,
                 },
-                GlobalBackupOpt { max_chunk_size },
+                // Compressed and encrypted, while the backup above is stored as is.
+                GlobalBackupOpt {
+                    max_chunk_size,
+                    codec: BackupCodecOpt {
+                        compression: Compression::Zstd,
+                        compression_level: 0,
+                        encryption_key: EncryptionKeyOpt {
+                            encryption_key_file: Some(encryption_key_file.path().to_path_buf()),
+                            ..EncryptionKeyOpt::default()
+                        },
+                    },
+                },
                 client,
                 Arc::clone(&store),
             )
@@ -101,6 +119,10 @@ fn end_to_end() {
                 rocksdb_opt: RocksdbOpt::default(),
                 concurrent_downloads: ConcurrentDownloadsOpt::default(),
                 replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+                encryption_key: EncryptionKeyOpt {
+                    encryption_key_file: Some(encryption_key_file.path().to_path_buf()),
+                    ..EncryptionKeyOpt::default()
+                },
             }
             .try_into()
             .unwrap(),
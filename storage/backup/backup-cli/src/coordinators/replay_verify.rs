@@ -10,7 +10,10 @@ use crate::{
     metadata,
     metadata::cache::MetadataCacheOpt,
     storage::BackupStorage,
-    utils::{GlobalRestoreOptions, RestoreRunMode, TrustedWaypointOpt},
+    utils::{
+        codec::{EncryptionKey, EncryptionKeyOpt},
+        GlobalRestoreOptions, RestoreRunMode, TrustedWaypointOpt,
+    },
 };
 use anyhow::{bail, ensure, Result};
 use aptos_db::backup::restore_handler::RestoreHandler;
@@ -31,6 +34,7 @@ pub struct ReplayVerifyCoordinator {
     end_version: Version,
     validate_modules: bool,
     verify_execution_mode: VerifyExecutionMode,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl ReplayVerifyCoordinator {
@@ -45,6 +49,7 @@ impl ReplayVerifyCoordinator {
         end_version: Version,
         validate_modules: bool,
         verify_execution_mode: VerifyExecutionMode,
+        encryption_key_opt: EncryptionKeyOpt,
     ) -> Result<Self> {
         Ok(Self {
             storage,
@@ -57,6 +62,7 @@ impl ReplayVerifyCoordinator {
             end_version,
             validate_modules,
             verify_execution_mode,
+            encryption_key: encryption_key_opt.load()?,
         })
     }
 
@@ -134,6 +140,7 @@ impl ReplayVerifyCoordinator {
             run_mode,
             concurrent_downloads: self.concurrent_downloads,
             replay_concurrency_level: 0, // won't replay, doesn't matter
+            encryption_key: self.encryption_key,
         };
 
         if let Some(backup) = state_snapshot {
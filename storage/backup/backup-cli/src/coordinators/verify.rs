@@ -14,7 +14,10 @@ use crate::{
         VERIFY_COORDINATOR_FAIL_TS, VERIFY_COORDINATOR_START_TS, VERIFY_COORDINATOR_SUCC_TS,
     },
     storage::BackupStorage,
-    utils::{unix_timestamp_sec, GlobalRestoreOptions, RestoreRunMode, TrustedWaypointOpt},
+    utils::{
+        codec::{EncryptionKey, EncryptionKeyOpt},
+        unix_timestamp_sec, GlobalRestoreOptions, RestoreRunMode, TrustedWaypointOpt,
+    },
 };
 use anyhow::Result;
 use aptos_db::state_restore::StateSnapshotRestoreMode;
@@ -34,6 +37,7 @@ pub struct VerifyCoordinator {
     skip_epoch_endings: bool,
     validate_modules: bool,
     output_transaction_analysis: Option<PathBuf>,
+    encryption_key: Option<EncryptionKey>,
 }
 
 impl VerifyCoordinator {
@@ -48,6 +52,7 @@ impl VerifyCoordinator {
         skip_epoch_endings: bool,
         validate_modules: bool,
         output_transaction_analysis: Option<PathBuf>,
+        encryption_key_opt: EncryptionKeyOpt,
     ) -> Result<Self> {
         Ok(Self {
             storage,
@@ -60,6 +65,7 @@ impl VerifyCoordinator {
             skip_epoch_endings,
             validate_modules,
             output_transaction_analysis,
+            encryption_key: encryption_key_opt.load()?,
         })
     }
 
@@ -102,6 +108,7 @@ impl VerifyCoordinator {
             run_mode: Arc::new(RestoreRunMode::Verify),
             concurrent_downloads: self.concurrent_downloads,
             replay_concurrency_level: 0, // won't replay, doesn't matter
+            encryption_key: self.encryption_key,
         };
 
         let epoch_history = if self.skip_epoch_endings {
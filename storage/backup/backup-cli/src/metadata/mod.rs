@@ -20,6 +20,7 @@ pub(crate) enum Metadata {
     TransactionBackup(TransactionBackupMeta),
     Identity(IdentityMeta),
     CompactionTimestamps(CompactionTimestampsMeta),
+    StateSnapshotIncrementBackup(StateSnapshotIncrementBackupMeta),
 }
 
 impl Metadata {
@@ -47,6 +48,20 @@ impl Metadata {
         })
     }
 
+    pub fn new_state_snapshot_increment_backup(
+        base_version: Version,
+        epoch: u64,
+        version: Version,
+        manifest: FileHandle,
+    ) -> Self {
+        Self::StateSnapshotIncrementBackup(StateSnapshotIncrementBackupMeta {
+            base_version,
+            epoch,
+            version,
+            manifest,
+        })
+    }
+
     pub fn new_transaction_backup(
         first_version: Version,
         last_version: Version,
@@ -112,6 +127,25 @@ impl Metadata {
         Ok((res, name.parse()?))
     }
 
+    pub fn compact_state_snapshot_increment_backup_range(
+        backup_metas: Vec<StateSnapshotIncrementBackupMeta>,
+    ) -> Result<(Vec<TextLine>, ShellSafeName)> {
+        ensure!(
+            !backup_metas.is_empty(),
+            "compacting an empty metadata vector"
+        );
+        let name = format!(
+            "state_snapshot_increment_compacted_{}-{}.meta",
+            backup_metas[0].version,
+            backup_metas[backup_metas.len() - 1].version
+        );
+        let res: Vec<TextLine> = backup_metas
+            .into_iter()
+            .map(|e| Metadata::StateSnapshotIncrementBackup(e).to_text_line())
+            .collect::<Result<_>>()?;
+        Ok((res, name.parse()?))
+    }
+
     pub fn compact_transaction_backup_range(
         backup_metas: Vec<TransactionBackupMeta>,
     ) -> Result<(Vec<TextLine>, ShellSafeName)> {
@@ -163,6 +197,10 @@ impl Metadata {
             Self::CompactionTimestamps(e) => {
                 format!("compaction_timestamps_{}.meta", e.file_compacted_at,)
             },
+            Self::StateSnapshotIncrementBackup(s) => format!(
+                "state_snapshot_increment_{}-{}.meta",
+                s.base_version, s.version
+            ),
         }
         .try_into()
         .unwrap()
@@ -189,6 +227,15 @@ pub struct StateSnapshotBackupMeta {
     pub manifest: FileHandle,
 }
 
+/// Ordered by `version` first, so that a chain of increments is in order.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd)]
+pub struct StateSnapshotIncrementBackupMeta {
+    pub version: Version,
+    pub base_version: Version,
+    pub epoch: u64,
+    pub manifest: FileHandle,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd)]
 pub struct TransactionBackupMeta {
     pub first_version: Version,
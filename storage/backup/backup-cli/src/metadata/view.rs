@@ -5,7 +5,7 @@
 use crate::{
     metadata::{
         CompactionTimestampsMeta, EpochEndingBackupMeta, IdentityMeta, Metadata,
-        StateSnapshotBackupMeta, TransactionBackupMeta,
+        StateSnapshotBackupMeta, StateSnapshotIncrementBackupMeta, TransactionBackupMeta,
     },
     storage::FileHandle,
 };
@@ -19,6 +19,7 @@ use std::{fmt, str::FromStr};
 pub struct MetadataView {
     epoch_ending_backups: Vec<EpochEndingBackupMeta>,
     state_snapshot_backups: Vec<StateSnapshotBackupMeta>,
+    state_snapshot_increment_backups: Vec<StateSnapshotIncrementBackupMeta>,
     transaction_backups: Vec<TransactionBackupMeta>,
     _identity: Option<IdentityMeta>,
     // The compaction timestamps of the file handles producing this view
@@ -29,6 +30,7 @@ impl MetadataView {
     pub(crate) fn new(metadata_vec: Vec<Metadata>, file_handles: Vec<FileHandle>) -> Self {
         let mut epoch_ending_backups = Vec::new();
         let mut state_snapshot_backups = Vec::new();
+        let mut state_snapshot_increment_backups = Vec::new();
         let mut transaction_backups = Vec::new();
         let mut identity = None;
         let mut compaction_timestamps = Vec::new();
@@ -40,12 +42,17 @@ impl MetadataView {
                 Metadata::TransactionBackup(t) => transaction_backups.push(t),
                 Metadata::Identity(i) => identity = Some(i),
                 Metadata::CompactionTimestamps(t) => compaction_timestamps.push(t),
+                Metadata::StateSnapshotIncrementBackup(s) => {
+                    state_snapshot_increment_backups.push(s)
+                },
             }
         }
         epoch_ending_backups.sort_unstable();
         epoch_ending_backups.dedup();
         state_snapshot_backups.sort_unstable();
         state_snapshot_backups.dedup();
+        state_snapshot_increment_backups.sort_unstable();
+        state_snapshot_increment_backups.dedup();
         transaction_backups.sort_unstable();
         transaction_backups.dedup();
 
@@ -71,6 +78,7 @@ impl MetadataView {
         Self {
             epoch_ending_backups,
             state_snapshot_backups,
+            state_snapshot_increment_backups,
             transaction_backups,
             _identity: identity,
             compaction_timestamps: compaction_meta_opt,
@@ -229,6 +237,13 @@ impl MetadataView {
         Self::compact_backups(&self.state_snapshot_backups, compaction_cnt)
     }
 
+    pub fn compact_state_snapshot_increment_backups(
+        &mut self,
+        compaction_cnt: usize,
+    ) -> Result<Vec<&[StateSnapshotIncrementBackupMeta]>> {
+        Self::compact_backups(&self.state_snapshot_increment_backups, compaction_cnt)
+    }
+
     pub fn get_file_handles(&self) -> Vec<FileHandle> {
         self.select_latest_compaction_timestamps()
             .as_ref()
@@ -7,6 +7,7 @@ use once_cell::sync::Lazy;
 
 pub mod backup;
 pub mod metadata;
+pub mod object_store;
 pub mod restore;
 pub mod verify;
 
@@ -0,0 +1,44 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_push_metrics::{
+    exponential_buckets, register_histogram_vec, register_int_counter, register_int_counter_vec,
+    HistogramVec, IntCounter, IntCounterVec,
+};
+use once_cell::sync::Lazy;
+
+pub static REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_backup_object_store_requests",
+        "Number of requests sent to the object store, by operation and result.",
+        &["operation", "result"]
+    )
+    .unwrap()
+});
+
+pub static RETRIES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_backup_object_store_retries",
+        "Number of object store requests retried after a failed attempt.",
+        &["operation"]
+    )
+    .unwrap()
+});
+
+pub static REQUEST_LATENCY_SECONDS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "aptos_backup_object_store_request_latency_seconds",
+        "Latency of a single object store request attempt.",
+        &["operation"],
+        exponential_buckets(/*start=*/ 1e-3, /*factor=*/ 2.0, /*count=*/ 20).unwrap(),
+    )
+    .unwrap()
+});
+
+pub static BYTES_UPLOADED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_backup_object_store_bytes_uploaded",
+        "Number of bytes uploaded to the object store."
+    )
+    .unwrap()
+});
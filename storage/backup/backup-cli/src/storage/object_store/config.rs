@@ -0,0 +1,106 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::error_notes::ErrorNotes;
+use anyhow::{ensure, format_err, Result};
+use serde::Deserialize;
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ObjectStoreConfig {
+    /// Base URL of the S3 compatible API, e.g. "https://s3.us-west-2.amazonaws.com" or
+    /// "http://localhost:9000" for a local MinIO.
+    pub endpoint: String,
+    /// Region used to sign the requests, stand-ins like MinIO accept any.
+    #[serde(default = "ObjectStoreConfig::default_region")]
+    pub region: String,
+    pub bucket: String,
+    /// Key prefix under which everything is stored, without leading or trailing "/".
+    #[serde(default)]
+    pub prefix: String,
+    /// Address the bucket as "<endpoint>/<bucket>/<key>" instead of
+    /// "<bucket>.<endpoint host>/<key>". Required by most S3 stand-ins.
+    #[serde(default)]
+    pub force_path_style: bool,
+    /// Files larger than this are uploaded in parts of this size with a multipart upload.
+    /// S3 requires parts of at least 5MB.
+    #[serde(default = "ObjectStoreConfig::default_part_size_mb")]
+    pub part_size_mb: usize,
+    /// How many times a failed request is retried before giving up.
+    #[serde(default = "ObjectStoreConfig::default_max_retries")]
+    pub max_retries: usize,
+    /// Credentials, read from the AWS_ACCESS_KEY_ID, AWS_SECRET_ACCESS_KEY and AWS_SESSION_TOKEN
+    /// environment variables if not set here.
+    #[serde(default)]
+    pub access_key_id: Option<String>,
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+    #[serde(default)]
+    pub session_token: Option<String>,
+}
+
+impl ObjectStoreConfig {
+    const ACCESS_KEY_ID_ENV_VAR: &'static str = "AWS_ACCESS_KEY_ID";
+    const SECRET_ACCESS_KEY_ENV_VAR: &'static str = "AWS_SECRET_ACCESS_KEY";
+    const SESSION_TOKEN_ENV_VAR: &'static str = "AWS_SESSION_TOKEN";
+    /// The smallest part S3 accepts in a multipart upload, except for the last one.
+    const MIN_PART_SIZE_MB: usize = 5;
+
+    fn default_region() -> String {
+        "us-east-1".to_string()
+    }
+
+    fn default_part_size_mb() -> usize {
+        16
+    }
+
+    fn default_max_retries() -> usize {
+        5
+    }
+
+    pub async fn load_from_file(path: &Path) -> Result<Self> {
+        let path_str = path.to_str().unwrap_or_default();
+        let mut file = tokio::fs::File::open(path).await.err_notes(path_str)?;
+        let mut content = Vec::new();
+        file.read_to_end(&mut content).await.err_notes(path_str)?;
+
+        serde_yaml::from_slice::<Self>(&content)?.validate()
+    }
+
+    pub fn load_from_str(content: &str) -> Result<Self> {
+        serde_yaml::from_str::<Self>(content)?.validate()
+    }
+
+    fn validate(self) -> Result<Self> {
+        ensure!(
+            self.part_size_mb >= Self::MIN_PART_SIZE_MB,
+            "part_size_mb must be at least {}, got {}.",
+            Self::MIN_PART_SIZE_MB,
+            self.part_size_mb,
+        );
+        Ok(self)
+    }
+
+    pub fn credentials(&self) -> Result<Credentials> {
+        let from_env = |value: &Option<String>, env_var: &str| {
+            value.clone().or_else(|| std::env::var(env_var).ok())
+        };
+        let required = |value: &Option<String>, env_var: &str| {
+            from_env(value, env_var).ok_or_else(|| format_err!("{} not set.", env_var))
+        };
+        Ok(Credentials {
+            access_key_id: required(&self.access_key_id, Self::ACCESS_KEY_ID_ENV_VAR)?,
+            secret_access_key: required(&self.secret_access_key, Self::SECRET_ACCESS_KEY_ENV_VAR)?,
+            session_token: from_env(&self.session_token, Self::SESSION_TOKEN_ENV_VAR),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
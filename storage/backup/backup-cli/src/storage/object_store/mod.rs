@@ -0,0 +1,337 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod config;
+mod s3;
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    storage::{
+        object_store::{config::ObjectStoreConfig, s3::S3Client},
+        BackupHandle, BackupHandleRef, BackupStorage, FileHandle, FileHandleRef, ShellSafeName,
+        TextLine,
+    },
+    utils::error_notes::ErrorNotes,
+};
+use anyhow::{format_err, Result};
+use aptos_logger::prelude::*;
+use async_trait::async_trait;
+use bytes::{Bytes, BytesMut};
+use clap::Parser;
+use futures::{
+    task::{Context, Poll},
+    Future,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    pin::Pin,
+    str::FromStr,
+    sync::Arc,
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, DuplexStream},
+    task::JoinHandle,
+};
+
+/// The operations of an S3 compatible object store that the backup storage is built upon.
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    async fn put_object(&self, key: &str, body: Bytes) -> Result<()>;
+
+    async fn get_object(&self, key: &str) -> Result<Box<dyn AsyncRead + Send + Unpin>>;
+
+    /// Lists the keys of all the objects starting with `prefix`.
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>>;
+
+    async fn copy_object(&self, source_key: &str, key: &str) -> Result<()>;
+
+    async fn delete_object(&self, key: &str) -> Result<()>;
+
+    /// Starts a multipart upload and returns its upload id.
+    async fn create_multipart_upload(&self, key: &str) -> Result<String>;
+
+    /// Uploads part `part_number` (starting from 1) and returns its ETag.
+    async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: usize,
+        body: Bytes,
+    ) -> Result<String>;
+
+    /// Assembles the object from the parts with the given ETags, in order.
+    async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> Result<()>;
+
+    async fn abort_multipart_upload(&self, key: &str, upload_id: &str) -> Result<()>;
+}
+
+#[derive(Parser, Clone, Debug, Serialize, Deserialize)]
+pub struct ObjectStoreOpt {
+    #[clap(
+        long = "config",
+        help = "Config file for the object store backup storage."
+    )]
+    config: PathBuf,
+}
+
+impl FromStr for ObjectStoreOpt {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ObjectStoreOpt {
+            config: PathBuf::from(s),
+        })
+    }
+}
+
+/// A BackupStorage that talks to an S3 compatible object store directly, see
+/// `ObjectStoreConfig`.
+///
+/// Files are stored as objects under the configured prefix, with keys laid out the same as the
+/// paths of `LocalFs`.
+pub struct ObjectStoreStorage {
+    store: Arc<dyn ObjectStore>,
+    prefix: String,
+    part_size: usize,
+}
+
+impl ObjectStoreStorage {
+    const METADATA_BACKUP_DIR: &'static str = "metadata_backup";
+    const METADATA_DIR: &'static str = "metadata";
+    /// Bytes buffered between the writer returned by `create_for_write()` and the upload.
+    const WRITE_BUFFER_SIZE: usize = 1024 * 1024;
+
+    pub fn new(store: Arc<dyn ObjectStore>, prefix: String, part_size: usize) -> Self {
+        Self {
+            store,
+            prefix: prefix.trim_matches('/').to_string(),
+            part_size,
+        }
+    }
+
+    pub async fn new_with_opt(opt: ObjectStoreOpt) -> Result<Self> {
+        let config = ObjectStoreConfig::load_from_file(&opt.config).await?;
+
+        Ok(Self::new(
+            Arc::new(S3Client::new(&config)?),
+            config.prefix,
+            config.part_size_mb * 1024 * 1024,
+        ))
+    }
+
+    fn key(&self, file_handle: &FileHandleRef) -> String {
+        if self.prefix.is_empty() {
+            file_handle.to_string()
+        } else {
+            format!("{}/{}", self.prefix, file_handle)
+        }
+    }
+
+    fn file_handle(&self, key: &str) -> Option<FileHandle> {
+        if self.prefix.is_empty() {
+            Some(key.to_string())
+        } else {
+            key.strip_prefix(&self.prefix)
+                .and_then(|k| k.strip_prefix('/'))
+                .map(str::to_string)
+        }
+    }
+}
+
+#[async_trait]
+impl BackupStorage for ObjectStoreStorage {
+    async fn create_backup(&self, name: &ShellSafeName) -> Result<BackupHandle> {
+        // There are no directories in an object store, files of the backup share a key prefix.
+        Ok(name.to_string())
+    }
+
+    async fn create_for_write(
+        &self,
+        backup_handle: &BackupHandleRef,
+        name: &ShellSafeName,
+    ) -> Result<(FileHandle, Box<dyn AsyncWrite + Send + Unpin>)> {
+        let file_handle = format!("{}/{}", backup_handle, name.as_ref());
+        let (writer, reader) = tokio::io::duplex(Self::WRITE_BUFFER_SIZE);
+        let upload = tokio::spawn(upload(
+            self.store.clone(),
+            self.key(&file_handle),
+            self.part_size,
+            reader,
+        ));
+        Ok((file_handle, Box::new(ObjectSink::new(writer, upload))))
+    }
+
+    async fn open_for_read(
+        &self,
+        file_handle: &FileHandleRef,
+    ) -> Result<Box<dyn AsyncRead + Send + Unpin>> {
+        self.store.get_object(&self.key(file_handle)).await
+    }
+
+    async fn list_metadata_files(&self) -> Result<Vec<FileHandle>> {
+        let prefix = self.key(&format!("{}/", Self::METADATA_DIR));
+        Ok(self
+            .store
+            .list_objects(&prefix)
+            .await?
+            .iter()
+            .filter_map(|key| self.file_handle(key))
+            .collect())
+    }
+
+    async fn backup_metadata_file(&self, file_handle: &FileHandleRef) -> Result<()> {
+        let name = Path::new(file_handle)
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or_else(|| format_err!("cannot extract filename from {}", file_handle))?;
+        let key = self.key(file_handle);
+        let backup_key = self.key(&format!("{}/{}", Self::METADATA_BACKUP_DIR, name));
+        self.store
+            .copy_object(&key, &backup_key)
+            .await
+            .err_notes(file_handle)?;
+        self.store.delete_object(&key).await.err_notes(file_handle)
+    }
+
+    async fn save_metadata_lines(
+        &self,
+        name: &ShellSafeName,
+        lines: &[TextLine],
+    ) -> Result<FileHandle> {
+        let file_handle = format!("{}/{}", Self::METADATA_DIR, name.as_ref());
+        let content = lines
+            .iter()
+            .map(|e| e.as_ref())
+            .collect::<Vec<&str>>()
+            .join("");
+        self.store
+            .put_object(&self.key(&file_handle), Bytes::from(content))
+            .await
+            .err_notes(name)?;
+        Ok(file_handle)
+    }
+}
+
+/// Uploads everything written into the other end of `reader` as the object `key`. Small files are
+/// uploaded in one go, larger ones in parts of `part_size` with a multipart upload.
+async fn upload(
+    store: Arc<dyn ObjectStore>,
+    key: String,
+    part_size: usize,
+    mut reader: DuplexStream,
+) -> Result<()> {
+    let first_part = read_part(&mut reader, part_size).await?;
+    if first_part.len() < part_size {
+        return store.put_object(&key, first_part).await.err_notes(&key);
+    }
+
+    let upload_id = store.create_multipart_upload(&key).await.err_notes(&key)?;
+    let result = async {
+        let mut etags = Vec::new();
+        let mut part = first_part;
+        while !part.is_empty() {
+            let etag = store
+                .upload_part(&key, &upload_id, etags.len() + 1, part)
+                .await?;
+            etags.push(etag);
+            part = read_part(&mut reader, part_size).await?;
+        }
+        store
+            .complete_multipart_upload(&key, &upload_id, etags)
+            .await
+    }
+    .await;
+
+    if result.is_err() {
+        // Otherwise the uploaded parts are kept (and billed) until the bucket lifecycle rules
+        // clean them up.
+        if let Err(e) = store.abort_multipart_upload(&key, &upload_id).await {
+            warn!(key = key, error = %e, "Failed to abort multipart upload.");
+        }
+    }
+    result.err_notes(&key)
+}
+
+/// Reads until `part_size` bytes are read or EOF is hit.
+async fn read_part(reader: &mut DuplexStream, part_size: usize) -> Result<Bytes> {
+    let mut buf = BytesMut::with_capacity(part_size);
+    while buf.len() < part_size {
+        if reader.read_buf(&mut buf).await? == 0 {
+            break;
+        }
+    }
+    Ok(buf.freeze())
+}
+
+/// The writer returned by `create_for_write()`. Data written is streamed to the upload task, and
+/// shutting it down waits for the upload to finish, so a file is durable once the writer shuts
+/// down successfully.
+struct ObjectSink {
+    writer: DuplexStream,
+    upload: JoinHandle<Result<()>>,
+    shut_down: bool,
+}
+
+impl ObjectSink {
+    fn new(writer: DuplexStream, upload: JoinHandle<Result<()>>) -> Self {
+        Self {
+            writer,
+            upload,
+            shut_down: false,
+        }
+    }
+}
+
+impl AsyncWrite for ObjectSink {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, tokio::io::Error>> {
+        if self.shut_down {
+            Poll::Ready(Err(tokio::io::ErrorKind::BrokenPipe.into()))
+        } else {
+            Pin::new(&mut self.writer).poll_write(cx, buf)
+        }
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), tokio::io::Error>> {
+        if self.shut_down {
+            Poll::Ready(Err(tokio::io::ErrorKind::BrokenPipe.into()))
+        } else {
+            Pin::new(&mut self.writer).poll_flush(cx)
+        }
+    }
+
+    fn poll_shutdown(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), tokio::io::Error>> {
+        if !self.shut_down {
+            let res = Pin::new(&mut self.writer).poll_shutdown(cx);
+            if let Poll::Ready(Ok(_)) = res {
+                // EOF sent to the upload task
+                self.shut_down = true;
+            } else {
+                return res;
+            }
+        }
+
+        Pin::new(&mut self.upload).poll(cx).map(|res| {
+            res.map_err(|e| tokio::io::Error::new(tokio::io::ErrorKind::Other, e))?
+                .map_err(|e| tokio::io::Error::new(tokio::io::ErrorKind::Other, e))
+        })
+    }
+}
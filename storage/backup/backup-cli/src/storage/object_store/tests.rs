@@ -0,0 +1,202 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::storage::test_util::{
+    arb_backups, arb_metadata_files, test_save_and_list_metadata_files_impl,
+    test_write_and_read_impl,
+};
+use anyhow::{bail, ensure};
+use aptos_infallible::Mutex;
+use proptest::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use tokio::{io::AsyncWriteExt, runtime::Runtime};
+
+/// In memory stand-in of an object store.
+struct MemObjectStore {
+    objects: Mutex<BTreeMap<String, Bytes>>,
+    uploads: Mutex<HashMap<String, BTreeMap<usize, Bytes>>>,
+    fail_upload_part: bool,
+}
+
+impl MemObjectStore {
+    fn new(fail_upload_part: bool) -> Self {
+        Self {
+            objects: Mutex::new(BTreeMap::new()),
+            uploads: Mutex::new(HashMap::new()),
+            fail_upload_part,
+        }
+    }
+
+    fn num_uploads(&self) -> usize {
+        self.uploads.lock().len()
+    }
+}
+
+#[async_trait]
+impl ObjectStore for MemObjectStore {
+    async fn put_object(&self, key: &str, body: Bytes) -> Result<()> {
+        self.objects.lock().insert(key.to_string(), body);
+        Ok(())
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Box<dyn AsyncRead + Send + Unpin>> {
+        match self.objects.lock().get(key) {
+            Some(body) => Ok(Box::new(std::io::Cursor::new(body.to_vec()))),
+            None => bail!("NoSuchKey: {}", key),
+        }
+    }
+
+    async fn list_objects(&self, prefix: &str) -> Result<Vec<String>> {
+        Ok(self
+            .objects
+            .lock()
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    async fn copy_object(&self, source_key: &str, key: &str) -> Result<()> {
+        let mut objects = self.objects.lock();
+        let body = objects
+            .get(source_key)
+            .cloned()
+            .ok_or_else(|| format_err!("NoSuchKey: {}", source_key))?;
+        objects.insert(key.to_string(), body);
+        Ok(())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<()> {
+        self.objects.lock().remove(key);
+        Ok(())
+    }
+
+    async fn create_multipart_upload(&self, key: &str) -> Result<String> {
+        let upload_id = format!("{}-{}", key, self.num_uploads());
+        self.uploads
+            .lock()
+            .insert(upload_id.clone(), BTreeMap::new());
+        Ok(upload_id)
+    }
+
+    async fn upload_part(
+        &self,
+        _key: &str,
+        upload_id: &str,
+        part_number: usize,
+        body: Bytes,
+    ) -> Result<String> {
+        ensure!(!self.fail_upload_part, "InternalError");
+        self.uploads
+            .lock()
+            .get_mut(upload_id)
+            .ok_or_else(|| format_err!("NoSuchUpload: {}", upload_id))?
+            .insert(part_number, body);
+        Ok(format!("etag-{}", part_number))
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        key: &str,
+        upload_id: &str,
+        etags: Vec<String>,
+    ) -> Result<()> {
+        let parts = self
+            .uploads
+            .lock()
+            .remove(upload_id)
+            .ok_or_else(|| format_err!("NoSuchUpload: {}", upload_id))?;
+        ensure!(parts.len() == etags.len(), "InvalidPart");
+        let body = parts.into_values().flatten().collect::<Vec<_>>();
+        self.objects.lock().insert(key.to_string(), body.into());
+        Ok(())
+    }
+
+    async fn abort_multipart_upload(&self, _key: &str, upload_id: &str) -> Result<()> {
+        self.uploads.lock().remove(upload_id);
+        Ok(())
+    }
+}
+
+fn get_store(prefix: &str, part_size: usize) -> Box<dyn BackupStorage> {
+    Box::new(ObjectStoreStorage::new(
+        Arc::new(MemObjectStore::new(false)),
+        prefix.to_string(),
+        part_size,
+    ))
+}
+
+fn block_on<F: Future<Output = ()>>(f: F) {
+    Runtime::new().unwrap().block_on(f)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+
+    #[test]
+    fn test_write_and_read(
+        backups in arb_backups(),
+        part_size in 1usize..2000,
+    ) {
+        block_on(test_write_and_read_impl(get_store("backups/mainnet", part_size), backups));
+    }
+
+    #[test]
+    fn test_save_list_metadata_files(
+        input in arb_metadata_files(),
+    ) {
+        block_on(test_save_and_list_metadata_files_impl(get_store("", 1024), input));
+    }
+}
+
+#[tokio::test]
+async fn test_backup_metadata_file() {
+    let object_store = Arc::new(MemObjectStore::new(false));
+    let store = ObjectStoreStorage::new(object_store.clone(), "prefix".to_string(), 1024);
+
+    let name = ShellSafeName::from_str("epoch_ending_1.meta").unwrap();
+    let line = TextLine::new("line").unwrap();
+    let file_handle = store.save_metadata_line(&name, &line).await.unwrap();
+    assert_eq!(file_handle, "metadata/epoch_ending_1.meta");
+
+    store.backup_metadata_file(&file_handle).await.unwrap();
+    assert!(store.list_metadata_files().await.unwrap().is_empty());
+    assert_eq!(
+        object_store.list_objects("").await.unwrap(),
+        vec!["prefix/metadata_backup/epoch_ending_1.meta".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_failed_multipart_upload_is_aborted() {
+    let object_store = Arc::new(MemObjectStore::new(true));
+    let store = ObjectStoreStorage::new(object_store.clone(), "".to_string(), 4);
+
+    let name = ShellSafeName::from_str("chunk_0").unwrap();
+    let (_, mut file) = store.create_for_write("backup", &name).await.unwrap();
+    file.write_all(b"more than one part").await.unwrap();
+    assert!(file.shutdown().await.is_err());
+    assert_eq!(object_store.num_uploads(), 0);
+    assert!(object_store.list_objects("").await.unwrap().is_empty());
+}
+
+#[test]
+fn test_load_config() {
+    for sample in [
+        include_str!("sample_configs/s3.sample.yaml"),
+        include_str!("sample_configs/minio.sample.yaml"),
+    ] {
+        ObjectStoreConfig::load_from_str(sample).unwrap();
+    }
+
+    let config = "endpoint: \"http://localhost:9000\"\nbucket: \"aptos-backup\"\n";
+    assert_eq!(
+        ObjectStoreConfig::load_from_str(config)
+            .unwrap()
+            .part_size_mb,
+        16
+    );
+    assert!(ObjectStoreConfig::load_from_str(&format!("{}part_size_mb: 5\n", config)).is_ok());
+    assert!(ObjectStoreConfig::load_from_str(&format!("{}part_size_mb: 4\n", config)).is_err());
+}
@@ -0,0 +1,332 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Optional compression and client-side encryption of the data files (chunks and proofs) of a
+//! backup.
+//!
+//! Manifests and metadata files are never encoded. The codec the data files are written with is
+//! recorded in the manifest, so restoring and verifying a backup only needs the encryption key,
+//! if any.
+
+use aes_gcm::{
+    aead::{Aead, NewAead},
+    Aes256Gcm, Key, Nonce,
+};
+use anyhow::{ensure, format_err, Result};
+use aptos_config::config::SecureBackend;
+use aptos_crypto::HashValue;
+use aptos_secure_storage::{KVStorage, Storage};
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::{fmt, path::PathBuf};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Compression {
+    #[default]
+    None,
+    Zstd,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Encryption {
+    #[default]
+    None,
+    /// AES-256-GCM, each file being `nonce || ciphertext || tag`.
+    Aes256Gcm {
+        /// Identifies the key without revealing it, so that restoring with a wrong key is reported
+        /// as such instead of as corrupted data.
+        key_fingerprint: String,
+    },
+}
+
+/// How the data files of a backup are encoded, as recorded in its manifest. Data is compressed
+/// first and then encrypted.
+///
+/// Manifests written before this was introduced don't carry it and default to no compression and
+/// no encryption.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BackupCodec {
+    #[serde(default)]
+    pub compression: Compression,
+    #[serde(default)]
+    pub encryption: Encryption,
+}
+
+#[derive(Clone)]
+pub struct EncryptionKey([u8; EncryptionKey::LENGTH]);
+
+impl EncryptionKey {
+    pub const LENGTH: usize = 32;
+    const NONCE_LENGTH: usize = 12;
+
+    pub fn new(key: [u8; Self::LENGTH]) -> Self {
+        Self(key)
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let bytes = hex::decode(hex.trim().trim_start_matches("0x"))?;
+        let key = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            format_err!(
+                "Encryption key must be {} bytes, got {}.",
+                Self::LENGTH,
+                bytes.len()
+            )
+        })?;
+        Ok(Self(key))
+    }
+
+    pub fn fingerprint(&self) -> String {
+        HashValue::sha3_256_of(&self.0).to_hex()[..16].to_string()
+    }
+
+    fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let cipher = Aes256Gcm::new(Key::from_slice(&self.0));
+        let nonce: [u8; Self::NONCE_LENGTH] = rand::random();
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| format_err!("Failed to encrypt."))?;
+
+        let mut bytes = nonce.to_vec();
+        bytes.extend(ciphertext);
+        Ok(bytes)
+    }
+
+    fn decrypt(&self, bytes: &[u8]) -> Result<Vec<u8>> {
+        ensure!(
+            bytes.len() >= Self::NONCE_LENGTH,
+            "Encrypted data too short: {} bytes.",
+            bytes.len()
+        );
+        let (nonce, ciphertext) = bytes.split_at(Self::NONCE_LENGTH);
+        let cipher = Aes256Gcm::new(Key::from_slice(&self.0));
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| format_err!("Failed to decrypt, data corrupted."))
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EncryptionKey({})", self.fingerprint())
+    }
+}
+
+#[derive(Clone, Default, Parser)]
+pub struct EncryptionKeyOpt {
+    #[clap(
+        long,
+        value_parser,
+        help = "File holding the hex encoded 32 byte key to encrypt backup files with, or to \
+        decrypt encrypted backups with."
+    )]
+    pub encryption_key_file: Option<PathBuf>,
+    #[clap(
+        long,
+        value_parser,
+        conflicts_with = "encryption_key_file",
+        help = "Config file of a secure storage backend, in the same YAML format as the backends in \
+        the node config, to read the hex encoded encryption key from."
+    )]
+    pub encryption_key_secure_backend: Option<PathBuf>,
+    #[clap(
+        long,
+        help = "Name of the encryption key in the secure storage backend. \
+        [Defaults to \"backup_encryption_key\"]"
+    )]
+    pub encryption_key_name: Option<String>,
+}
+
+impl EncryptionKeyOpt {
+    const DEFAULT_KEY_NAME: &'static str = "backup_encryption_key";
+
+    pub fn load(&self) -> Result<Option<EncryptionKey>> {
+        let hex = if let Some(path) = &self.encryption_key_file {
+            std::fs::read_to_string(path)
+                .map_err(|e| format_err!("Failed to read encryption key file {:?}: {}", path, e))?
+        } else if let Some(path) = &self.encryption_key_secure_backend {
+            let backend: SecureBackend = serde_yaml::from_slice(&std::fs::read(path)?)?;
+            let name = self
+                .encryption_key_name
+                .as_deref()
+                .unwrap_or(Self::DEFAULT_KEY_NAME);
+            Storage::from(&backend).get::<String>(name)?.value
+        } else {
+            return Ok(None);
+        };
+
+        EncryptionKey::from_hex(&hex).map(Some)
+    }
+}
+
+#[derive(Clone, Default, Parser)]
+pub struct BackupCodecOpt {
+    #[clap(
+        long,
+        value_enum,
+        default_value_t = Compression::None,
+        help = "Compress the chunk and proof files with this algorithm."
+    )]
+    pub compression: Compression,
+    #[clap(
+        long,
+        default_value_t = 0,
+        help = "zstd compression level, from 1 (fastest) to 22 (smallest). 0 means the zstd \
+        default, which is 3."
+    )]
+    pub compression_level: i32,
+    #[clap(flatten)]
+    pub encryption_key: EncryptionKeyOpt,
+}
+
+impl BackupCodecOpt {
+    pub fn init(&self) -> Result<Codec> {
+        Ok(Codec {
+            compression: self.compression,
+            compression_level: self.compression_level,
+            encryption_key: self.encryption_key.load()?,
+        })
+    }
+}
+
+/// Encodes data files as configured for a backup, or decodes those of an existing backup.
+#[derive(Clone, Debug, Default)]
+pub struct Codec {
+    compression: Compression,
+    compression_level: i32,
+    encryption_key: Option<EncryptionKey>,
+}
+
+impl Codec {
+    /// Returns the codec to decode the data files of a backup with `backup_codec` recorded in its
+    /// manifest.
+    pub fn for_backup(
+        backup_codec: &BackupCodec,
+        encryption_key: Option<&EncryptionKey>,
+    ) -> Result<Self> {
+        let encryption_key = match &backup_codec.encryption {
+            Encryption::None => None,
+            Encryption::Aes256Gcm { key_fingerprint } => {
+                let key = encryption_key.ok_or_else(|| {
+                    format_err!(
+                        "Backup is encrypted with key {}, but no encryption key is provided.",
+                        key_fingerprint,
+                    )
+                })?;
+                ensure!(
+                    key.fingerprint() == *key_fingerprint,
+                    "Backup is encrypted with key {}, but key {} is provided.",
+                    key_fingerprint,
+                    key.fingerprint(),
+                );
+                Some(key.clone())
+            },
+        };
+
+        Ok(Self {
+            compression: backup_codec.compression,
+            compression_level: 0,
+            encryption_key,
+        })
+    }
+
+    /// What goes into the manifest.
+    pub fn backup_codec(&self) -> BackupCodec {
+        BackupCodec {
+            compression: self.compression,
+            encryption: match &self.encryption_key {
+                None => Encryption::None,
+                Some(key) => Encryption::Aes256Gcm {
+                    key_fingerprint: key.fingerprint(),
+                },
+            },
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.compression == Compression::None && self.encryption_key.is_none()
+    }
+
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let compressed = match self.compression {
+            Compression::None => data.to_vec(),
+            Compression::Zstd => zstd::encode_all(data, self.compression_level)?,
+        };
+        match &self.encryption_key {
+            None => Ok(compressed),
+            Some(key) => key.encrypt(&compressed),
+        }
+    }
+
+    pub fn decode(&self, data: Vec<u8>) -> Result<Vec<u8>> {
+        let compressed = match &self.encryption_key {
+            None => data,
+            Some(key) => key.decrypt(&data)?,
+        };
+        Ok(match self.compression {
+            Compression::None => compressed,
+            Compression::Zstd => zstd::decode_all(compressed.as_slice())?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> EncryptionKey {
+        EncryptionKey::new([byte; EncryptionKey::LENGTH])
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let data = b"backup chunk ".repeat(100);
+        for compression in [Compression::None, Compression::Zstd] {
+            for encryption_key in [None, Some(key(1))] {
+                let codec = Codec {
+                    compression,
+                    compression_level: 0,
+                    encryption_key,
+                };
+                let encoded = codec.encode(&data).unwrap();
+                assert_eq!(codec.is_identity(), encoded == data);
+
+                let decoder = Codec::for_backup(&codec.backup_codec(), Some(&key(1))).unwrap();
+                assert_eq!(decoder.decode(encoded).unwrap(), data);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrong_or_missing_key() {
+        let codec = Codec {
+            compression: Compression::Zstd,
+            compression_level: 0,
+            encryption_key: Some(key(1)),
+        };
+        let backup_codec = codec.backup_codec();
+        assert!(Codec::for_backup(&backup_codec, None).is_err());
+        assert!(Codec::for_backup(&backup_codec, Some(&key(2))).is_err());
+
+        // tampered data is rejected
+        let mut encoded = codec.encode(b"data").unwrap();
+        *encoded.last_mut().unwrap() ^= 1;
+        assert!(codec.decode(encoded).is_err());
+    }
+
+    #[test]
+    fn test_manifest_without_codec() {
+        #[derive(Deserialize)]
+        struct Manifest {
+            #[serde(default)]
+            codec: BackupCodec,
+        }
+
+        let manifest: Manifest = serde_json::from_str("{}").unwrap();
+        assert_eq!(manifest.codec, BackupCodec::default());
+        assert!(Codec::for_backup(&manifest.codec, None)
+            .unwrap()
+            .is_identity());
+    }
+}
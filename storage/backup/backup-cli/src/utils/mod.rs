@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod backup_service_client;
+pub mod codec;
 pub(crate) mod error_notes;
 pub mod read_record_bytes;
 pub mod storage_ext;
@@ -11,9 +12,10 @@ pub(crate) mod stream;
 #[cfg(any(test, feature = "testing"))]
 pub mod test_utils;
 
+use crate::utils::codec::{BackupCodecOpt, EncryptionKey, EncryptionKeyOpt};
 use anyhow::{anyhow, Result};
 use aptos_config::config::{
-    RocksdbConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+    ColdStorageConfig, RocksdbConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
     DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
 };
 use aptos_crypto::HashValue;
@@ -54,6 +56,9 @@ pub struct GlobalBackupOpt {
         help = "Maximum chunk file size in bytes."
     )]
     pub max_chunk_size: usize,
+
+    #[clap(flatten)]
+    pub codec: BackupCodecOpt,
 }
 
 #[derive(Clone, Parser)]
@@ -153,6 +158,9 @@ pub struct GlobalRestoreOpt {
 
     #[clap(flatten)]
     pub replay_concurrency_level: ReplayConcurrencyLevelOpt,
+
+    #[clap(flatten)]
+    pub encryption_key: EncryptionKeyOpt,
 }
 
 pub enum RestoreRunMode {
@@ -274,6 +282,7 @@ pub struct GlobalRestoreOptions {
     pub run_mode: Arc<RestoreRunMode>,
     pub concurrent_downloads: usize,
     pub replay_concurrency_level: usize,
+    pub encryption_key: Option<EncryptionKey>,
 }
 
 impl TryFrom<GlobalRestoreOpt> for GlobalRestoreOptions {
@@ -291,8 +300,10 @@ impl TryFrom<GlobalRestoreOpt> for GlobalRestoreOptions {
                 NO_OP_STORAGE_PRUNER_CONFIG, /* pruner config */
                 opt.rocksdb_opt.clone().into(),
                 false,
+                false,
                 BUFFERED_STATE_TARGET_ITEMS,
                 DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+                ColdStorageConfig::default(),
             )?)
             .get_restore_handler();
 
@@ -306,6 +317,7 @@ impl TryFrom<GlobalRestoreOpt> for GlobalRestoreOptions {
             run_mode: Arc::new(run_mode),
             concurrent_downloads,
             replay_concurrency_level,
+            encryption_key: opt.encryption_key.load()?,
         })
     }
 }
@@ -2,13 +2,18 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::storage::{BackupHandle, BackupStorage, FileHandleRef};
+use crate::{
+    storage::{
+        BackupHandle, BackupHandleRef, BackupStorage, FileHandle, FileHandleRef, ShellSafeName,
+    },
+    utils::codec::Codec,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use rand::random;
 use serde::de::DeserializeOwned;
-use std::{convert::TryInto, sync::Arc};
-use tokio::io::AsyncReadExt;
+use std::{convert::TryInto, io::Cursor, sync::Arc};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
 
 #[async_trait]
 pub trait BackupStorageExt {
@@ -18,6 +23,25 @@ pub trait BackupStorageExt {
     /// Adds a random suffix ".XXXX" to the backup name, so a retry won't pass a same backup name to
     /// the storage.
     async fn create_backup_with_random_suffix(&self, name: &str) -> Result<BackupHandle>;
+    /// Opens a data file (chunk or proof) of a backup, decoded with `codec`.
+    async fn open_for_read_with_codec(
+        &self,
+        file_handle: &FileHandleRef,
+        codec: &Codec,
+    ) -> Result<Box<dyn AsyncRead + Send + Unpin>>;
+    async fn load_bcs_file_with_codec<T: DeserializeOwned>(
+        &self,
+        file_handle: &FileHandleRef,
+        codec: &Codec,
+    ) -> Result<T>;
+    /// Writes a data file (chunk or proof) of a backup, encoded with `codec`.
+    async fn write_with_codec(
+        &self,
+        backup_handle: &BackupHandleRef,
+        name: &ShellSafeName,
+        content: &[u8],
+        codec: &Codec,
+    ) -> Result<FileHandle>;
 }
 
 #[async_trait]
@@ -41,4 +65,53 @@ impl BackupStorageExt for Arc<dyn BackupStorage> {
         self.create_backup(&format!("{}.{:04x}", name, random::<u16>()).try_into()?)
             .await
     }
+
+    async fn open_for_read_with_codec(
+        &self,
+        file_handle: &FileHandleRef,
+        codec: &Codec,
+    ) -> Result<Box<dyn AsyncRead + Send + Unpin>> {
+        if codec.is_identity() {
+            // Keep streaming files that are stored as is.
+            return self.open_for_read(file_handle).await;
+        }
+
+        let bytes = self.read_all(file_handle).await?;
+        let codec = codec.clone();
+        let decoded = tokio::task::spawn_blocking(move || codec.decode(bytes)).await??;
+        Ok(Box::new(Cursor::new(decoded)))
+    }
+
+    async fn load_bcs_file_with_codec<T: DeserializeOwned>(
+        &self,
+        file_handle: &FileHandleRef,
+        codec: &Codec,
+    ) -> Result<T> {
+        let mut bytes = Vec::new();
+        self.open_for_read_with_codec(file_handle, codec)
+            .await?
+            .read_to_end(&mut bytes)
+            .await?;
+        Ok(bcs::from_bytes(&bytes)?)
+    }
+
+    async fn write_with_codec(
+        &self,
+        backup_handle: &BackupHandleRef,
+        name: &ShellSafeName,
+        content: &[u8],
+        codec: &Codec,
+    ) -> Result<FileHandle> {
+        let (file_handle, mut file) = self.create_for_write(backup_handle, name).await?;
+        if codec.is_identity() {
+            file.write_all(content).await?;
+        } else {
+            let codec = codec.clone();
+            let content = content.to_vec();
+            let encoded = tokio::task::spawn_blocking(move || codec.encode(&content)).await??;
+            file.write_all(&encoded).await?;
+        }
+        file.shutdown().await?;
+        Ok(file_handle)
+    }
 }
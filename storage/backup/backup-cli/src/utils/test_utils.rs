@@ -32,12 +32,43 @@ pub fn tmp_db_with_random_content() -> (
     TempPath,
     Arc<AptosDB>,
     Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
+) {
+    tmp_db_with_blocks(ValueGenerator::new().generate(arb_blocks_to_commit()))
+}
+
+/// Like `tmp_db_with_random_content`, with at least `min_num_epochs` epochs ended.
+pub fn tmp_db_with_random_epochs(
+    min_num_epochs: usize,
+) -> (
+    TempPath,
+    Arc<AptosDB>,
+    Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
+) {
+    let mut value_gen = ValueGenerator::new();
+    let blocks = loop {
+        let blocks = value_gen.generate(arb_blocks_to_commit());
+        let num_epochs = blocks
+            .iter()
+            .filter(|(_, ledger_info)| ledger_info.ledger_info().ends_epoch())
+            .count();
+        if num_epochs >= min_num_epochs {
+            break blocks;
+        }
+    };
+    tmp_db_with_blocks(blocks)
+}
+
+fn tmp_db_with_blocks(
+    blocks: Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
+) -> (
+    TempPath,
+    Arc<AptosDB>,
+    Vec<(Vec<TransactionToCommit>, LedgerInfoWithSignatures)>,
 ) {
     let (tmpdir, db) = tmp_db_empty();
     let mut cur_ver: Version = 0;
     let mut in_memory_state = db.buffered_state().lock().current_state().clone();
     let _ancestor = in_memory_state.base.clone();
-    let blocks = ValueGenerator::new().generate(arb_blocks_to_commit());
     for (txns_to_commit, ledger_info_with_sigs) in &blocks {
         update_in_memory_state(&mut in_memory_state, txns_to_commit.as_slice());
         db.save_transactions(
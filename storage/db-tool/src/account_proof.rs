@@ -0,0 +1,374 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, ensure, Result};
+use aptos_backup_cli::{
+    backup_types::{
+        epoch_ending::manifest::EpochEndingBackup,
+        state_snapshot::{
+            manifest::StateSnapshotBackup,
+            restore::{StateSnapshotRestoreController, StateSnapshotRestoreOpt},
+        },
+    },
+    metadata::{cache, cache::MetadataCacheOpt},
+    storage::{BackupStorage, DBToolStorageOpt},
+    utils::{
+        codec::{Codec, EncryptionKeyOpt},
+        read_record_bytes::ReadRecordBytes,
+        storage_ext::BackupStorageExt,
+        ConcurrentDownloadsOpt, GlobalRestoreOpt, GlobalRestoreOptions, ReplayConcurrencyLevelOpt,
+        RocksdbOpt, TrustedWaypointOpt,
+    },
+};
+use aptos_config::config::{
+    ColdStorageConfig, RocksdbConfigs, BUFFERED_STATE_TARGET_ITEMS,
+    DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD, NO_OP_STORAGE_PRUNER_CONFIG,
+};
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_db::{state_restore::StateSnapshotRestoreMode, AptosDB};
+use aptos_storage_interface::DbReader;
+use aptos_temppath::TempPath;
+use aptos_types::{
+    account_address::AccountAddress,
+    epoch_change::{EpochChangeProof, Verifier},
+    ledger_info::LedgerInfoWithSignatures,
+    proof::{SparseMerkleProof, TransactionInfoWithProof},
+    state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
+    transaction::Version,
+    waypoint::Waypoint,
+};
+use clap::{Parser, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Export the state values under an account with their proofs, and verify the exports offline
+#[derive(Subcommand)]
+pub enum Command {
+    #[clap(about = "Export the state values under an account from a DB")]
+    ExportFromDb(ExportFromDbOpt),
+    #[clap(about = "Export the state values under an account from a state snapshot backup")]
+    ExportFromBackup(ExportFromBackupOpt),
+    #[clap(about = "Verify an exported bundle offline")]
+    Verify(VerifyOpt),
+}
+
+#[derive(Parser)]
+pub struct ExportOpt {
+    /// Address of the account to export
+    #[clap(long)]
+    pub address: AccountAddress,
+    /// Version to export at, which needs a state snapshot: a state checkpoint version kept by the
+    /// DB, or the version of a state snapshot backup
+    #[clap(long)]
+    pub version: Version,
+    /// File to write the bundle to
+    #[clap(long, value_parser)]
+    pub output_file: PathBuf,
+}
+
+#[derive(Parser)]
+pub struct ExportFromDbOpt {
+    #[clap(flatten)]
+    pub export: ExportOpt,
+    #[clap(long, value_parser)]
+    pub db_dir: PathBuf,
+}
+
+#[derive(Parser)]
+pub struct ExportFromBackupOpt {
+    #[clap(flatten)]
+    pub export: ExportOpt,
+    #[clap(flatten)]
+    pub metadata_cache_opt: MetadataCacheOpt,
+    #[clap(flatten)]
+    pub storage: DBToolStorageOpt,
+    #[clap(flatten)]
+    pub concurrent_downloads: ConcurrentDownloadsOpt,
+    #[clap(flatten)]
+    pub encryption_key: EncryptionKeyOpt,
+    /// Directory to restore the state snapshot to, which needs room for the whole state. A new
+    /// directory under the system temp directory if not specified, deleted once done.
+    #[clap(long, value_parser)]
+    pub scratch_db_dir: Option<PathBuf>,
+}
+
+#[derive(Parser)]
+pub struct VerifyOpt {
+    /// Bundle written by one of the export commands
+    #[clap(long, value_parser)]
+    pub bundle_file: PathBuf,
+    /// Waypoint to start trusting the epoch changes from, usually the genesis waypoint
+    #[clap(long)]
+    pub trusted_waypoint: Waypoint,
+}
+
+/// The state values under an account at a version, with everything needed to check them offline
+/// given a trusted waypoint.
+///
+/// Each value is proven to be in the state, but since the keys of an account are spread over the
+/// whole tree, the bundle alone can't prove that no key of the account was left out.
+#[derive(Deserialize, Serialize)]
+pub struct AccountProofBundle {
+    pub address: AccountAddress,
+    pub version: Version,
+    /// Proofs are against the state root hash at `version`.
+    pub state_values: Vec<(StateKey, StateValue, SparseMerkleProof)>,
+    /// Proves the state root hash at `version` against `ledger_info`.
+    pub txn_info_with_proof: TransactionInfoWithProof,
+    pub ledger_info: LedgerInfoWithSignatures,
+    /// The epoch ending ledger infos of all the epochs before that of `ledger_info`.
+    pub epoch_change_proof: EpochChangeProof,
+}
+
+impl AccountProofBundle {
+    /// Verifies the whole bundle and returns the state root hash the values are proven against.
+    pub fn verify(&self, trusted_waypoint: &Waypoint) -> Result<HashValue> {
+        if self.epoch_change_proof.ledger_info_with_sigs.is_empty() {
+            trusted_waypoint.verify(self.ledger_info.ledger_info())?;
+        } else {
+            let epoch_state = self
+                .epoch_change_proof
+                .verify(trusted_waypoint)?
+                .ledger_info()
+                .next_epoch_state()
+                .ok_or_else(|| anyhow!("Last epoch change doesn't carry a validator set."))?;
+            Verifier::verify(epoch_state, &self.ledger_info)?;
+        }
+
+        self.txn_info_with_proof
+            .verify(self.ledger_info.ledger_info(), self.version)?;
+        let root_hash = self
+            .txn_info_with_proof
+            .transaction_info()
+            .ensure_state_checkpoint_hash()?;
+
+        let prefix = StateKeyPrefix::from(self.address);
+        for (key, value, proof) in &self.state_values {
+            ensure!(
+                prefix.is_prefix(key)?,
+                "State key {:?} is not under account {}.",
+                key,
+                self.address,
+            );
+            proof.verify(root_hash, key.hash(), Some(value))?;
+        }
+
+        Ok(root_hash)
+    }
+}
+
+impl Command {
+    pub async fn run(self) -> Result<()> {
+        match self {
+            Command::ExportFromDb(opt) => {
+                let db = open_db(&opt.db_dir)?;
+                let ledger_info = db.get_latest_ledger_info()?;
+                ensure!(
+                    opt.export.version <= ledger_info.ledger_info().version(),
+                    "Version {} is newer than the latest version {} in the DB.",
+                    opt.export.version,
+                    ledger_info.ledger_info().version(),
+                );
+                let txn_info_with_proof = db
+                    .get_transaction_by_version(
+                        opt.export.version,
+                        ledger_info.ledger_info().version(),
+                        false, /* fetch_events */
+                    )?
+                    .proof;
+                let mut epoch_ending_lis = Vec::new();
+                loop {
+                    let EpochChangeProof {
+                        ledger_info_with_sigs,
+                        more,
+                    } = DbReader::get_epoch_ending_ledger_infos(
+                        &db,
+                        epoch_ending_lis.len() as u64,
+                        ledger_info.ledger_info().epoch(),
+                    )?;
+                    epoch_ending_lis.extend(ledger_info_with_sigs);
+                    if !more {
+                        break;
+                    }
+                }
+
+                let bundle = AccountProofBundle {
+                    address: opt.export.address,
+                    version: opt.export.version,
+                    state_values: get_state_values_with_proofs(
+                        &db,
+                        opt.export.address,
+                        opt.export.version,
+                    )?,
+                    txn_info_with_proof,
+                    ledger_info,
+                    epoch_change_proof: EpochChangeProof::new(epoch_ending_lis, false),
+                };
+                write_bundle(&bundle, &opt.export.output_file)
+            },
+            Command::ExportFromBackup(opt) => {
+                let storage = opt.storage.init_storage().await?;
+                let metadata_view = cache::sync_and_load(
+                    &opt.metadata_cache_opt,
+                    Arc::clone(&storage),
+                    opt.concurrent_downloads.get(),
+                )
+                .await?;
+                let snapshot = metadata_view.expect_state_snapshot(opt.export.version)?;
+
+                // The backup only carries range proofs for whole chunks, so the snapshot is
+                // restored to a scratch DB to get the proofs for the individual keys.
+                let temp_db_dir = TempPath::new();
+                let db_dir = opt
+                    .scratch_db_dir
+                    .unwrap_or_else(|| temp_db_dir.path().to_path_buf());
+                let global_opt: GlobalRestoreOptions = GlobalRestoreOpt {
+                    dry_run: false,
+                    db_dir: Some(db_dir.clone()),
+                    target_version: None,
+                    trusted_waypoints: TrustedWaypointOpt::default(),
+                    rocksdb_opt: RocksdbOpt::default(),
+                    concurrent_downloads: opt.concurrent_downloads,
+                    replay_concurrency_level: ReplayConcurrencyLevelOpt::default(),
+                    encryption_key: opt.encryption_key,
+                }
+                .try_into()?;
+                let encryption_key = global_opt.encryption_key.clone();
+                StateSnapshotRestoreController::new(
+                    StateSnapshotRestoreOpt {
+                        manifest_handle: snapshot.manifest.clone(),
+                        version: snapshot.version,
+                        validate_modules: false,
+                        restore_mode: StateSnapshotRestoreMode::Default,
+                    },
+                    global_opt,
+                    Arc::clone(&storage),
+                    None, /* epoch_history */
+                )
+                .run()
+                .await?;
+
+                let manifest: StateSnapshotBackup =
+                    storage.load_json_file(&snapshot.manifest).await?;
+                let codec = Codec::for_backup(&manifest.codec, encryption_key.as_ref())?;
+                let (txn_info_with_proof, ledger_info): (
+                    TransactionInfoWithProof,
+                    LedgerInfoWithSignatures,
+                ) = storage
+                    .load_bcs_file_with_codec(&manifest.proof, &codec)
+                    .await?;
+
+                let mut epoch_ending_lis = Vec::new();
+                for backup in metadata_view.select_epoch_ending_backups(opt.export.version)? {
+                    let manifest: EpochEndingBackup =
+                        storage.load_json_file(&backup.manifest).await?;
+                    manifest.verify()?;
+                    let codec = Codec::for_backup(&manifest.codec, encryption_key.as_ref())?;
+                    for chunk in &manifest.chunks {
+                        epoch_ending_lis.extend(
+                            read_ledger_infos(&storage, &chunk.ledger_infos, &codec)
+                                .await?
+                                .into_iter()
+                                .filter(|li| {
+                                    li.ledger_info().epoch() < ledger_info.ledger_info().epoch()
+                                }),
+                        );
+                    }
+                }
+                ensure!(
+                    epoch_ending_lis.len() as u64 == ledger_info.ledger_info().epoch(),
+                    "Epoch ending backups cover {} epochs, expecting {}.",
+                    epoch_ending_lis.len(),
+                    ledger_info.ledger_info().epoch(),
+                );
+
+                let db = open_db(&db_dir)?;
+                let bundle = AccountProofBundle {
+                    address: opt.export.address,
+                    version: opt.export.version,
+                    state_values: get_state_values_with_proofs(
+                        &db,
+                        opt.export.address,
+                        opt.export.version,
+                    )?,
+                    txn_info_with_proof,
+                    ledger_info,
+                    epoch_change_proof: EpochChangeProof::new(epoch_ending_lis, false),
+                };
+                write_bundle(&bundle, &opt.export.output_file)
+            },
+            Command::Verify(opt) => {
+                let bundle: AccountProofBundle =
+                    bcs::from_bytes(&std::fs::read(&opt.bundle_file)?)?;
+                let root_hash = bundle.verify(&opt.trusted_waypoint)?;
+                println!(
+                    "Verified {} state values under account {} at version {}, state root hash {}.",
+                    bundle.state_values.len(),
+                    bundle.address,
+                    bundle.version,
+                    root_hash,
+                );
+                for (key, value, _) in &bundle.state_values {
+                    println!("{:?}: {} bytes", key, value.size());
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+fn open_db(db_dir: &Path) -> Result<AptosDB> {
+    AptosDB::open(
+        db_dir,
+        true, /* readonly */
+        NO_OP_STORAGE_PRUNER_CONFIG,
+        RocksdbConfigs::default(),
+        false, /* indexer */
+        false, /* event type index */
+        BUFFERED_STATE_TARGET_ITEMS,
+        DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+        ColdStorageConfig::default(),
+    )
+}
+
+fn get_state_values_with_proofs(
+    db: &AptosDB,
+    address: AccountAddress,
+    version: Version,
+) -> Result<Vec<(StateKey, StateValue, SparseMerkleProof)>> {
+    db.get_prefixed_state_value_iterator(&StateKeyPrefix::from(address), None, version)?
+        .map(|res| {
+            let (key, value) = res?;
+            let proof = db.get_state_proof_by_version_ext(&key, version)?.into();
+            Ok((key, value, proof))
+        })
+        .collect()
+}
+
+async fn read_ledger_infos(
+    storage: &Arc<dyn BackupStorage>,
+    file_handle: &str,
+    codec: &Codec,
+) -> Result<Vec<LedgerInfoWithSignatures>> {
+    let mut file = storage.open_for_read_with_codec(file_handle, codec).await?;
+    let mut lis = Vec::new();
+    while let Some(record_bytes) = file.read_record_bytes().await? {
+        lis.push(bcs::from_bytes(&record_bytes)?);
+    }
+    Ok(lis)
+}
+
+fn write_bundle(bundle: &AccountProofBundle, output_file: &Path) -> Result<()> {
+    std::fs::write(output_file, bcs::to_bytes(bundle)?)?;
+    println!(
+        "Exported {} state values under account {} at version {} to {}.",
+        bundle.state_values.len(),
+        bundle.address,
+        bundle.version,
+        output_file.display(),
+    );
+    Ok(())
+}
@@ -7,6 +7,9 @@ use aptos_backup_cli::{
     backup_types::{
         epoch_ending::backup::{EpochEndingBackupController, EpochEndingBackupOpt},
         state_snapshot::backup::{StateSnapshotBackupController, StateSnapshotBackupOpt},
+        state_snapshot_increment::backup::{
+            StateSnapshotIncrementBackupController, StateSnapshotIncrementBackupOpt,
+        },
         transaction::backup::{TransactionBackupController, TransactionBackupOpt},
     },
     coordinators::{
@@ -17,6 +20,7 @@ use aptos_backup_cli::{
     storage::DBToolStorageOpt,
     utils::{
         backup_service_client::{BackupServiceClient, BackupServiceClientOpt},
+        codec::EncryptionKeyOpt,
         ConcurrentDownloadsOpt, GlobalBackupOpt, TrustedWaypointOpt,
     },
 };
@@ -98,6 +102,12 @@ enum BackupType {
         #[clap[flatten]]
         storage: DBToolStorageOpt,
     },
+    StateSnapshotIncrement {
+        #[clap(flatten)]
+        opt: StateSnapshotIncrementBackupOpt,
+        #[clap[flatten]]
+        storage: DBToolStorageOpt,
+    },
     Transaction {
         #[clap(flatten)]
         opt: TransactionBackupOpt,
@@ -131,6 +141,8 @@ pub struct VerifyOpt {
     storage: DBToolStorageOpt,
     #[clap(flatten)]
     concurrent_downloads: ConcurrentDownloadsOpt,
+    #[clap(flatten)]
+    encryption_key_opt: EncryptionKeyOpt,
     #[clap(
         long,
         value_parser = parse_maxable_u64,
@@ -193,6 +205,16 @@ impl Command {
                         .run()
                         .await?;
                     },
+                    BackupType::StateSnapshotIncrement { opt, storage } => {
+                        StateSnapshotIncrementBackupController::new(
+                            opt,
+                            global_opt,
+                            client,
+                            storage.init_storage().await?,
+                        )
+                        .run()
+                        .await?;
+                    },
                     BackupType::Transaction { opt, storage } => {
                         TransactionBackupController::new(
                             opt,
@@ -246,6 +268,7 @@ impl Command {
                     opt.skip_epoch_endings,
                     opt.validate_modules,
                     opt.output_transaction_analysis,
+                    opt.encryption_key_opt,
                 )?
                 .run()
                 .await?
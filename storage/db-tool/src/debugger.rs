@@ -2,15 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use aptos_db::db_debugger::{checkpoint, ledger, state_tree, truncate};
+use aptos_db::db_debugger::{check, checkpoint, ledger, state_tree, truncate};
 use clap::Parser;
 
-/// List snapshots, print nodes, make DB checkpoints and validate ledger hash
+/// List snapshots, print nodes, make DB checkpoints, validate ledger hash and check DB consistency
 #[derive(Parser)]
 pub enum Command {
     #[clap(subcommand)]
     StateTree(state_tree::Cmd),
     Checkpoint(checkpoint::Cmd),
+    Check(check::Cmd),
     #[clap(subcommand)]
     Ledger(ledger::Cmd),
     Truncate(truncate::Cmd),
@@ -21,6 +22,7 @@ impl Command {
         match self {
             Command::StateTree(cmd) => cmd.run(),
             Command::Checkpoint(cmd) => cmd.run(),
+            Command::Check(cmd) => cmd.run(),
             Command::Ledger(cmd) => cmd.run(),
             Command::Truncate(cmd) => cmd.run(),
         }
@@ -3,6 +3,7 @@
 
 extern crate core;
 
+mod account_proof;
 mod backup;
 mod backup_maintenance;
 mod debugger;
@@ -27,6 +28,8 @@ pub enum DBTool {
     Debug(debugger::Command),
     #[clap(subcommand)]
     BackupMaintenance(backup_maintenance::Command),
+    #[clap(subcommand)]
+    AccountProof(account_proof::Command),
 }
 
 impl DBTool {
@@ -37,6 +40,7 @@ impl DBTool {
             DBTool::ReplayVerify(cmd) => cmd.run().await,
             DBTool::BackupMaintenance(cmd) => cmd.run().await,
             DBTool::Debug(cmd) => cmd.run(),
+            DBTool::AccountProof(cmd) => cmd.run().await,
         }
     }
 }
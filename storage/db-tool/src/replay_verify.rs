@@ -6,10 +6,13 @@ use aptos_backup_cli::{
     coordinators::replay_verify::ReplayVerifyCoordinator,
     metadata::cache::MetadataCacheOpt,
     storage::DBToolStorageOpt,
-    utils::{ConcurrentDownloadsOpt, ReplayConcurrencyLevelOpt, RocksdbOpt, TrustedWaypointOpt},
+    utils::{
+        codec::EncryptionKeyOpt, ConcurrentDownloadsOpt, ReplayConcurrencyLevelOpt, RocksdbOpt,
+        TrustedWaypointOpt,
+    },
 };
 use aptos_config::config::{
-    BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+    ColdStorageConfig, BUFFERED_STATE_TARGET_ITEMS, DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
     NO_OP_STORAGE_PRUNER_CONFIG,
 };
 use aptos_db::{AptosDB, GetRestoreHandler};
@@ -31,6 +34,8 @@ pub struct Opt {
     concurrent_downloads: ConcurrentDownloadsOpt,
     #[clap(flatten)]
     replay_concurrency_level: ReplayConcurrencyLevelOpt,
+    #[clap(flatten)]
+    encryption_key_opt: EncryptionKeyOpt,
     #[clap(long = "target-db-dir", value_parser)]
     pub db_dir: PathBuf,
     #[clap(flatten)]
@@ -66,8 +71,10 @@ impl Opt {
             NO_OP_STORAGE_PRUNER_CONFIG, /* pruner config */
             self.rocksdb_opt.into(),
             false,
+            false,
             BUFFERED_STATE_TARGET_ITEMS,
             DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
+            ColdStorageConfig::default(),
         )?)
         .get_restore_handler();
         ReplayVerifyCoordinator::new(
@@ -81,6 +88,7 @@ impl Opt {
             self.end_version.unwrap_or(Version::MAX),
             self.validate_modules,
             VerifyExecutionMode::verify_except(self.txns_to_skip).set_lazy_quit(self.lazy_quit),
+            self.encryption_key_opt,
         )?
         .run()
         .await
@@ -45,6 +45,58 @@ fn test_various_cmd_parsing() {
         "--db-dir",
         ".",
     ]);
+    run_cmd(&[
+        "aptos-db-tool",
+        "debug",
+        "check",
+        "--db-dir",
+        ".",
+        "--cold-store-dir",
+        ".",
+        "--skip-index-and-usage",
+        "--check-pruned-indexes",
+        "--report-file",
+        "report.json",
+        "100",
+        "1000",
+    ]);
+    run_cmd(&[
+        "aptos-db-tool",
+        "account-proof",
+        "export-from-db",
+        "--address",
+        "0x1",
+        "--version",
+        "100",
+        "--output-file",
+        "bundle.json",
+        "--db-dir",
+        ".",
+    ]);
+    run_cmd(&[
+        "aptos-db-tool",
+        "account-proof",
+        "export-from-backup",
+        "--address",
+        "0x1",
+        "--version",
+        "100",
+        "--output-file",
+        "bundle.json",
+        "--local-fs-dir",
+        ".",
+        "--scratch-db-dir",
+        ".",
+    ]);
+    run_cmd(&[
+        "aptos-db-tool",
+        "account-proof",
+        "verify",
+        "--bundle-file",
+        "bundle.json",
+        "--trusted-waypoint",
+        "0:0000000000000000000000000000000000000000000000000000000000000000",
+    ]);
 
     run_cmd(&["aptos-db-tool", "backup", "verify", "--local-fs-dir", "."]);
     run_cmd(&[
@@ -547,3 +599,180 @@ mod compaction_tests {
         rt.shutdown_timeout(Duration::from_secs(1));
     }
 }
+
+#[cfg(test)]
+mod account_proof_tests {
+    use crate::{account_proof::AccountProofBundle, DBTool};
+    use aptos_backup_cli::utils::test_utils::start_local_backup_service;
+    use aptos_executor_test_helpers::integration_test_impl::{
+        test_execution_with_storage_impl, test_execution_with_storage_impl_inner,
+    };
+    use aptos_storage_interface::DbReader;
+    use aptos_temppath::TempPath;
+    use aptos_types::{state_store::state_value::StateValue, waypoint::Waypoint};
+    use clap::Parser;
+    use std::{sync::Arc, time::Duration};
+    use tokio::runtime::Runtime;
+
+    #[test]
+    fn test_account_proof_from_backup() {
+        let db = test_execution_with_storage_impl();
+        let epoch_ending_lis = DbReader::get_epoch_ending_ledger_infos(db.as_ref(), 0, 2)
+            .unwrap()
+            .ledger_info_with_sigs;
+        let genesis_waypoint =
+            Waypoint::new_epoch_boundary(epoch_ending_lis[0].ledger_info()).unwrap();
+        let version = epoch_ending_lis[1].ledger_info().version();
+        let backup_dir = TempPath::new();
+        backup_dir.create_as_dir().unwrap();
+        let scratch_db_dir = TempPath::new();
+        let bundle_file = TempPath::new();
+        let (rt, port) = start_local_backup_service(Arc::clone(&db));
+        let server_addr = format!(" http://localhost:{}", port);
+
+        rt.block_on(
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "backup",
+                "oneoff",
+                "--backup-service-address",
+                server_addr.as_str(),
+                "epoch-ending",
+                "--start-epoch",
+                "0",
+                "--end-epoch",
+                "2",
+                "--local-fs-dir",
+                backup_dir.path().to_str().unwrap(),
+            ])
+            .unwrap()
+            .run(),
+        )
+        .unwrap();
+        rt.block_on(
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "backup",
+                "oneoff",
+                "--backup-service-address",
+                server_addr.as_str(),
+                "state-snapshot",
+                "--state-snapshot-epoch",
+                "1",
+                "--local-fs-dir",
+                backup_dir.path().to_str().unwrap(),
+            ])
+            .unwrap()
+            .run(),
+        )
+        .unwrap();
+
+        rt.block_on(
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "account-proof",
+                "export-from-backup",
+                "--address",
+                "0x1",
+                "--version",
+                format!("{}", version).as_str(),
+                "--output-file",
+                bundle_file.path().to_str().unwrap(),
+                "--local-fs-dir",
+                backup_dir.path().to_str().unwrap(),
+                "--scratch-db-dir",
+                scratch_db_dir.path().to_str().unwrap(),
+            ])
+            .unwrap()
+            .run(),
+        )
+        .unwrap();
+        // The snapshot was restored to the given directory.
+        assert!(scratch_db_dir.path().read_dir().unwrap().next().is_some());
+        let verify_cmd = || {
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "account-proof",
+                "verify",
+                "--bundle-file",
+                bundle_file.path().to_str().unwrap(),
+                "--trusted-waypoint",
+                genesis_waypoint.to_string().as_str(),
+            ])
+            .unwrap()
+        };
+        rt.block_on(verify_cmd().run()).unwrap();
+
+        // Tampering with any value breaks its proof.
+        let mut bundle: AccountProofBundle =
+            bcs::from_bytes(&std::fs::read(bundle_file.path()).unwrap()).unwrap();
+        assert!(!bundle.state_values.is_empty());
+        bundle.state_values[0].1 = StateValue::new_legacy(b"tampered".to_vec());
+        std::fs::write(bundle_file.path(), bcs::to_bytes(&bundle).unwrap()).unwrap();
+        assert!(rt.block_on(verify_cmd().run()).is_err());
+
+        rt.shutdown_timeout(Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_account_proof_from_db() {
+        let db_dir = TempPath::new();
+        db_dir.create_as_dir().unwrap();
+        let db = test_execution_with_storage_impl_inner(db_dir.path());
+        let epoch_ending_lis = DbReader::get_epoch_ending_ledger_infos(db.as_ref(), 0, 2)
+            .unwrap()
+            .ledger_info_with_sigs;
+        let genesis_waypoint =
+            Waypoint::new_epoch_boundary(epoch_ending_lis[0].ledger_info()).unwrap();
+        let version = epoch_ending_lis[1].ledger_info().version();
+        let latest_version = db.get_latest_version().unwrap();
+        drop(db);
+        let bundle_file = TempPath::new();
+        let rt = Runtime::new().unwrap();
+
+        let export_cmd = |version: u64| {
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "account-proof",
+                "export-from-db",
+                "--address",
+                "0x1",
+                "--version",
+                format!("{}", version).as_str(),
+                "--output-file",
+                bundle_file.path().to_str().unwrap(),
+                "--db-dir",
+                db_dir.path().to_str().unwrap(),
+            ])
+            .unwrap()
+        };
+        rt.block_on(export_cmd(version).run()).unwrap();
+        rt.block_on(
+            DBTool::try_parse_from([
+                "aptos-db-tool",
+                "account-proof",
+                "verify",
+                "--bundle-file",
+                bundle_file.path().to_str().unwrap(),
+                "--trusted-waypoint",
+                genesis_waypoint.to_string().as_str(),
+            ])
+            .unwrap()
+            .run(),
+        )
+        .unwrap();
+
+        let bundle: AccountProofBundle =
+            bcs::from_bytes(&std::fs::read(bundle_file.path()).unwrap()).unwrap();
+        assert_eq!(bundle.version, version);
+        assert!(!bundle.state_values.is_empty());
+        // The bundle carries the epoch changes up to the latest ledger info of the DB.
+        assert_eq!(
+            bundle.epoch_change_proof.ledger_info_with_sigs.len() as u64,
+            bundle.ledger_info.ledger_info().epoch(),
+        );
+
+        // Versions newer than what the DB has can't be exported.
+        assert!(rt.block_on(export_cmd(latest_version + 1).run()).is_err());
+    }
+}
@@ -9,7 +9,8 @@ use crate::{
     db::INDEX_DB_NAME,
     metadata::{MetadataKey, MetadataValue},
     schema::{
-        column_families, indexer_metadata::IndexerMetadataSchema, table_info::TableInfoSchema,
+        column_families, event_by_type::EventByTypeSchema, indexer_metadata::IndexerMetadataSchema,
+        table_info::TableInfoSchema,
     },
 };
 use anyhow::{bail, ensure, Result};
@@ -21,6 +22,7 @@ use aptos_storage_interface::{state_view::DbStateView, DbReader};
 use aptos_types::{
     access_path::Path,
     account_address::AccountAddress,
+    contract_event::ContractEvent,
     state_store::{
         state_key::{StateKey, StateKeyInner},
         table::{TableHandle, TableInfo},
@@ -44,12 +46,16 @@ use std::{
 pub struct Indexer {
     db: DB,
     next_version: AtomicVersion,
+    /// Next version whose events are to be indexed by type, `None` if the event type index is
+    /// not enabled.
+    event_type_index_next_version: Option<AtomicVersion>,
 }
 
 impl Indexer {
     pub fn open(
         db_root_path: impl AsRef<std::path::Path>,
         rocksdb_config: RocksdbConfig,
+        enable_event_type_index: bool,
     ) -> Result<Self> {
         let db_path = db_root_path.as_ref().join(INDEX_DB_NAME);
 
@@ -63,10 +69,19 @@ impl Indexer {
         let next_version = db
             .get::<IndexerMetadataSchema>(&MetadataKey::LatestVersion)?
             .map_or(0, |v| v.expect_version());
+        let event_type_index_next_version = if enable_event_type_index {
+            let next_version = db
+                .get::<IndexerMetadataSchema>(&MetadataKey::EventTypeIndexLatestVersion)?
+                .map_or(0, |v| v.expect_version() + 1);
+            Some(AtomicVersion::new(next_version))
+        } else {
+            None
+        };
 
         Ok(Self {
             db,
             next_version: AtomicVersion::new(next_version),
+            event_type_index_next_version,
         })
     }
 
@@ -148,6 +163,136 @@ impl Indexer {
     pub fn get_table_info(&self, handle: TableHandle) -> Result<Option<TableInfo>> {
         self.db.get::<TableInfoSchema>(&handle)
     }
+
+    /// Indexes by type the events of the transactions starting at `first_version`, `events`
+    /// holding the events of each transaction. Does nothing if the event type index is not
+    /// enabled.
+    pub fn index_events(&self, first_version: Version, events: &[&[ContractEvent]]) -> Result<()> {
+        let next_version = match self.event_type_index_next_version() {
+            Some(next_version) => next_version,
+            None => return Ok(()),
+        };
+        ensure!(
+            first_version <= next_version,
+            "Event type index expects to see continuous transaction versions. Expecting: {}, got: {}",
+            next_version,
+            first_version,
+        );
+        let end_version = first_version + events.len() as Version;
+        if end_version <= next_version {
+            return Ok(());
+        }
+
+        let batch = SchemaBatch::new();
+        for (version, events) in (first_version..end_version).zip(events) {
+            if version < next_version {
+                continue;
+            }
+            for (index, event) in events.iter().enumerate() {
+                batch.put::<EventByTypeSchema>(
+                    &(event.type_tag().clone(), version, index as u64),
+                    &(),
+                )?;
+            }
+        }
+        batch.put::<IndexerMetadataSchema>(
+            &MetadataKey::EventTypeIndexLatestVersion,
+            &MetadataValue::Version(end_version - 1),
+        )?;
+        self.db.write_schemas(batch)?;
+        if let Some(next_version) = &self.event_type_index_next_version {
+            next_version.store(end_version, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Moves the event type index forward to `next_version` without indexing the versions before
+    /// it, whose events can't be read anymore, e.g. because they have been pruned. Does nothing
+    /// if the event type index is not enabled or is already past `next_version`.
+    pub fn skip_event_type_index_to(&self, next_version: Version) -> Result<()> {
+        match self.event_type_index_next_version() {
+            Some(current) if current < next_version => {},
+            _ => return Ok(()),
+        }
+        // `next_version` is past `current`, so it's not 0.
+        self.db.put::<IndexerMetadataSchema>(
+            &MetadataKey::EventTypeIndexLatestVersion,
+            &MetadataValue::Version(next_version - 1),
+        )?;
+        if let Some(current) = &self.event_type_index_next_version {
+            current.store(next_version, Ordering::Relaxed);
+        }
+
+        Ok(())
+    }
+
+    /// Removes from the event type index the events of the transactions starting at
+    /// `first_version`, `events` holding the events of each transaction, as they are pruned from
+    /// the ledger. Does nothing if the event type index is not enabled.
+    pub fn prune_events(&self, first_version: Version, events: &[&[ContractEvent]]) -> Result<()> {
+        if self.event_type_index_next_version.is_none() {
+            return Ok(());
+        }
+
+        let batch = SchemaBatch::new();
+        for (version, events) in (first_version..).zip(events) {
+            for (index, event) in events.iter().enumerate() {
+                batch.delete::<EventByTypeSchema>(&(
+                    event.type_tag().clone(),
+                    version,
+                    index as u64,
+                ))?;
+            }
+        }
+        self.db.write_schemas(batch)
+    }
+
+    /// Next version whose events are to be indexed by type, `None` if the event type index is
+    /// not enabled.
+    pub fn event_type_index_next_version(&self) -> Option<Version> {
+        self.event_type_index_next_version
+            .as_ref()
+            .map(|next_version| next_version.load(Ordering::Relaxed))
+    }
+
+    /// Returns the version and index within the transaction of the events of type `type_tag`,
+    /// emitted from `start_version` up to `end_version` (exclusive), in version order.
+    ///
+    /// Stops after `limit` events, but only at the end of a transaction, so that the next page
+    /// can start at the version following the last event returned.
+    pub fn get_events_by_type(
+        &self,
+        type_tag: &TypeTag,
+        start_version: Version,
+        end_version: Version,
+        limit: usize,
+    ) -> Result<Vec<(Version, u64)>> {
+        ensure!(
+            self.event_type_index_next_version.is_some(),
+            "Event type index not enabled."
+        );
+
+        let mut iter = self.db.iter::<EventByTypeSchema>(Default::default())?;
+        iter.seek(&(type_tag.clone(), start_version, 0))?;
+        let mut events = vec![];
+        for res in iter {
+            let ((event_type_tag, version, index), ()) = res?;
+            if &event_type_tag != type_tag || version >= end_version {
+                break;
+            }
+            if events.len() >= limit
+                && events
+                    .last()
+                    .map_or(true, |(last_version, _)| *last_version != version)
+            {
+                break;
+            }
+            events.push((version, index));
+        }
+
+        Ok(events)
+    }
 }
 
 struct TableInfoParser<'a> {
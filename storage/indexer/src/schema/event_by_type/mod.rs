@@ -0,0 +1,75 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module defines physical storage schema for an index of events by their type, via which
+//! an event (represented by a <txn_version, event_idx> tuple so that it can be fetched from the
+//! ledger db) can be found by its type, in version order.
+//!
+//! ```text
+//! |<---------------key--------------->|<-value->|
+//! | bcs(type_tag) | txn_ver | event_idx |   ()    |
+//! ```
+
+use crate::schema::EVENT_BY_TYPE_CF_NAME;
+use anyhow::{ensure, Result};
+use aptos_schemadb::{
+    define_schema,
+    schema::{KeyCodec, ValueCodec},
+};
+use aptos_types::transaction::Version;
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use move_core_types::language_storage::TypeTag;
+use std::mem::size_of;
+
+type Index = u64;
+type Key = (TypeTag, Version, Index);
+
+define_schema!(EventByTypeSchema, Key, (), EVENT_BY_TYPE_CF_NAME);
+
+impl KeyCodec<EventByTypeSchema> for Key {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        let (ref type_tag, version, index) = *self;
+
+        let mut encoded = bcs::to_bytes(type_tag)?;
+        encoded.write_u64::<BigEndian>(version)?;
+        encoded.write_u64::<BigEndian>(index)?;
+
+        Ok(encoded)
+    }
+
+    fn decode_key(data: &[u8]) -> Result<Self> {
+        const VERSION_AND_INDEX_LEN: usize = size_of::<(Version, Index)>();
+        ensure!(
+            data.len() > VERSION_AND_INDEX_LEN,
+            "Unexpected data len {}, expected to be greater than {}.",
+            data.len(),
+            VERSION_AND_INDEX_LEN,
+        );
+
+        let type_tag_len = data.len() - VERSION_AND_INDEX_LEN;
+        let type_tag = bcs::from_bytes(&data[..type_tag_len])?;
+        let mut rest = &data[type_tag_len..];
+        let version = rest.read_u64::<BigEndian>()?;
+        let index = rest.read_u64::<BigEndian>()?;
+
+        Ok((type_tag, version, index))
+    }
+}
+
+impl ValueCodec<EventByTypeSchema> for () {
+    fn encode_value(&self) -> Result<Vec<u8>> {
+        Ok(Vec::new())
+    }
+
+    fn decode_value(data: &[u8]) -> Result<Self> {
+        ensure!(
+            data.is_empty(),
+            "Unexpected data len {}, expected 0.",
+            data.len()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -24,6 +24,7 @@ use std::ops::Deref;
 pub mod account_with_state_cache;
 pub mod account_with_state_view;
 pub mod in_memory_state_view;
+pub mod overlay_state_view;
 
 /// `StateView` is a trait that defines a read-only snapshot of the global state. It is passed to
 /// the VM for transaction execution, during which the VM is guaranteed to read anything at the
@@ -0,0 +1,66 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{StateView, StateViewId, TStateView};
+use anyhow::Result;
+use aptos_types::{
+    state_store::{
+        state_key::StateKey, state_storage_usage::StateStorageUsage, state_value::StateValue,
+    },
+    write_set::{TransactionWrite, WriteSet},
+};
+use std::collections::HashMap;
+
+/// A state view as if a sequence of write sets, or individual state values, is applied on top of
+/// the base state view, without committing them anywhere. Used to run transactions against the
+/// state left behind by the ones run before them, e.g. when simulating a bundle of transactions,
+/// or against hypothetical state.
+pub struct OverlayStateView<'a, S> {
+    base: &'a S,
+    /// `None` for deleted state values.
+    overlay: HashMap<StateKey, Option<StateValue>>,
+}
+
+impl<'a, S: StateView> OverlayStateView<'a, S> {
+    pub fn new(base: &'a S) -> Self {
+        Self {
+            base,
+            overlay: HashMap::new(),
+        }
+    }
+
+    pub fn apply_write_set(&mut self, write_set: &WriteSet) {
+        for (state_key, write_op) in write_set {
+            self.set_state_value(state_key.clone(), write_op.as_state_value());
+        }
+    }
+
+    /// Replaces the state value at `state_key`, `None` meaning deleted.
+    pub fn set_state_value(&mut self, state_key: StateKey, state_value: Option<StateValue>) {
+        self.overlay.insert(state_key, state_value);
+    }
+}
+
+impl<'a, S: StateView> TStateView for OverlayStateView<'a, S> {
+    type Key = StateKey;
+
+    fn id(&self) -> StateViewId {
+        self.base.id()
+    }
+
+    fn get_state_value(&self, state_key: &StateKey) -> Result<Option<StateValue>> {
+        match self.overlay.get(state_key) {
+            Some(state_value) => Ok(state_value.clone()),
+            None => self.base.get_state_value(state_key),
+        }
+    }
+
+    fn is_genesis(&self) -> bool {
+        self.base.is_genesis()
+    }
+
+    /// The usage of the base state view, the overlay is not accounted for.
+    fn get_usage(&self) -> Result<StateStorageUsage> {
+        self.base.get_usage()
+    }
+}
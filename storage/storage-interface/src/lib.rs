@@ -35,6 +35,7 @@ use aptos_types::{
     },
     write_set::WriteSet,
 };
+use move_core_types::language_storage::TypeTag;
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc};
 use thiserror::Error;
@@ -528,6 +529,25 @@ pub trait DbReader: Send + Sync {
         unimplemented!()
     }
 
+    /// Returns the events of type `type_tag` emitted from `start_version` up to `end_version`
+    /// (exclusive), in version order, using the event type index of the internal indexer. Stops
+    /// after `limit` events, at the end of a transaction, so more can be returned.
+    fn get_events_by_type(
+        &self,
+        type_tag: &TypeTag,
+        start_version: Version,
+        end_version: Version,
+        limit: u64,
+        ledger_version: Version,
+    ) -> Result<Vec<EventWithVersion>> {
+        Err(format_err!("Event type index not supported."))
+    }
+
+    /// Returns whether the event type index of the internal indexer has been enabled or not
+    fn event_type_index_enabled(&self) -> bool {
+        false
+    }
+
     /// Returns state storage usage at the end of an epoch.
     fn get_state_storage_usage(&self, version: Option<Version>) -> Result<StateStorageUsage> {
         unimplemented!()
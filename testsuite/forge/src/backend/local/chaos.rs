@@ -0,0 +1,435 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Network chaos for the local swarm.
+//!
+//! Each validator listens on a private port, while the address it advertises on-chain is served
+//! by a [`ChaosProxy`] running in the Forge process. All validator network traffic between local
+//! nodes therefore passes through a proxy, which delays, throttles or cuts it according to the
+//! currently injected [`SwarmChaos`].
+//!
+//! The proxies work on TCP streams rather than packets, so the chaos is emulated as follows:
+//! * delay and jitter hold every chunk of a stream back by the sampled latency,
+//! * packet loss holds a "lost" chunk back by a TCP retransmission timeout, as dropping bytes
+//!   would corrupt the stream,
+//! * bandwidth limits pace the chunks written to the stream,
+//! * partitions close the connections between the partitioned nodes and refuse new ones.
+//!
+//! Correlation settings are ignored, and CPU stress is not supported.
+
+use crate::{SwarmChaos, SwarmNetworkPartition};
+use anyhow::{bail, Result};
+use aptos_infallible::RwLock;
+use aptos_logger::{debug, info, warn};
+use aptos_sdk::types::PeerId;
+use rand::Rng;
+use std::{collections::HashSet, net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    task::JoinHandle,
+    time::Instant,
+};
+
+/// The delay a lost chunk is held back by, i.e. the minimum TCP retransmission timeout
+const RETRANSMISSION_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Bandwidth rates are in megabits per second, like the `mbps` of chaos mesh
+const BYTES_PER_SEC_PER_MBPS: u64 = 125_000;
+
+/// The max number of bytes forwarded at once
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// The max number of chunks in flight in each direction of a proxied connection
+const MAX_CHUNKS_IN_FLIGHT: usize = 64;
+
+/// The chaos injected into the local swarm, shared by all the proxies
+#[derive(Debug)]
+pub struct LocalChaos {
+    /// The validators ordered by index
+    validators: Vec<PeerId>,
+    chaoses: HashSet<SwarmChaos>,
+}
+
+impl LocalChaos {
+    pub fn new(validators: Vec<PeerId>) -> Self {
+        Self {
+            validators,
+            chaoses: HashSet::new(),
+        }
+    }
+
+    pub fn inject(&mut self, chaos: SwarmChaos) -> Result<()> {
+        if let SwarmChaos::CpuStress(_) = chaos {
+            bail!("CPU stress chaos is not supported by the local swarm");
+        }
+        info!("Injecting chaos: {:?}", chaos);
+        self.chaoses.insert(chaos);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, chaos: &SwarmChaos) -> Result<()> {
+        if !self.chaoses.remove(chaos) {
+            bail!("Chaos {:?} not found", chaos);
+        }
+        info!("Removed chaos: {:?}", chaos);
+        Ok(())
+    }
+
+    pub fn remove_all(&mut self) {
+        self.chaoses.clear();
+    }
+
+    /// Returns the conditions of the traffic sent from `source` to `target`
+    pub fn link_conditions(&self, source: PeerId, target: PeerId) -> LinkConditions {
+        let mut conditions = LinkConditions::default();
+        for chaos in &self.chaoses {
+            match chaos {
+                SwarmChaos::Delay(delay) => {
+                    for group in &delay.group_network_delays {
+                        if applies(&group.source_nodes, &group.target_nodes, source, target) {
+                            conditions.latency_ms += group.latency_ms;
+                            conditions.jitter_ms += group.jitter_ms;
+                        }
+                    }
+                },
+                SwarmChaos::Partition(partition) => {
+                    let partitioned = self.partitioned_validators(partition);
+                    if partitioned.contains(&source) || partitioned.contains(&target) {
+                        conditions.blocked = true;
+                    }
+                },
+                SwarmChaos::Bandwidth(bandwidth) => {
+                    for group in &bandwidth.group_network_bandwidths {
+                        conditions.limit_rate(group.rate);
+                    }
+                },
+                SwarmChaos::Loss(loss) => {
+                    conditions.add_loss(loss.loss_percentage);
+                },
+                SwarmChaos::NetEm(netem) => {
+                    for group in &netem.group_netems {
+                        if applies(&group.source_nodes, &group.target_nodes, source, target) {
+                            conditions.latency_ms += group.delay_latency_ms;
+                            conditions.jitter_ms += group.delay_jitter_ms;
+                            conditions.add_loss(group.loss_percentage);
+                            conditions.limit_rate(group.rate_in_mbps);
+                        }
+                    }
+                },
+                SwarmChaos::CpuStress(_) => {},
+            }
+        }
+        conditions
+    }
+
+    /// The partition cuts off the given percentage of the validators, lowest indices first
+    fn partitioned_validators(&self, partition: &SwarmNetworkPartition) -> &[PeerId] {
+        let num_partitioned = self.validators.len() * partition.partition_percentage as usize / 100;
+        &self.validators[..num_partitioned.min(self.validators.len())]
+    }
+}
+
+/// Chaos between groups of nodes applies to the traffic in both directions, same as on k8s
+fn applies(
+    source_nodes: &[PeerId],
+    target_nodes: &[PeerId],
+    source: PeerId,
+    target: PeerId,
+) -> bool {
+    (source_nodes.contains(&source) && target_nodes.contains(&target))
+        || (source_nodes.contains(&target) && target_nodes.contains(&source))
+}
+
+/// How the traffic sent from one node to another is disturbed
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct LinkConditions {
+    pub blocked: bool,
+    pub latency_ms: u64,
+    pub jitter_ms: u64,
+    pub loss_percentage: u64,
+    /// Rate in megabits per second, if limited
+    pub rate: Option<u64>,
+}
+
+impl LinkConditions {
+    fn add_loss(&mut self, loss_percentage: u64) {
+        self.loss_percentage = (self.loss_percentage + loss_percentage).min(100);
+    }
+
+    fn limit_rate(&mut self, rate: u64) {
+        self.rate = Some(self.rate.map_or(rate, |current| current.min(rate)));
+    }
+
+    /// Samples how long a chunk sent now is held back for
+    fn sample_delay(&self) -> Duration {
+        let mut rng = rand::thread_rng();
+        let jitter = rng.gen_range(0, 2 * self.jitter_ms + 1);
+        let mut delay =
+            Duration::from_millis((self.latency_ms + jitter).saturating_sub(self.jitter_ms));
+        if self.loss_percentage > 0 && rng.gen_range(0, 100) < self.loss_percentage {
+            delay += RETRANSMISSION_TIMEOUT;
+        }
+        delay
+    }
+
+    /// How long sending `num_bytes` occupies the link for
+    fn transmission_time(&self, num_bytes: usize) -> Option<Duration> {
+        self.rate.map(|rate| {
+            // a zero rate would never let anything through, treat it as 1 byte per second
+            let bytes_per_sec = (rate * BYTES_PER_SEC_PER_MBPS).max(1);
+            Duration::from_secs_f64(num_bytes as f64 / bytes_per_sec as f64)
+        })
+    }
+}
+
+/// Forwards the validator network traffic sent to a local validator
+#[derive(Debug)]
+pub struct ChaosProxy {
+    peer_id: PeerId,
+    /// The address the validator advertises to the other validators
+    listen_address: SocketAddr,
+    /// The address the validator actually listens on
+    upstream_address: SocketAddr,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ChaosProxy {
+    pub fn new(peer_id: PeerId, listen_address: SocketAddr, upstream_address: SocketAddr) -> Self {
+        Self {
+            peer_id,
+            listen_address,
+            upstream_address,
+            handle: None,
+        }
+    }
+
+    /// Starts accepting connections, must be called from within a tokio runtime
+    pub fn start(&mut self, chaos: Arc<RwLock<LocalChaos>>) -> Result<()> {
+        if self.handle.is_some() {
+            bail!("Chaos proxy for {} already started", self.peer_id);
+        }
+        let listener = std::net::TcpListener::bind(self.listen_address)?;
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+        info!(
+            "Proxying validator network of {} from {} to {}",
+            self.peer_id, self.listen_address, self.upstream_address
+        );
+
+        let target = self.peer_id;
+        let upstream_address = self.upstream_address;
+        self.handle = Some(tokio::spawn(async move {
+            loop {
+                let inbound = match listener.accept().await {
+                    Ok((inbound, _)) => inbound,
+                    Err(e) => {
+                        warn!("Chaos proxy for {} failed to accept: {}", target, e);
+                        continue;
+                    },
+                };
+                let chaos = chaos.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = proxy_connection(inbound, upstream_address, target, chaos).await
+                    {
+                        debug!("Chaos proxy connection to {} closed: {}", target, e);
+                    }
+                });
+            }
+        }));
+        Ok(())
+    }
+}
+
+impl Drop for ChaosProxy {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn proxy_connection(
+    mut inbound: TcpStream,
+    upstream_address: SocketAddr,
+    target: PeerId,
+    chaos: Arc<RwLock<LocalChaos>>,
+) -> Result<()> {
+    // The noise handshake starts with the dialer's peer id in plaintext
+    let mut prologue = [0u8; PeerId::LENGTH];
+    inbound.read_exact(&mut prologue).await?;
+    let source = PeerId::try_from(&prologue[..])?;
+    if chaos.read().link_conditions(source, target).blocked {
+        bail!("{} is partitioned from {}", source, target);
+    }
+
+    let mut upstream = TcpStream::connect(upstream_address).await?;
+    upstream.write_all(&prologue).await?;
+
+    let (inbound_reader, inbound_writer) = inbound.into_split();
+    let (upstream_reader, upstream_writer) = upstream.into_split();
+    tokio::try_join!(
+        forward(
+            inbound_reader,
+            upstream_writer,
+            source,
+            target,
+            chaos.clone()
+        ),
+        forward(upstream_reader, inbound_writer, target, source, chaos),
+    )?;
+    Ok(())
+}
+
+/// Forwards the traffic sent from `source` to `target`, applying the current link conditions.
+/// Chunks are read as soon as they arrive, so the delay doesn't limit the throughput.
+async fn forward<R, W>(
+    mut reader: R,
+    mut writer: W,
+    source: PeerId,
+    target: PeerId,
+    chaos: Arc<RwLock<LocalChaos>>,
+) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let (sender, mut receiver) =
+        mpsc::channel::<(Instant, Option<Duration>, Vec<u8>)>(MAX_CHUNKS_IN_FLIGHT);
+
+    let read = async move {
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+        loop {
+            let num_bytes = reader.read(&mut buffer).await?;
+            if num_bytes == 0 {
+                return Ok::<_, anyhow::Error>(());
+            }
+            let conditions = chaos.read().link_conditions(source, target);
+            if conditions.blocked {
+                bail!("{} is partitioned from {}", source, target);
+            }
+            let deliver_at = Instant::now() + conditions.sample_delay();
+            let transmission_time = conditions.transmission_time(num_bytes);
+            if sender
+                .send((deliver_at, transmission_time, buffer[..num_bytes].to_vec()))
+                .await
+                .is_err()
+            {
+                return Ok(());
+            }
+        }
+    };
+
+    let write = async move {
+        // Chunks are never reordered, a chunk is released after the ones before it
+        let mut release_at = Instant::now();
+        while let Some((deliver_at, transmission_time, chunk)) = receiver.recv().await {
+            release_at = release_at.max(deliver_at);
+            tokio::time::sleep_until(release_at).await;
+            writer.write_all(&chunk).await?;
+            if let Some(transmission_time) = transmission_time {
+                release_at = Instant::now() + transmission_time;
+            }
+        }
+        writer.shutdown().await?;
+        Ok::<_, anyhow::Error>(())
+    };
+
+    tokio::try_join!(read, write)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GroupNetworkDelay, SwarmNetworkDelay, SwarmNetworkLoss};
+
+    fn new_chaos() -> (LocalChaos, Vec<PeerId>) {
+        let validators: Vec<_> = (0..4).map(|_| PeerId::random()).collect();
+        (LocalChaos::new(validators.clone()), validators)
+    }
+
+    #[test]
+    fn test_link_conditions_delay() {
+        let (mut chaos, validators) = new_chaos();
+        chaos
+            .inject(SwarmChaos::Delay(SwarmNetworkDelay {
+                group_network_delays: vec![GroupNetworkDelay {
+                    name: "delay".to_string(),
+                    source_nodes: vec![validators[0]],
+                    target_nodes: vec![validators[1], validators[2]],
+                    latency_ms: 100,
+                    jitter_ms: 10,
+                    correlation_percentage: 0,
+                }],
+            }))
+            .unwrap();
+
+        let conditions = chaos.link_conditions(validators[0], validators[1]);
+        assert_eq!(conditions.latency_ms, 100);
+        assert_eq!(conditions.jitter_ms, 10);
+        assert_eq!(
+            chaos.link_conditions(validators[2], validators[0]),
+            conditions
+        );
+        assert_eq!(
+            chaos.link_conditions(validators[1], validators[2]),
+            LinkConditions::default()
+        );
+        for _ in 0..100 {
+            let delay = conditions.sample_delay();
+            assert!(delay >= Duration::from_millis(90) && delay <= Duration::from_millis(110));
+        }
+    }
+
+    #[test]
+    fn test_link_conditions_transmission_time() {
+        assert_eq!(LinkConditions::default().transmission_time(1_000_000), None);
+
+        // 8 megabits per second is 1 MB per second
+        let conditions = LinkConditions {
+            rate: Some(8),
+            ..LinkConditions::default()
+        };
+        assert_eq!(
+            conditions.transmission_time(1_000_000),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            conditions.transmission_time(125_000),
+            Some(Duration::from_millis(125))
+        );
+    }
+
+    #[test]
+    fn test_link_conditions_partition_and_loss() {
+        let (mut chaos, validators) = new_chaos();
+        let partition = SwarmChaos::Partition(SwarmNetworkPartition {
+            partition_percentage: 25,
+        });
+        chaos.inject(partition.clone()).unwrap();
+        chaos
+            .inject(SwarmChaos::Loss(SwarmNetworkLoss {
+                loss_percentage: 10,
+                correlation_percentage: 0,
+            }))
+            .unwrap();
+
+        assert!(chaos.link_conditions(validators[0], validators[1]).blocked);
+        assert!(chaos.link_conditions(validators[3], validators[0]).blocked);
+        let conditions = chaos.link_conditions(validators[1], validators[2]);
+        assert!(!conditions.blocked);
+        assert_eq!(conditions.loss_percentage, 10);
+
+        chaos.remove(&partition).unwrap();
+        assert!(!chaos.link_conditions(validators[0], validators[1]).blocked);
+        assert!(chaos.remove(&partition).is_err());
+
+        chaos.remove_all();
+        assert_eq!(
+            chaos.link_conditions(validators[0], validators[1]),
+            LinkConditions::default()
+        );
+    }
+}
@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    interface::system_metrics::SystemMetricsThreshold, ChainInfo, FullNode, HealthCheckError,
-    LocalNode, LocalVersion, Node, Swarm, SwarmChaos, SwarmExt, Validator, Version,
+    backend::local::chaos::{ChaosProxy, LocalChaos},
+    interface::system_metrics::SystemMetricsThreshold,
+    ChainInfo, FullNode, HealthCheckError, LocalNode, LocalVersion, Node, Swarm, SwarmChaos,
+    SwarmExt, Validator, Version,
 };
 use anyhow::{anyhow, bail, Result};
 use aptos::common::types::EncodingType;
@@ -12,10 +14,11 @@ use aptos_config::{
     config::{NetworkConfig, NodeConfig},
     keys::ConfigKey,
     network_id::NetworkId,
+    utils::get_available_port,
 };
 use aptos_framework::ReleaseBundle;
 use aptos_genesis::builder::{FullnodeNodeConfig, InitConfigFn, InitGenesisConfigFn};
-use aptos_infallible::Mutex;
+use aptos_infallible::{Mutex, RwLock};
 use aptos_logger::{info, warn};
 use aptos_sdk::{
     crypto::ed25519::Ed25519PrivateKey,
@@ -31,6 +34,7 @@ use std::{
     fs::File,
     io::Write,
     mem,
+    net::{Ipv4Addr, SocketAddr},
     num::NonZeroUsize,
     ops,
     path::{Path, PathBuf},
@@ -98,6 +102,8 @@ pub struct LocalSwarm {
     root_account: LocalAccount,
     chain_id: ChainId,
     root_key: ConfigKey<Ed25519PrivateKey>,
+    chaos: Arc<RwLock<LocalChaos>>,
+    chaos_proxies: Vec<ChaosProxy>,
 
     launched: bool,
     #[allow(dead_code)]
@@ -218,6 +224,48 @@ impl LocalSwarm {
             })
             .collect::<Result<HashMap<_, _>>>()?;
 
+        // Move the validator network listeners to fresh ports and serve the addresses the
+        // validators advertise on-chain with proxies instead, so chaos can be injected between them
+        let chaos_proxies = validators
+            .values_mut()
+            .map(|validator| {
+                let mut validator_config = validator.config().clone();
+                let validator_network = validator_config
+                    .validator_network
+                    .as_mut()
+                    .expect("Validator should have a validator network");
+                let advertised_address = &validator_network.listen_address;
+                let listen_address = SocketAddr::new(
+                    advertised_address.find_ip_addr().ok_or_else(|| {
+                        anyhow!("No IP in validator address: {}", advertised_address)
+                    })?,
+                    advertised_address.find_port().ok_or_else(|| {
+                        anyhow!("No port in validator address: {}", advertised_address)
+                    })?,
+                );
+                let upstream_address =
+                    SocketAddr::new(Ipv4Addr::LOCALHOST.into(), get_available_port());
+                validator_network.listen_address = format!(
+                    "/ip4/{}/tcp/{}",
+                    Ipv4Addr::UNSPECIFIED,
+                    upstream_address.port()
+                )
+                .parse()?;
+
+                validator_config.save_to_path(validator.config_path())?;
+                *validator.config_mut() = validator_config;
+
+                Ok(ChaosProxy::new(
+                    validator.peer_id(),
+                    listen_address,
+                    upstream_address,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut ordered_validators: Vec<&LocalNode> = validators.values().collect();
+        ordered_validators.sort_by_key(|v| v.index());
+        let chaos = LocalChaos::new(ordered_validators.iter().map(|v| v.peer_id()).collect());
+
         // We print out the root key to make it easy for users to deploy a local faucet
         let encoded_root_key = EncodingType::Hex.encode_key("root_key", &root_key)?;
         info!(
@@ -255,6 +303,8 @@ impl LocalSwarm {
             root_account,
             chain_id: ChainId::test(),
             root_key,
+            chaos: Arc::new(RwLock::new(chaos)),
+            chaos_proxies,
             launched: false,
             guard,
         })
@@ -266,6 +316,11 @@ impl LocalSwarm {
         }
         self.launched = true;
 
+        // Start the proxies before the validators try to connect to each other
+        for proxy in &mut self.chaos_proxies {
+            proxy.start(self.chaos.clone())?;
+        }
+
         // Start all the validators
         for validator in self.validators.values_mut() {
             validator.start()?;
@@ -599,16 +654,17 @@ impl Swarm for LocalSwarm {
         self.dir.display().to_string()
     }
 
-    fn inject_chaos(&mut self, _chaos: SwarmChaos) -> Result<()> {
-        todo!()
+    fn inject_chaos(&mut self, chaos: SwarmChaos) -> Result<()> {
+        self.chaos.write().inject(chaos)
     }
 
-    fn remove_chaos(&mut self, _chaos: SwarmChaos) -> Result<()> {
-        todo!()
+    fn remove_chaos(&mut self, chaos: SwarmChaos) -> Result<()> {
+        self.chaos.write().remove(&chaos)
     }
 
     fn remove_all_chaos(&mut self) -> Result<()> {
-        todo!()
+        self.chaos.write().remove_all();
+        Ok(())
     }
 
     async fn ensure_no_validator_restart(&self) -> Result<()> {
@@ -60,7 +60,7 @@ impl Display for SwarmNetworkBandwidth {
 #[derive(Eq, Hash, PartialEq, Debug, Clone)]
 pub struct GroupNetworkBandwidth {
     pub name: String,
-    /// Rate in megabytes per second
+    /// Rate in megabits per second
     pub rate: u64,
     pub limit: u64,
     pub buffer: u64,
@@ -2,9 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::smoke_test_environment::SwarmBuilder;
-use aptos::{account::create::DEFAULT_FUNDED_COINS, common::types::GasOptions};
+use aptos::{
+    account::create::DEFAULT_FUNDED_COINS,
+    common::types::{GasOptions, TransactionOutcome},
+};
 use aptos_crypto::{PrivateKey, ValidCryptoMaterialStringExt};
 use aptos_keygen::KeyGen;
+use aptos_temppath::TempPath;
 
 #[tokio::test]
 async fn test_account_flow() {
@@ -91,6 +95,42 @@ async fn test_account_flow() {
     assert!(cli.account_balance_now(2).await.unwrap() <= new_expected_balance);
 }
 
+#[tokio::test]
+async fn test_offline_sign_and_submit() {
+    let (_swarm, cli, _faucet) = SwarmBuilder::new_local(1)
+        .with_aptos()
+        .build_with_cli(2)
+        .await;
+    let dir = TempPath::new();
+    dir.create_as_dir().unwrap();
+    let unsigned_file = dir.path().join("transfer.unsigned");
+    let signed_file = dir.path().join("transfer.signed");
+
+    match cli
+        .transfer_coins_unsigned(0, 1, 100, 10_000, unsigned_file.clone())
+        .await
+    {
+        Ok(TransactionOutcome::SavedUnsigned(summary)) => {
+            assert_eq!(summary.sender, cli.account_id(0));
+            assert_eq!(summary.max_gas_amount, 10_000);
+        },
+        other => panic!("Expected the transaction to be saved, got {:?}", other),
+    }
+    // Nothing was submitted yet
+    cli.assert_account_balance_now(1, DEFAULT_FUNDED_COINS)
+        .await;
+
+    let signed = cli
+        .sign_transaction(0, unsigned_file, signed_file.clone())
+        .await
+        .unwrap();
+    let summary = cli.submit_transaction(signed_file).await.unwrap();
+    assert_eq!(summary.transaction_hash, signed.transaction_hash);
+    assert_eq!(summary.success, Some(true));
+    cli.assert_account_balance_now(1, DEFAULT_FUNDED_COINS + 100)
+        .await;
+}
+
 #[tokio::test]
 async fn test_account_key_rotation() {
     let (_swarm, mut cli, _faucet) = SwarmBuilder::new_local(1)
@@ -2,7 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::smoke_test_environment::SwarmBuilder;
-use aptos::{move_tool::MemberId, test::CliTestFramework};
+use aptos::{
+    move_tool::{check_upgrade::ModuleUpgradeStatus, MemberId},
+    test::CliTestFramework,
+};
 use aptos_framework::{BuildOptions, BuiltPackage};
 use aptos_logger::info;
 use move_core_types::account_address::AccountAddress;
@@ -132,6 +135,37 @@ async fn test_move_publish_flow() {
         .await
         .is_ok());
 
+    // Publishing the same package again is a compatible upgrade
+    let mut named_addresses = BTreeMap::new();
+    named_addresses.insert(HELLO_BLOCKCHAIN, account.as_str());
+    let report = cli
+        .check_upgrade(0, named_addresses.clone())
+        .await
+        .expect("Should check upgrade");
+    assert!(report.upgradable);
+    assert!(report
+        .modules
+        .iter()
+        .all(|module| module.breaking_changes.is_empty()));
+
+    // Renaming a field of a struct breaks its layout
+    let source_path = cli.move_dir().join("sources").join("hello_blockchain.move");
+    let source = std::fs::read_to_string(&source_path).unwrap();
+    std::fs::write(&source_path, source.replace("from_message", "old_message")).unwrap();
+    let report = cli
+        .check_upgrade(0, named_addresses)
+        .await
+        .expect("Should check upgrade");
+    assert!(!report.upgradable);
+    let module = report
+        .modules
+        .iter()
+        .find(|module| module.name == "message")
+        .unwrap();
+    assert_eq!(module.status, ModuleUpgradeStatus::Incompatible);
+    assert!(!module.breaking_changes.is_empty());
+    std::fs::write(&source_path, source).unwrap();
+
     // Now download the package. It will be stored in a directory PACKAGE_NAME inside move_dir.
     let _ = match cli
         .download_package(0, PACKAGE_NAME.to_owned(), cli.move_dir())
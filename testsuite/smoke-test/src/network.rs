@@ -5,7 +5,7 @@
 use crate::{
     smoke_test_environment::{new_local_swarm_with_aptos, SwarmBuilder},
     state_sync::test_all_validator_failures,
-    test_utils::{MAX_CONNECTIVITY_WAIT_SECS, MAX_HEALTHY_WAIT_SECS},
+    test_utils::{MAX_CATCH_UP_WAIT_SECS, MAX_CONNECTIVITY_WAIT_SECS, MAX_HEALTHY_WAIT_SECS},
 };
 use aptos::{common::types::EncodingType, test::CliTestFramework};
 use aptos_config::{
@@ -16,7 +16,10 @@ use aptos_config::{
     network_id::NetworkId,
 };
 use aptos_crypto::{x25519, x25519::PrivateKey};
-use aptos_forge::{FullNode, Node, NodeExt, Swarm};
+use aptos_forge::{
+    FullNode, GroupNetworkDelay, Node, NodeExt, Swarm, SwarmChaos, SwarmExt, SwarmNetworkDelay,
+    SwarmNetworkPartition,
+};
 use aptos_genesis::config::HostAndPort;
 use aptos_sdk::move_types::account_address::AccountAddress;
 use aptos_temppath::TempPath;
@@ -249,6 +252,73 @@ async fn test_network_performance_monitoring() {
     test_all_validator_failures(swarm).await;
 }
 
+#[tokio::test]
+async fn test_local_swarm_network_chaos() {
+    let mut swarm = new_local_swarm_with_aptos(4).await;
+    let isolated_client = swarm.validators().next().unwrap().rest_client();
+    let connected_client = swarm.validators().nth(1).unwrap().rest_client();
+
+    // Partition off the first validator, the remaining validators still form a quorum
+    swarm
+        .inject_chaos(SwarmChaos::Partition(SwarmNetworkPartition {
+            partition_percentage: 25,
+        }))
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    let isolated_version = isolated_client
+        .get_ledger_information()
+        .await
+        .unwrap()
+        .into_inner()
+        .version;
+    let connected_version = connected_client
+        .get_ledger_information()
+        .await
+        .unwrap()
+        .into_inner()
+        .version;
+
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(
+        isolated_client
+            .get_ledger_information()
+            .await
+            .unwrap()
+            .into_inner()
+            .version,
+        isolated_version
+    );
+    assert!(
+        connected_client
+            .get_ledger_information()
+            .await
+            .unwrap()
+            .into_inner()
+            .version
+            > connected_version
+    );
+
+    // Once the partition heals, the isolated validator catches up while the network is slow
+    swarm.remove_all_chaos().unwrap();
+    let validators: Vec<_> = swarm.validators().map(|v| v.peer_id()).collect();
+    swarm
+        .inject_chaos(SwarmChaos::Delay(SwarmNetworkDelay {
+            group_network_delays: vec![GroupNetworkDelay {
+                name: "all-to-all".to_string(),
+                source_nodes: validators.clone(),
+                target_nodes: validators,
+                latency_ms: 50,
+                jitter_ms: 10,
+                correlation_percentage: 0,
+            }],
+        }))
+        .unwrap();
+    swarm
+        .wait_for_all_nodes_to_catchup_to_next(Duration::from_secs(MAX_CATCH_UP_WAIT_SECS))
+        .await
+        .unwrap();
+}
+
 /// Creates a discovery file with the given `PeerSet`
 fn create_discovery_file(peer_set: PeerSet) -> TempPath {
     let discovery_file = TempPath::new();